@@ -1,15 +1,38 @@
 use std::fmt;
 use std::path::PathBuf;
+use std::collections::HashMap;
 use serde::{Serialize, Deserialize};
-use rusqlite::{Connection, Result as SqlResult, params};
+use rusqlite::{Connection, OptionalExtension, Result as SqlResult, params};
 use thiserror::Error;
 use crate::config::config::Config;
-use crate::lib::db::DBItem;
+use crate::config::server_profile::ServerProfile;
+use crate::config::item_tables::ItemTablePaths;
+use crate::parser::error::{ParseError, ensure_len};
+use crate::util::Util;
+use crate::parser::character::tech_level_cap;
+use crate::lib::portrait::render_portrait;
+use crate::lib::mag::{MagState, FeedStock, FeedingPlan, PredictedEvolution, next_evolution, plan_feeding};
+use crate::config::mag_feed::FEED_ITEMS;
+use crate::lib::db::{DBItem, insert_weapon_reference, weapon_reference};
+use crate::lib::atp::{WeaponAtp, weapon_atp};
+use crate::lib::equip::{EquipCharacter, ItemEligibility, equip_compatibility};
+use crate::lib::build::{Loadout, BuildStats, evaluate_build};
+use crate::lib::migrations::{has_schema, migrate};
 use crate::lib::db::{
     translate_items,
     insert_item,
+    insert_appearance,
+    get_appearance,
+    insert_story_progress,
+    insert_quest_log,
+    insert_challenge_records,
+    insert_learned_techs,
+    insert_equipped_items,
+    get_battle_records,
+    get_challenge_title,
     get_items,
-    get_character_data
+    get_character_data,
+    translate_item
 };
 use crate::parser::types::{
     ParsedFiles,
@@ -19,6 +42,17 @@ use crate::parser::types::{
     WrappedItem,
     SharedBank,
     Character,
+    CharacterStats,
+    ExperienceProgress,
+    MaterialUsage,
+    MaterialHeadroom,
+    StoryProgress,
+    StageRecord,
+    CharacterAppearance,
+    MagStats,
+    BattleRecords,
+    ChallengeTitle,
+    Attribute,
 };
 
 #[derive(Error, Debug, Serialize, Deserialize)]
@@ -26,6 +60,8 @@ pub enum SqlError {
     DatabaseError(String),
     IOError(String),
     SerdeError(String),
+    ParseError(String),
+    ImageError(String),
 }
 
 impl fmt::Display for SqlError {
@@ -34,6 +70,8 @@ impl fmt::Display for SqlError {
             SqlError::DatabaseError(msg) => write!(f, "Database error: {}", msg),
             SqlError::IOError(msg) => write!(f, "IO error: {}", msg),
             SqlError::SerdeError(msg) => write!(f, "Serialization error: {}", msg),
+            SqlError::ParseError(msg) => write!(f, "Parse error: {}", msg),
+            SqlError::ImageError(msg) => write!(f, "Image error: {}", msg),
         }
     }
 }
@@ -56,12 +94,31 @@ impl From<base64::DecodeError> for SqlError {
     }
 }
 
+impl From<png::DecodingError> for SqlError {
+    fn from(error: png::DecodingError) -> Self {
+        SqlError::ImageError(error.to_string())
+    }
+}
+
+impl From<png::EncodingError> for SqlError {
+    fn from(error: png::EncodingError) -> Self {
+        SqlError::ImageError(error.to_string())
+    }
+}
+
+impl From<ParseError> for SqlError {
+    fn from(error: ParseError) -> Self {
+        SqlError::ParseError(error.to_string())
+    }
+}
+
 static DB_CONN: &'static str = "PSOBB_DB.sqlite";
 
 #[tauri::command]
 pub fn init_app() -> Result<(), SqlError> {
     let mut conn = Connection::open(DB_CONN)?;
     let transaction = conn.transaction()?;
+    let existing = has_schema(&transaction)?;
 
     transaction.execute(
         "CREATE TABLE IF NOT EXISTS user (
@@ -110,9 +167,121 @@ pub fn init_app() -> Result<(), SqlError> {
             section_id TEXT NOT NULL,
             level INTEGER NOT NULL,
             experience INTEGER NOT NULL,
-            ep1_progress TEXT NOT NULL,
-            ep2_progress TEXT NOT NULL,
-            image BLOB DEFAULT NULL
+            ep1_progress TEXT DEFAULT NULL,
+            ep2_progress TEXT DEFAULT NULL,
+            image BLOB DEFAULT NULL,
+            atp INTEGER NOT NULL DEFAULT 0,
+            mst INTEGER NOT NULL DEFAULT 0,
+            evp INTEGER NOT NULL DEFAULT 0,
+            hp INTEGER NOT NULL DEFAULT 0,
+            dfp INTEGER NOT NULL DEFAULT 0,
+            ata INTEGER NOT NULL DEFAULT 0,
+            lck INTEGER NOT NULL DEFAULT 0,
+            power_materials INTEGER NOT NULL DEFAULT 0,
+            mind_materials INTEGER NOT NULL DEFAULT 0,
+            evade_materials INTEGER NOT NULL DEFAULT 0,
+            def_materials INTEGER NOT NULL DEFAULT 0,
+            luck_materials INTEGER NOT NULL DEFAULT 0,
+            hp_materials INTEGER NOT NULL DEFAULT 0,
+            tp_materials INTEGER NOT NULL DEFAULT 0
+        )",
+        [],
+    )?;
+
+    transaction.execute(
+        "CREATE TABLE IF NOT EXISTS character_appearance (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            character_id INTEGER NOT NULL,
+            name_color INTEGER NOT NULL,
+            costume INTEGER NOT NULL,
+            skin INTEGER NOT NULL,
+            face INTEGER NOT NULL,
+            head INTEGER NOT NULL,
+            hair INTEGER NOT NULL,
+            hair_r INTEGER NOT NULL,
+            hair_g INTEGER NOT NULL,
+            hair_b INTEGER NOT NULL,
+            proportion_x REAL NOT NULL,
+            proportion_y REAL NOT NULL
+        )",
+        [],
+    )?;
+
+    transaction.execute(
+        "CREATE TABLE IF NOT EXISTS story_progress (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            character_id INTEGER NOT NULL,
+            episode INTEGER NOT NULL,
+            stages INTEGER NOT NULL,
+            normal INTEGER NOT NULL,
+            hard INTEGER NOT NULL,
+            very_hard INTEGER NOT NULL,
+            ultimate INTEGER NOT NULL
+        )",
+        [],
+    )?;
+
+    transaction.execute(
+        "CREATE TABLE IF NOT EXISTS quest_log (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            character_id INTEGER NOT NULL,
+            episode INTEGER NOT NULL,
+            flag INTEGER NOT NULL,
+            difficulty INTEGER NOT NULL,
+            completed INTEGER CHECK(completed IN (0, 1))
+        )",
+        [],
+    )?;
+
+    transaction.execute(
+        "CREATE TABLE IF NOT EXISTS learned_tech (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            character_id INTEGER NOT NULL,
+            tech INTEGER NOT NULL,
+            level INTEGER NOT NULL
+        )",
+        [],
+    )?;
+
+    transaction.execute(
+        "CREATE TABLE IF NOT EXISTS challenge_record (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            character_id INTEGER NOT NULL,
+            episode INTEGER NOT NULL,
+            stage INTEGER NOT NULL,
+            time INTEGER
+        )",
+        [],
+    )?;
+
+    transaction.execute(
+        "CREATE TABLE IF NOT EXISTS challenge_title (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            character_id INTEGER NOT NULL,
+            rank TEXT,
+            title_color INTEGER NOT NULL
+        )",
+        [],
+    )?;
+
+    transaction.execute(
+        "CREATE TABLE IF NOT EXISTS battle_record (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            character_id INTEGER NOT NULL,
+            first INTEGER NOT NULL,
+            second INTEGER NOT NULL,
+            third INTEGER NOT NULL,
+            fourth INTEGER NOT NULL,
+            disconnects INTEGER NOT NULL
+        )",
+        [],
+    )?;
+
+    transaction.execute(
+        "CREATE TABLE IF NOT EXISTS equipped_item (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            character_id INTEGER NOT NULL,
+            item_data TEXT NOT NULL
         )",
         [],
     )?;
@@ -137,7 +306,10 @@ pub fn init_app() -> Result<(), SqlError> {
             rare INTEGER CHECK(rare IN (0, 1)),
             item_data TEXT NOT NULL,
             account_type TEXT NOT NULL,
-            lang TEXT NOT NULL
+            lang TEXT NOT NULL,
+            kills INTEGER DEFAULT NULL,
+            kills_remaining INTEGER DEFAULT NULL,
+            wrapped INTEGER NOT NULL DEFAULT 0 CHECK(wrapped IN (0, 1))
         )",
         [],
     )?;
@@ -155,7 +327,8 @@ pub fn init_app() -> Result<(), SqlError> {
             special_code TEXT NOT NULL,
             item_data TEXT NOT NULL,
             account_type TEXT NOT NULL,
-            lang TEXT NOT NULL
+            lang TEXT NOT NULL,
+            wrapped INTEGER NOT NULL DEFAULT 0 CHECK(wrapped IN (0, 1))
         )",
         [],
     )?;
@@ -175,7 +348,8 @@ pub fn init_app() -> Result<(), SqlError> {
             max_evp INTEGER NOT NULL,
             item_data TEXT NOT NULL,
             account_type TEXT NOT NULL,
-            lang TEXT NOT NULL
+            lang TEXT NOT NULL,
+            wrapped INTEGER NOT NULL DEFAULT 0 CHECK(wrapped IN (0, 1))
         )",
         [],
     )?;
@@ -194,7 +368,8 @@ pub fn init_app() -> Result<(), SqlError> {
             max_evp INTEGER NOT NULL,
             item_data TEXT NOT NULL,
             account_type TEXT NOT NULL,
-            lang TEXT NOT NULL
+            lang TEXT NOT NULL,
+            wrapped INTEGER NOT NULL DEFAULT 0 CHECK(wrapped IN (0, 1))
         )",
         [],
     )?;
@@ -209,7 +384,11 @@ pub fn init_app() -> Result<(), SqlError> {
             name TEXT NOT NULL,
             item_data TEXT NOT NULL,
             account_type TEXT NOT NULL,
-            lang TEXT NOT NULL
+            lang TEXT NOT NULL,
+            modifier INTEGER NOT NULL DEFAULT 0,
+            kills INTEGER DEFAULT NULL,
+            kills_remaining INTEGER DEFAULT NULL,
+            wrapped INTEGER NOT NULL DEFAULT 0 CHECK(wrapped IN (0, 1))
         )",
         [],
     )?;
@@ -234,7 +413,10 @@ pub fn init_app() -> Result<(), SqlError> {
             pbs TEXT NOT NULL,
             item_data TEXT NOT NULL,
             account_type TEXT NOT NULL,
-            lang TEXT NOT NULL
+            lang TEXT NOT NULL,
+            evolution_stage INTEGER NOT NULL DEFAULT 0,
+            affinity TEXT DEFAULT NULL,
+            wrapped INTEGER NOT NULL DEFAULT 0 CHECK(wrapped IN (0, 1))
         )",
         [],
     )?;
@@ -250,7 +432,8 @@ pub fn init_app() -> Result<(), SqlError> {
             level INTEGER NOT NULL,
             item_data TEXT NOT NULL,
             account_type TEXT NOT NULL,
-            lang TEXT NOT NULL
+            lang TEXT NOT NULL,
+            wrapped INTEGER NOT NULL DEFAULT 0 CHECK(wrapped IN (0, 1))
         )",
         [],
     )?;
@@ -266,7 +449,8 @@ pub fn init_app() -> Result<(), SqlError> {
             number INTEGER NOT NULL,
             item_data TEXT NOT NULL,
             account_type TEXT NOT NULL,
-            lang TEXT NOT NULL
+            lang TEXT NOT NULL,
+            wrapped INTEGER NOT NULL DEFAULT 0 CHECK(wrapped IN (0, 1))
         )",
         [],
     )?;
@@ -297,7 +481,8 @@ pub fn init_app() -> Result<(), SqlError> {
             number INTEGER NOT NULL,
             item_data TEXT NOT NULL,
             account_type TEXT NOT NULL,
-            lang TEXT NOT NULL
+            lang TEXT NOT NULL,
+            wrapped INTEGER NOT NULL DEFAULT 0 CHECK(wrapped IN (0, 1))
         )",
         [],
     )?;
@@ -330,6 +515,14 @@ pub fn init_app() -> Result<(), SqlError> {
         []
     )?;
 
+    transaction.execute(
+        "CREATE TABLE IF NOT EXISTS reference_version (
+            name TEXT PRIMARY KEY,
+            hash TEXT NOT NULL
+        )",
+        []
+    )?;
+
     transaction.execute(
         "CREATE TABLE IF NOT EXISTS dashboard_state (
             id INTEGER PRIMARY KEY CHECK (id = 1),
@@ -356,6 +549,9 @@ pub fn init_app() -> Result<(), SqlError> {
         )?;
     }
 
+    migrate(&transaction, existing)?;
+    insert_weapon_reference(&transaction)?;
+
     transaction.commit()?;
 
     Ok(())
@@ -446,8 +642,11 @@ pub struct AccountPayload {
 }
 
 #[tauri::command]
-pub fn create_account(account: AccountPayload, files: Vec<ParsedFile>) -> Result<(), SqlError> {
+pub fn create_account(app_handle: tauri::AppHandle, account: AccountPayload, files: Vec<ParsedFile>) -> Result<(), SqlError> {
     let mut conn = Connection::open(DB_CONN)?;
+    let public_dir = app_handle.path_resolver().resolve_resource("../public");
+    let tables = ItemTablePaths::from_app(&app_handle);
+    let config = Config::init(account.lang.clone(), ServerProfile::from_server(&account.server), &tables)?;
     let transaction = conn.transaction()?;
 
     transaction.execute(
@@ -473,46 +672,65 @@ pub fn create_account(account: AccountPayload, files: Vec<ParsedFile>) -> Result
         match file.data {
             Data::SharedBank(shared_bank) => {
                 for item in shared_bank.bank {
-                    insert_item(&transaction, &item, account_id, 0, String::from("SHARED_BANK"), &shared_bank.account_type, &account.lang);
+                    insert_item(&transaction, &item, account_id, 0, String::from("SHARED_BANK"), &shared_bank.account_type, &config)?;
                 }
             },
             Data::Character(character) => {
                 let Character { 
                     slot, mode, guild_card_number, name,
-                    lang, class, section_id, level, experience,
-                    ep1_progress, ep2_progress, bank, inventory
+                    lang, class, section_id, level, experience, appearance, stats, materials,
+                    story_progress, quest_log, techniques, challenge, bank, inventory, equipped
                 } = character;
+
+                // A missing class icon shouldn't stop the import, the frontend falls back to it anyway.
+                let image = public_dir
+                    .as_ref()
+                    .and_then(|dir| render_portrait(dir, &class, &section_id, &appearance).ok());
                 
                 transaction.execute(
                     "INSERT INTO character 
                     (
                         account_id, slot, mode, guild_card, name,
-                        class, section_id, level, experience,
-                        ep1_progress, ep2_progress, image
+                        class, section_id, level, experience, image,
+                        atp, mst, evp, hp, dfp, ata, lck,
+                        power_materials, mind_materials, evade_materials, def_materials,
+                        luck_materials, hp_materials, tp_materials
+                    )
+                    VALUES (
+                        ?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14,
+                        ?15, ?16, ?17, ?18, ?19, ?20, ?21, ?22, ?23, ?24
                     )
-                    VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, NULL)
                     ",
                     params![
                         account_id, slot, mode, guild_card_number, name,
-                        class, section_id, level, experience,
-                        ep1_progress, ep2_progress
+                        class, section_id, level, experience, image,
+                        stats.atp, stats.mst, stats.evp, stats.hp,
+                        stats.dfp, stats.ata, stats.lck,
+                        materials.power, materials.mind, materials.evade, materials.def,
+                        materials.luck, materials.hp, materials.tp
                     ]
                 )?;
 
                 let character_id = transaction.last_insert_rowid();
 
+                insert_appearance(&transaction, character_id, &appearance)?;
+                insert_story_progress(&transaction, character_id, &story_progress)?;
+                insert_quest_log(&transaction, character_id, &quest_log)?;
+                insert_learned_techs(&transaction, character_id, &techniques)?;
+                insert_equipped_items(&transaction, character_id, &inventory, &equipped)?;
+
+                if let Some(challenge) = challenge {
+                    insert_challenge_records(&transaction, character_id, &challenge)?;
+                }
+
                 for item in bank {
-                    insert_item(&transaction, &item, account_id, character_id, String::from("BANK"), &mode, &account.lang);
+                    insert_item(&transaction, &item, account_id, character_id, String::from("BANK"), &mode, &config)?;
                 }
 
                 for item in inventory {
-                    insert_item(&transaction, &item, account_id, character_id, String::from("INVENTORY"), &mode, &account.lang);
+                    insert_item(&transaction, &item, account_id, character_id, String::from("INVENTORY"), &mode, &config)?;
                 }
             }
-            _ => {
-                // Send back custom error message here
-                println!("No SharedBank or Character data in this file");
-            }
         }
     }
 
@@ -539,18 +757,28 @@ pub struct CharacterData {
     pub section_id: String,
     pub level: u8,
     pub experience: u64,
-    pub ep1_progress: String,
-    pub ep2_progress: String,
+    pub appearance: CharacterAppearance,
+    pub story_progress: StoryProgress,
+    pub stats: CharacterStats,
+    pub experience_progress: ExperienceProgress,
+    pub materials: MaterialUsage,
+    pub material_headroom: MaterialHeadroom,
     pub image: Option<Vec<u8>>,
     pub inventory: Vec<DBItem>,
     pub bank: Vec<DBItem>,
 }
 
 #[tauri::command]
-pub fn translate_account_data(account_id: i64, account_data: AccountData, lang: String) -> Result<(), SqlError> {
+pub fn translate_account_data(app_handle: tauri::AppHandle, account_id: i64, account_data: AccountData, lang: String) -> Result<(), SqlError> {
     let mut conn = Connection::open(DB_CONN)?;
     let transaction = conn.transaction()?;
-    let config = Config::init(lang.clone());
+    let server: String = transaction.query_row(
+        "SELECT server FROM account WHERE id = ?1",
+        params![account_id],
+        |row| row.get(0),
+    )?;
+    let tables = ItemTablePaths::from_app(&app_handle);
+    let config = Config::init(lang.clone(), ServerProfile::from_server(&server), &tables)?;
 
     let account_language_count: u8 = transaction.query_row(
         "SELECT COUNT(*) FROM account_languages WHERE account_id = ?1 AND lang = ?2",
@@ -566,11 +794,11 @@ pub fn translate_account_data(account_id: i64, account_data: AccountData, lang:
              params![account_id, lang]
         )?;
 
-        translate_items(&transaction, account_id, 0, &account_data.shared_bank, String::from("SHARED_BANK"), config.clone()).unwrap();
+        translate_items(&transaction, account_id, 0, &account_data.shared_bank, String::from("SHARED_BANK"), config.clone())?;
 
         for character in account_data.characters {
-            translate_items(&transaction, account_id, character.id, &character.bank, String::from("BANK"), config.clone()).unwrap();
-            translate_items(&transaction, account_id, character.id, &character.inventory, String::from("INVENTORY"), config.clone()).unwrap();
+            translate_items(&transaction, account_id, character.id, &character.bank, String::from("BANK"), config.clone())?;
+            translate_items(&transaction, account_id, character.id, &character.inventory, String::from("INVENTORY"), config.clone())?;
         }
 
         transaction.commit()?;
@@ -596,6 +824,564 @@ pub fn get_account_data(account_id: i64, lang: String) -> Result<AccountData, Sq
     })
 }
 
+/// Renders the character's portrait again from its class default and appearance, stores it
+/// as the character image and returns the PNG bytes.
+#[tauri::command]
+pub fn generate_portrait(app_handle: tauri::AppHandle, character_id: i64) -> Result<Vec<u8>, SqlError> {
+    let conn = Connection::open(DB_CONN)?;
+    let public_dir = app_handle
+        .path_resolver()
+        .resolve_resource("../public")
+        .ok_or(SqlError::IOError(String::from("Bundled public directory not found")))?;
+
+    let (class, section_id): (String, String) = conn.query_row(
+        "SELECT class, section_id FROM character WHERE id = ?1",
+        [character_id],
+        |row| Ok((row.get(0)?, row.get(1)?))
+    )?;
+    let appearance = get_appearance(&conn, character_id)?;
+    let image = render_portrait(&public_dir, &class, &section_id, &appearance)?;
+
+    conn.execute(
+        "UPDATE character SET image = ?1 WHERE id = ?2",
+        params![image, character_id]
+    )?;
+
+    Ok(image)
+}
+
+fn get_mag_state(conn: &Connection, mag_id: i64) -> Result<MagState, SqlError> {
+    let item_data: String = conn.query_row(
+        "SELECT item_data FROM mag WHERE id = ?1",
+        [mag_id],
+        |row| row.get(0)
+    )?;
+    let item_data = Util::hex_string_to_array(&item_data);
+    ensure_len(&item_data, 20)?;
+
+    Ok(MagState::from_item_data(&item_data))
+}
+
+fn get_character_class(conn: &Connection, character_id: i64) -> Result<String, SqlError> {
+    Ok(conn.query_row(
+        "SELECT class FROM character WHERE id = ?1",
+        [character_id],
+        |row| row.get(0)
+    )?)
+}
+
+/// Effective ATP of each weapon at its grind and at max grind, with the average ATP against
+/// every enemy category. Weapons missing from the reference data are left out.
+#[tauri::command]
+pub fn calculate_weapon_atp(weapon_ids: Vec<i64>) -> Result<Vec<WeaponAtp>, SqlError> {
+    let conn = Connection::open(DB_CONN)?;
+    let mut stmt = conn.prepare(
+        "SELECT w.id, w.name, w.grind, w.native, w.a_beast, w.machine, w.dark, w.hit,
+            r.min_atp, r.max_atp, r.grind, r.ata, r.requirement_atp, r.requirement_ata,
+            r.requirement_mst, r.classes
+        FROM weapon w
+        LEFT JOIN weapon_reference r ON r.hex_code = substr(w.item_data, 1, 6)
+        WHERE w.id = ?1"
+    )?;
+
+    let mut weapons = Vec::new();
+
+    for weapon_id in weapon_ids {
+        let weapon = stmt.query_row([weapon_id], |row| {
+            let attribute = Attribute {
+                native: row.get(3)?,
+                a_beast: row.get(4)?,
+                machine: row.get(5)?,
+                dark: row.get(6)?,
+                hit: row.get(7)?,
+            };
+
+            let id: i64 = row.get(0)?;
+            let name: String = row.get(1)?;
+            let grind: u8 = row.get(2)?;
+
+            Ok(weapon_reference(row, 8)?.map(|reference| weapon_atp(id, name, &reference, grind, &attribute)))
+        })?;
+
+        if let Some(weapon) = weapon {
+            weapons.push(weapon);
+        }
+    }
+
+    Ok(weapons)
+}
+
+/// Every equippable item on the account, from the shared bank and each character's inventory
+/// and bank, with the characters whose class, level and stats allow them to use it and those
+/// the reference data can't decide for.
+#[tauri::command]
+pub fn get_equip_compatibility(account_id: i64, lang: String) -> Result<Vec<ItemEligibility>, SqlError> {
+    let conn = Connection::open(DB_CONN)?;
+    let mut stmt = conn.prepare(
+        "SELECT id, name, class, level, atp, ata, mst FROM character WHERE account_id = ?1"
+    )?;
+
+    let characters = stmt
+        .query_map([account_id], |row| {
+            Ok(EquipCharacter {
+                character_id: row.get(0)?,
+                name: row.get(1)?,
+                class: row.get(2)?,
+                level: row.get(3)?,
+                atp: row.get(4)?,
+                ata: row.get(5)?,
+                mst: row.get(6)?,
+            })
+        })?
+        .collect::<SqlResult<Vec<EquipCharacter>>>()?;
+
+    let shared_bank_id = 0;
+    let mut items = get_items(&conn, account_id, shared_bank_id, &lang)?;
+
+    for character in &characters {
+        items.extend(get_items(&conn, account_id, character.character_id, &lang)?);
+    }
+
+    Ok(equip_compatibility(&items, &characters))
+}
+
+/// An item picked for a loadout, by its type as reported in `ItemEligibility` and its row id.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct LoadoutItem {
+    item_type: String,
+    item_id: i64,
+}
+
+fn item_table(item_type: &str) -> Result<&'static str, SqlError> {
+    match item_type {
+        "WEAPON" => Ok("weapon"),
+        "SRANK_WEAPON" => Ok("srank_weapon"),
+        "FRAME" => Ok("frame"),
+        "BARRIER" => Ok("barrier"),
+        "UNIT" => Ok("unit"),
+        "MAG" => Ok("mag"),
+        _ => Err(SqlError::ParseError(format!("{} can't be equipped", item_type))),
+    }
+}
+
+/// Final ATP, DFP, MST, ATA, EVP and LCK of a character with the gear it has equipped, or with
+/// `loadout` instead when one is given. Loadout items can come from any storage on the
+/// character's account, an item from another account is an error.
+#[tauri::command]
+pub fn calculate_build(app_handle: tauri::AppHandle, character_id: i64, loadout: Option<Vec<LoadoutItem>>, lang: String) -> Result<BuildStats, SqlError> {
+    let conn = Connection::open(DB_CONN)?;
+    let (stats, server, account_id) = conn.query_row(
+        "SELECT c.atp, c.mst, c.evp, c.hp, c.dfp, c.ata, c.lck, a.server, a.id
+        FROM character c
+        JOIN account a ON a.id = c.account_id
+        WHERE c.id = ?1",
+        [character_id],
+        |row| {
+            let stats = CharacterStats {
+                atp: row.get(0)?,
+                mst: row.get(1)?,
+                evp: row.get(2)?,
+                hp: row.get(3)?,
+                dfp: row.get(4)?,
+                ata: row.get(5)?,
+                lck: row.get(6)?,
+            };
+            let server: String = row.get(7)?;
+            let account_id: i64 = row.get(8)?;
+
+            Ok((stats, server, account_id))
+        }
+    )?;
+
+    let tables = ItemTablePaths::from_app(&app_handle);
+    let config = Config::init(lang, ServerProfile::from_server(&server), &tables)?;
+
+    let item_data: Vec<String> = match loadout {
+        Some(items) => items
+            .iter()
+            .map(|item| {
+                let query = format!("SELECT item_data FROM {} WHERE id = ?1 AND account_id = ?2", item_table(&item.item_type)?);
+                conn.query_row(&query, [item.item_id, account_id], |row| row.get(0))
+                    .optional()?
+                    .ok_or_else(|| SqlError::DatabaseError(format!(
+                        "{} {} is not on the account of character {}", item.item_type, item.item_id, character_id
+                    )))
+            })
+            .collect::<Result<Vec<String>, SqlError>>()?,
+        None => conn
+            .prepare("SELECT item_data FROM equipped_item WHERE character_id = ?1")?
+            .query_map([character_id], |row| row.get(0))?
+            .collect::<SqlResult<Vec<String>>>()?,
+    };
+
+    let mut gear = Loadout::default();
+
+    for data in &item_data {
+        if let Some(item) = translate_item(data, &config)?.item {
+            gear.equip(item);
+        }
+    }
+
+    let reference = match &gear.weapon {
+        Some(Item::Weapon { item_data, .. }) => conn
+            .query_row(
+                "SELECT min_atp, max_atp, grind, ata, requirement_atp, requirement_ata, requirement_mst, classes
+                FROM weapon_reference
+                WHERE hex_code = substr(?1, 1, 6)",
+                [item_data],
+                |row| weapon_reference(row, 0),
+            )
+            .optional()?
+            .flatten(),
+        _ => None,
+    };
+
+    Ok(evaluate_build(&stats, &gear, reference.as_ref()))
+}
+
+/// Predicts the next evolution of a mag if it's fed by the given character.
+#[tauri::command]
+pub fn predict_mag_evolution(mag_id: i64, character_id: i64) -> Result<Option<PredictedEvolution>, SqlError> {
+    let conn = Connection::open(DB_CONN)?;
+    let state = get_mag_state(&conn, mag_id)?;
+    let class = get_character_class(&conn, character_id)?;
+
+    Ok(next_evolution(&state, &class))
+}
+
+/// Plans how to feed a mag towards `target` levels with the mag food stored anywhere on
+/// the account, characters' inventories and banks as well as the shared bank.
+#[tauri::command]
+pub fn plan_mag_feeding(account_id: i64, mag_id: i64, character_id: i64, target: MagStats, lang: String) -> Result<FeedingPlan, SqlError> {
+    let conn = Connection::open(DB_CONN)?;
+    let state = get_mag_state(&conn, mag_id)?;
+    let class = get_character_class(&conn, character_id)?;
+
+    let mut stmt = conn.prepare(
+        "SELECT name, number, item_data FROM tool WHERE account_id = ?1 AND lang = ?2"
+    )?;
+    let tools = stmt
+        .query_map(params![account_id, lang], |row| {
+            Ok((row.get::<_, String>(0)?, row.get::<_, u32>(1)?, row.get::<_, String>(2)?))
+        })?
+        .collect::<SqlResult<Vec<(String, u32, String)>>>()?;
+
+    let mut stock: Vec<FeedStock> = Vec::new();
+    for (name, number, item_data) in tools {
+        let item_code = match item_data.get(0..6).and_then(|code| u32::from_str_radix(code, 16).ok()) {
+            Some(item_code) if FEED_ITEMS.contains(&item_code) => item_code,
+            _ => continue,
+        };
+
+        match stock.iter_mut().find(|tool| tool.item_code == item_code) {
+            Some(tool) => tool.number += number,
+            None => stock.push(FeedStock { item_code, name, number }),
+        }
+    }
+    stock.sort_by_key(|tool| FEED_ITEMS.iter().position(|&code| code == tool.item_code));
+
+    Ok(plan_feeding(&state, &class, &target, &stock))
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SealedItem {
+    pub id: i64,
+    pub item_type: String,
+    pub name: String,
+    pub character_id: i64,
+    pub character_name: Option<String>,
+    pub storage_type: String,
+    pub kills: u16,
+    pub kills_remaining: u16
+}
+
+/// Every kill counting weapon and unit on the account, sorted by kills remaining to unseal,
+/// closest first unless `descending` is set.
+#[tauri::command]
+pub fn get_sealed_items(account_id: i64, lang: String, descending: Option<bool>) -> Result<Vec<SealedItem>, SqlError> {
+    let conn = Connection::open(DB_CONN)?;
+    let order = if descending.unwrap_or(false) { "DESC" } else { "ASC" };
+
+    let mut stmt = conn.prepare(&format!(
+        "SELECT i.id, i.item_type, i.name, i.character_id, c.name, i.storage_type, i.kills, i.kills_remaining
+        FROM (
+            SELECT id, 'WEAPON' AS item_type, name, character_id, storage_type, kills, kills_remaining, account_id, lang
+            FROM weapon
+            UNION ALL
+            SELECT id, 'UNIT' AS item_type, name, character_id, storage_type, kills, kills_remaining, account_id, lang
+            FROM unit
+        ) i
+        LEFT JOIN character c ON i.character_id = c.id
+        WHERE i.account_id = ?1 AND i.lang = ?2 AND i.kills_remaining IS NOT NULL
+        ORDER BY i.kills_remaining {}, i.name",
+        order
+    ))?;
+    let sealed_iter = stmt.query_map(params![account_id, lang], |row| {
+        Ok(SealedItem {
+            id: row.get(0)?,
+            item_type: row.get(1)?,
+            name: row.get(2)?,
+            character_id: row.get(3)?,
+            character_name: row.get(4)?,
+            storage_type: row.get(5)?,
+            kills: row.get(6)?,
+            kills_remaining: row.get(7)?
+        })
+    })?;
+
+    let mut sealed_items = Vec::new();
+    for sealed_item in sealed_iter {
+        sealed_items.push(sealed_item?);
+    }
+
+    Ok(sealed_items)
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct QuestLogEntry {
+    pub character_id: i64,
+    pub character_name: String,
+    pub episode: u8,
+    pub flag: u16,
+    pub name: String,
+    pub difficulty: u8,
+    pub completed: bool
+}
+
+/// Quest names come from the language pack for `lang`. Rows for flags the quest table no
+/// longer knows are left out.
+#[tauri::command]
+pub fn get_quest_log(
+    app_handle: tauri::AppHandle,
+    account_id: i64,
+    character_id: Option<i64>,
+    episode: Option<u8>,
+    difficulty: Option<u8>,
+    completed: Option<bool>,
+    lang: String
+) -> Result<Vec<QuestLogEntry>, SqlError> {
+    let conn = Connection::open(DB_CONN)?;
+    let server: String = conn.query_row(
+        "SELECT server FROM account WHERE id = ?1",
+        params![account_id],
+        |row| row.get(0),
+    )?;
+    let tables = ItemTablePaths::from_app(&app_handle);
+    let config = Config::init(lang, ServerProfile::from_server(&server), &tables)?;
+
+    let mut stmt = conn.prepare(
+        "SELECT q.character_id, c.name, q.episode, q.flag, q.difficulty, q.completed
+        FROM quest_log q
+        JOIN character c ON q.character_id = c.id
+        WHERE c.account_id = ?1
+            AND (?2 IS NULL OR q.character_id = ?2)
+            AND (?3 IS NULL OR q.episode = ?3)
+            AND (?4 IS NULL OR q.difficulty = ?4)
+            AND (?5 IS NULL OR q.completed = ?5)
+        ORDER BY q.character_id, q.episode, q.flag, q.difficulty"
+    )?;
+    let quest_iter = stmt.query_map(
+        params![account_id, character_id, episode, difficulty, completed],
+        |row| {
+            let flag: u16 = row.get(3)?;
+            let name = match config.quest_name(flag) {
+                Some(name) => name,
+                None => return Ok(None),
+            };
+
+            Ok(Some(QuestLogEntry {
+                character_id: row.get(0)?,
+                character_name: row.get(1)?,
+                episode: row.get(2)?,
+                flag,
+                name,
+                difficulty: row.get(4)?,
+                completed: row.get(5)?
+            }))
+        }
+    )?;
+
+    let mut quest_log = Vec::new();
+    for quest in quest_iter {
+        quest_log.extend(quest?);
+    }
+
+    Ok(quest_log)
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ChallengeStage {
+    pub stage: u8,
+    pub time: Option<u32>
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ChallengeEpisode {
+    pub episode: u8,
+    pub stages_cleared: u8,
+    pub total_time: Option<u32>,
+    pub stages: Vec<ChallengeStage>
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CharacterChallengeRecords {
+    pub character_id: i64,
+    pub character_name: String,
+    pub title: Option<ChallengeTitle>,
+    pub episodes: Vec<ChallengeEpisode>,
+    pub battle: Option<BattleRecords>
+}
+
+/// Best challenge times per stage and episode for each character of an account, with the
+/// rank title the character earned in challenge mode.
+#[tauri::command]
+pub fn get_challenge_records(account_id: i64) -> Result<Vec<CharacterChallengeRecords>, SqlError> {
+    let conn = Connection::open(DB_CONN)?;
+
+    let mut characters_stmt = conn.prepare(
+        "SELECT id, name FROM character WHERE account_id = ?1 ORDER BY slot"
+    )?;
+    let characters = characters_stmt
+        .query_map([account_id], |row| Ok((row.get::<_, i64>(0)?, row.get::<_, String>(1)?)))?
+        .collect::<SqlResult<Vec<(i64, String)>>>()?;
+
+    let mut stages_stmt = conn.prepare(
+        "SELECT episode, stage, time
+        FROM challenge_record
+        WHERE character_id = ?1
+        ORDER BY episode, stage"
+    )?;
+
+    let mut records = Vec::new();
+    for (character_id, character_name) in characters {
+        let stages = stages_stmt
+            .query_map([character_id], |row| {
+                Ok((row.get::<_, u8>(0)?, StageRecord { stage: row.get(1)?, time: row.get(2)? }))
+            })?
+            .collect::<SqlResult<Vec<(u8, StageRecord)>>>()?;
+
+        let mut episodes: Vec<ChallengeEpisode> = Vec::new();
+        for (episode, record) in stages {
+            if episodes.last().map_or(true, |last| last.episode != episode) {
+                episodes.push(ChallengeEpisode { episode, stages_cleared: 0, total_time: None, stages: Vec::new() });
+            }
+
+            let current = episodes.last_mut().unwrap();
+
+            if let Some(time) = record.time {
+                current.stages_cleared += 1;
+                current.total_time = Some(current.total_time.unwrap_or(0) + time);
+            }
+
+            current.stages.push(ChallengeStage { stage: record.stage, time: record.time });
+        }
+
+        records.push(CharacterChallengeRecords {
+            character_id,
+            character_name,
+            title: get_challenge_title(&conn, character_id)?,
+            episodes,
+            battle: get_battle_records(&conn, character_id)?
+        });
+    }
+
+    Ok(records)
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TechCandidate {
+    pub character_id: i64,
+    pub character_name: String,
+    pub class: String,
+    pub current_level: Option<u8>,
+    pub max_level: u8
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TechDiskCandidates {
+    pub id: i64,
+    pub name: String,
+    pub level: u8,
+    pub storage_type: String,
+    pub character_id: i64,
+    pub candidates: Vec<TechCandidate>
+}
+
+/// Lists every tech disk an account owns together with the characters that would gain a
+/// level by learning it.
+#[tauri::command]
+pub fn get_tech_disk_candidates(account_id: i64, lang: String) -> Result<Vec<TechDiskCandidates>, SqlError> {
+    let conn = Connection::open(DB_CONN)?;
+
+    let mut characters_stmt = conn.prepare(
+        "SELECT id, name, class FROM character WHERE account_id = ?1 ORDER BY slot"
+    )?;
+    let characters = characters_stmt
+        .query_map([account_id], |row| Ok((row.get::<_, i64>(0)?, row.get::<_, String>(1)?, row.get::<_, String>(2)?)))?
+        .collect::<SqlResult<Vec<(i64, String, String)>>>()?;
+
+    let mut learned_stmt = conn.prepare(
+        "SELECT level FROM learned_tech WHERE character_id = ?1 AND tech = ?2"
+    )?;
+
+    let mut disks_stmt = conn.prepare(
+        "SELECT id, name, level, item_data, storage_type, character_id
+        FROM tech
+        WHERE account_id = ?1 AND lang = ?2
+        ORDER BY name, level DESC"
+    )?;
+    let disks = disks_stmt
+        .query_map(params![account_id, lang], |row| {
+            Ok((
+                row.get::<_, i64>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, u8>(2)?,
+                row.get::<_, String>(3)?,
+                row.get::<_, String>(4)?,
+                row.get::<_, i64>(5)?
+            ))
+        })?
+        .collect::<SqlResult<Vec<(i64, String, u8, String, String, i64)>>>()?;
+
+    let mut tech_disks = Vec::new();
+    for (id, name, level, item_data, storage_type, owner_id) in disks {
+        let tech = item_data
+            .get(8..10)
+            .and_then(|byte| u8::from_str_radix(byte, 16).ok())
+            .ok_or(ParseError::BadItemData { offset: 4 })?;
+
+        let mut candidates = Vec::new();
+        for (character_id, character_name, class) in &characters {
+            let max_level = tech_level_cap(class, tech);
+            let current_level = learned_stmt
+                .query_map(params![character_id, tech], |row| row.get::<_, u8>(0))?
+                .next()
+                .transpose()?;
+
+            if level <= max_level && current_level.map_or(true, |current| current < level) {
+                candidates.push(TechCandidate {
+                    character_id: *character_id,
+                    character_name: character_name.clone(),
+                    class: class.clone(),
+                    current_level,
+                    max_level
+                });
+            }
+        }
+
+        tech_disks.push(TechDiskCandidates {
+            id,
+            name,
+            level,
+            storage_type,
+            character_id: owner_id,
+            candidates
+        });
+    }
+
+    Ok(tech_disks)
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct DashboardState {
     logged_in_account_id: u8,
@@ -708,6 +1494,24 @@ pub fn save_lang(lang: String) -> Result<(), SqlError> {
     Ok(())
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+pub struct LocaleNames {
+    classes: HashMap<String, String>,
+    section_ids: HashMap<String, String>,
+}
+
+/// Display names for classes and section IDs, which are stored in English.
+#[tauri::command]
+pub fn get_locale_names(app_handle: tauri::AppHandle, lang: String) -> Result<LocaleNames, SqlError> {
+    let tables = ItemTablePaths::from_app(&app_handle);
+    let config = Config::init(lang, ServerProfile::VanillaBB, &tables)?;
+
+    Ok(LocaleNames {
+        classes: config.class_names.unwrap_or_default(),
+        section_ids: config.section_id_names.unwrap_or_default(),
+    })
+}
+
 #[tauri::command]
 pub fn save_theme(theme: String) -> Result<(), SqlError> {
     let conn = Connection::open(DB_CONN)?;
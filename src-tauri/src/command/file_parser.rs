@@ -1,88 +1,119 @@
 use std::str;
 use std::collections::HashMap;
-use tauri::regex::Regex;
 use crate::config::config::Config;
-use crate::parser::{character,shared_bank};
+use crate::config::server_profile::ServerProfile;
+use crate::config::item_tables::ItemTablePaths;
+use crate::parser::{character,shared_bank,writer};
+use crate::parser::detect::{detect, classic_import, Detected};
+use crate::parser::error::ParseError;
 use crate::parser::types::{
     File,
     Files,
+    FileKind,
     Data,
     ParsedFile,
     ParsedFiles,
+    FailedFile,
     Character,
     SharedBank
 };
 
-fn parse(files_to_parse: Files, config: Config) -> Vec<ParsedFile> {
-    let mut parsed_files: Vec<ParsedFile> = Vec::new();
-    let re_psobank = Regex::new(r"psobank").unwrap();
-    let re_psochar = Regex::new(r"psochar").unwrap();
-    let re_psoclassicbank = Regex::new(r"psoclassicbank").unwrap();
-    
-    for file in files_to_parse.iter() {
-        let binary = &file.binary;
+fn parse_file(file: &File, detected: &Detected, slot: usize, config: &Config) -> Result<Data, ParseError> {
+    let binary = &file.binary;
 
-        if re_psobank.is_match(&file.filename) {
+    match detected.kind {
+        FileKind::SharedBank => {
             let normal = String::from("NORMAL");
-            parsed_files.push(ParsedFile {
-                filename: String::from(&file.filename),
-                data: Data::SharedBank(shared_bank::create(&binary, Config::mode(normal), config.clone())),
-            });
-            continue;
-        }
-
-        if re_psoclassicbank.is_match(&file.filename) {
+            Ok(Data::SharedBank(shared_bank::create(&binary, Config::mode(normal), config.clone())?))
+        },
+        FileKind::ClassicSharedBank => {
             let classic = String::from("CLASSIC");
-            parsed_files.push(ParsedFile {
-                filename: String::from(&file.filename),
-                data: Data::SharedBank(shared_bank::create(&binary, Config::mode(classic), config.clone())),
-            });
-            continue;
+            Ok(Data::SharedBank(shared_bank::create(&binary, Config::mode(classic), config.clone())?))
+        },
+        FileKind::Character => {
+            Ok(Data::Character(character::create(&binary, slot + 1, config.clone())?))
         }
+    }
+}
 
-        if re_psochar.is_match(&file.filename) {
-            let re_slot = Regex::new(r"Slot_ (\d+)").unwrap();
+fn next_free_slot(used_slots: &Vec<usize>) -> usize {
+    (0..).find(|slot| !used_slots.contains(slot)).unwrap_or(0)
+}
 
-            let slot_match = re_slot
-                .captures(&file.filename)
-                .and_then(|caps| caps.get(1))
-                .expect("Failed to match the slot");
+fn parse(files_to_parse: Files, config: Config) -> ParsedFiles {
+    let mut parsed_files: Vec<ParsedFile> = Vec::new();
+    let mut failed_files: Vec<FailedFile> = Vec::new();
+    let classic = config.server().has_classic_mode() && classic_import(&files_to_parse);
+    let detected_files: Vec<Option<Detected>> = files_to_parse.iter().map(|file| detect(file, classic)).collect();
+    let mut used_slots: Vec<usize> = detected_files
+        .iter()
+        .filter_map(|detected| detected.as_ref().and_then(|detected| detected.slot))
+        .collect();
+    
+    for (file, detected) in files_to_parse.iter().zip(detected_files.iter()) {
+        let result = match detected {
+            Some(detected) => {
+                let slot = match (detected.kind, detected.slot) {
+                    (_, Some(slot)) => slot,
+                    (FileKind::Character, None) => {
+                        let slot = next_free_slot(&used_slots);
+                        used_slots.push(slot);
+                        slot
+                    },
+                    _ => 0,
+                };
+                parse_file(file, detected, slot, &config)
+            },
+            None => Err(ParseError::UnknownFileType { filename: file.filename.clone() }),
+        };
 
-            let slot: usize = slot_match
-                .as_str()
-                .parse()
-                .expect("Failed to parse the slot to a number");
-            
-            parsed_files.push(ParsedFile {
-                filename: String::from(&file.filename.to_owned()),
-                data: Data::Character(character::create(&binary, slot + 1, config.clone())),
-            });
+        match result {
+            Ok(data) => {
+                parsed_files.push(ParsedFile {
+                    filename: String::from(&file.filename),
+                    data: data,
+                });
+            },
+            Err(error) => {
+                failed_files.push(FailedFile {
+                    filename: String::from(&file.filename),
+                    message: error.to_string(),
+                    error: error,
+                });
+            }
         }
     }
     
-    parsed_files
+    ParsedFiles {
+        files: parsed_files,
+        failed: failed_files
+    }
 }
 
 #[tauri::command]
-pub fn parse_files(files: Files, lang: String) -> Result<ParsedFiles, ()> {
-    let reg_ex = Regex::new(r"psobank|psoclassicbank|psochar").unwrap();
-    let mut files_to_parse: Files = Vec::new();
-    let config = Config::init(lang.clone());
+pub fn parse_files(app_handle: tauri::AppHandle, files: Files, lang: String, server: String) -> Result<ParsedFiles, ParseError> {
+    let tables = ItemTablePaths::from_app(&app_handle);
+    let config = Config::init(lang.clone(), ServerProfile::from_server(&server), &tables)?;
 
-    for file in &files {
-        if reg_ex.is_match(&file.filename) {
-            files_to_parse.push(File {
-                filename: file.filename.clone(),
-                binary: file.binary.clone(),
-            });
-        }
+    if files.is_empty() {
+        return Err(ParseError::NoSaveFiles);
     }
 
-    if files_to_parse.is_empty() {
-        return Err(());
+    Ok(parse(files, config))
+}
+
+#[tauri::command]
+pub fn encode_file(data: Data, template: Option<Vec<u8>>) -> Result<Vec<u8>, ParseError> {
+    match data {
+        Data::Character(character) => {
+            let template = template.ok_or(ParseError::MissingTemplate)?;
+            writer::encode_character(&character, &template)
+        },
+        Data::SharedBank(shared_bank) => writer::encode_shared_bank(&shared_bank, template.as_deref()),
     }
+}
 
-    Ok(ParsedFiles {
-        files: parse(files_to_parse, config)
-    })
-}
\ No newline at end of file
+#[tauri::command]
+pub fn encode_shared_bank_items(items: Vec<String>, meseta: u32) -> Result<Vec<u8>, ParseError> {
+    writer::encode_shared_bank_items(&items, meseta)
+}
@@ -0,0 +1,275 @@
+use std::collections::HashMap;
+
+/// Base (DFP, EVP), the most DFP/EVP a drop can roll on top of them, the slot limit and the
+/// level requirement, keyed by item code. Base stats and level requirements are `None` where
+/// they are not known yet.
+pub type ArmorRow = (Option<[i16; 2]>, [i32; 2], u8, Option<u8>);
+
+pub fn armor_data() -> HashMap<u32, ArmorRow> {
+    let mut map = HashMap::new();
+    map.insert(0x010100, (None, [2, 2], 4, None)); // Frame
+    map.insert(0x010101, (None, [2, 2], 4, None)); // Armor
+    map.insert(0x010102, (None, [3, 2], 4, None)); // Psy Armor
+    map.insert(0x010103, (None, [4, 2], 4, None)); // Giga Frame
+    map.insert(0x010104, (None, [4, 2], 4, None)); // Soul Frame
+    map.insert(0x010105, (None, [4, 2], 4, None)); // Cross Armor
+    map.insert(0x010106, (None, [4, 2], 4, None)); // Solid Frame
+    map.insert(0x010107, (None, [4, 2], 4, None)); // Brave Armor
+    map.insert(0x010108, (None, [4, 2], 4, None)); // Hyper Frame
+    map.insert(0x010109, (None, [4, 2], 4, None)); // Grand Armor
+    map.insert(0x01010A, (None, [4, 2], 4, None)); // Shock Frame
+    map.insert(0x01010B, (None, [4, 2], 4, None)); // King's Frame
+    map.insert(0x01010C, (None, [4, 2], 4, None)); // Dragon Frame
+    map.insert(0x01010D, (None, [4, 2], 4, None)); // Absorb Armor
+    map.insert(0x01010E, (None, [4, 2], 4, None)); // Protect Frame
+    map.insert(0x01010F, (None, [4, 2], 4, None)); // General Armor
+    map.insert(0x010110, (None, [4, 2], 4, None)); // Perfect Frame
+    map.insert(0x010111, (None, [4, 2], 4, None)); // Valiant Frame
+    map.insert(0x010112, (None, [4, 2], 4, None)); // Imperial Armor
+    map.insert(0x010113, (None, [4, 2], 4, None)); // Holiness Armor
+    map.insert(0x010114, (None, [4, 2], 4, None)); // Guardian Armor
+    map.insert(0x010115, (None, [4, 2], 4, None)); // Divinity Armor
+    map.insert(0x010116, (None, [4, 2], 4, None)); // Ultimate Frame
+    map.insert(0x010117, (None, [10, 4], 4, None)); // Celestial Armor
+    map.insert(0x010118, (None, [8, 8], 4, None)); // HUNTER FIELD
+    map.insert(0x010119, (None, [8, 8], 4, None)); // RANGER FIELD
+    map.insert(0x01011A, (None, [8, 8], 4, None)); // FORCE FIELD
+    map.insert(0x01011B, (None, [5, 10], 4, None)); // REVIVAL GARMENT
+    map.insert(0x01011C, (None, [7, 5], 4, None)); // SPIRIT GARMENT
+    map.insert(0x01011D, (None, [85, 85], 4, None)); // STINK FRAME
+    map.insert(0x01011E, (None, [10, 7], 4, None)); // D-PARTS ver1.01
+    map.insert(0x01011F, (None, [10, 8], 4, None)); // D-PARTS ver2.10
+    map.insert(0x010123, (None, [8, 8], 4, None)); // SENSE PLATE
+    map.insert(0x010124, (None, [8, 0], 4, None)); // GRAVITON PLATE
+    map.insert(0x010125, (None, [8, 8], 4, None)); // ATTRIBUTE PLATE
+    map.insert(0x010126, (None, [10, 10], 4, None)); // FLOWEN'S FRAME
+    map.insert(0x010127, (None, [10, 10], 4, None)); // CUSTOM FRAME ver.OO
+    map.insert(0x010128, (None, [10, 10], 4, None)); // DB'S ARMOR
+    map.insert(0x010129, (None, [50, 20], 4, None)); // GUARD WAVE
+    map.insert(0x01012A, (None, [50, 20], 4, None)); // DF FIELD
+    map.insert(0x01012B, (None, [50, 20], 4, None)); // LUMINOUS FIELD
+    map.insert(0x01012C, (None, [0, 0], 4, None)); // CHU CHU FEVER
+    map.insert(0x01012D, (None, [50, 20], 4, None)); // LOVE HEART
+    map.insert(0x01012E, (None, [50, 20], 4, None)); // FLAME GARMENT
+    map.insert(0x010130, (None, [50, 20], 4, None)); // BRIGHTNESS CIRCLE
+    map.insert(0x010131, (None, [50, 20], 4, None)); // AURA FIELD
+    map.insert(0x010132, (None, [50, 20], 4, None)); // ELECTRO FRAME
+    map.insert(0x010133, (None, [50, 20], 4, None)); // SACRED CLOTH
+    map.insert(0x010134, (None, [50, 20], 4, None)); // SMOKING PLATE
+    map.insert(0x010135, (None, [30, 0], 4, None)); // STAR CUIRASS
+    map.insert(0x010136, (None, [0, 0], 4, None)); // BLACK HOUND CUIRASS
+    map.insert(0x010137, (None, [10, 20], 4, None)); // MORNING PRAYER
+    map.insert(0x010138, (None, [10, 10], 4, None)); // BLACK ODOSHI DOMARU
+    map.insert(0x010139, (None, [10, 10], 4, None)); // RED ODOSHI DOMARU
+    map.insert(0x01013A, (None, [10, 10], 4, None)); // BLACK ODOSHI RED NIMAIDOU
+    map.insert(0x01013B, (None, [10, 10], 4, None)); // BLUE ODOSHI VIOLET NIMAIDOU
+    map.insert(0x01013C, (None, [0, 0], 4, None)); // DIRTY LIFEJACKET
+    map.insert(0x01013D, (None, [0, 0], 4, None)); // KROE'S SWEATER
+    map.insert(0x01013E, (None, [0, 0], 4, None)); // WEDDING DRESS
+    map.insert(0x01013F, (None, [0, 0], 4, None)); // SONICTEAM ARMOR
+    map.insert(0x010140, (None, [10, 10], 4, None)); // RED COAT
+    map.insert(0x010141, (None, [8, 8], 4, None)); // THIRTEEN
+    map.insert(0x010142, (None, [15, 5], 4, None)); // MOTHER GARB
+    map.insert(0x010143, (None, [15, 5], 4, None)); // MOTHER GARB+
+    map.insert(0x010144, (None, [0, 0], 4, None)); // DRESS PLATE
+    map.insert(0x010145, (None, [50, 20], 4, None)); // SWEETHEART
+    map.insert(0x010146, (None, [8, 8], 4, None)); // IGNITION CLOAK
+    map.insert(0x010147, (None, [8, 8], 4, None)); // CONGEAL CLOAK
+    map.insert(0x010148, (None, [8, 8], 4, None)); // TEMPEST CLOAK
+    map.insert(0x010149, (None, [8, 8], 4, None)); // CURSED CLOAK
+    map.insert(0x01014A, (None, [8, 8], 4, None)); // SELECT CLOAK
+    map.insert(0x01014B, (None, [7, 5], 4, None)); // SPIRIT CUIRASS
+    map.insert(0x01014C, (None, [5, 10], 4, None)); // REVIVAL CURIASS
+    map.insert(0x01014D, (None, [12, 0], 4, None)); // ALLIANCE UNIFORM
+    map.insert(0x01014E, (None, [14, 0], 4, None)); // OFFICER UNIFORM
+    map.insert(0x01014F, (None, [16, 0], 4, None)); // COMMANDER UNIFORM
+    map.insert(0x010150, (None, [12, 12], 4, None)); // CRIMSON COAT
+    map.insert(0x010151, (None, [12, 8], 4, None)); // INFANTRY GEAR
+    map.insert(0x010152, (None, [18, 16], 4, None)); // LIEUTENANT GEAR
+    map.insert(0x010153, (None, [10, 10], 4, None)); // INFANTRY MANTLE
+    map.insert(0x010154, (None, [21, 18], 4, None)); // LIEUTENANT MANTLE
+    map.insert(0x010155, (None, [0, 0], 4, None)); // UNION FIELD
+    map.insert(0x010156, (None, [0, 0], 4, None)); // SAMURAI ARMOR
+    map.insert(0x010157, (None, [0, 25], 4, None)); // STEALTH SUIT
+    map.insert(0x010200, (None, [5, 5], 0, None)); // Barrier
+    map.insert(0x010201, (None, [5, 5], 0, None)); // Shield
+    map.insert(0x010202, (None, [5, 5], 0, None)); // Core Shield
+    map.insert(0x010203, (None, [5, 5], 0, None)); // Giga Shield
+    map.insert(0x010204, (None, [5, 5], 0, None)); // Soul Barrier
+    map.insert(0x010205, (None, [5, 5], 0, None)); // Hard Shield
+    map.insert(0x010206, (None, [5, 5], 0, None)); // Brave Barrier
+    map.insert(0x010207, (None, [5, 5], 0, None)); // Solid Shield
+    map.insert(0x010208, (None, [5, 5], 0, None)); // Flame Barrier
+    map.insert(0x010209, (None, [5, 5], 0, None)); // Plasma Barrier
+    map.insert(0x01020A, (None, [5, 5], 0, None)); // Freeze Barrier
+    map.insert(0x01020B, (None, [5, 5], 0, None)); // Psychic Barrier
+    map.insert(0x01020C, (None, [5, 5], 0, None)); // General Shield
+    map.insert(0x01020D, (None, [5, 5], 0, None)); // Protect Barrier
+    map.insert(0x01020E, (None, [5, 5], 0, None)); // Glorious Shield
+    map.insert(0x01020F, (None, [5, 5], 0, None)); // Imperial Barrier
+    map.insert(0x010210, (None, [5, 5], 0, None)); // Guardian Shield
+    map.insert(0x010211, (None, [5, 5], 0, None)); // Divinity Barrier
+    map.insert(0x010212, (None, [5, 5], 0, None)); // Ultimate Shield
+    map.insert(0x010213, (None, [5, 5], 0, None)); // Spiritual Shield
+    map.insert(0x010214, (None, [5, 5], 0, None)); // Celestial Shield
+    map.insert(0x010215, (None, [8, 8], 0, None)); // INVISIBLE GUARD
+    map.insert(0x010216, (None, [8, 8], 0, None)); // SACRED GUARD
+    map.insert(0x010217, (None, [8, 8], 0, None)); // S-PARTS ver1.16
+    map.insert(0x010218, (None, [7, 7], 0, None)); // S-PARTS ver2.01
+    map.insert(0x010219, (None, [7, 7], 0, None)); // LIGHT RELIEF
+    map.insert(0x01021A, (None, [7, 7], 0, None)); // SHIELD OF DELSABER
+    map.insert(0x01021B, (None, [10, 10], 0, None)); // FORCE WALL
+    map.insert(0x01021C, (None, [10, 10], 0, None)); // RANGER WALL
+    map.insert(0x01021D, (None, [10, 10], 0, None)); // HUNTER WALL
+    map.insert(0x01021E, (None, [10, 10], 0, None)); // ATTRIBUTE WALL
+    map.insert(0x01021F, (None, [10, 10], 0, None)); // SECRET GEAR
+    map.insert(0x010220, (None, [0, 0], 0, None)); // COMBAT GEAR
+    map.insert(0x010221, (None, [7, 7], 0, None)); // PROTO REGENE GEAR
+    map.insert(0x010222, (None, [7, 7], 0, None)); // REGENERATE GEAR
+    map.insert(0x010223, (None, [7, 7], 0, None)); // REGENE GEAR ADV.
+    map.insert(0x010224, (None, [10, 10], 0, None)); // FLOWEN'S SHIELD
+    map.insert(0x010225, (None, [10, 10], 0, None)); // CUSTOM BARRIER ver.OO
+    map.insert(0x010226, (None, [10, 10], 0, None)); // DB'S SHIELD
+    map.insert(0x010227, (None, [85, 25], 0, None)); // RED RING
+    map.insert(0x010228, (None, [50, 15], 0, None)); // TRIPOLIC SHIELD
+    map.insert(0x010229, (None, [50, 15], 0, None)); // STANDSTILL SHIELD
+    map.insert(0x01022A, (None, [50, 15], 0, None)); // SAFETY HEART
+    map.insert(0x01022B, (None, [50, 15], 0, None)); // KASAMI BRACER
+    map.insert(0x01022C, (None, [0, 0], 0, None)); // GODS SHIELD SUZAKU
+    map.insert(0x01022D, (None, [0, 0], 0, None)); // GODS SHIELD GENBU
+    map.insert(0x01022E, (None, [0, 0], 0, None)); // GODS SHIELD BYAKKO
+    map.insert(0x01022F, (None, [0, 0], 0, None)); // GODS SHIELD SEIRYU
+    map.insert(0x010230, (None, [50, 15], 0, None)); // HUNTER'S SHELL
+    map.insert(0x010231, (None, [0, 0], 0, None)); // RICO'S GLASSES
+    map.insert(0x010232, (None, [85, 25], 0, None)); // RICO'S EARRING
+    map.insert(0x010233, (None, [5, 5], 0, None)); // BLUE RING
+    map.insert(0x010234, (None, [5, 5], 0, None)); // Barrier
+    map.insert(0x010235, (None, [50, 15], 0, None)); // SECURE FEET
+    map.insert(0x010236, (None, [5, 5], 0, None)); // Barrier
+    map.insert(0x010237, (None, [5, 5], 0, None)); // Barrier
+    map.insert(0x010238, (None, [5, 5], 0, None)); // Barrier
+    map.insert(0x010239, (None, [5, 5], 0, None)); // Barrier
+    map.insert(0x01023A, (None, [5, 5], 0, None)); // RESTA MERGE
+    map.insert(0x01023B, (None, [5, 5], 0, None)); // ANTI MERGE
+    map.insert(0x01023C, (None, [5, 5], 0, None)); // SHIFTA MERGE
+    map.insert(0x01023D, (None, [5, 5], 0, None)); // DEBAND MERGE
+    map.insert(0x01023E, (None, [5, 5], 0, None)); // FOIE MERGE
+    map.insert(0x01023F, (None, [5, 5], 0, None)); // GIFOIE MERGE
+    map.insert(0x010240, (None, [5, 5], 0, None)); // RAFOIE MERGE
+    map.insert(0x010241, (None, [5, 5], 0, None)); // RED MERGE
+    map.insert(0x010242, (None, [5, 5], 0, None)); // BARTA MERGE
+    map.insert(0x010243, (None, [5, 5], 0, None)); // GIBARTA MERGE
+    map.insert(0x010244, (None, [5, 5], 0, None)); // RABARTA MERGE
+    map.insert(0x010245, (None, [5, 5], 0, None)); // BLUE MERGE
+    map.insert(0x010246, (None, [5, 5], 0, None)); // ZONDE MERGE
+    map.insert(0x010247, (None, [5, 5], 0, None)); // GIZONDE MERGE
+    map.insert(0x010248, (None, [5, 5], 0, None)); // RAZONDE MERGE
+    map.insert(0x010249, (None, [5, 5], 0, None)); // YELLOW MERGE
+    map.insert(0x01024A, (None, [5, 5], 0, None)); // RECOVERY BARRIER
+    map.insert(0x01024C, (None, [5, 5], 0, None)); // RED BARRIER
+    map.insert(0x01024D, (None, [5, 5], 0, None)); // BLUE BARRIER
+    map.insert(0x01024E, (None, [5, 5], 0, None)); // YELLOW BARRIER
+    map.insert(0x01024F, (None, [0, 0], 0, None)); // WEAPONS GOLD SHIELD
+    map.insert(0x010250, (None, [5, 5], 0, None)); // BLACK GEAR
+    map.insert(0x010251, (None, [5, 5], 0, None)); // WORKS GUARD
+    map.insert(0x010252, (None, [0, 0], 0, None)); // RAGOL RING
+    map.insert(0x010253, (None, [85, 25], 0, None)); // RED RING* - Blue Paint
+    map.insert(0x010254, (None, [5, 5], 0, None)); // BLUE RING
+    map.insert(0x010255, (None, [5, 5], 0, None)); // BLUE RING
+    map.insert(0x010256, (None, [5, 5], 0, None)); // BLUE RING
+    map.insert(0x010257, (None, [5, 5], 0, None)); // BLUE RING
+    map.insert(0x010258, (None, [5, 5], 0, None)); // BLUE RING
+    map.insert(0x010259, (None, [5, 5], 0, None)); // BLUE RING
+    map.insert(0x01025A, (None, [5, 5], 0, None)); // BLUE RING
+    map.insert(0x01025B, (None, [85, 25], 0, None)); // RED RING* - Green Paint
+    map.insert(0x01025C, (None, [5, 5], 0, None)); // GREEN RING
+    map.insert(0x01025D, (None, [5, 5], 0, None)); // GREEN RING
+    map.insert(0x01025E, (None, [5, 5], 0, None)); // GREEN RING
+    map.insert(0x01025F, (None, [5, 5], 0, None)); // GREEN RING
+    map.insert(0x010260, (None, [5, 5], 0, None)); // GREEN RING
+    map.insert(0x010261, (None, [5, 5], 0, None)); // GREEN RING
+    map.insert(0x010262, (None, [5, 5], 0, None)); // GREEN RING
+    map.insert(0x010263, (None, [85, 25], 0, None)); // RED RING* - Yellow Paint
+    map.insert(0x010264, (None, [5, 5], 0, None)); // YELLOW RING
+    map.insert(0x010265, (None, [5, 5], 0, None)); // YELLOW RING
+    map.insert(0x010266, (None, [5, 5], 0, None)); // YELLOW RING
+    map.insert(0x010267, (None, [5, 5], 0, None)); // YELLOW RING
+    map.insert(0x010268, (None, [5, 5], 0, None)); // YELLOW RING
+    map.insert(0x010269, (None, [5, 5], 0, None)); // YELLOW RING
+    map.insert(0x01026A, (None, [5, 5], 0, None)); // YELLOW RING
+    map.insert(0x01026B, (None, [85, 25], 0, None)); // RED RING* - Purple Paint
+    map.insert(0x01026C, (None, [5, 5], 0, None)); // PURPLE RING
+    map.insert(0x01026D, (None, [5, 5], 0, None)); // PURPLE RING
+    map.insert(0x01026E, (None, [5, 5], 0, None)); // PURPLE RING
+    map.insert(0x01026F, (None, [5, 5], 0, None)); // PURPLE RING
+    map.insert(0x010270, (None, [5, 5], 0, None)); // PURPLE RING
+    map.insert(0x010271, (None, [5, 5], 0, None)); // PURPLE RING
+    map.insert(0x010272, (None, [5, 5], 0, None)); // PURPLE RING
+    map.insert(0x010273, (None, [0, 0], 0, None)); // Anti-Dark Ring
+    map.insert(0x010274, (None, [85, 25], 0, None)); // RED RING* - White Paint
+    map.insert(0x010275, (None, [5, 5], 0, None)); // WHITE RING
+    map.insert(0x010276, (None, [5, 5], 0, None)); // WHITE RING
+    map.insert(0x010277, (None, [5, 5], 0, None)); // WHITE RING
+    map.insert(0x010278, (None, [5, 5], 0, None)); // WHITE RING
+    map.insert(0x010279, (None, [5, 5], 0, None)); // WHITE RING
+    map.insert(0x01027A, (None, [5, 5], 0, None)); // WHITE RING
+    map.insert(0x01027B, (None, [0, 0], 0, None)); // Anti-Light Ring
+    map.insert(0x01027C, (None, [85, 25], 0, None)); // RED RING* - Black Paint
+    map.insert(0x01027D, (None, [5, 5], 0, None)); // BLACK RING
+    map.insert(0x01027E, (None, [5, 5], 0, None)); // BLACK RING
+    map.insert(0x01027F, (None, [5, 5], 0, None)); // BLACK RING
+    map.insert(0x010280, (None, [5, 5], 0, None)); // BLACK RING
+    map.insert(0x010281, (None, [5, 5], 0, None)); // BLACK RING
+    map.insert(0x010282, (None, [5, 5], 0, None)); // BLACK RING
+    map.insert(0x010283, (None, [0, 0], 0, None)); // WEAPONS SILVER SHIELD
+    map.insert(0x010284, (None, [0, 0], 0, None)); // WEAPONS COPPER SHIELD
+    map.insert(0x010285, (None, [20, 15], 0, None)); // GRATIA
+    map.insert(0x010286, (None, [50, 15], 0, None)); // TRIPOLIC REFLECTOR
+    map.insert(0x010287, (None, [10, 5], 0, None)); // STRIKER PLUS
+    map.insert(0x010288, (None, [7, 7], 0, None)); // REGENERATE GEAR B.P.
+    map.insert(0x010289, (None, [10, 20], 0, None)); // RUPIKA
+    map.insert(0x01028A, (None, [20, 25], 0, None)); // YATA MIRROR
+    map.insert(0x01028B, (None, [0, 0], 0, None)); // BUNNY EARS
+    map.insert(0x01028C, (None, [0, 0], 0, None)); // CAT EARS
+    map.insert(0x01028D, (None, [3, 3], 0, None)); // THREE SEALS
+    map.insert(0x01028E, (None, [0, 0], 0, None)); // GOD'S SHIELD "KOURYU"
+    map.insert(0x01028F, (None, [85, 25], 0, None)); // DF SHIELD
+    map.insert(0x010290, (None, [0, 0], 0, None)); // FROM THE DEPTHS
+    map.insert(0x010291, (None, [75, 75], 0, None)); // DE ROL LE SHIELD
+    map.insert(0x010292, (None, [10, 10], 0, None)); // HONEYCOMB REFLECTOR
+    map.insert(0x010293, (None, [75, 75], 0, None)); // EPSIGUARD
+    map.insert(0x010294, (None, [0, 0], 0, None)); // ANGEL RING
+    map.insert(0x010295, (None, [0, 0], 0, None)); // UNION GUARD
+    map.insert(0x010296, (None, [0, 0], 0, None)); // UNION GUARD
+    map.insert(0x010297, (None, [0, 0], 0, None)); // UNION GUARD
+    map.insert(0x010298, (None, [0, 0], 0, None)); // UNION GUARD
+    map.insert(0x010299, (None, [75, 75], 0, None)); // STINK SHIELD
+    map.insert(0x01029A, (None, [0, 0], 0, None)); // UNKNOWN_B
+    map.insert(0x01029B, (None, [0, 0], 0, None)); // GENPEI
+    map.insert(0x01029C, (None, [0, 0], 0, None)); // GENPEI
+    map.insert(0x01029D, (None, [0, 0], 0, None)); // GENPEI
+    map.insert(0x01029E, (None, [0, 0], 0, None)); // GENPEI
+    map.insert(0x01029F, (None, [0, 0], 0, None)); // GENPEI
+    map.insert(0x0102A0, (None, [0, 0], 0, None)); // GENPEI
+    map.insert(0x0102A1, (None, [0, 0], 0, None)); // GENPEI
+    map.insert(0x0102A2, (None, [0, 0], 0, None)); // GENPEI
+    map.insert(0x0102A3, (None, [0, 0], 0, None)); // GENPEI
+    map.insert(0x0102A4, (None, [0, 0], 0, None)); // GENPEI
+    map.insert(0x0102A5, (None, [85, 25], 0, None)); // RED RING* - Chartreuse Paint
+    map.insert(0x0102A6, (None, [85, 25], 0, None)); // RED RING* - Cyan Paint
+    map.insert(0x0102A7, (None, [85, 25], 0, None)); // RED RING* - Onyx Paint
+    map.insert(0x0102A8, (None, [85, 25], 0, None)); // RED RING* - Orange Paint
+    map.insert(0x0102A9, (None, [85, 25], 0, None)); // RED RING* - Rose Paint
+    map.insert(0x0102AA, (None, [85, 25], 0, None)); // RED RING* - Ultramarine Paint
+    map.insert(0x0102AB, (None, [85, 25], 0, None)); // RED RING* - Violet Paint
+    map.insert(0x0102AC, (None, [85, 25], 0, None)); // RED RING* - Delsaber Plating
+    map.insert(0x0102AD, (None, [85, 25], 0, None)); // RED RING* - Standstill Plating
+    map.insert(0x0102AE, (None, [85, 25], 0, None)); // RED RING* - Honeycomb Plating
+    map.insert(0x0102AF, (None, [85, 25], 0, None)); // RED RING* - Epsilon Plating
+    map.insert(0x0102B0, (None, [85, 25], 0, None)); // RED RING* - Heart Plating
+    map.insert(0x0102B1, (None, [85, 25], 0, None)); // RED RING* - Angel Plating
+    map.insert(0x0102B2, (None, [85, 25], 0, None)); // RED RING* - Bunny Plating
+    map.insert(0x0102B3, (None, [85, 25], 0, None)); // RED RING* - Cat Plating
+    map.insert(0x0102B4, (None, [85, 25], 0, None)); // RED RING* - Deep Plating
+    map
+}
@@ -1,16 +1,21 @@
+use std::hash::Hash;
 use std::collections::HashMap;
 use crate::config::{item_codes, item_codes_ja};
+use crate::parser::types::MagClass;
+use crate::config::ephinea_items::ServerItemRow;
+use crate::config::server_profile::ServerProfile;
+use crate::config::item_tables::{ItemTablePaths, load_item_codes};
+use crate::config::language_pack::{LanguagePack, load_language_pack};
+use crate::config::quests::quest_table;
+use crate::parser::error::ParseError;
 use item_codes::{
     mag_color_codes,
     weapon_special_codes,
     rare_weapon_special_codes,
     srank_special_codes,
     specials_list,
-    frames,
-    barriers,
     tech_codes,
-    item_codes,
-    pbs
+    photon_blasts
 };
 use item_codes_ja::{
     mag_color_codes_ja,
@@ -18,25 +23,26 @@ use item_codes_ja::{
     rare_weapon_special_codes_ja,
     srank_special_codes_ja,
     specials_list_ja,
-    frames_ja,
-    barriers_ja,
     tech_codes_ja,
-    item_codes_ja,
-    pbs_ja
+    photon_blasts_ja
 };
 
 #[derive(Debug, Clone)]
 pub struct Config<'a> {
     pub lang: Option<String>,
-    pub item_codes: Option<HashMap<u32, &'a str>>,
-    pub weapon_special_codes: Option<HashMap<u8, &'a str>>,
-    pub rare_weapon_special_codes: Option<HashMap<u32, &'a str>>,
-    pub srank_special_codes: Option<HashMap<u8, &'a str>>,
-    pub frames: Option<HashMap<&'a str, [i32; 2]>>,
-    pub barriers: Option<HashMap<&'a str, [i32; 2]>>,
-    pub tech_codes: Option<HashMap<u8, &'a str>>,
-    pub pbs: Option<HashMap<&'a str, [&'a str; 3]>>,
-    pub mag_color_codes: Option<HashMap<u8, (&'a str, &'a str)>>,
+    pub item_codes: Option<HashMap<u32, String>>,
+    pub weapon_special_codes: Option<HashMap<u8, String>>,
+    pub rare_weapon_special_codes: Option<HashMap<u32, String>>,
+    pub srank_special_codes: Option<HashMap<u8, String>>,
+    pub tech_codes: Option<HashMap<u8, String>>,
+    pub mag_color_codes: Option<HashMap<u8, (&'a str, String)>>,
+    pub photon_blasts: Option<HashMap<u8, String>>,
+    pub meseta_name: Option<String>,
+    pub class_names: Option<HashMap<String, String>>,
+    pub section_id_names: Option<HashMap<String, String>>,
+    pub quest_names: Option<HashMap<u16, String>>,
+    pub server: Option<ServerProfile>,
+    pub server_items: Option<HashMap<u32, ServerItemRow>>,
 }
 
 impl<'a> Config<'a> {
@@ -48,7 +54,6 @@ impl<'a> Config<'a> {
     pub const TOOL_RANGE: (u32, u32) = (0x030000, 0x030900);
     pub const MESETA_RANGE: (u32, u32) = (0x040000, 0x040000);
     pub const TECH_RANGE: (u32, u32) = (0x050000, 0x05121D);
-    pub const EPHINEA_RANGE: (u32, u32) = (0x031005, 0x031810);
     pub const TECH_CODE: u32 = 0x0302;
 
     pub fn item_type() -> HashMap<&'static str, u32> {
@@ -90,6 +95,110 @@ impl<'a> Config<'a> {
         map
     }
 
+    /// Short class names used by the weapon reference data.
+    pub fn class_abbreviations() -> HashMap<&'static str, &'static str> {
+        let mut map = HashMap::new();
+        map.insert("HUmr", "HUmar");
+        map.insert("HUnl", "Hunewearl");
+        map.insert("HUct", "HUcast");
+        map.insert("HUcl", "HUcaseal");
+        map.insert("RAmr", "RAmar");
+        map.insert("RAml", "RAmarl");
+        map.insert("RAct", "RAcast");
+        map.insert("RAcl", "RAcaseal");
+        map.insert("FOmr", "FOmar");
+        map.insert("FOml", "FOmarl");
+        map.insert("FOnm", "FOnewm");
+        map.insert("FOnl", "FOnewearl");
+        map
+    }
+
+    /// (Power/Mind/Evade/Def/Luck combined, HP, TP) material limits per class.
+    pub fn material_limits() -> HashMap<&'static str, (u16, u8, u8)> {
+        let mut map = HashMap::new();
+        map.insert("HUmar", (250, 125, 125));
+        map.insert("Hunewearl", (150, 125, 125));
+        map.insert("HUcast", (150, 125, 0));
+        map.insert("RAmar", (250, 125, 125));
+        map.insert("RAcast", (150, 125, 0));
+        map.insert("RAcaseal", (150, 125, 0));
+        map.insert("FOmarl", (250, 125, 125));
+        map.insert("FOnewm", (150, 125, 125));
+        map.insert("FOnewearl", (150, 125, 125));
+        map.insert("HUcaseal", (150, 125, 0));
+        map.insert("FOmar", (250, 125, 125));
+        map.insert("RAmarl", (250, 125, 125));
+        map
+    }
+
+    /// Highest technique level each class can learn, from the max tech level table in
+    /// the BB ItemPMT.prs (the one newserv loads in its ItemParameterTable). Per-tech
+    /// exceptions are handled by `tech_level_cap`. Androids cannot learn techniques.
+    pub fn tech_limits() -> HashMap<&'static str, u8> {
+        let mut map = HashMap::new();
+        map.insert("HUmar", 15);
+        map.insert("Hunewearl", 20);
+        map.insert("HUcast", 0);
+        map.insert("RAmar", 15);
+        map.insert("RAcast", 0);
+        map.insert("RAcaseal", 0);
+        map.insert("FOmarl", 30);
+        map.insert("FOnewm", 30);
+        map.insert("FOnewearl", 30);
+        map.insert("HUcaseal", 0);
+        map.insert("FOmar", 30);
+        map.insert("RAmarl", 20);
+        map
+    }
+
+    /// Evolution stage and class line of the mags whose form gives them away. Level 50+
+    /// forms depend on section ID as well and are left out.
+    pub fn mag_evolutions() -> HashMap<u8, (u8, Option<MagClass>)> {
+        let mut map = HashMap::new();
+        map.insert(0x00, (0, None));
+        map.insert(0x01, (1, Some(MagClass::Hunter)));
+        map.insert(0x0D, (1, Some(MagClass::Ranger)));
+        map.insert(0x19, (1, Some(MagClass::Force)));
+        map.insert(0x0E, (2, Some(MagClass::Hunter)));
+        map.insert(0x0F, (2, Some(MagClass::Hunter)));
+        map.insert(0x04, (2, Some(MagClass::Hunter)));
+        map.insert(0x02, (2, Some(MagClass::Ranger)));
+        map.insert(0x03, (2, Some(MagClass::Ranger)));
+        map.insert(0x0B, (2, Some(MagClass::Ranger)));
+        map.insert(0x1A, (2, Some(MagClass::Force)));
+        map.insert(0x1B, (2, Some(MagClass::Force)));
+        map.insert(0x14, (2, Some(MagClass::Force)));
+        map
+    }
+
+    /// Items that count kills, and the kills they need before they unseal.
+    pub fn kill_counter_items() -> HashMap<u32, u16> {
+        let mut map = HashMap::new();
+        map.insert(0x003300, 23000); // SEALED J-SWORD
+        map.insert(0x00AB00, 10000); // LAME D'ARGENT
+        map.insert(0x01034D, 10000); // LIMITER
+        map.insert(0x01034F, 10000); // SWORDSMAN LORE
+        map
+    }
+
+    pub fn server(&self) -> ServerProfile {
+        self.server.unwrap_or(ServerProfile::VanillaBB)
+    }
+
+    /// Armor and units known to be limited to one class line. Anything missing from here has
+    /// no restriction data yet, rather than being known to be open to every class.
+    pub fn equip_restrictions() -> HashMap<u32, MagClass> {
+        let mut map = HashMap::new();
+        map.insert(0x010118, MagClass::Hunter); // HUNTER FIELD
+        map.insert(0x010119, MagClass::Ranger); // RANGER FIELD
+        map.insert(0x01011A, MagClass::Force); // FORCE FIELD
+        map.insert(0x01021B, MagClass::Force); // FORCE WALL
+        map.insert(0x01021C, MagClass::Ranger); // RANGER WALL
+        map.insert(0x01021D, MagClass::Hunter); // HUNTER WALL
+        map.insert(0x010351, MagClass::Ranger); // SMARTLINK
+        map
+    }
+
     pub fn mode(mode: String) -> u8 {
         match mode.as_str() {
             "NORMAL" => 0,
@@ -106,17 +215,16 @@ impl<'a> Config<'a> {
         }
     }
 
-    pub fn titles() -> HashMap<usize, &'static str> {
+    /// Quest flags the game sets as each story boss is beaten, in order, per episode, with one
+    /// flag per difficulty. Only the final bosses are documented, as the flags newserv watches
+    /// for to send the boss drop request (ReceiveSubcommands.cc): Dark Falz sets 0x35 on Normal,
+    /// where he has no third form, and 0x37 otherwise, Olga Flow sets 0x57. Earlier bosses and
+    /// Episode 4 have no known flags yet.
+    pub fn story_flags() -> HashMap<u8, Vec<[u16; 4]>> {
         let mut map = HashMap::new();
-        map.insert(1, "Ra-GOU");
-        map.insert(2, "Gi-GOU");
-        map.insert(3, "Bu-GOU");
-        map.insert(4, "Ra-ZAN");
-        map.insert(5, "Gi-ZAN");
-        map.insert(6, "Bu-ZAN");
-        map.insert(7, "Ra-EI");
-        map.insert(8, "Gi-EI");
-        map.insert(9, "Bu-EI");
+        map.insert(1, vec![[0x0035, 0x0037, 0x0037, 0x0037]]);
+        map.insert(2, vec![[0x0057, 0x0057, 0x0057, 0x0057]]);
+        map.insert(4, vec![]);
         map
     }
 
@@ -227,32 +335,116 @@ impl<'a> Config<'a> {
         map
     }
 
-    pub fn init(lang: String) -> Self {
-        match lang.as_str() {
-            "JA" => Config {
-                lang: Some(lang),
-                item_codes: Some(item_codes_ja()),
-                weapon_special_codes: Some(weapon_special_codes_ja()),
-                rare_weapon_special_codes: Some(rare_weapon_special_codes_ja()),
-                srank_special_codes: Some(srank_special_codes_ja()),
-                frames: Some(frames_ja()),
-                barriers: Some(barriers_ja()),
-                tech_codes: Some(tech_codes_ja()),
-                pbs: Some(pbs_ja()),
-                mag_color_codes: Some(mag_color_codes_ja()),
-            },
-            _ => Config {
-                lang: Some(lang),
-                item_codes: Some(item_codes()),
-                weapon_special_codes: Some(weapon_special_codes()),
-                rare_weapon_special_codes: Some(rare_weapon_special_codes()),
-                srank_special_codes: Some(srank_special_codes()),
-                frames: Some(frames()),
-                barriers: Some(barriers()),
-                tech_codes: Some(tech_codes()),
-                pbs: Some(pbs()),
-                mag_color_codes: Some(mag_color_codes()),
-            },
+    /// Builds the tables for a language and server. Japanese and English tables are built in,
+    /// any other locale starts from English and takes whatever its language pack translates.
+    /// Item names and packs come from the data files in `tables`, which are validated here so
+    /// a broken override fails before any parsing.
+    pub fn init(lang: String, server: ServerProfile, tables: &ItemTablePaths) -> Result<Self, ParseError> {
+        let ja = lang == "JA";
+        let mut config = if ja {
+            Config {
+                lang: Some(lang.clone()),
+                item_codes: Some(load_item_codes(&lang, tables)?),
+                weapon_special_codes: Some(owned(weapon_special_codes_ja())),
+                rare_weapon_special_codes: Some(owned(rare_weapon_special_codes_ja())),
+                srank_special_codes: Some(owned(srank_special_codes_ja())),
+                tech_codes: Some(owned(tech_codes_ja())),
+                mag_color_codes: Some(owned_colors(mag_color_codes_ja())),
+                photon_blasts: Some(owned(photon_blasts_ja())),
+                meseta_name: Some(String::from("メセタ")),
+                class_names: Some(default_names(Config::classes())),
+                section_id_names: Some(default_names(Config::section_ids())),
+                quest_names: Some(default_quest_names()),
+                server: Some(server),
+                server_items: Some(server.items()),
+            }
+        } else {
+            Config {
+                lang: Some(lang.clone()),
+                item_codes: Some(load_item_codes(&lang, tables)?),
+                weapon_special_codes: Some(owned(weapon_special_codes())),
+                rare_weapon_special_codes: Some(owned(rare_weapon_special_codes())),
+                srank_special_codes: Some(owned(srank_special_codes())),
+                tech_codes: Some(owned(tech_codes())),
+                mag_color_codes: Some(owned_colors(mag_color_codes())),
+                photon_blasts: Some(owned(photon_blasts())),
+                meseta_name: Some(String::from("MESETA")),
+                class_names: Some(default_names(Config::classes())),
+                section_id_names: Some(default_names(Config::section_ids())),
+                quest_names: Some(default_quest_names()),
+                server: Some(server),
+                server_items: Some(server.items()),
+            }
+        };
+
+        config.apply_language_pack(load_language_pack(&lang, tables)?);
+
+        Ok(config)
+    }
+
+    fn apply_language_pack(&mut self, pack: LanguagePack) {
+        if pack.meseta.is_some() {
+            self.meseta_name = pack.meseta;
         }
+
+        extend(&mut self.weapon_special_codes, pack.weapon_specials);
+        extend(&mut self.rare_weapon_special_codes, pack.rare_weapon_specials);
+        extend(&mut self.srank_special_codes, pack.srank_specials);
+        extend(&mut self.tech_codes, pack.techs);
+        extend(&mut self.photon_blasts, pack.photon_blasts);
+        extend(&mut self.class_names, pack.classes);
+        extend(&mut self.section_id_names, pack.section_ids);
+        extend(&mut self.quest_names, pack.quests);
+
+        if let Some(colors) = self.mag_color_codes.as_mut() {
+            for (code, name) in pack.mag_colors {
+                if let Some(color) = colors.get_mut(&code) {
+                    color.1 = name;
+                }
+            }
+        }
+    }
+
+    pub fn class_name(&self, class: &str) -> String {
+        localized_name(&self.class_names, class)
+    }
+
+    pub fn section_id_name(&self, section_id: &str) -> String {
+        localized_name(&self.section_id_names, section_id)
+    }
+
+    /// Name of the quest a clear flag belongs to, `None` for flags the quest table doesn't know.
+    pub fn quest_name(&self, flag: u16) -> Option<String> {
+        self.quest_names.as_ref().and_then(|names| names.get(&flag)).cloned()
+    }
+}
+
+fn owned<K: Eq + Hash>(map: HashMap<K, &str>) -> HashMap<K, String> {
+    map.into_iter().map(|(key, name)| (key, String::from(name))).collect()
+}
+
+fn owned_colors(map: HashMap<u8, (&'static str, &'static str)>) -> HashMap<u8, (&'static str, String)> {
+    map.into_iter().map(|(key, (rgb, name))| (key, (rgb, String::from(name)))).collect()
+}
+
+fn default_names(map: HashMap<u8, &str>) -> HashMap<String, String> {
+    map.into_values().map(|name| (String::from(name), String::from(name))).collect()
+}
+
+fn default_quest_names() -> HashMap<u16, String> {
+    quest_table().into_iter().map(|(flag, (_, name))| (flag, String::from(name))).collect()
+}
+
+fn extend<K: Eq + Hash>(map: &mut Option<HashMap<K, String>>, entries: HashMap<K, String>) {
+    if let Some(map) = map.as_mut() {
+        map.extend(entries);
     }
-}
\ No newline at end of file
+}
+
+fn localized_name(names: &Option<HashMap<String, String>>, name: &str) -> String {
+    names
+        .as_ref()
+        .and_then(|names| names.get(name))
+        .cloned()
+        .unwrap_or_else(|| String::from(name))
+}
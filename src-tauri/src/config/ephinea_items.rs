@@ -0,0 +1,21 @@
+use std::collections::HashMap;
+
+/// English name, Japanese name, item type (see `Config::item_type`) and the largest stack the
+/// server allows, 1 for items that never stack.
+pub type ServerItemRow = (&'static str, &'static str, u32, u8);
+
+pub fn ephinea_items() -> HashMap<u32, ServerItemRow> {
+    let mut map = HashMap::new();
+    map.insert(0x031005, ("Photon Booster", "フォトンブースター", 7, 10));
+    map.insert(0x031006, ("Meseta Booster", "メセタブースター", 7, 10));
+    map.insert(0x031007, ("Experience Booster", "経験値ブースター", 7, 10));
+    map.insert(0x031103, ("Book of KATANA4", "カタナの書4", 9, 1));
+    map.insert(0x031214, ("Ephinea Anniversary Badge", "エフィネア記念バッジ", 9, 99));
+    map.insert(0x031215, ("Event Ticket", "イベントチケット", 9, 99));
+    map.insert(0x031503, ("Halloween Cookie", "ハロウィンクッキー", 7, 99));
+    map.insert(0x031504, ("Coal", "石炭", 7, 99));
+    map.insert(0x031505, ("Event Egg", "イベントエッグ", 7, 99));
+    map.insert(0x031506, ("Valentine Heart", "バレンタインハート", 7, 99));
+    map.insert(0x031810, ("Cell of MAG 0508", "マグ細胞0508", 9, 1));
+    map
+}
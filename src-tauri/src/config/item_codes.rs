@@ -601,233 +601,6 @@ pub fn specials_list() -> Vec<&'static str> {
     ]
 }
 
-pub fn frames() -> HashMap<&'static str, [i32; 2]> {
-    let mut additions = HashMap::new();
-    additions.insert("Frame", [2, 2]);
-    additions.insert("Armor", [2, 2]);
-    additions.insert("Psy Armor", [3, 2]);
-    additions.insert("Giga Frame", [4, 2]);
-    additions.insert("Soul Frame", [4, 2]);
-    additions.insert("Cross Armor", [4, 2]);
-    additions.insert("Solid Frame", [4, 2]);
-    additions.insert("Brave Armor", [4, 2]);
-    additions.insert("Hyper Frame", [4, 2]);
-    additions.insert("Grand Armor", [4, 2]);
-    additions.insert("Shock Frame", [4, 2]);
-    additions.insert("King's Frame", [4, 2]);
-    additions.insert("Dragon Frame", [4, 2]);
-    additions.insert("Absorb Armor", [4, 2]);
-    additions.insert("Protect Frame", [4, 2]);
-    additions.insert("General Armor", [4, 2]);
-    additions.insert("Perfect Frame", [4, 2]);
-    additions.insert("Valiant Frame", [4, 2]);
-    additions.insert("Imperial Armor", [4, 2]);
-    additions.insert("Holiness Armor", [4, 2]);
-    additions.insert("Guardian Armor", [4, 2]);
-    additions.insert("Divinity Armor", [4, 2]);
-    additions.insert("Ultimate Frame", [4, 2]);
-    additions.insert("Celestial Armor", [10, 4]);
-    additions.insert("HUNTER FIELD", [8, 8]);
-    additions.insert("RANGER FIELD", [8, 8]);
-    additions.insert("FORCE FIELD", [8, 8]);
-    additions.insert("REVIVAL GARMENT", [5, 10]);
-    additions.insert("SPIRIT GARMENT", [7, 5]);
-    additions.insert("STINK FRAME", [85, 85]);
-    additions.insert("D-PARTS ver1.01", [10, 7]);
-    additions.insert("D-PARTS ver2.10", [10, 8]);
-    additions.insert("PARASITE WEAR:De Rol", [0, 0]);
-    additions.insert("PARASITE WEAR:Nelgal", [0, 0]);
-    additions.insert("PARASITE WEAR:Vajulla", [0, 0]);
-    additions.insert("SENSE PLATE", [8, 8]);
-    additions.insert("GRAVITON PLATE", [8, 0]);
-    additions.insert("ATTRIBUTE PLATE", [8, 8]);
-    additions.insert("FLOWEN'S FRAME", [10, 10]);
-    additions.insert("CUSTOM FRAME ver.OO", [10, 10]);
-    additions.insert("DB'S ARMOR", [10, 10]);
-    additions.insert("GUARD WAVE", [50, 20]);
-    additions.insert("DF FIELD", [50, 20]);
-    additions.insert("LUMINOUS FIELD", [50, 20]);
-    additions.insert("CHU CHU FEVER", [0, 0]);
-    additions.insert("LOVE HEART", [50, 20]);
-    additions.insert("FLAME GARMENT", [50, 20]);
-    additions.insert("VIRUS ARMOR:Lafuteria", [0, 0]);
-    additions.insert("BRIGHTNESS CIRCLE", [50, 20]);
-    additions.insert("AURA FIELD", [50, 20]);
-    additions.insert("ELECTRO FRAME", [50, 20]);
-    additions.insert("SACRED CLOTH", [50, 20]);
-    additions.insert("SMOKING PLATE", [50, 20]);
-    additions.insert("STAR CUIRASS", [30, 0]);
-    additions.insert("BLACK HOUND CUIRASS", [0, 0]);
-    additions.insert("MORNING PRAYER", [10, 20]);
-    additions.insert("BLACK ODOSHI DOMARU", [10, 10]);
-    additions.insert("RED ODOSHI DOMARU", [10, 10]);
-    additions.insert("BLACK ODOSHI RED NIMAIDOU", [10, 10]);
-    additions.insert("BLUE ODOSHI VIOLET NIMAIDOU", [10, 10]);
-    additions.insert("DIRTY LIFEJACKET", [0, 0]);
-    additions.insert("KROE'S SWEATER", [0, 0]);
-    additions.insert("WEDDING DRESS", [0, 0]);
-    additions.insert("SONICTEAM ARMOR", [0, 0]);
-    additions.insert("RED COAT", [10, 10]);
-    additions.insert("THIRTEEN", [8, 8]);
-    additions.insert("MOTHER GARB", [15, 5]);
-    additions.insert("MOTHER GARB+", [15, 5]);
-    additions.insert("DRESS PLATE", [0, 0]);
-    additions.insert("SWEETHEART", [50, 20]);
-    additions.insert("IGNITION CLOAK", [8, 8]);
-    additions.insert("CONGEAL CLOAK", [8, 8]);
-    additions.insert("TEMPEST CLOAK", [8, 8]);
-    additions.insert("CURSED CLOAK", [8, 8]);
-    additions.insert("SELECT CLOAK", [8, 8]);
-    additions.insert("SPIRIT CUIRASS", [7, 5]);
-    additions.insert("REVIVAL CUIRASS", [5, 10]);
-    additions.insert("ALLIANCE UNIFORM", [12, 0]);
-    additions.insert("OFFICER UNIFORM", [14, 0]);
-    additions.insert("COMMANDER UNIFORM", [16, 0]);
-    additions.insert("CRIMSON COAT", [12, 12]);
-    additions.insert("INFANTRY GEAR", [12, 8]);
-    additions.insert("LIEUTENANT GEAR", [18, 16]);
-    additions.insert("INFANTRY MANTLE", [10, 10]);
-    additions.insert("LIEUTENANT MANTLE", [21, 18]);
-    additions.insert("UNION FIELD", [0, 0]);
-    additions.insert("SAMURAI ARMOR", [0, 0]);
-    additions.insert("STEALTH SUIT", [0, 25]);
-    additions
-}
-
-pub fn barriers() -> HashMap<&'static str, [i32; 2]> {
-    let mut additions = HashMap::new();
-    additions.insert("Barrier", [5, 5]);
-    additions.insert("Shield", [5, 5]);
-    additions.insert("Core Shield", [5, 5]);
-    additions.insert("Giga Shield", [5, 5]);
-    additions.insert("Soul Barrier", [5, 5]);
-    additions.insert("Hard Shield", [5, 5]);
-    additions.insert("Brave Barrier", [5, 5]);
-    additions.insert("Solid Shield", [5, 5]);
-    additions.insert("Flame Barrier", [5, 5]);
-    additions.insert("Plasma Barrier", [5, 5]);
-    additions.insert("Freeze Barrier", [5, 5]);
-    additions.insert("Psychic Barrier", [5, 5]);
-    additions.insert("General Shield", [5, 5]);
-    additions.insert("Protect Barrier", [5, 5]);
-    additions.insert("Glorious Shield", [5, 5]);
-    additions.insert("Imperial Barrier", [5, 5]);
-    additions.insert("Guardian Shield", [5, 5]);
-    additions.insert("Divinity Barrier", [5, 5]);
-    additions.insert("Ultimate Shield", [5, 5]);
-    additions.insert("Spiritual Shield", [5, 5]);
-    additions.insert("Celestial Shield", [5, 5]);
-    additions.insert("INVISIBLE GUARD", [8, 8]);
-    additions.insert("SACRED GUARD", [8, 8]);
-    additions.insert("S-PARTS ver1.16", [8, 8]);
-    additions.insert("S-PARTS ver2.01", [7, 7]);
-    additions.insert("LIGHT RELIEF", [7, 7]);
-    additions.insert("SHIELD OF DELSABER", [7, 7]);
-    additions.insert("FORCE WALL", [10, 10]);
-    additions.insert("RANGER WALL", [10, 10]);
-    additions.insert("HUNTER WALL", [10, 10]);
-    additions.insert("ATTRIBUTE WALL", [10, 10]);
-    additions.insert("SECRET GEAR", [10, 10]);
-    additions.insert("COMBAT GEAR", [0, 0]);
-    additions.insert("PROTO REGENE GEAR", [7, 7]);
-    additions.insert("REGENERATE GEAR", [7, 7]);
-    additions.insert("REGENE GEAR ADV.", [7, 7]);
-    additions.insert("FLOWEN'S SHIELD", [10, 10]);
-    additions.insert("CUSTOM BARRIER ver.OO", [10, 10]);
-    additions.insert("DB'S SHIELD", [10, 10]);
-    additions.insert("RED RING", [85, 25]);
-    additions.insert("TRIPOLIC SHIELD", [50, 15]);
-    additions.insert("STANDSTILL SHIELD", [50, 15]);
-    additions.insert("SAFETY HEART", [50, 15]);
-    additions.insert("KASAMI BRACER", [50, 15]);
-    additions.insert("GODS SHIELD SUZAKU", [0, 0]);
-    additions.insert("GODS SHIELD GENBU", [0, 0]);
-    additions.insert("GODS SHIELD BYAKKO", [0, 0]);
-    additions.insert("GODS SHIELD SEIRYU", [0, 0]);
-    additions.insert("HUNTER'S SHELL", [50, 15]);
-    additions.insert("RICO'S GLASSES", [0, 0]);
-    additions.insert("RICO'S EARRING", [85, 25]);
-    additions.insert("SECURE FEET", [50, 15]);
-    additions.insert("RESTA MERGE", [5, 5]);
-    additions.insert("ANTI MERGE", [5, 5]);
-    additions.insert("SHIFTA MERGE", [5, 5]);
-    additions.insert("DEBAND MERGE", [5, 5]);
-    additions.insert("FOIE MERGE", [5, 5]);
-    additions.insert("GIFOIE MERGE", [5, 5]);
-    additions.insert("RAFOIE MERGE", [5, 5]);
-    additions.insert("RED MERGE", [5, 5]);
-    additions.insert("BARTA MERGE", [5, 5]);
-    additions.insert("GIBARTA MERGE", [5, 5]);
-    additions.insert("RABARTA MERGE", [5, 5]);
-    additions.insert("BLUE MERGE", [5, 5]);
-    additions.insert("ZONDE MERGE", [5, 5]);
-    additions.insert("GIZONDE MERGE", [5, 5]);
-    additions.insert("RAZONDE MERGE", [5, 5]);
-    additions.insert("YELLOW MERGE", [5, 5]);
-    additions.insert("RECOVERY BARRIER", [5, 5]);
-    additions.insert("ASSIST BARRIER", [5, 5]);
-    additions.insert("RED BARRIER", [5, 5]);
-    additions.insert("BLUE BARRIER", [5, 5]);
-    additions.insert("YELLOW BARRIER", [5, 5]);
-    additions.insert("WEAPONS GOLD SHIELD", [0, 0]);
-    additions.insert("BLACK GEAR", [5, 5]);
-    additions.insert("WORKS GUARD", [5, 5]);
-    additions.insert("RAGOL RING", [0, 0]);
-    additions.insert("BLUE RING", [5, 5]);
-    additions.insert("GREEN RING", [5, 5]);
-    additions.insert("YELLOW RING", [5, 5]);
-    additions.insert("PURPLE RING", [5, 5]);
-    additions.insert("WHITE RING", [5, 5]);
-    additions.insert("BLACK RING", [5, 5]);
-    additions.insert("WEAPONS SILVER SHIELD", [0, 0]);
-    additions.insert("WEAPONS COPPER SHIELD", [0, 0]);
-    additions.insert("GRATIA", [20, 15]);
-    additions.insert("TRIPOLIC REFLECTOR", [50, 15]);
-    additions.insert("STRIKER PLUS", [10, 5]);
-    additions.insert("REGENERATE GEAR B.P.", [7, 7]);
-    additions.insert("RUPIKA", [10, 20]);
-    additions.insert("YATA MIRROR", [20, 25]);
-    additions.insert("BUNNY EARS", [0, 0]);
-    additions.insert("CAT EARS", [0, 0]);
-    additions.insert("THREE SEALS", [3, 3]);
-    additions.insert("GOD'S SHIELD \"KOURYU\"", [0, 0]);
-    additions.insert("DF SHIELD", [85, 25]);
-    additions.insert("FROM THE DEPTHS", [0, 0]);
-    additions.insert("DE ROL LE SHIELD", [75, 75]);
-    additions.insert("HONEYCOMB REFLECTOR", [10, 10]);
-    additions.insert("EPSIGUARD", [75, 75]);
-    additions.insert("ANGEL RING", [0, 0]);
-    additions.insert("UNION GUARD", [0, 0]);
-    additions.insert("STINK SHIELD", [75, 75]);
-    additions.insert("UNKNOWN_B", [0, 0]);
-    additions.insert("GENPEI", [0, 0]);
-    additions.insert("Anti-Light Ring", [0, 0]);
-    additions.insert("Anti-Dark Ring", [0, 0]);
-    additions.insert("RED RING* - Angel Plating", [85, 25]);
-    additions.insert("RED RING* - Black Paint", [85, 25]);
-    additions.insert("RED RING* - Blue Paint", [85, 25]);
-    additions.insert("RED RING* - Bunny Plating", [85, 25]);
-    additions.insert("RED RING* - Cat Plating", [85, 25]);
-    additions.insert("RED RING* - Chartreuse Paint", [85, 25]);
-    additions.insert("RED RING* - Cyan Paint", [85, 25]);
-    additions.insert("RED RING* - Deep Plating", [85, 25]);
-    additions.insert("RED RING* - Delsaber Plating", [85, 25]);
-    additions.insert("RED RING* - Epsilon Plating", [85, 25]);
-    additions.insert("RED RING* - Green Paint", [85, 25]);
-    additions.insert("RED RING* - Heart Plating", [85, 25]);
-    additions.insert("RED RING* - Honeycomb Plating", [85, 25]);
-    additions.insert("RED RING* - Onyx Paint", [85, 25]);
-    additions.insert("RED RING* - Orange Paint", [85, 25]);
-    additions.insert("RED RING* - Purple Paint", [85, 25]);
-    additions.insert("RED RING* - Rose Paint", [85, 25]);
-    additions.insert("RED RING* - Standstill Plating", [85, 25]);
-    additions.insert("RED RING* - Ultramarine Paint", [85, 25]);
-    additions.insert("RED RING* - Violet Paint", [85, 25]);
-    additions.insert("RED RING* - White Paint", [85, 25]);
-    additions.insert("RED RING* - Yellow Paint", [85, 25]);
-    additions
-}
-
 pub fn tech_codes() -> HashMap<u8, &'static str> {
     let mut codes = HashMap::new();
     codes.insert(0x00, "Foie");
@@ -852,1791 +625,13 @@ pub fn tech_codes() -> HashMap<u8, &'static str> {
     codes
 }
 
-pub fn pbs() -> HashMap<&'static str, [&'static str; 3]> {
+pub fn photon_blasts() -> HashMap<u8, &'static str> {
     let mut pbs = HashMap::new();
-    pbs.insert("0000", ["", "", ""]);
-    pbs.insert("0001", ["Farlla", "", ""]);
-    pbs.insert("0101", ["Estlla", "", ""]);
-    pbs.insert("0401", ["Leilla", "", ""]);
-    pbs.insert("1003", ["Farlla", "Golla", ""]);
-    pbs.insert("5007", ["Farlla", "Golla", "Pilla"]);
-    pbs.insert("D007", ["Farlla", "Golla", "Mylla & Youlla"]);
-    pbs.insert("1007", ["Farlla", "Golla", "Estlla"]);
-    pbs.insert("1803", ["Farlla", "Pilla", ""]);
-    pbs.insert("5807", ["Farlla", "Pilla", "Golla"]);
-    pbs.insert("D807", ["Farlla", "Pilla", "Mylla & Youlla"]);
-    pbs.insert("1807", ["Farlla", "Pilla", "Estlla"]);
-    pbs.insert("2803", ["Farlla", "Mylla & Youlla", ""]);
-    pbs.insert("6807", ["Farlla", "Mylla & Youlla", "Golla"]);
-    pbs.insert("A807", ["Farlla", "Mylla & Youlla", "Pilla"]);
-    pbs.insert("2807", ["Farlla", "Mylla & Youlla", "Estlla"]);
-    pbs.insert("1103", ["Estlla", "Golla", ""]);
-    pbs.insert("5107", ["Estlla", "Golla", "Pilla"]);
-    pbs.insert("D107", ["Estlla", "Golla", "Mylla & Youlla"]);
-    pbs.insert("1107", ["Estlla", "Golla", "Farlla"]);
-    pbs.insert("1903", ["Estlla", "Pilla", ""]);
-    pbs.insert("5907", ["Estlla", "Pilla", "Golla"]);
-    pbs.insert("D907", ["Estlla", "Pilla", "Mylla & Youlla"]);
-    pbs.insert("1907", ["Estlla", "Pilla", "Farlla"]);
-    pbs.insert("2903", ["Estlla", "Mylla & Youlla", ""]);
-    pbs.insert("6907", ["Estlla", "Mylla & Youlla", "Golla"]);
-    pbs.insert("A907", ["Estlla", "Mylla & Youlla", "Pilla"]);
-    pbs.insert("2907", ["Estlla", "Mylla & Youlla", "Farlla"]);
-    pbs.insert("1403", ["Leilla", "Golla", ""]);
-    pbs.insert("9407", ["Leilla", "Golla", "Pilla"]);
-    pbs.insert("D407", ["Leilla", "Golla", "Mylla & Youlla"]);
-    pbs.insert("1407", ["Leilla", "Golla", "Farlla"]);
-    pbs.insert("5407", ["Leilla", "Golla", "Estlla"]);
-    pbs.insert("1C03", ["Leilla", "Pilla", ""]);
-    pbs.insert("9C07", ["Leilla", "Pilla", "Golla"]);
-    pbs.insert("DC07", ["Leilla", "Pilla", "Mylla & Youlla"]);
-    pbs.insert("1C07", ["Leilla", "Pilla", "Farlla"]);
-    pbs.insert("5C07", ["Leilla", "Pilla", "Estlla"]);
-    pbs.insert("2C03", ["Leilla", "Mylla & Youlla", ""]);
-    pbs.insert("AC07", ["Leilla", "Mylla & Youlla", "Golla"]);
-    pbs.insert("EC07", ["Leilla", "Mylla & Youlla", "Pilla"]);
-    pbs.insert("2C07", ["Leilla", "Mylla & Youlla", "Golla"]);
-    pbs.insert("6C07", ["Leilla", "Mylla & Youlla", "Pilla"]);
+    pbs.insert(0x00, "Farlla");
+    pbs.insert(0x01, "Estlla");
+    pbs.insert(0x02, "Golla");
+    pbs.insert(0x03, "Pilla");
+    pbs.insert(0x04, "Leilla");
+    pbs.insert(0x05, "Mylla & Youlla");
     pbs
 }
-
-pub fn item_codes() -> HashMap<u32, &'static str> {
-    let mut codes = HashMap::new();
-    codes.insert(0x000000, "Saber");
-    codes.insert(0x000100, "Saber");
-    codes.insert(0x000101, "Brand");
-    codes.insert(0x000102, "Buster");
-    codes.insert(0x000103, "Pallasch");
-    codes.insert(0x000104, "Gladius");
-    codes.insert(0x000105, "DB'S SABER");
-    codes.insert(0x000106, "KALADBOLG");
-    codes.insert(0x000107, "DURANDAL");
-    codes.insert(0x000108, "GALATINE");
-    codes.insert(0x000200, "Sword");
-    codes.insert(0x000201, "Gigush");
-    codes.insert(0x000202, "Breaker");
-    codes.insert(0x000203, "Claymore");
-    codes.insert(0x000204, "Calibur");
-    codes.insert(0x000205, "FLOWEN'S SWORD");
-    codes.insert(0x000206, "LAST SURVIVOR");
-    codes.insert(0x000207, "DRAGON SLAYER");
-    codes.insert(0x000300, "Dagger");
-    codes.insert(0x000301, "Knife");
-    codes.insert(0x000302, "Blade");
-    codes.insert(0x000303, "Edge");
-    codes.insert(0x000304, "Ripper");
-    codes.insert(0x000305, "BLADE DANCE");
-    codes.insert(0x000306, "BLOODY ART");
-    codes.insert(0x000307, "CROSS SCAR");
-    codes.insert(0x000308, "ZERO DIVIDE");
-    codes.insert(0x000309, "TWO KAMUI");
-    codes.insert(0x000400, "Partisan");
-    codes.insert(0x000401, "Halbert");
-    codes.insert(0x000402, "Glaive");
-    codes.insert(0x000403, "Berdys");
-    codes.insert(0x000404, "Gungnir");
-    codes.insert(0x000405, "BRIONAC");
-    codes.insert(0x000406, "VJAYA");
-    codes.insert(0x000407, "GAE BOLG");
-    codes.insert(0x000408, "ASTERON BELT");
-    codes.insert(0x000500, "Slicer");
-    codes.insert(0x000501, "Spinner");
-    codes.insert(0x000502, "Cutter");
-    codes.insert(0x000503, "Sawcer");
-    codes.insert(0x000504, "Diska");
-    codes.insert(0x000505, "SLICER OF ASSASSIN");
-    codes.insert(0x000506, "DISKA OF LIBERATOR");
-    codes.insert(0x000507, "DISKA OF BRAVEMAN");
-    codes.insert(0x000508, "IZMAELA");
-    codes.insert(0x000600, "Handgun");
-    codes.insert(0x000601, "Autogun");
-    codes.insert(0x000602, "Lockgun");
-    codes.insert(0x000603, "Railgun");
-    codes.insert(0x000604, "Raygun");
-    codes.insert(0x000605, "VARISTA");
-    codes.insert(0x000606, "CUSTOM RAY ver.OO");
-    codes.insert(0x000607, "BRAVACE");
-    codes.insert(0x000608, "TENSION BLASTER");
-    codes.insert(0x000700, "Rifle");
-    codes.insert(0x000701, "Sniper");
-    codes.insert(0x000702, "Blaster");
-    codes.insert(0x000703, "Beam");
-    codes.insert(0x000704, "Laser");
-    codes.insert(0x000705, "VISK-235W");
-    codes.insert(0x000706, "WALS-MK2");
-    codes.insert(0x000707, "JUSTY-23ST");
-    codes.insert(0x000708, "RIANOV 303SNR");
-    codes.insert(0x000709, "RIANOV 303SNR-1");
-    codes.insert(0x00070A, "RIANOV 303SNR-2");
-    codes.insert(0x00070B, "RIANOV 303SNR-3");
-    codes.insert(0x00070C, "RIANOV 303SNR-4");
-    codes.insert(0x00070D, "RIANOV 303SNR-5");
-    codes.insert(0x000800, "Mechgun");
-    codes.insert(0x000801, "Assault");
-    codes.insert(0x000802, "Repeater");
-    codes.insert(0x000803, "Gatling");
-    codes.insert(0x000804, "Vulcan");
-    codes.insert(0x000805, "M&A60 VISE");
-    codes.insert(0x000806, "H&S25 JUSTICE");
-    codes.insert(0x000807, "L&K14 COMBAT");
-    codes.insert(0x000900, "Shot");
-    codes.insert(0x000901, "Spread");
-    codes.insert(0x000902, "Cannon");
-    codes.insert(0x000903, "Launcher");
-    codes.insert(0x000904, "Arms");
-    codes.insert(0x000905, "CRUSH BULLET");
-    codes.insert(0x000906, "METEOR SMASH");
-    codes.insert(0x000907, "FINAL IMPACT");
-    codes.insert(0x000A00, "Cane");
-    codes.insert(0x000A01, "Stick");
-    codes.insert(0x000A02, "Mace");
-    codes.insert(0x000A03, "Club");
-    codes.insert(0x000A04, "CLUB OF LACONIUM");
-    codes.insert(0x000A05, "MACE OF ADAMAN");
-    codes.insert(0x000A06, "CLUB OF ZUMIURAN");
-    codes.insert(0x000A07, "LOLLIPOP");
-    codes.insert(0x000B00, "Rod");
-    codes.insert(0x000B01, "Pole");
-    codes.insert(0x000B02, "Pillar");
-    codes.insert(0x000B03, "Striker");
-    codes.insert(0x000B04, "BATTLE VERGE");
-    codes.insert(0x000B05, "BRAVE HAMMER");
-    codes.insert(0x000B06, "ALIVE AQHU");
-    codes.insert(0x000B07, "VALKYRIE");
-    codes.insert(0x000C00, "Wand");
-    codes.insert(0x000C01, "Staff");
-    codes.insert(0x000C02, "Baton");
-    codes.insert(0x000C03, "Scepter");
-    codes.insert(0x000C04, "FIRE SCEPTER,AGNI");
-    codes.insert(0x000C05, "ICE STAFF,DAGON");
-    codes.insert(0x000C06, "STORM WAND,INDRA");
-    codes.insert(0x000C07, "EARTH WAND BROWNIE");
-    codes.insert(0x000D00, "PHOTON CLAW");
-    codes.insert(0x000D01, "SILENCE CLAW");
-    codes.insert(0x000D02, "NEI'S CLAW(FAKE)");
-    codes.insert(0x000D03, "PHOENIX CLAW");
-    codes.insert(0x000E00, "DOUBLE SABER");
-    codes.insert(0x000E01, "STAG CUTLERY");
-    codes.insert(0x000E02, "TWIN BRAND");
-    codes.insert(0x000F00, "BRAVE KNUCKLE");
-    codes.insert(0x000F01, "ANGRY FIST");
-    codes.insert(0x000F02, "GOD HAND");
-    codes.insert(0x000F03, "SONIC KNUCKLE");
-    codes.insert(0x000F04, "LOGiN");
-    codes.insert(0x001000, "OROTIAGITO");
-    codes.insert(0x001001, "AGITO 1975");
-    codes.insert(0x001002, "AGITO 1983");
-    codes.insert(0x001003, "AGITO 2001");
-    codes.insert(0x001004, "AGITO 1991");
-    codes.insert(0x001005, "AGITO 1977");
-    codes.insert(0x001006, "AGITO 1980");
-    codes.insert(0x001007, "RAIKIRI");
-    codes.insert(0x001100, "SOUL EATER");
-    codes.insert(0x001101, "SOUL BANISH");
-    codes.insert(0x001200, "SPREAD NEEDLE");
-    codes.insert(0x001300, "HOLY RAY");
-    codes.insert(0x001400, "INFERNO BAZOOKA");
-    codes.insert(0x001401, "RAMBLING MAY");
-    codes.insert(0x001402, "L&K38 COMBAT");
-    codes.insert(0x001500, "FLAME VISIT");
-    codes.insert(0x001501, "BURNING VISIT");
-    codes.insert(0x001600, "AKIKO'S FRYING PAN");
-    codes.insert(0x001700, "SORCERER'S CANE");
-    codes.insert(0x001800, "S-BEAT'S BLADE");
-    codes.insert(0x001900, "P-ARMS'S BLADE");
-    codes.insert(0x001A00, "DELSABER'S BUSTER");
-    codes.insert(0x001B00, "BRINGER'S RIFLE");
-    codes.insert(0x001C00, "EGG BLASTER");
-    codes.insert(0x001D00, "PSYCHO WAND");
-    codes.insert(0x001E00, "HEAVEN PUNISHER");
-    codes.insert(0x001F00, "LAVIS CANNON");
-    codes.insert(0x002000, "VICTOR AXE");
-    codes.insert(0x002001, "LACONIUM AXE");
-    codes.insert(0x002100, "CHAIN SAWD");
-    codes.insert(0x002200, "CADUCEUS");
-    codes.insert(0x002201, "MERCURIUS ROD");
-    codes.insert(0x002300, "STING TIP");
-    codes.insert(0x002400, "MAGICAL PIECE");
-    codes.insert(0x002500, "TECHNICAL CROZIER");
-    codes.insert(0x002600, "SUPPRESSED GUN");
-    codes.insert(0x002700, "ANCIENT SABER");
-    codes.insert(0x002800, "HARISEN BATTLE FAN");
-    codes.insert(0x002900, "YAMIGARASU");
-    codes.insert(0x002A00, "AKIKO'S WOK");
-    codes.insert(0x002B00, "TOY HAMMER");
-    codes.insert(0x002C00, "ELYSION");
-    codes.insert(0x002D00, "RED SABER");
-    codes.insert(0x002E00, "METEOR CUDGEL");
-    codes.insert(0x002F00, "MONKEY KING BAR");
-    codes.insert(0x002F01, "BLACK KING BAR");
-    codes.insert(0x003000, "DOUBLE CANNON");
-    codes.insert(0x003001, "GIRASOLE");
-    codes.insert(0x003100, "HUGE BATTLE FAN");
-    codes.insert(0x003200, "TSUMIKIRI J-SWORD");
-    codes.insert(0x003300, "SEALED J-SWORD");
-    codes.insert(0x003400, "RED SWORD");
-    codes.insert(0x003500, "CRAZY TUNE");
-    codes.insert(0x003600, "TWIN CHAKRAM");
-    codes.insert(0x003700, "WOK OF AKIKO'S SHOP");
-    codes.insert(0x003800, "LAVIS BLADE");
-    codes.insert(0x003900, "RED DAGGER");
-    codes.insert(0x003A00, "MADAM'S PARASOL");
-    codes.insert(0x003B00, "MADAM'S UMBRELLA");
-    codes.insert(0x003C00, "IMPERIAL PICK");
-    codes.insert(0x003D00, "BERDYSH");
-    codes.insert(0x003E00, "RED PARTISAN");
-    codes.insert(0x003F00, "FLIGHT CUTTER");
-    codes.insert(0x004000, "FLIGHT FAN");
-    codes.insert(0x004100, "RED SLICER");
-    codes.insert(0x004200, "HANDGUN,GULD");
-    codes.insert(0x004201, "MASTER RAVEN");
-    codes.insert(0x004300, "HANDGUN,MILLA");
-    codes.insert(0x004301, "LAST SWAN");
-    codes.insert(0x004400, "RED HANDGUN");
-    codes.insert(0x004500, "FROZEN SHOOTER");
-    codes.insert(0x004501, "SNOW QUEEN");
-    codes.insert(0x004600, "ANTI ANDROID RIFLE");
-    codes.insert(0x004700, "ROCKET PUNCH");
-    codes.insert(0x004800, "SAMBA MARACAS");
-    codes.insert(0x004900, "TWIN PSYCHOGUN");
-    codes.insert(0x004A00, "DRILL LAUNCHER");
-    codes.insert(0x004B00, "GULD MILLA");
-    codes.insert(0x004B01, "DUAL BIRD");
-    codes.insert(0x004C00, "RED MECHGUN");
-    codes.insert(0x004D00, "BELRA CANNON");
-    codes.insert(0x004E00, "PANZER FAUST");
-    codes.insert(0x004E01, "IRON FAUST");
-    codes.insert(0x004F00, "SUMMIT MOON");
-    codes.insert(0x005000, "WINDMILL");
-    codes.insert(0x005100, "EVIL CURST");
-    codes.insert(0x005200, "FLOWER CANE");
-    codes.insert(0x005300, "HILDEBEAR'S CANE");
-    codes.insert(0x005400, "HILDEBLUE'S CANE");
-    codes.insert(0x005500, "RABBIT WAND");
-    codes.insert(0x005600, "PLANTAIN LEAF");
-    codes.insert(0x005601, "FATSIA");
-    codes.insert(0x005700, "DEMONIC FORK");
-    codes.insert(0x005800, "STRIKER OF CHAO");
-    codes.insert(0x005900, "BROOM");
-    codes.insert(0x005A00, "PROPHETS OF MOTAV");
-    codes.insert(0x005B00, "THE SIGH OF A GOD");
-    codes.insert(0x005C00, "TWINKLE STAR");
-    codes.insert(0x005D00, "PLANTAIN FAN");
-    codes.insert(0x005E00, "TWIN BLAZE");
-    codes.insert(0x005F00, "MARINA'S BAG");
-    codes.insert(0x006000, "DRAGON'S CLAW");
-    codes.insert(0x006100, "PANTHER'S CLAW");
-    codes.insert(0x006200, "S-RED'S BLADE");
-    codes.insert(0x006300, "PLANTAIN HUGE FAN");
-    codes.insert(0x006400, "CHAMELEON SCYTHE");
-    codes.insert(0x006500, "YASMINKOV 3000R");
-    codes.insert(0x006600, "ANO RIFLE");
-    codes.insert(0x006700, "BARANZ LAUNCHER");
-    codes.insert(0x006800, "BRANCH OF PAKUPAKU");
-    codes.insert(0x006900, "HEART OF POUMN");
-    codes.insert(0x006A00, "YASMINKOV 2000H");
-    codes.insert(0x006B00, "YASMINKOV 7000V");
-    codes.insert(0x006C00, "YASMINKOV 9000M");
-    codes.insert(0x006D00, "MASER BEAM");
-    codes.insert(0x006D01, "POWER MASER");
-    codes.insert(0x006E00, "GAME MAGAZNE");
-    codes.insert(0x006E01, "LOGiN");
-    codes.insert(0x006F00, "FLOWER BOUQUET");
-    codes.insert(0x008900, "MUSASHI");
-    codes.insert(0x008901, "YAMATO");
-    codes.insert(0x008902, "ASUKA");
-    codes.insert(0x008903, "SANGE & YASHA");
-    codes.insert(0x008A00, "SANGE");
-    codes.insert(0x008A01, "YASHA");
-    codes.insert(0x008A02, "KAMUI");
-    codes.insert(0x008B00, "PHOTON LAUNCHER");
-    codes.insert(0x008B01, "GUILTY LIGHT");
-    codes.insert(0x008B02, "RED SCORPIO");
-    codes.insert(0x008B03, "PHONON MASER");
-    codes.insert(0x008C00, "TALIS");
-    codes.insert(0x008C01, "MAHU");
-    codes.insert(0x008C02, "HITOGATA");
-    codes.insert(0x008C03, "DANCING HITOGATA");
-    codes.insert(0x008C04, "KUNAI");
-    codes.insert(0x008D00, "NUG2000-BAZOOKA");
-    codes.insert(0x008E00, "S-BERILL'S HANDS #0");
-    codes.insert(0x008E01, "S-BERILL'S HANDS #1");
-    codes.insert(0x008F00, "FLOWEN'S SWORD 3060");
-    codes.insert(0x008F01, "FLOWEN'S SWORD 3064");
-    codes.insert(0x008F02, "FLOWEN'S SWORD 3067");
-    codes.insert(0x008F03, "FLOWEN'S SWORD 3073");
-    codes.insert(0x008F04, "FLOWEN'S SWORD 3077");
-    codes.insert(0x008F05, "FLOWEN'S SWORD 3082");
-    codes.insert(0x008F06, "FLOWEN'S SWORD 3083");
-    codes.insert(0x008F07, "FLOWEN'S SWORD 3084");
-    codes.insert(0x008F08, "FLOWEN'S SWORD 3079");
-    codes.insert(0x009000, "DB'S SABER 3062");
-    codes.insert(0x009001, "DB'S SABER 3067");
-    codes.insert(0x009002, "DB'S SABER 3069 Chris");
-    codes.insert(0x009003, "DB'S SABER 3064");
-    codes.insert(0x009004, "DB'S SABER 3069 Torato");
-    codes.insert(0x009005, "DB'S SABER 3073");
-    codes.insert(0x009006, "DB'S SABER 3070");
-    codes.insert(0x009007, "DB'S SABER 3075");
-    codes.insert(0x009008, "DB'S SABER 3077");
-    codes.insert(0x009100, "GI GUE BAZOOKA");
-    codes.insert(0x009200, "GUARDIANNA");
-    codes.insert(0x009300, "VIRIDIA CARD");
-    codes.insert(0x009301, "GREENILL CARD");
-    codes.insert(0x009302, "SKYLY CARD");
-    codes.insert(0x009303, "BLUEFULL CARD");
-    codes.insert(0x009304, "PURPLENUM CARD");
-    codes.insert(0x009305, "PINKAL CARD");
-    codes.insert(0x009306, "REDRIA CARD");
-    codes.insert(0x009307, "ORAN CARD");
-    codes.insert(0x009308, "YELLOWBOZE CARD");
-    codes.insert(0x009309, "WHITILL CARD");
-    codes.insert(0x009400, "MORNING GLORY");
-    codes.insert(0x009500, "PARTISAN of LIGHTNING");
-    codes.insert(0x009600, "GAL WIND");
-    codes.insert(0x009700, "ZANBA");
-    codes.insert(0x009800, "RIKA'S CLAW");
-    codes.insert(0x009900, "ANGEL HARP");
-    codes.insert(0x009A00, "DEMOLITION COMET");
-    codes.insert(0x009B00, "NEI'S CLAW(Genuine)");
-    codes.insert(0x009C00, "RAINBOW BATON");
-    codes.insert(0x009D00, "DARK FLOW");
-    codes.insert(0x009E00, "DARK METEOR");
-    codes.insert(0x009F00, "DARK BRIDGE");
-    codes.insert(0x00A000, "G-ASSASSIN'S SABERS");
-    codes.insert(0x00A100, "RAPPY'S FAN");
-    codes.insert(0x00A200, "BOOMA'S CLAW");
-    codes.insert(0x00A201, "GOBOOMA'S CLAW");
-    codes.insert(0x00A202, "GIGOBOOMA'S CLAW");
-    codes.insert(0x00A300, "RUBY BULLET");
-    codes.insert(0x00A400, "AMORE ROSE");
-    codes.insert(0x00AA00, "SLICER OF FANATIC");
-    codes.insert(0x00AB00, "LAME D'ARGENT");
-    codes.insert(0x00AC00, "EXCALIBUR");
-    codes.insert(0x00AD00, "RAGE DE FOUDRE");
-    codes.insert(0x00AD01, "RAGE DE GLACE");
-    codes.insert(0x00AD02, "RAGE D'AME");
-    codes.insert(0x00AD03, "RAGE DE FEU");
-    codes.insert(0x00AE00, "DAISY CHAIN");
-    codes.insert(0x00AF00, "OPHELIE SEIZE");
-    codes.insert(0x00B000, "MILLE MARTEAUX");
-    codes.insert(0x00B100, "LE COGNEUR");
-    codes.insert(0x00B200, "COMMANDER BLADE");
-    codes.insert(0x00B300, "VIVIENNE");
-    codes.insert(0x00B400, "KUSANAGI");
-    codes.insert(0x00B500, "SACRED DUSTER");
-    codes.insert(0x00B600, "GUREN");
-    codes.insert(0x00B700, "SHOUREN");
-    codes.insert(0x00B800, "JIZAI");
-    codes.insert(0x00B900, "FLAMBERGE");
-    codes.insert(0x00BA00, "YUNCHANG");
-    codes.insert(0x00BB00, "SNAKE SPIRE");
-    codes.insert(0x00BC00, "FLAPJACK FLAPPER");
-    codes.insert(0x00BD00, "GETSUGASAN");
-    codes.insert(0x00BE00, "MAGUWA");
-    codes.insert(0x00BF00, "HEAVEN STRIKER");
-    codes.insert(0x00C000, "CANNON ROUGE");
-    codes.insert(0x00C100, "METEOR ROUGE");
-    codes.insert(0x00C200, "SOLFERINO");
-    codes.insert(0x00C300, "CLIO");
-    codes.insert(0x00C400, "SIREN GLASS HAMMER");
-    codes.insert(0x00C500, "GLIDE DIVINE");
-    codes.insert(0x00C600, "SHICHISHITO");
-    codes.insert(0x00C700, "MURASAME");
-    codes.insert(0x00C800, "DAYLIGHT SCAR");
-    codes.insert(0x00C900, "DECALOG");
-    codes.insert(0x00CA00, "5TH ANNIV. BLADE");
-    codes.insert(0x00CB00, "TYRELL'S PARASOL");
-    codes.insert(0x00CC00, "AKIKO'S CLEAVER");
-    codes.insert(0x00CD00, "TANEGASHIMA");
-    codes.insert(0x00CE00, "TREE CLIPPERS");
-    codes.insert(0x00CF00, "NICE SHOT");
-    codes.insert(0x00D000, "UNKNOWN3");
-    codes.insert(0x00D100, "UNKNOWN4");
-    codes.insert(0x00D200, "ANO BAZOOKA");
-    codes.insert(0x00D300, "SYNTHESIZER");
-    codes.insert(0x00D400, "BAMBOO SPEAR");
-    codes.insert(0x00D500, "KAN'EI TSUHO");
-    codes.insert(0x00D600, "JITTE");
-    codes.insert(0x00D700, "BUTTERFLY NET");
-    codes.insert(0x00D800, "SYRINGE");
-    codes.insert(0x00D900, "BATTLEDORE");
-    codes.insert(0x00DA00, "RACKET");
-    codes.insert(0x00DB00, "HAMMER");
-    codes.insert(0x00DC00, "GREAT BOUQUET");
-    codes.insert(0x00DD00, "TypeSA/Saber");
-    codes.insert(0x00DE00, "TypeSL/Saber");
-    codes.insert(0x00DE01, "TypeSL/Slicer");
-    codes.insert(0x00DE02, "TypeSL/Claw");
-    codes.insert(0x00DE03, "TypeSL/Katana");
-    codes.insert(0x00DF00, "TypeJS/Saber");
-    codes.insert(0x00DF01, "TypeJS/Slicer");
-    codes.insert(0x00DF02, "TypeJS/J-Sword");
-    codes.insert(0x00E000, "TypeSW/Sword");
-    codes.insert(0x00E001, "TypeSW/Slicer");
-    codes.insert(0x00E002, "TypeSW/J-Sword");
-    codes.insert(0x00E100, "TypeRO/Sword");
-    codes.insert(0x00E101, "TypeRO/Halbert");
-    codes.insert(0x00E102, "TypeRO/ROD");
-    codes.insert(0x00E200, "TypeBL/BLADE");
-    codes.insert(0x00E300, "TypeKN/BLADE");
-    codes.insert(0x00E301, "TypeKN/CLAW");
-    codes.insert(0x00E400, "TypeHA/HALBERT");
-    codes.insert(0x00E401, "TypeHA/ROD");
-    codes.insert(0x00E500, "TypeDS/D.SABER");
-    codes.insert(0x00E501, "TypeDS/ROD");
-    codes.insert(0x00E502, "TypeDS/WAND");
-    codes.insert(0x00E600, "TypeCL/CLAW");
-    codes.insert(0x00E700, "TypeSS/SW");
-    codes.insert(0x00E800, "TypeGU/HANDGUN");
-    codes.insert(0x00E801, "TypeGU/MECHGUN");
-    codes.insert(0x00E900, "TypeRI/RIFLE");
-    codes.insert(0x00EA00, "TypeME/MECHGUN");
-    codes.insert(0x00EB00, "TypeSH/SHOT");
-    codes.insert(0x00EC00, "TypeWA/WAND");
-    codes.insert(0x00ED00, "????");
-    codes.insert(0x010100, "Frame");
-    codes.insert(0x010101, "Armor");
-    codes.insert(0x010102, "Psy Armor");
-    codes.insert(0x010103, "Giga Frame");
-    codes.insert(0x010104, "Soul Frame");
-    codes.insert(0x010105, "Cross Armor");
-    codes.insert(0x010106, "Solid Frame");
-    codes.insert(0x010107, "Brave Armor");
-    codes.insert(0x010108, "Hyper Frame");
-    codes.insert(0x010109, "Grand Armor");
-    codes.insert(0x01010A, "Shock Frame");
-    codes.insert(0x01010B, "King's Frame");
-    codes.insert(0x01010C, "Dragon Frame");
-    codes.insert(0x01010D, "Absorb Armor");
-    codes.insert(0x01010E, "Protect Frame");
-    codes.insert(0x01010F, "General Armor");
-    codes.insert(0x010110, "Perfect Frame");
-    codes.insert(0x010111, "Valiant Frame");
-    codes.insert(0x010112, "Imperial Armor");
-    codes.insert(0x010113, "Holiness Armor");
-    codes.insert(0x010114, "Guardian Armor");
-    codes.insert(0x010115, "Divinity Armor");
-    codes.insert(0x010116, "Ultimate Frame");
-    codes.insert(0x010117, "Celestial Armor");
-    codes.insert(0x010118, "HUNTER FIELD");
-    codes.insert(0x010119, "RANGER FIELD");
-    codes.insert(0x01011A, "FORCE FIELD");
-    codes.insert(0x01011B, "REVIVAL GARMENT");
-    codes.insert(0x01011C, "SPIRIT GARMENT");
-    codes.insert(0x01011D, "STINK FRAME");
-    codes.insert(0x01011E, "D-PARTS ver1.01");
-    codes.insert(0x01011F, "D-PARTS ver2.10");
-    codes.insert(0x010120, "PARAScodes.insert(ITE WEAR,De Rol");
-    codes.insert(0x010121, "PARAScodes.insert(ITE WEAR,Nelgal");
-    codes.insert(0x010122, "PARAScodes.insert(ITE WEAR,Vajulla");
-    codes.insert(0x010123, "SENSE PLATE");
-    codes.insert(0x010124, "GRAVITON PLATE");
-    codes.insert(0x010125, "ATTRIBUTE PLATE");
-    codes.insert(0x010126, "FLOWEN'S FRAME");
-    codes.insert(0x010127, "CUSTOM FRAME ver.OO");
-    codes.insert(0x010128, "DB'S ARMOR");
-    codes.insert(0x010129, "GUARD WAVE");
-    codes.insert(0x01012A, "DF FIELD");
-    codes.insert(0x01012B, "LUMINOUS FIELD");
-    codes.insert(0x01012C, "CHU CHU FEVER");
-    codes.insert(0x01012D, "LOVE HEART");
-    codes.insert(0x01012E, "FLAME GARMENT");
-    codes.insert(0x01012F, "VIRcodes.insert(US ARMOR,Lafuteria");
-    codes.insert(0x010130, "BRIGHTNESS CIRCLE");
-    codes.insert(0x010131, "AURA FIELD");
-    codes.insert(0x010132, "ELECTRO FRAME");
-    codes.insert(0x010133, "SACRED CLOTH");
-    codes.insert(0x010134, "SMOKING PLATE");
-    codes.insert(0x010135, "STAR CUIRASS");
-    codes.insert(0x010136, "BLACK HOUND CUIRASS");
-    codes.insert(0x010137, "MORNING PRAYER");
-    codes.insert(0x010138, "BLACK ODOSHI DOMARU");
-    codes.insert(0x010139, "RED ODOSHI DOMARU");
-    codes.insert(0x01013A, "BLACK ODOSHI RED NIMAIDOU");
-    codes.insert(0x01013B, "BLUE ODOSHI VIOLET NIMAIDOU");
-    codes.insert(0x01013C, "DIRTY LIFEJACKET");
-    codes.insert(0x01013D, "KROE'S SWEATER");
-    codes.insert(0x01013E, "WEDDING DRESS");
-    codes.insert(0x01013F, "SONICTEAM ARMOR");
-    codes.insert(0x010140, "RED COAT");
-    codes.insert(0x010141, "THIRTEEN");
-    codes.insert(0x010142, "MOTHER GARB");
-    codes.insert(0x010143, "MOTHER GARB+");
-    codes.insert(0x010144, "DRESS PLATE");
-    codes.insert(0x010145, "SWEETHEART");
-    codes.insert(0x010146, "IGNITION CLOAK");
-    codes.insert(0x010147, "CONGEAL CLOAK");
-    codes.insert(0x010148, "TEMPEST CLOAK");
-    codes.insert(0x010149, "CURSED CLOAK");
-    codes.insert(0x01014A, "SELECT CLOAK");
-    codes.insert(0x01014B, "SPIRIT CUIRASS");
-    codes.insert(0x01014C, "REVIVAL CURIASS");
-    codes.insert(0x01014D, "ALLIANCE UNIFORM");
-    codes.insert(0x01014E, "OFFICER UNIFORM");
-    codes.insert(0x01014F, "COMMANDER UNIFORM");
-    codes.insert(0x010150, "CRIMSON COAT");
-    codes.insert(0x010151, "INFANTRY GEAR");
-    codes.insert(0x010152, "LIEUTENANT GEAR");
-    codes.insert(0x010153, "INFANTRY MANTLE");
-    codes.insert(0x010154, "LIEUTENANT MANTLE");
-    codes.insert(0x010155, "UNION FIELD");
-    codes.insert(0x010156, "SAMURAI ARMOR");
-    codes.insert(0x010157, "STEALTH SUIT");
-    codes.insert(0x010158, "????");
-    codes.insert(0x010200, "Barrier");
-    codes.insert(0x010201, "Shield");
-    codes.insert(0x010202, "Core Shield");
-    codes.insert(0x010203, "Giga Shield");
-    codes.insert(0x010204, "Soul Barrier");
-    codes.insert(0x010205, "Hard Shield");
-    codes.insert(0x010206, "Brave Barrier");
-    codes.insert(0x010207, "Solid Shield");
-    codes.insert(0x010208, "Flame Barrier");
-    codes.insert(0x010209, "Plasma Barrier");
-    codes.insert(0x01020A, "Freeze Barrier");
-    codes.insert(0x01020B, "Psychic Barrier");
-    codes.insert(0x01020C, "General Shield");
-    codes.insert(0x01020D, "Protect Barrier");
-    codes.insert(0x01020E, "Glorious Shield");
-    codes.insert(0x01020F, "Imperial Barrier");
-    codes.insert(0x010210, "Guardian Shield");
-    codes.insert(0x010211, "Divinity Barrier");
-    codes.insert(0x010212, "Ultimate Shield");
-    codes.insert(0x010213, "Spiritual Shield");
-    codes.insert(0x010214, "Celestial Shield");
-    codes.insert(0x010215, "INVISIBLE GUARD");
-    codes.insert(0x010216, "SACRED GUARD");
-    codes.insert(0x010217, "S-PARTS ver1.16");
-    codes.insert(0x010218, "S-PARTS ver2.01");
-    codes.insert(0x010219, "LIGHT RELIEF");
-    codes.insert(0x01021A, "SHIELD OF DELSABER");
-    codes.insert(0x01021B, "FORCE WALL");
-    codes.insert(0x01021C, "RANGER WALL");
-    codes.insert(0x01021D, "HUNTER WALL");
-    codes.insert(0x01021E, "ATTRIBUTE WALL");
-    codes.insert(0x01021F, "SECRET GEAR");
-    codes.insert(0x010220, "COMBAT GEAR");
-    codes.insert(0x010221, "PROTO REGENE GEAR");
-    codes.insert(0x010222, "REGENERATE GEAR");
-    codes.insert(0x010223, "REGENE GEAR ADV.");
-    codes.insert(0x010224, "FLOWEN'S SHIELD");
-    codes.insert(0x010225, "CUSTOM BARRIER ver.OO");
-    codes.insert(0x010226, "DB'S SHIELD");
-    codes.insert(0x010227, "RED RING");
-    codes.insert(0x010228, "TRIPOLIC SHIELD");
-    codes.insert(0x010229, "STANDSTILL SHIELD");
-    codes.insert(0x01022A, "SAFETY HEART");
-    codes.insert(0x01022B, "KASAMI BRACER");
-    codes.insert(0x01022C, "GODS SHIELD SUZAKU");
-    codes.insert(0x01022D, "GODS SHIELD GENBU");
-    codes.insert(0x01022E, "GODS SHIELD BYAKKO");
-    codes.insert(0x01022F, "GODS SHIELD SEIRYU");
-    codes.insert(0x010230, "HUNTER'S SHELL");
-    codes.insert(0x010231, "RICO'S GLASSES");
-    codes.insert(0x010232, "RICO'S EARRING");
-    codes.insert(0x010233, "BLUE RING");
-    codes.insert(0x010234, "Barrier");
-    codes.insert(0x010235, "SECURE FEET");
-    codes.insert(0x010236, "Barrier");
-    codes.insert(0x010237, "Barrier");
-    codes.insert(0x010238, "Barrier");
-    codes.insert(0x010239, "Barrier");
-    codes.insert(0x01023A, "RESTA MERGE");
-    codes.insert(0x01023B, "ANTI MERGE");
-    codes.insert(0x01023C, "SHIFTA MERGE");
-    codes.insert(0x01023D, "DEBAND MERGE");
-    codes.insert(0x01023E, "FOIE MERGE");
-    codes.insert(0x01023F, "GIFOIE MERGE");
-    codes.insert(0x010240, "RAFOIE MERGE");
-    codes.insert(0x010241, "RED MERGE");
-    codes.insert(0x010242, "BARTA MERGE");
-    codes.insert(0x010243, "GIBARTA MERGE");
-    codes.insert(0x010244, "RABARTA MERGE");
-    codes.insert(0x010245, "BLUE MERGE");
-    codes.insert(0x010246, "ZONDE MERGE");
-    codes.insert(0x010247, "GIZONDE MERGE");
-    codes.insert(0x010248, "RAZONDE MERGE");
-    codes.insert(0x010249, "YELLOW MERGE");
-    codes.insert(0x01024A, "RECOVERY BARRIER");
-    codes.insert(0x01024B, "ASSIST  BARRIER");
-    codes.insert(0x01024C, "RED BARRIER");
-    codes.insert(0x01024D, "BLUE BARRIER");
-    codes.insert(0x01024E, "YELLOW BARRIER");
-    codes.insert(0x01024F, "WEAPONS GOLD SHIELD");
-    codes.insert(0x010250, "BLACK GEAR");
-    codes.insert(0x010251, "WORKS GUARD");
-    codes.insert(0x010252, "RAGOL RING");
-    codes.insert(0x010253, "BLUE RING");
-    codes.insert(0x010254, "BLUE RING");
-    codes.insert(0x010255, "BLUE RING");
-    codes.insert(0x010256, "BLUE RING");
-    codes.insert(0x010257, "BLUE RING");
-    codes.insert(0x010258, "BLUE RING");
-    codes.insert(0x010259, "BLUE RING");
-    codes.insert(0x01025A, "BLUE RING");
-    codes.insert(0x01025B, "GREEN RING");
-    codes.insert(0x01025C, "GREEN RING");
-    codes.insert(0x01025D, "GREEN RING");
-    codes.insert(0x01025E, "GREEN RING");
-    codes.insert(0x01025F, "GREEN RING");
-    codes.insert(0x010260, "GREEN RING");
-    codes.insert(0x010261, "GREEN RING");
-    codes.insert(0x010262, "GREEN RING");
-    codes.insert(0x010263, "YELLOW RING");
-    codes.insert(0x010264, "YELLOW RING");
-    codes.insert(0x010265, "YELLOW RING");
-    codes.insert(0x010266, "YELLOW RING");
-    codes.insert(0x010267, "YELLOW RING");
-    codes.insert(0x010268, "YELLOW RING");
-    codes.insert(0x010269, "YELLOW RING");
-    codes.insert(0x01026A, "YELLOW RING");
-    codes.insert(0x01026B, "PURPLE RING");
-    codes.insert(0x01026C, "PURPLE RING");
-    codes.insert(0x01026D, "PURPLE RING");
-    codes.insert(0x01026E, "PURPLE RING");
-    codes.insert(0x01026F, "PURPLE RING");
-    codes.insert(0x010270, "PURPLE RING");
-    codes.insert(0x010271, "PURPLE RING");
-    codes.insert(0x010272, "PURPLE RING");
-    codes.insert(0x010273, "WHITE RING");
-    codes.insert(0x010274, "WHITE RING");
-    codes.insert(0x010275, "WHITE RING");
-    codes.insert(0x010276, "WHITE RING");
-    codes.insert(0x010277, "WHITE RING");
-    codes.insert(0x010278, "WHITE RING");
-    codes.insert(0x010279, "WHITE RING");
-    codes.insert(0x01027A, "WHITE RING");
-    codes.insert(0x01027B, "BLACK RING");
-    codes.insert(0x01027C, "BLACK RING");
-    codes.insert(0x01027D, "BLACK RING");
-    codes.insert(0x01027E, "BLACK RING");
-    codes.insert(0x01027F, "BLACK RING");
-    codes.insert(0x010280, "BLACK RING");
-    codes.insert(0x010281, "BLACK RING");
-    codes.insert(0x010282, "BLACK RING");
-    codes.insert(0x010283, "WEAPONS SILVER SHIELD");
-    codes.insert(0x010284, "WEAPONS COPPER SHIELD");
-    codes.insert(0x010285, "GRATIA");
-    codes.insert(0x010286, "TRIPOLIC REFLECTOR");
-    codes.insert(0x010287, "STRIKER PLUS");
-    codes.insert(0x010288, "REGENERATE GEAR B.P.");
-    codes.insert(0x010289, "RUPIKA");
-    codes.insert(0x01028A, "YATA MIRROR");
-    codes.insert(0x01028B, "BUNNY EARS");
-    codes.insert(0x01028C, "CAT EARS");
-    codes.insert(0x01028D, "THREE SEALS");
-    codes.insert(0x01028E, "GOD'S SHIELD \"KOURYU\"");
-    codes.insert(0x01028F, "DF SHIELD");
-    codes.insert(0x010290, "FROM THE DEPTHS");
-    codes.insert(0x010291, "DE ROL LE SHIELD");
-    codes.insert(0x010292, "HONEYCOMB REFLECTOR");
-    codes.insert(0x010293, "EPSIGUARD");
-    codes.insert(0x010294, "ANGEL RING");
-    codes.insert(0x010295, "UNION GUARD");
-    codes.insert(0x010296, "UNION GUARD");
-    codes.insert(0x010297, "UNION GUARD");
-    codes.insert(0x010298, "UNION GUARD");
-    codes.insert(0x010299, "STINK SHIELD");
-    codes.insert(0x01029A, "UNKNOWN_B");
-    codes.insert(0x01029B, "GENPEI");
-    codes.insert(0x01029C, "GENPEI");
-    codes.insert(0x01029D, "GENPEI");
-    codes.insert(0x01029E, "GENPEI");
-    codes.insert(0x01029F, "GENPEI");
-    codes.insert(0x0102A0, "GENPEI");
-    codes.insert(0x0102A1, "GENPEI");
-    codes.insert(0x0102A2, "GENPEI");
-    codes.insert(0x0102A3, "GENPEI");
-    codes.insert(0x0102A4, "GENPEI");
-    codes.insert(0x0102A5, "????");
-    codes.insert(0x010300, "Knight/Power");
-    codes.insert(0x010301, "General/Power");
-    codes.insert(0x010302, "Ogre/Power");
-    codes.insert(0x010303, "God/Power");
-    codes.insert(0x010304, "Priest/Mind");
-    codes.insert(0x010305, "General/Mind");
-    codes.insert(0x010306, "Angel/Mind");
-    codes.insert(0x010307, "God/Mind");
-    codes.insert(0x010308, "Marksman/Arm");
-    codes.insert(0x010309, "General/Arm");
-    codes.insert(0x01030A, "Elf/Arm");
-    codes.insert(0x01030B, "God/Arm");
-    codes.insert(0x01030C, "Thief/Legs");
-    codes.insert(0x01030D, "General/Legs");
-    codes.insert(0x01030E, "Elf/Legs");
-    codes.insert(0x01030F, "God/Legs");
-    codes.insert(0x010310, "Digger/HP");
-    codes.insert(0x010311, "General/HP");
-    codes.insert(0x010312, "Dragon/HP");
-    codes.insert(0x010313, "God/HP");
-    codes.insert(0x010314, "Magician/TP");
-    codes.insert(0x010315, "General/TP");
-    codes.insert(0x010316, "Angel/TP");
-    codes.insert(0x010317, "God/TP");
-    codes.insert(0x010318, "Warrior/Body");
-    codes.insert(0x010319, "General/Body");
-    codes.insert(0x01031A, "Metal/Body");
-    codes.insert(0x01031B, "God/Body");
-    codes.insert(0x01031C, "Angel/Luck");
-    codes.insert(0x01031D, "God/Luck");
-    codes.insert(0x01031E, "Master/Ability");
-    codes.insert(0x01031F, "Hero/Ability");
-    codes.insert(0x010320, "God/Ability");
-    codes.insert(0x010321, "Resist/Fire");
-    codes.insert(0x010322, "Resist/Flame");
-    codes.insert(0x010323, "Resist/Burning");
-    codes.insert(0x010324, "Resist/Cold");
-    codes.insert(0x010325, "Resist/Freeze");
-    codes.insert(0x010326, "Resist/Blizzard");
-    codes.insert(0x010327, "Resist/Shock");
-    codes.insert(0x010328, "Resist/Thunder");
-    codes.insert(0x010329, "Resist/Storm");
-    codes.insert(0x01032A, "Resist/Light");
-    codes.insert(0x01032B, "Resist/Saint");
-    codes.insert(0x01032C, "Resist/Holy");
-    codes.insert(0x01032D, "Resist/Dark");
-    codes.insert(0x01032E, "Resist/Evil");
-    codes.insert(0x01032F, "Resist/Devil");
-    codes.insert(0x010330, "All/Resist");
-    codes.insert(0x010331, "Super/Resist");
-    codes.insert(0x010332, "Perfect/Resist");
-    codes.insert(0x010333, "HP/Restorate");
-    codes.insert(0x010334, "HP/Generate");
-    codes.insert(0x010335, "HP/Revival");
-    codes.insert(0x010336, "TP/Restorate");
-    codes.insert(0x010337, "TP/Generate");
-    codes.insert(0x010338, "TP/Revival");
-    codes.insert(0x010339, "PB/Amplifier");
-    codes.insert(0x01033A, "PB/Generate");
-    codes.insert(0x01033B, "PB/Create");
-    codes.insert(0x01033C, "Wizard/Technique");
-    codes.insert(0x01033D, "Devil/Technique");
-    codes.insert(0x01033E, "God/Technique");
-    codes.insert(0x01033F, "General/Battle");
-    codes.insert(0x010340, "Devil/Battle");
-    codes.insert(0x010341, "God/Battle");
-    codes.insert(0x010342, "Cure/Poison");
-    codes.insert(0x010343, "Cure/Paralysis");
-    codes.insert(0x010344, "Cure/Slow");
-    codes.insert(0x010345, "Cure/Confuse");
-    codes.insert(0x010346, "Cure/Freeze");
-    codes.insert(0x010347, "Cure/Shock");
-    codes.insert(0x010348, "YASAKANI MAGATAMA");
-    codes.insert(0x010349, "V101");
-    codes.insert(0x01034A, "V501");
-    codes.insert(0x01034B, "V502");
-    codes.insert(0x01034C, "V801");
-    codes.insert(0x01034D, "LIMITER");
-    codes.insert(0x01034E, "ADEPT");
-    codes.insert(0x01034F, "SWORDSMAN LORE");
-    codes.insert(0x010350, "PROOF OF SWORD-SAINT");
-    codes.insert(0x010351, "SMARTLINK");
-    codes.insert(0x010352, "DIVINE PROTECTION");
-    codes.insert(0x010353, "Heavenly/Battle");
-    codes.insert(0x010354, "Heavenly/Power");
-    codes.insert(0x010355, "Heavenly/Mind");
-    codes.insert(0x010356, "Heavenly/Arms");
-    codes.insert(0x010357, "Heavenly/Legs");
-    codes.insert(0x010358, "Heavenly/Body");
-    codes.insert(0x010359, "Heavenly/Luck");
-    codes.insert(0x01035A, "Heavenly/Ability");
-    codes.insert(0x01035B, "Centurion/Ability");
-    codes.insert(0x01035C, "Friend Ring");
-    codes.insert(0x01035D, "Heavenly/HP");
-    codes.insert(0x01035E, "Heavenly/TP");
-    codes.insert(0x01035F, "Heavenly/Resist");
-    codes.insert(0x010360, "Heavenly/Technique");
-    codes.insert(0x010361, "HP/Ressurection");
-    codes.insert(0x010362, "TP/Ressurection");
-    codes.insert(0x010363, "PB/Increase");
-    codes.insert(0x010364, "????");
-    codes.insert(0x020000, "Mag");
-    codes.insert(0x020100, "Varuna");
-    codes.insert(0x020200, "Mitra");
-    codes.insert(0x020300, "Surya");
-    codes.insert(0x020400, "Vayu");
-    codes.insert(0x020500, "Varaha");
-    codes.insert(0x020600, "Kama");
-    codes.insert(0x020700, "Ushasu");
-    codes.insert(0x020800, "Apsaras");
-    codes.insert(0x020900, "Kumara");
-    codes.insert(0x020A00, "Kaitabha");
-    codes.insert(0x020B00, "Tapas");
-    codes.insert(0x020C00, "Bhirava");
-    codes.insert(0x020D00, "Kalki");
-    codes.insert(0x020E00, "Rudra");
-    codes.insert(0x020F00, "Marutah");
-    codes.insert(0x021000, "Yaksa");
-    codes.insert(0x021100, "Sita");
-    codes.insert(0x021200, "Garuda");
-    codes.insert(0x021300, "Nandin");
-    codes.insert(0x021400, "Ashvinau");
-    codes.insert(0x021500, "Ribhava");
-    codes.insert(0x021600, "Soma");
-    codes.insert(0x021700, "Ila");
-    codes.insert(0x021800, "Durga");
-    codes.insert(0x021900, "Vritra");
-    codes.insert(0x021A00, "Namuci");
-    codes.insert(0x021B00, "Sumba");
-    codes.insert(0x021C00, "Naga");
-    codes.insert(0x021D00, "Pitri");
-    codes.insert(0x021E00, "Kabanda");
-    codes.insert(0x021F00, "Ravana");
-    codes.insert(0x022000, "Marica");
-    codes.insert(0x022100, "Soniti");
-    codes.insert(0x022200, "Preta");
-    codes.insert(0x022300, "Andhaka");
-    codes.insert(0x022400, "Bana");
-    codes.insert(0x022500, "Naraka");
-    codes.insert(0x022600, "Madhu");
-    codes.insert(0x022700, "Churel");
-    codes.insert(0x022800, "ROBOCHAO");
-    codes.insert(0x022900, "OPA-OPA");
-    codes.insert(0x022A00, "PIAN");
-    codes.insert(0x022B00, "CHAO");
-    codes.insert(0x022C00, "CHU CHU");
-    codes.insert(0x022D00, "KAPU KAPU");
-    codes.insert(0x022E00, "ANGEL'S WING");
-    codes.insert(0x022F00, "DEVIL'S WING");
-    codes.insert(0x023000, "ELENOR");
-    codes.insert(0x023100, "MARK3");
-    codes.insert(0x023200, "MASTER SYSTEM");
-    codes.insert(0x023300, "GENESIS");
-    codes.insert(0x023400, "SEGA SATURN");
-    codes.insert(0x023500, "DREAMCAST");
-    codes.insert(0x023600, "HAMBURGER");
-    codes.insert(0x023700, "PANZER'S TAIL");
-    codes.insert(0x023800, "DEVIL'S TAIL");
-    codes.insert(0x023900, "Deva");
-    codes.insert(0x023A00, "Rati");
-    codes.insert(0x023B00, "Savitri");
-    codes.insert(0x023C00, "Rukmin");
-    codes.insert(0x023D00, "Pushan");
-    codes.insert(0x023E00, "Diwari");
-    codes.insert(0x023F00, "Sato");
-    codes.insert(0x024000, "Bhima");
-    codes.insert(0x024100, "Nidra");
-    codes.insert(0x024200, "Geung-si");
-    codes.insert(0x024300, "\n");
-    codes.insert(0x024400, "Tellusis");
-    codes.insert(0x024500, "Striker Unit");
-    codes.insert(0x024600, "Pioneer");
-    codes.insert(0x024700, "Puyo");
-    codes.insert(0x024800, "Moro");
-    codes.insert(0x024900, "Rappy");
-    codes.insert(0x024A00, "Yahoo!");
-    codes.insert(0x024B00, "Gael Giel");
-    codes.insert(0x024C00, "Agastya");
-    codes.insert(0x024D00, "Cell of MAG 0503");
-    codes.insert(0x024E00, "Cell of MAG 0504");
-    codes.insert(0x024F00, "Cell of MAG 0505");
-    codes.insert(0x025000, "Cell of MAG 0506");
-    codes.insert(0x025100, "Cell of MAG 0507");
-    codes.insert(0x025200, "????");
-    codes.insert(0x030000, "Monomate");
-    codes.insert(0x030001, "Dimate");
-    codes.insert(0x030002, "Trimate");
-    codes.insert(0x030100, "Monofluid");
-    codes.insert(0x030101, "Difluid");
-    codes.insert(0x030102, "Trifluid");
-    codes.insert(0x030300, "Sol Atomizer");
-    codes.insert(0x030400, "Moon Atomizer");
-    codes.insert(0x030500, "Star Atomizer");
-    codes.insert(0x030600, "Antidote");
-    codes.insert(0x030601, "Antiparalysis");
-    codes.insert(0x030700, "Telepipe");
-    codes.insert(0x030800, "Trap Vision");
-    codes.insert(0x030900, "Scape Doll");
-    codes.insert(0x030A00, "Monogrinder");
-    codes.insert(0x030A01, "Digrinder");
-    codes.insert(0x030A02, "Trigrinder");
-    codes.insert(0x030B00, "Power Material");
-    codes.insert(0x030B01, "Mind Material");
-    codes.insert(0x030B02, "Evade Material");
-    codes.insert(0x030B03, "HP Material");
-    codes.insert(0x030B04, "TP Material");
-    codes.insert(0x030B05, "Def Material");
-    codes.insert(0x030B06, "Luck Material");
-    codes.insert(0x030C00, "Cell of MAG 502");
-    codes.insert(0x030C01, "Cell of MAG 213");
-    codes.insert(0x030C02, "Parts of RoboChao");
-    codes.insert(0x030C03, "Heart of Opa Opa");
-    codes.insert(0x030C04, "Heart of Pian");
-    codes.insert(0x030C05, "Heart of Chao");
-    codes.insert(0x030D00, "Sorcerer's Right Arm");
-    codes.insert(0x030D01, "S-beat's Arms");
-    codes.insert(0x030D02, "P-arm's Arms");
-    codes.insert(0x030D03, "Delsaber's Right Arm");
-    codes.insert(0x030D04, "Bringer's Right Arm");
-    codes.insert(0x030D05, "Delsaber's Left Arm");
-    codes.insert(0x030D06, "S-red's Arms");
-    codes.insert(0x030D07, "Dragon's Claw");
-    codes.insert(0x030D08, "Hildebear's Head");
-    codes.insert(0x030D09, "Hildeblue's Head");
-    codes.insert(0x030D0A, "Parts of Baranz");
-    codes.insert(0x030D0B, "Belra's Right Arm");
-    codes.insert(0x030D0C, "Gi Gue's body");
-    codes.insert(0x030D0D, "Sinow Berill's Arms");
-    codes.insert(0x030D0E, "Grass Assassin's Arms");
-    codes.insert(0x030D0F, "Booma's Right Arm");
-    codes.insert(0x030D10, "Gobooma's Right Arm");
-    codes.insert(0x030D11, "Gigobooma's Right Arm");
-    codes.insert(0x030D12, "Gal Gryphon's Wing");
-    codes.insert(0x030D13, "Rappy's Wing");
-    codes.insert(0x030D14, "Cladding of Epsilon");
-    codes.insert(0x030D15, "De Rol Le Shell");
-    codes.insert(0x030E00, "Berill Photon");
-    codes.insert(0x030E01, "Parasitic gene \"Flow\"");
-    codes.insert(0x030E02, "Magic Stone \"Iritista\"");
-    codes.insert(0x030E03, "Blue-black stone");
-    codes.insert(0x030E04, "Syncesta");
-    codes.insert(0x030E05, "Magic Water");
-    codes.insert(0x030E06, "Parasitic cell Type D");
-    codes.insert(0x030E07, "magic rock \"Heart Key\"");
-    codes.insert(0x030E08, "magic rock \"Moola\"");
-    codes.insert(0x030E09, "Star Amplifier");
-    codes.insert(0x030E0A, "Book of HITOGATA");
-    codes.insert(0x030E0B, "Heart of Chu Chu");
-    codes.insert(0x030E0C, "Parts of EGG BLASTER");
-    codes.insert(0x030E0D, "Heart of Angel");
-    codes.insert(0x030E0E, "Heart of Devil");
-    codes.insert(0x030E0F, "Kit of Hamburger");
-    codes.insert(0x030E10, "Panther's Spirit");
-    codes.insert(0x030E11, "Kit of MARK3");
-    codes.insert(0x030E12, "Kit of MASTER SYSTEM");
-    codes.insert(0x030E13, "Kit of GENESIS");
-    codes.insert(0x030E14, "Kit of SEGA SATURN");
-    codes.insert(0x030E15, "Kit of DREAMCAST");
-    codes.insert(0x030E16, "Amplifier of Resta");
-    codes.insert(0x030E17, "Amplifier of Anti");
-    codes.insert(0x030E18, "Amplifier of Shifta");
-    codes.insert(0x030E19, "Amplifier of Deband");
-    codes.insert(0x030E1A, "Amplifier of Foie");
-    codes.insert(0x030E1B, "Amplifier of Gifoie");
-    codes.insert(0x030E1C, "Amplifier of Rafoie");
-    codes.insert(0x030E1D, "Amplifier of Barta");
-    codes.insert(0x030E1E, "Amplifier of Gibarta");
-    codes.insert(0x030E1F, "Amplifier of Rabarta");
-    codes.insert(0x030E20, "Amplifier of Zonde");
-    codes.insert(0x030E21, "Amplifier of Gizonde");
-    codes.insert(0x030E22, "Amplifier of Razonde");
-    codes.insert(0x030E23, "Amplifier of Red");
-    codes.insert(0x030E24, "Amplifier of Blue");
-    codes.insert(0x030E25, "Amplifier of Yellow");
-    codes.insert(0x030E26, "Heart of KAPU KAPU");
-    codes.insert(0x030E27, "Photon Booster");
-    codes.insert(0x030F00, "AddSlot");
-    codes.insert(0x031000, "Photon Drop");
-    codes.insert(0x031001, "Photon Sphere");
-    codes.insert(0x031002, "Photon Crystal");
-    codes.insert(0x031003, "Secret Ticket");
-    codes.insert(0x031004, "Photon Ticket");
-    codes.insert(0x031100, "Book of KATANA1");
-    codes.insert(0x031101, "Book of KATANA2");
-    codes.insert(0x031102, "Book of KATANA3");
-    codes.insert(0x031200, "Weapons Bronze Badge");
-    codes.insert(0x031201, "Weapons Silver Badge");
-    codes.insert(0x031202, "Weapons Gold Badge");
-    codes.insert(0x031203, "Weapons Crystal Badge");
-    codes.insert(0x031204, "Weapons Steel Badge");
-    codes.insert(0x031205, "Weapons Aluminum Badge");
-    codes.insert(0x031206, "Weapons Leather Badge");
-    codes.insert(0x031207, "Weapons Bone Badge");
-    codes.insert(0x031208, "Letter of appreciation");
-    codes.insert(0x031209, "Item Ticket");
-    codes.insert(0x03120A, "Valentine's Chocolate");
-    codes.insert(0x03120B, "New Year's Card");
-    codes.insert(0x03120C, "Christmas Card");
-    codes.insert(0x03120D, "Birthday Card");
-    codes.insert(0x03120E, "Proof of Sonic Team");
-    codes.insert(0x03120F, "Special Event Ticket");
-    codes.insert(0x031210, "Flower Bouquet");
-    codes.insert(0x031211, "Cake");
-    codes.insert(0x031212, "Accessories");
-    codes.insert(0x031213, "Mr.Naka's Business Card");
-    codes.insert(0x031300, "Present");
-    codes.insert(0x031400, "Chocolate");
-    codes.insert(0x031401, "Candy");
-    codes.insert(0x031402, "Cake");
-    codes.insert(0x031403, "Weapons Silver Badge");
-    codes.insert(0x031404, "Weapons Gold Badge");
-    codes.insert(0x031405, "Weapons Crystal Badge");
-    codes.insert(0x031406, "Weapons Steel Badge");
-    codes.insert(0x031407, "Weapons Aluminum Badge");
-    codes.insert(0x031408, "Weapons Leather Badge");
-    codes.insert(0x031409, "Weapons Bone Badge");
-    codes.insert(0x03140A, "Bouquet");
-    codes.insert(0x03140B, "Decoction");
-    codes.insert(0x031500, "Christmas Present");
-    codes.insert(0x031501, "Easter Egg");
-    codes.insert(0x031502, "Jack-O'-Lantern");
-    codes.insert(0x031600, "DISK Vol.1 \"Wedding March\"");
-    codes.insert(0x031601, "DISK Vol.2 \"Day Light\"");
-    codes.insert(0x031602, "DISK Vol.3 \"Burning Rangers\"");
-    codes.insert(0x031603, "DISK Vol.4 \"Open Your Heart\"");
-    codes.insert(0x031604, "DISK Vol.5 \"Live & Learn\"");
-    codes.insert(0x031605, "DISK Vol.6 \"NiGHTS\"");
-    codes.insert(0x031606, "DISK Vol.7 \"Ending Theme (Piano ver.)\"");
-    codes.insert(0x031607, "DISK Vol.8 \"Heart to Heart\"");
-    codes.insert(0x031608, "DISK Vol.9 \"Strange Blue\"");
-    codes.insert(0x031609, "DISK Vol.10 \"Reunion System\"");
-    codes.insert(0x03160A, "DISK Vol.11 \"Pinnacles\"");
-    codes.insert(0x03160B, "DISK Vol.12 \"Fight inside the Spaceship\"");
-    codes.insert(0x031700, "Hunters Report");
-    codes.insert(0x031701, "Hunters Report");
-    codes.insert(0x031702, "Hunters Report");
-    codes.insert(0x031703, "Hunters Report");
-    codes.insert(0x031704, "Hunters Report");
-    codes.insert(0x031800, "Tablet");
-    codes.insert(0x031801, "UNKNOWN2");
-    codes.insert(0x031802, "Dragon Scale");
-    codes.insert(0x031803, "Heaven Striker Coat");
-    codes.insert(0x031804, "Pioneer Parts");
-    codes.insert(0x031805, "Amitie's Memo");
-    codes.insert(0x031806, "Heart of Morolian");
-    codes.insert(0x031807, "Rappy's Beak");
-    codes.insert(0x031808, "Yahoo!'s engine");
-    codes.insert(0x031809, "D-Photon Core");
-    codes.insert(0x03180A, "Liberta Kit");
-    codes.insert(0x03180B, "Cell of MAG 0503");
-    codes.insert(0x03180C, "Cell of MAG 0504");
-    codes.insert(0x03180D, "Cell of MAG 0505");
-    codes.insert(0x03180E, "Cell of MAG 0506");
-    codes.insert(0x03180F, "Cell of MAG 0507");
-    codes.insert(0x031900, "Team Points 500");
-    codes.insert(0x031901, "Team Points 1000");
-    codes.insert(0x031902, "Team Points 5000");
-    codes.insert(0x031903, "Team Points 10000");
-    codes.insert(0x031A00, "????");
-    codes.insert(0x040000, "Meseta");
-    codes.insert(0x050000, "Foie Lv1");
-    codes.insert(0x050001, "Foie Lv2");
-    codes.insert(0x050002, "Foie Lv3");
-    codes.insert(0x050003, "Foie Lv4");
-    codes.insert(0x050004, "Foie Lv5");
-    codes.insert(0x050005, "Foie Lv6");
-    codes.insert(0x050006, "Foie Lv7");
-    codes.insert(0x050007, "Foie Lv8");
-    codes.insert(0x050008, "Foie Lv9");
-    codes.insert(0x050009, "Foie Lv10");
-    codes.insert(0x05000A, "Foie Lv11");
-    codes.insert(0x05000B, "Foie Lv12");
-    codes.insert(0x05000C, "Foie Lv13");
-    codes.insert(0x05000D, "Foie Lv14");
-    codes.insert(0x05000E, "Foie Lv15");
-    codes.insert(0x05000F, "Foie Lv16");
-    codes.insert(0x050010, "Foie Lv17");
-    codes.insert(0x050011, "Foie Lv18");
-    codes.insert(0x050012, "Foie Lv19");
-    codes.insert(0x050013, "Foie Lv20");
-    codes.insert(0x050014, "Foie Lv21");
-    codes.insert(0x050015, "Foie Lv22");
-    codes.insert(0x050016, "Foie Lv23");
-    codes.insert(0x050017, "Foie Lv24");
-    codes.insert(0x050018, "Foie Lv25");
-    codes.insert(0x050019, "Foie Lv26");
-    codes.insert(0x05001A, "Foie Lv27");
-    codes.insert(0x05001B, "Foie Lv28");
-    codes.insert(0x05001C, "Foie Lv29");
-    codes.insert(0x05001D, "Foie Lv30");
-    codes.insert(0x050100, "Gifoie Lv1");
-    codes.insert(0x050101, "Gifoie Lv2");
-    codes.insert(0x050102, "Gifoie Lv3");
-    codes.insert(0x050103, "Gifoie Lv4");
-    codes.insert(0x050104, "Gifoie Lv5");
-    codes.insert(0x050105, "Gifoie Lv6");
-    codes.insert(0x050106, "Gifoie Lv7");
-    codes.insert(0x050107, "Gifoie Lv8");
-    codes.insert(0x050108, "Gifoie Lv9");
-    codes.insert(0x050109, "Gifoie Lv10");
-    codes.insert(0x05010A, "Gifoie Lv11");
-    codes.insert(0x05010B, "Gifoie Lv12");
-    codes.insert(0x05010C, "Gifoie Lv13");
-    codes.insert(0x05010D, "Gifoie Lv14");
-    codes.insert(0x05010E, "Gifoie Lv15");
-    codes.insert(0x05010F, "Gifoie Lv16");
-    codes.insert(0x050110, "Gifoie Lv17");
-    codes.insert(0x050111, "Gifoie Lv18");
-    codes.insert(0x050112, "Gifoie Lv19");
-    codes.insert(0x050113, "Gifoie Lv20");
-    codes.insert(0x050114, "Gifoie Lv21");
-    codes.insert(0x050115, "Gifoie Lv22");
-    codes.insert(0x050116, "Gifoie Lv23");
-    codes.insert(0x050117, "Gifoie Lv24");
-    codes.insert(0x050118, "Gifoie Lv25");
-    codes.insert(0x050119, "Gifoie Lv26");
-    codes.insert(0x05011A, "Gifoie Lv27");
-    codes.insert(0x05011B, "Gifoie Lv28");
-    codes.insert(0x05011C, "Gifoie Lv29");
-    codes.insert(0x05011D, "Gifoie Lv30");
-    codes.insert(0x050200, "Rafoie Lv1");
-    codes.insert(0x050201, "Rafoie Lv2");
-    codes.insert(0x050202, "Rafoie Lv3");
-    codes.insert(0x050203, "Rafoie Lv4");
-    codes.insert(0x050204, "Rafoie Lv5");
-    codes.insert(0x050205, "Rafoie Lv6");
-    codes.insert(0x050206, "Rafoie Lv7");
-    codes.insert(0x050207, "Rafoie Lv8");
-    codes.insert(0x050208, "Rafoie Lv9");
-    codes.insert(0x050209, "Rafoie Lv10");
-    codes.insert(0x05020A, "Rafoie Lv11");
-    codes.insert(0x05020B, "Rafoie Lv12");
-    codes.insert(0x05020C, "Rafoie Lv13");
-    codes.insert(0x05020D, "Rafoie Lv14");
-    codes.insert(0x05020E, "Rafoie Lv15");
-    codes.insert(0x05020F, "Rafoie Lv16");
-    codes.insert(0x050210, "Rafoie Lv17");
-    codes.insert(0x050211, "Rafoie Lv18");
-    codes.insert(0x050212, "Rafoie Lv19");
-    codes.insert(0x050213, "Rafoie Lv20");
-    codes.insert(0x050214, "Rafoie Lv21");
-    codes.insert(0x050215, "Rafoie Lv22");
-    codes.insert(0x050216, "Rafoie Lv23");
-    codes.insert(0x050217, "Rafoie Lv24");
-    codes.insert(0x050218, "Rafoie Lv25");
-    codes.insert(0x050219, "Rafoie Lv26");
-    codes.insert(0x05021A, "Rafoie Lv27");
-    codes.insert(0x05021B, "Rafoie Lv28");
-    codes.insert(0x05021C, "Rafoie Lv29");
-    codes.insert(0x05021D, "Rafoie Lv30");
-    codes.insert(0x050300, "Barta Lv1");
-    codes.insert(0x050301, "Barta Lv2");
-    codes.insert(0x050302, "Barta Lv3");
-    codes.insert(0x050303, "Barta Lv4");
-    codes.insert(0x050304, "Barta Lv5");
-    codes.insert(0x050305, "Barta Lv6");
-    codes.insert(0x050306, "Barta Lv7");
-    codes.insert(0x050307, "Barta Lv8");
-    codes.insert(0x050308, "Barta Lv9");
-    codes.insert(0x050309, "Barta Lv10");
-    codes.insert(0x05030A, "Barta Lv11");
-    codes.insert(0x05030B, "Barta Lv12");
-    codes.insert(0x05030C, "Barta Lv13");
-    codes.insert(0x05030D, "Barta Lv14");
-    codes.insert(0x05030E, "Barta Lv15");
-    codes.insert(0x05030F, "Barta Lv16");
-    codes.insert(0x050310, "Barta Lv17");
-    codes.insert(0x050311, "Barta Lv18");
-    codes.insert(0x050312, "Barta Lv19");
-    codes.insert(0x050313, "Barta Lv20");
-    codes.insert(0x050314, "Barta Lv21");
-    codes.insert(0x050315, "Barta Lv22");
-    codes.insert(0x050316, "Barta Lv23");
-    codes.insert(0x050317, "Barta Lv24");
-    codes.insert(0x050318, "Barta Lv25");
-    codes.insert(0x050319, "Barta Lv26");
-    codes.insert(0x05031A, "Barta Lv27");
-    codes.insert(0x05031B, "Barta Lv28");
-    codes.insert(0x05031C, "Barta Lv29");
-    codes.insert(0x05031D, "Barta Lv30");
-    codes.insert(0x050400, "Gibarta Lv1");
-    codes.insert(0x050401, "Gibarta Lv2");
-    codes.insert(0x050402, "Gibarta Lv3");
-    codes.insert(0x050403, "Gibarta Lv4");
-    codes.insert(0x050404, "Gibarta Lv5");
-    codes.insert(0x050405, "Gibarta Lv6");
-    codes.insert(0x050406, "Gibarta Lv7");
-    codes.insert(0x050407, "Gibarta Lv8");
-    codes.insert(0x050408, "Gibarta Lv9");
-    codes.insert(0x050409, "Gibarta Lv10");
-    codes.insert(0x05040A, "Gibarta Lv11");
-    codes.insert(0x05040B, "Gibarta Lv12");
-    codes.insert(0x05040C, "Gibarta Lv13");
-    codes.insert(0x05040D, "Gibarta Lv14");
-    codes.insert(0x05040E, "Gibarta Lv15");
-    codes.insert(0x05040F, "Gibarta Lv16");
-    codes.insert(0x050410, "Gibarta Lv17");
-    codes.insert(0x050411, "Gibarta Lv18");
-    codes.insert(0x050412, "Gibarta Lv19");
-    codes.insert(0x050413, "Gibarta Lv20");
-    codes.insert(0x050414, "Gibarta Lv21");
-    codes.insert(0x050415, "Gibarta Lv22");
-    codes.insert(0x050416, "Gibarta Lv23");
-    codes.insert(0x050417, "Gibarta Lv24");
-    codes.insert(0x050418, "Gibarta Lv25");
-    codes.insert(0x050419, "Gibarta Lv26");
-    codes.insert(0x05041A, "Gibarta Lv27");
-    codes.insert(0x05041B, "Gibarta Lv28");
-    codes.insert(0x05041C, "Gibarta Lv29");
-    codes.insert(0x05041D, "Gibarta Lv30");
-    codes.insert(0x050500, "Rabarta Lv1");
-    codes.insert(0x050501, "Rabarta Lv2");
-    codes.insert(0x050502, "Rabarta Lv3");
-    codes.insert(0x050503, "Rabarta Lv4");
-    codes.insert(0x050504, "Rabarta Lv5");
-    codes.insert(0x050505, "Rabarta Lv6");
-    codes.insert(0x050506, "Rabarta Lv7");
-    codes.insert(0x050507, "Rabarta Lv8");
-    codes.insert(0x050508, "Rabarta Lv9");
-    codes.insert(0x050509, "Rabarta Lv10");
-    codes.insert(0x05050A, "Rabarta Lv11");
-    codes.insert(0x05050B, "Rabarta Lv12");
-    codes.insert(0x05050C, "Rabarta Lv13");
-    codes.insert(0x05050D, "Rabarta Lv14");
-    codes.insert(0x05050E, "Rabarta Lv15");
-    codes.insert(0x05050F, "Rabarta Lv16");
-    codes.insert(0x050510, "Rabarta Lv17");
-    codes.insert(0x050511, "Rabarta Lv18");
-    codes.insert(0x050512, "Rabarta Lv19");
-    codes.insert(0x050513, "Rabarta Lv20");
-    codes.insert(0x050514, "Rabarta Lv21");
-    codes.insert(0x050515, "Rabarta Lv22");
-    codes.insert(0x050516, "Rabarta Lv23");
-    codes.insert(0x050517, "Rabarta Lv24");
-    codes.insert(0x050518, "Rabarta Lv25");
-    codes.insert(0x050519, "Rabarta Lv26");
-    codes.insert(0x05051A, "Rabarta Lv27");
-    codes.insert(0x05051B, "Rabarta Lv28");
-    codes.insert(0x05051C, "Rabarta Lv29");
-    codes.insert(0x05051D, "Rabarta Lv30");
-    codes.insert(0x050600, "Zonde Lv1");
-    codes.insert(0x050601, "Zonde Lv2");
-    codes.insert(0x050602, "Zonde Lv3");
-    codes.insert(0x050603, "Zonde Lv4");
-    codes.insert(0x050604, "Zonde Lv5");
-    codes.insert(0x050605, "Zonde Lv6");
-    codes.insert(0x050606, "Zonde Lv7");
-    codes.insert(0x050607, "Zonde Lv8");
-    codes.insert(0x050608, "Zonde Lv9");
-    codes.insert(0x050609, "Zonde Lv10");
-    codes.insert(0x05060A, "Zonde Lv11");
-    codes.insert(0x05060B, "Zonde Lv12");
-    codes.insert(0x05060C, "Zonde Lv13");
-    codes.insert(0x05060D, "Zonde Lv14");
-    codes.insert(0x05060E, "Zonde Lv15");
-    codes.insert(0x05060F, "Zonde Lv16");
-    codes.insert(0x050610, "Zonde Lv17");
-    codes.insert(0x050611, "Zonde Lv18");
-    codes.insert(0x050612, "Zonde Lv19");
-    codes.insert(0x050613, "Zonde Lv20");
-    codes.insert(0x050614, "Zonde Lv21");
-    codes.insert(0x050615, "Zonde Lv22");
-    codes.insert(0x050616, "Zonde Lv23");
-    codes.insert(0x050617, "Zonde Lv24");
-    codes.insert(0x050618, "Zonde Lv25");
-    codes.insert(0x050619, "Zonde Lv26");
-    codes.insert(0x05061A, "Zonde Lv27");
-    codes.insert(0x05061B, "Zonde Lv28");
-    codes.insert(0x05061C, "Zonde Lv29");
-    codes.insert(0x05061D, "Zonde Lv30");
-    codes.insert(0x050700, "Gizonde Lv1");
-    codes.insert(0x050701, "Gizonde Lv2");
-    codes.insert(0x050702, "Gizonde Lv3");
-    codes.insert(0x050703, "Gizonde Lv4");
-    codes.insert(0x050704, "Gizonde Lv5");
-    codes.insert(0x050705, "Gizonde Lv6");
-    codes.insert(0x050706, "Gizonde Lv7");
-    codes.insert(0x050707, "Gizonde Lv8");
-    codes.insert(0x050708, "Gizonde Lv9");
-    codes.insert(0x050709, "Gizonde Lv10");
-    codes.insert(0x05070A, "Gizonde Lv11");
-    codes.insert(0x05070B, "Gizonde Lv12");
-    codes.insert(0x05070C, "Gizonde Lv13");
-    codes.insert(0x05070D, "Gizonde Lv14");
-    codes.insert(0x05070E, "Gizonde Lv15");
-    codes.insert(0x05070F, "Gizonde Lv16");
-    codes.insert(0x050710, "Gizonde Lv17");
-    codes.insert(0x050711, "Gizonde Lv18");
-    codes.insert(0x050712, "Gizonde Lv19");
-    codes.insert(0x050713, "Gizonde Lv20");
-    codes.insert(0x050714, "Gizonde Lv21");
-    codes.insert(0x050715, "Gizonde Lv22");
-    codes.insert(0x050716, "Gizonde Lv23");
-    codes.insert(0x050717, "Gizonde Lv24");
-    codes.insert(0x050718, "Gizonde Lv25");
-    codes.insert(0x050719, "Gizonde Lv26");
-    codes.insert(0x05071A, "Gizonde Lv27");
-    codes.insert(0x05071B, "Gizonde Lv28");
-    codes.insert(0x05071C, "Gizonde Lv29");
-    codes.insert(0x05071D, "Gizonde Lv30");
-    codes.insert(0x050800, "Razonde Lv1");
-    codes.insert(0x050801, "Razonde Lv2");
-    codes.insert(0x050802, "Razonde Lv3");
-    codes.insert(0x050803, "Razonde Lv4");
-    codes.insert(0x050804, "Razonde Lv5");
-    codes.insert(0x050805, "Razonde Lv6");
-    codes.insert(0x050806, "Razonde Lv7");
-    codes.insert(0x050807, "Razonde Lv8");
-    codes.insert(0x050808, "Razonde Lv9");
-    codes.insert(0x050809, "Razonde Lv10");
-    codes.insert(0x05080A, "Razonde Lv11");
-    codes.insert(0x05080B, "Razonde Lv12");
-    codes.insert(0x05080C, "Razonde Lv13");
-    codes.insert(0x05080D, "Razonde Lv14");
-    codes.insert(0x05080E, "Razonde Lv15");
-    codes.insert(0x05080F, "Razonde Lv16");
-    codes.insert(0x050810, "Razonde Lv17");
-    codes.insert(0x050811, "Razonde Lv18");
-    codes.insert(0x050812, "Razonde Lv19");
-    codes.insert(0x050813, "Razonde Lv20");
-    codes.insert(0x050814, "Razonde Lv21");
-    codes.insert(0x050815, "Razonde Lv22");
-    codes.insert(0x050816, "Razonde Lv23");
-    codes.insert(0x050817, "Razonde Lv24");
-    codes.insert(0x050818, "Razonde Lv25");
-    codes.insert(0x050819, "Razonde Lv26");
-    codes.insert(0x05081A, "Razonde Lv27");
-    codes.insert(0x05081B, "Razonde Lv28");
-    codes.insert(0x05081C, "Razonde Lv29");
-    codes.insert(0x05081D, "Razonde Lv30");
-    codes.insert(0x050900, "Grants Lv1");
-    codes.insert(0x050901, "Grants Lv2");
-    codes.insert(0x050902, "Grants Lv3");
-    codes.insert(0x050903, "Grants Lv4");
-    codes.insert(0x050904, "Grants Lv5");
-    codes.insert(0x050905, "Grants Lv6");
-    codes.insert(0x050906, "Grants Lv7");
-    codes.insert(0x050907, "Grants Lv8");
-    codes.insert(0x050908, "Grants Lv9");
-    codes.insert(0x050909, "Grants Lv10");
-    codes.insert(0x05090A, "Grants Lv11");
-    codes.insert(0x05090B, "Grants Lv12");
-    codes.insert(0x05090C, "Grants Lv13");
-    codes.insert(0x05090D, "Grants Lv14");
-    codes.insert(0x05090E, "Grants Lv15");
-    codes.insert(0x05090F, "Grants Lv16");
-    codes.insert(0x050910, "Grants Lv17");
-    codes.insert(0x050911, "Grants Lv18");
-    codes.insert(0x050912, "Grants Lv19");
-    codes.insert(0x050913, "Grants Lv20");
-    codes.insert(0x050914, "Grants Lv21");
-    codes.insert(0x050915, "Grants Lv22");
-    codes.insert(0x050916, "Grants Lv23");
-    codes.insert(0x050917, "Grants Lv24");
-    codes.insert(0x050918, "Grants Lv25");
-    codes.insert(0x050919, "Grants Lv26");
-    codes.insert(0x05091A, "Grants Lv27");
-    codes.insert(0x05091B, "Grants Lv28");
-    codes.insert(0x05091C, "Grants Lv29");
-    codes.insert(0x05091D, "Grants Lv30");
-    codes.insert(0x050A00, "Deband Lv1");
-    codes.insert(0x050A01, "Deband Lv2");
-    codes.insert(0x050A02, "Deband Lv3");
-    codes.insert(0x050A03, "Deband Lv4");
-    codes.insert(0x050A04, "Deband Lv5");
-    codes.insert(0x050A05, "Deband Lv6");
-    codes.insert(0x050A06, "Deband Lv7");
-    codes.insert(0x050A07, "Deband Lv8");
-    codes.insert(0x050A08, "Deband Lv9");
-    codes.insert(0x050A09, "Deband Lv10");
-    codes.insert(0x050A0A, "Deband Lv11");
-    codes.insert(0x050A0B, "Deband Lv12");
-    codes.insert(0x050A0C, "Deband Lv13");
-    codes.insert(0x050A0D, "Deband Lv14");
-    codes.insert(0x050A0E, "Deband Lv15");
-    codes.insert(0x050A0F, "Deband Lv16");
-    codes.insert(0x050A10, "Deband Lv17");
-    codes.insert(0x050A11, "Deband Lv18");
-    codes.insert(0x050A12, "Deband Lv19");
-    codes.insert(0x050A13, "Deband Lv20");
-    codes.insert(0x050A14, "Deband Lv21");
-    codes.insert(0x050A15, "Deband Lv22");
-    codes.insert(0x050A16, "Deband Lv23");
-    codes.insert(0x050A17, "Deband Lv24");
-    codes.insert(0x050A18, "Deband Lv25");
-    codes.insert(0x050A19, "Deband Lv26");
-    codes.insert(0x050A1A, "Deband Lv27");
-    codes.insert(0x050A1B, "Deband Lv28");
-    codes.insert(0x050A1C, "Deband Lv29");
-    codes.insert(0x050A1D, "Deband Lv30");
-    codes.insert(0x050B00, "Jellen Lv1");
-    codes.insert(0x050B01, "Jellen Lv2");
-    codes.insert(0x050B02, "Jellen Lv3");
-    codes.insert(0x050B03, "Jellen Lv4");
-    codes.insert(0x050B04, "Jellen Lv5");
-    codes.insert(0x050B05, "Jellen Lv6");
-    codes.insert(0x050B06, "Jellen Lv7");
-    codes.insert(0x050B07, "Jellen Lv8");
-    codes.insert(0x050B08, "Jellen Lv9");
-    codes.insert(0x050B09, "Jellen Lv10");
-    codes.insert(0x050B0A, "Jellen Lv11");
-    codes.insert(0x050B0B, "Jellen Lv12");
-    codes.insert(0x050B0C, "Jellen Lv13");
-    codes.insert(0x050B0D, "Jellen Lv14");
-    codes.insert(0x050B0E, "Jellen Lv15");
-    codes.insert(0x050B0F, "Jellen Lv16");
-    codes.insert(0x050B10, "Jellen Lv17");
-    codes.insert(0x050B11, "Jellen Lv18");
-    codes.insert(0x050B12, "Jellen Lv19");
-    codes.insert(0x050B13, "Jellen Lv20");
-    codes.insert(0x050B14, "Jellen Lv21");
-    codes.insert(0x050B15, "Jellen Lv22");
-    codes.insert(0x050B16, "Jellen Lv23");
-    codes.insert(0x050B17, "Jellen Lv24");
-    codes.insert(0x050B18, "Jellen Lv25");
-    codes.insert(0x050B19, "Jellen Lv26");
-    codes.insert(0x050B1A, "Jellen Lv27");
-    codes.insert(0x050B1B, "Jellen Lv28");
-    codes.insert(0x050B1C, "Jellen Lv29");
-    codes.insert(0x050B1D, "Jellen Lv30");
-    codes.insert(0x050C00, "Zalure Lv1");
-    codes.insert(0x050C01, "Zalure Lv2");
-    codes.insert(0x050C02, "Zalure Lv3");
-    codes.insert(0x050C03, "Zalure Lv4");
-    codes.insert(0x050C04, "Zalure Lv5");
-    codes.insert(0x050C05, "Zalure Lv6");
-    codes.insert(0x050C06, "Zalure Lv7");
-    codes.insert(0x050C07, "Zalure Lv8");
-    codes.insert(0x050C08, "Zalure Lv9");
-    codes.insert(0x050C09, "Zalure Lv10");
-    codes.insert(0x050C0A, "Zalure Lv11");
-    codes.insert(0x050C0B, "Zalure Lv12");
-    codes.insert(0x050C0C, "Zalure Lv13");
-    codes.insert(0x050C0D, "Zalure Lv14");
-    codes.insert(0x050C0E, "Zalure Lv15");
-    codes.insert(0x050C0F, "Zalure Lv16");
-    codes.insert(0x050C10, "Zalure Lv17");
-    codes.insert(0x050C11, "Zalure Lv18");
-    codes.insert(0x050C12, "Zalure Lv19");
-    codes.insert(0x050C13, "Zalure Lv20");
-    codes.insert(0x050C14, "Zalure Lv21");
-    codes.insert(0x050C15, "Zalure Lv22");
-    codes.insert(0x050C16, "Zalure Lv23");
-    codes.insert(0x050C17, "Zalure Lv24");
-    codes.insert(0x050C18, "Zalure Lv25");
-    codes.insert(0x050C19, "Zalure Lv26");
-    codes.insert(0x050C1A, "Zalure Lv27");
-    codes.insert(0x050C1B, "Zalure Lv28");
-    codes.insert(0x050C1C, "Zalure Lv29");
-    codes.insert(0x050C1D, "Zalure Lv30");
-    codes.insert(0x050D00, "Shifta Lv1");
-    codes.insert(0x050D01, "Shifta Lv2");
-    codes.insert(0x050D02, "Shifta Lv3");
-    codes.insert(0x050D03, "Shifta Lv4");
-    codes.insert(0x050D04, "Shifta Lv5");
-    codes.insert(0x050D05, "Shifta Lv6");
-    codes.insert(0x050D06, "Shifta Lv7");
-    codes.insert(0x050D07, "Shifta Lv8");
-    codes.insert(0x050D08, "Shifta Lv9");
-    codes.insert(0x050D09, "Shifta Lv10");
-    codes.insert(0x050D0A, "Shifta Lv11");
-    codes.insert(0x050D0B, "Shifta Lv12");
-    codes.insert(0x050D0C, "Shifta Lv13");
-    codes.insert(0x050D0D, "Shifta Lv14");
-    codes.insert(0x050D0E, "Shifta Lv15");
-    codes.insert(0x050D0F, "Shifta Lv16");
-    codes.insert(0x050D10, "Shifta Lv17");
-    codes.insert(0x050D11, "Shifta Lv18");
-    codes.insert(0x050D12, "Shifta Lv19");
-    codes.insert(0x050D13, "Shifta Lv20");
-    codes.insert(0x050D14, "Shifta Lv21");
-    codes.insert(0x050D15, "Shifta Lv22");
-    codes.insert(0x050D16, "Shifta Lv23");
-    codes.insert(0x050D17, "Shifta Lv24");
-    codes.insert(0x050D18, "Shifta Lv25");
-    codes.insert(0x050D19, "Shifta Lv26");
-    codes.insert(0x050D1A, "Shifta Lv27");
-    codes.insert(0x050D1B, "Shifta Lv28");
-    codes.insert(0x050D1C, "Shifta Lv29");
-    codes.insert(0x050D1D, "Shifta Lv30");
-    codes.insert(0x050E00, "Ryuker Lv1");
-    codes.insert(0x050F00, "Resta Lv1");
-    codes.insert(0x050F01, "Resta Lv2");
-    codes.insert(0x050F02, "Resta Lv3");
-    codes.insert(0x050F03, "Resta Lv4");
-    codes.insert(0x050F04, "Resta Lv5");
-    codes.insert(0x050F05, "Resta Lv6");
-    codes.insert(0x050F06, "Resta Lv7");
-    codes.insert(0x050F07, "Resta Lv8");
-    codes.insert(0x050F08, "Resta Lv9");
-    codes.insert(0x050F09, "Resta Lv10");
-    codes.insert(0x050F0A, "Resta Lv11");
-    codes.insert(0x050F0B, "Resta Lv12");
-    codes.insert(0x050F0C, "Resta Lv13");
-    codes.insert(0x050F0D, "Resta Lv14");
-    codes.insert(0x050F0E, "Resta Lv15");
-    codes.insert(0x050F0F, "Resta Lv16");
-    codes.insert(0x050F10, "Resta Lv17");
-    codes.insert(0x050F11, "Resta Lv18");
-    codes.insert(0x050F12, "Resta Lv19");
-    codes.insert(0x050F13, "Resta Lv20");
-    codes.insert(0x050F14, "Resta Lv21");
-    codes.insert(0x050F15, "Resta Lv22");
-    codes.insert(0x050F16, "Resta Lv23");
-    codes.insert(0x050F17, "Resta Lv24");
-    codes.insert(0x050F18, "Resta Lv25");
-    codes.insert(0x050F19, "Resta Lv26");
-    codes.insert(0x050F1A, "Resta Lv27");
-    codes.insert(0x050F1B, "Resta Lv28");
-    codes.insert(0x050F1C, "Resta Lv29");
-    codes.insert(0x050F1D, "Resta Lv30");
-    codes.insert(0x051000, "Anti Lv1");
-    codes.insert(0x051001, "Anti Lv2");
-    codes.insert(0x051002, "Anti Lv3");
-    codes.insert(0x051003, "Anti Lv4");
-    codes.insert(0x051004, "Anti Lv5");
-    codes.insert(0x051005, "Anti Lv6");
-    codes.insert(0x051006, "Anti Lv7");
-    codes.insert(0x051100, "Reverser Lv1");
-    codes.insert(0x051200, "Megid Lv1");
-    codes.insert(0x051201, "Megid Lv2");
-    codes.insert(0x051202, "Megid Lv3");
-    codes.insert(0x051203, "Megid Lv4");
-    codes.insert(0x051204, "Megid Lv5");
-    codes.insert(0x051205, "Megid Lv6");
-    codes.insert(0x051206, "Megid Lv7");
-    codes.insert(0x051207, "Megid Lv8");
-    codes.insert(0x051208, "Megid Lv9");
-    codes.insert(0x051209, "Megid Lv10");
-    codes.insert(0x05120A, "Megid Lv11");
-    codes.insert(0x05120B, "Megid Lv12");
-    codes.insert(0x05120C, "Megid Lv13");
-    codes.insert(0x05120D, "Megid Lv14");
-    codes.insert(0x05120E, "Megid Lv15");
-    codes.insert(0x05120F, "Megid Lv16");
-    codes.insert(0x051210, "Megid Lv17");
-    codes.insert(0x051211, "Megid Lv18");
-    codes.insert(0x051212, "Megid Lv19");
-    codes.insert(0x051213, "Megid Lv20");
-    codes.insert(0x051214, "Megid Lv21");
-    codes.insert(0x051215, "Megid Lv22");
-    codes.insert(0x051216, "Megid Lv23");
-    codes.insert(0x051217, "Megid Lv24");
-    codes.insert(0x051218, "Megid Lv25");
-    codes.insert(0x051219, "Megid Lv26");
-    codes.insert(0x05121A, "Megid Lv27");
-    codes.insert(0x05121B, "Megid Lv28");
-    codes.insert(0x05121C, "Megid Lv29");
-    codes.insert(0x05121D, "Megid Lv30");
-    // Ephinea Original
-    codes.insert(0x00040C, "Asteron Belt* - Plantain Huge Fan");
-    codes.insert(0x00040D, "Vjaya* - Plantain Huge Fan");
-    codes.insert(0x00040E, "Vjaya* - Chameleon Scythe");
-    codes.insert(0x00040F, "Vjaya* - Soul Banish");
-    codes.insert(0x000410, "Asteron Belt* - Chameleon Scythe");
-    codes.insert(0x000411, "Asteron Belt* - Soul Banish");
-    codes.insert(0x00050C, "Diska of Braveman* - Diska of Liberator");
-    codes.insert(0x00050D, "Diska of Braveman* - Izmaela");
-    codes.insert(0x000808, "M&A60 Vise* - Yasminkov 9000M");
-    codes.insert(0x00080C, "M&A60 Vise* - Samba Maracas");
-    codes.insert(0x001201, "Spread Needle* - Rianov 303SNR");
-    codes.insert(0x001B01, "Bringer's Rifle* - Rianov 303SNR");
-    codes.insert(0x001B02, "Bringer's Rifle* - Egg Blaster");
-    codes.insert(0x001B03, "Bringer's Rifle* - Angel Harp");
-    codes.insert(0x001D01, "Psycho Wand* - Rabbit Wand");
-    codes.insert(0x001D02, "Psycho Wand* - Sorcerer's Cane");
-    codes.insert(0x001E01, "Heaven Punisher* - Tension Blaster");
-    codes.insert(0x001E02, "Heaven Punisher* - Suppressed Gun");
-    codes.insert(0x001E03, "Heaven Punisher* - Ruby Bullet");
-    codes.insert(0x002101, "Chain Sawd* - Daisy Chain");
-    codes.insert(0x002102, "Chain Sawd* - Crazy Tune");
-    codes.insert(0x002401, "Magical Piece* - The Sigh of a God");
-    codes.insert(0x002D01, "Red Saber* - DB's Saber");
-    codes.insert(0x002D02, "Red Saber* - Ancient Saber");
-    codes.insert(0x002D03, "Red Saber* - Delsaber's Buster");
-    codes.insert(0x002D04, "Red Saber* - Flamberge");
-    codes.insert(0x003201, "Tsumikiri J-Sword* - Daisy Chain");
-    codes.insert(0x003202, "Tsumikiri J-Sword* - Crazy Tune");
-    codes.insert(0x003203, "Tsumikiri J-Sword* - Lollipop");
-    codes.insert(0x003204, "Tsumikiri J-Sword* - Laconium Axe");
-    codes.insert(0x003401, "Red Sword* - Daisy Chain");
-    codes.insert(0x003402, "Red Sword* - Crazy Tune");
-    codes.insert(0x004502, "Frozen Shooter* - Rianov 303SNR");
-    codes.insert(0x004503, "Frozen Shooter* - Egg Blaster");
-    codes.insert(0x004504, "Snow Queen* - Egg Blaster");
-    codes.insert(0x004505, "Frozen Shooter* - Angel Harp");
-    codes.insert(0x004506, "Snow Queen* - Angel Harp");
-    codes.insert(0x004507, "Snow Queen* - Rianov 303SNR");
-    codes.insert(0x004B02, "Guld Milla* - Yasminkov 9000M");
-    codes.insert(0x004B03, "Dual Bird* - Yasminkov 9000M");
-    codes.insert(0x004B04, "Guld Milla* - Samba Maracas");
-    codes.insert(0x004B05, "Dual Bird* - Samba Maracas");
-    codes.insert(0x004F01, "Summit Moon* - Lollipop");
-    codes.insert(0x005801, "Striker of Chao* - Rabbit Wand");
-    codes.insert(0x005802, "Striker of Chao* - Sorcerer's Cane");
-    codes.insert(0x005A01, "Prophets of Motav* - The Sigh of a God");
-    codes.insert(0x008F09, "Flowen's Sword (3084)* - Daisy Chain");
-    codes.insert(0x008F0A, "Flowen's Sword (3084)* - Crazy Tune");
-    codes.insert(0x009701, "Zanba* - Daisy Chain");
-    codes.insert(0x009702, "Zanba* - Crazy Tune");
-    codes.insert(0x009703, "Zanba* - Laconium Axe");
-    codes.insert(0x009C01, "Rainbow Baton* - Diska of Liberator");
-    codes.insert(0x009C02, "Rainbow Baton* - Izmaela");
-    codes.insert(0x009D01, "Dark Flow* - Daisy Chain (Yellow)");
-    codes.insert(0x009D02, "Dark Flow* - Crazy Tune");
-    codes.insert(0x009D03, "Dark Flow* - Ancient Saber");
-    codes.insert(0x009D04, "Dark Flow* - Laconium Axe");
-    codes.insert(0x009D05, "Dark Flow* - Daisy Chain (Blue and Green)");
-    codes.insert(0x009D06, "Dark Flow* - Daisy Chain (Rose)");
-    codes.insert(0x009D07, "Dark Flow* - Daisy Chain (Chartreuse)");
-    codes.insert(0x009D08, "Dark Flow* - Daisy Chain (Mauve)");
-    codes.insert(0x009D09, "Dark Flow* - Daisy Chain (Cyan)");
-    codes.insert(0x009D0A, "Dark Flow* - Daisy Chain (Orange)");
-    codes.insert(0x009D0B, "Dark Flow* - Daisy Chain (White)");
-    codes.insert(0x009D0C, "Dark Flow* - Daisy Chain (Green)");
-    codes.insert(0x009D0D, "Dark Flow* - Daisy Chain (Blue)");
-    codes.insert(0x009D0E, "Dark Flow* - Daisy Chain (Purple)");
-    codes.insert(0x009D0F, "Dark Flow* - Daisy Chain (Red)");
-    codes.insert(0x00AA01, "Slicer of Fanatic* - Diska of Liberator");
-    codes.insert(0x00AA02, "Slicer of Fanatic* - Izmaela");
-    codes.insert(0x00AC01, "Excalibur* - Lollipop");
-    codes.insert(0x00AC02, "Excalibur* - DB's Saber");
-    codes.insert(0x00AC03, "Excalibur* - Ancient Saber");
-    codes.insert(0x00AC04, "Excalibur* - Delsaber's Buster");
-    codes.insert(0x00AC05, "Excalibur* - Flamberge (Blue)");
-    codes.insert(0x00AC06, "Excalibur* - Flamberge (Purple)");
-    codes.insert(0x00AC07, "Excalibur* - Flamberge (Red)");
-    codes.insert(0x00AC08, "Excalibur* - Flamberge (Yellow)");
-    codes.insert(0x00AC09, "Excalibur* - Flamberge (Blue and Green)");
-    codes.insert(0x00AC0A, "Excalibur* - Flamberge (Rose)");
-    codes.insert(0x00AC0B, "Excalibur* - Flamberge (Chartreuse)");
-    codes.insert(0x00AC0C, "Excalibur* - Flamberge (Mauve)");
-    codes.insert(0x00AC0D, "Excalibur* - Flamberge (Cyan)");
-    codes.insert(0x00AC0E, "Excalibur* - Flamberge (Orange)");
-    codes.insert(0x00AC0F, "Excalibur* - Flamberge (White)");
-    codes.insert(0x00AC10, "Excalibur* - Flamberge (Green)");
-    codes.insert(0x00B001, "Mille Marteaux* - Yasminkov 9000M");
-    codes.insert(0x00B002, "Mille Marteaux* - Samba Maracas");
-    codes.insert(0x00BA01, "Yunchang* - Plantain Huge Fan");
-    codes.insert(0x00BA02, "Yunchang* - Chameleon Scythe");
-    codes.insert(0x00BA03, "Yunchang* - Soul Banish");
-    codes.insert(0x00BF01, "Heaven Striker* - Tension Blaster");
-    codes.insert(0x00BF02, "Heaven Striker* - Suppressed Gun (Green)");
-    codes.insert(0x00BF03, "Heaven Striker* - Ruby Bullet");
-    codes.insert(0x00BF04, "Heaven Striker* - Suppressed Gun (Blue)");
-    codes.insert(0x00BF05, "Heaven Striker* - Suppressed Gun (Purple)");
-    codes.insert(0x00BF06, "Heaven Striker* - Suppressed Gun (Red)");
-    codes.insert(0x00BF07, "Heaven Striker* - Suppressed Gun (Yellow)");
-    codes.insert(0x00BF08, "Heaven Striker* - Suppressed Gun (Blue and Green)");
-    codes.insert(0x00BF09, "Heaven Striker* - Suppressed Gun (Rose)");
-    codes.insert(0x00BF0A, "Heaven Striker* - Suppressed Gun (Chartreuse)");
-    codes.insert(0x00BF0B, "Heaven Striker* - Suppressed Gun (Mauve)");
-    codes.insert(0x00BF0C, "Heaven Striker* - Suppressed Gun (Cyan)");
-    codes.insert(0x00BF0D, "Heaven Striker* - Suppressed Gun (Orange)");
-    codes.insert(0x00BF0E, "Heaven Striker* - Suppressed Gun (White)");
-    codes.insert(0x00C501, "Glide Divine* - Rabbit Wand");
-    codes.insert(0x00C502, "Glide Divine* - Sorcerer's Cane");
-    codes.insert(0x010253, "RED RING* - Blue Paint");
-    codes.insert(0x01025B, "RED RING* - Green Paint");
-    codes.insert(0x010263, "RED RING* - Yellow Paint");
-    codes.insert(0x01026B, "RED RING* - Purple Paint");
-    codes.insert(0x010273, "Anti-Dark Ring");
-    codes.insert(0x010274, "RED RING* - White Paint");
-    codes.insert(0x01027B, "Anti-Light Ring");
-    codes.insert(0x01027C, "RED RING* - Black Paint");
-    codes.insert(0x0102A5, "RED RING* - Chartreuse Paint");
-    codes.insert(0x0102A6, "RED RING* - Cyan Paint");
-    codes.insert(0x0102A7, "RED RING* - Onyx Paint");
-    codes.insert(0x0102A8, "RED RING* - Orange Paint");
-    codes.insert(0x0102A9, "RED RING* - Rose Paint");
-    codes.insert(0x0102AA, "RED RING* - Ultramarine Paint");
-    codes.insert(0x0102AB, "RED RING* - Violet Paint");
-    codes.insert(0x0102AC, "RED RING* - Delsaber Plating");
-    codes.insert(0x0102AD, "RED RING* - Standstill Plating");
-    codes.insert(0x0102AE, "RED RING* - Honeycomb Plating");
-    codes.insert(0x0102AF, "RED RING* - Epsilon Plating");
-    codes.insert(0x0102B0, "RED RING* - Heart Plating");
-    codes.insert(0x0102B1, "RED RING* - Angel Plating");
-    codes.insert(0x0102B2, "RED RING* - Bunny Plating");
-    codes.insert(0x0102B3, "RED RING* - Cat Plating");
-    codes.insert(0x0102B4, "RED RING* - Deep Plating");
-    codes.insert(0x024D00, "Stealth");
-    codes.insert(0x024E00, "Mag*");
-    codes.insert(0x024F00, "Varuna*");
-    codes.insert(0x025000, "Kalki*");
-    codes.insert(0x025100, "Vritra*");
-    codes.insert(0x030E28, "Heart of Daisy Chain");
-    codes.insert(0x030E29, "Heart of Crazy Tune");
-    codes.insert(0x030E2A, "Heart of Rianov 303SNR");
-    codes.insert(0x030E2B, "Heart of Yasminkov 9000M");
-    codes.insert(0x030E2C, "Heart of Rabbit Wand");
-    codes.insert(0x030E2D, "Heart of Sorcerer's Cane");
-    codes.insert(0x030E2E, "Neutralizer");
-    codes.insert(0x030E2F, "Blue Paint");
-    codes.insert(0x030E30, "Green Paint");
-    codes.insert(0x030E31, "Yellow Paint");
-    codes.insert(0x030E32, "Purple Paint");
-    codes.insert(0x030E33, "White Paint");
-    codes.insert(0x030E34, "Black Paint");
-    codes.insert(0x030E35, "Red Paint");
-    codes.insert(0x030E36, "Heart of Suppressed Gun");
-    codes.insert(0x030E37, "Heart of Tension Blaster");
-    codes.insert(0x030E38, "Heart of Samba Maracas");
-    codes.insert(0x030E39, "Heart of Lollipop");
-    codes.insert(0x030E3A, "Heart of DB's Saber");
-    codes.insert(0x030E3B, "Heart of Plantain Huge Fan");
-    codes.insert(0x030E3C, "Heart of Diska of Liberator");
-    codes.insert(0x030E3D, "Heart of Izmaela");
-    codes.insert(0x030E3E, "Heart of The Sigh of a God");
-    codes.insert(0x030E3F, "Heart of Angel Harp");
-    codes.insert(0x030E40, "Heart of Ruby Bullet");
-    codes.insert(0x030E41, "Heart of Ancient Saber");
-    codes.insert(0x030E42, "Heart of Laconium Axe");
-    codes.insert(0x030E43, "Heart of Delsaber's Buster");
-    codes.insert(0x030E44, "Heart of Egg Blaster");
-    codes.insert(0x030E45, "Photon Filter");
-    codes.insert(0x030E46, "Heart of Flamberge");
-    codes.insert(0x030E47, "Heart of Chameleon Scythe");
-    codes.insert(0x030E48, "Heart of Soul Banish");
-    codes.insert(0x030E49, "Chartreuse Paint");
-    codes.insert(0x030E4A, "Cyan Paint");
-    codes.insert(0x030E4B, "Onyx Paint");
-    codes.insert(0x030E4C, "Orange Paint");
-    codes.insert(0x030E4D, "Rose Paint");
-    codes.insert(0x030E4E, "Ultramarine Paint");
-    codes.insert(0x030E4F, "Violet Paint");
-    codes.insert(0x030E50, "Delsaber Plating");
-    codes.insert(0x030E51, "Standstill Plating");
-    codes.insert(0x030E52, "Honeycomb Plating");
-    codes.insert(0x030E53, "Epsilon Plating");
-    codes.insert(0x030E54, "Heart Plating");
-    codes.insert(0x030E55, "Angel Plating");
-    codes.insert(0x030E56, "Bunny Plating");
-    codes.insert(0x030E57, "Cat Plating");
-    codes.insert(0x030E58, "Deep Plating");
-    codes.insert(0x031005, "Event Egg");
-    codes.insert(0x031006, "1st Anniv. Bronze Badge");
-    codes.insert(0x031007, "1st Anniv. Silver Badge");
-    codes.insert(0x031008, "1st Anniv. Gold Badge");
-    codes.insert(0x031009, "1st Anniv. Platinum Badge");
-    codes.insert(0x03100A, "2nd Anniv. Bronze Badge");
-    codes.insert(0x03100B, "2nd Anniv. Silver Badge");
-    codes.insert(0x03100C, "2nd Anniv. Gold Badge");
-    codes.insert(0x03100D, "2nd Anniv. Platinum Badge");
-    codes.insert(0x031010, "3rd Anniv. Bronze Badge");
-    codes.insert(0x031011, "3rd Anniv. Silver Badge");
-    codes.insert(0x031012, "3rd Anniv. Gold Badge");
-    codes.insert(0x031013, "3rd Anniv. Platinum Badge");
-    codes.insert(0x031014, "Photon Hoard");
-    codes.insert(0x031015, "4th Anniv. Bronze Badge");
-    codes.insert(0x031016, "4th Anniv. Silver Badge");
-    codes.insert(0x031017, "4th Anniv. Gold Badge");
-    codes.insert(0x031018, "4th Anniv. Platinum Badge");
-    codes.insert(0x031019, "Anniv. Bronze Badge");
-    codes.insert(0x03101A, "Anniv. Silver Badge");
-    codes.insert(0x03101B, "Anniv. Gold Badge");
-    codes.insert(0x03101C, "Anniv. Platinum Badge");
-    codes.insert(0x03100E, "Halloween Cookie");
-    codes.insert(0x03100F, "Coal");
-    codes.insert(0x03160C, "Disk Vol.13 \"Get It Up\"");
-    codes.insert(0x03160D, "Disk Vol.14 \"Flight\"");
-    codes.insert(0x03160E, "Disk Vol.15 \"Space Harrier\"");
-    codes.insert(0x03160F, "Disk Vol.16 \"Deathwatch\"");
-    codes.insert(0x031610, "Disk Vol.17 \"Fly Me To The Moon\"");
-    codes.insert(0x031611, "Disk Vol.18 \"Puyo Puyo\"");
-    codes.insert(0x031612, "Disk Vol.19 \"Rhythm And Balance\"");
-    codes.insert(0x031613, "Disk Vol.20 \"The Party Must Go On\"");
-    codes.insert(0x031614, "Disk Vol.21 \"Armada Battle\"");
-    codes.insert(0x031615, "Disk Vol.22 \"Back 2 Back\"");
-    codes.insert(0x031616, "Disk Vol.23 \"The Strange Fruits\"");
-    codes.insert(0x031617, "Disk Vol.24 \"The Whims of Fate\"");
-    codes.insert(0x031618, "Disk Vol.25 \"Last Impression\"");
-    codes.insert(0x031705, "Viridia Badge");
-    codes.insert(0x031706, "Greenill Badge");
-    codes.insert(0x031707, "Skyly Badge");
-    codes.insert(0x031708, "Bluefull Badge");
-    codes.insert(0x031709, "Purplenum Badge");
-    codes.insert(0x03170A, "Pinkal Badge");
-    codes.insert(0x03170B, "Redria Badge");
-    codes.insert(0x03170C, "Oran Badge");
-    codes.insert(0x03170D, "Yellowboze Badge");
-    codes.insert(0x03170E, "Whitill Badge");
-    codes.insert(0x03180B, "Stealth Kit");
-    codes.insert(0x03180C, "Mag Kit");
-    codes.insert(0x03180D, "Varuna Kit");
-    codes.insert(0x03180E, "Kalki Kit");
-    codes.insert(0x03180F, "Vritra Kit");
-    codes.insert(0x031810, "Heart of YN-0117");
-    codes
-}
\ No newline at end of file
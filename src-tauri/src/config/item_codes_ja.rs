@@ -602,233 +602,6 @@ pub fn specials_list_ja() -> Vec<&'static str> {
     ]
 }
 
-pub fn frames_ja() -> HashMap<&'static str, [i32; 2]> {
-    let mut map = HashMap::new();
-    map.insert("フレーム", [ 2,2 ]);
-    map.insert("アーマー", [ 2,2 ]);
-    map.insert("サイアーマー", [ 3,2 ]);
-    map.insert("ギガフレーム", [ 4,2 ]);
-    map.insert("ソウルフレーム", [ 4,2 ]);
-    map.insert("クロスアーマー", [ 4,2 ]);
-    map.insert("ソリッドフレーム", [ 4,2 ]);
-    map.insert("ブレイブアーマー", [ 4,2 ]);
-    map.insert("ハイパーフレーム", [ 4,2 ]);
-    map.insert("グランドアーマー", [ 4,2 ]);
-    map.insert("ショックフレーム", [ 4,2 ]);
-    map.insert("キングスフレーム", [ 4,2 ]);
-    map.insert("ドラゴンフレーム", [ 4,2 ]);
-    map.insert("アブソーブアーマー", [ 4,2 ]);
-    map.insert("プロテクトフレーム", [ 4,2 ]);
-    map.insert("ジェネラルアーマー", [ 4,2 ]);
-    map.insert("パーフェクトフレーム", [ 4,2 ]);
-    map.insert("ヴァリアントフレーム", [ 4,2 ]);
-    map.insert("インペリアルアーマー", [ 4,2 ]);
-    map.insert("ホーリネスアーマー", [ 4,2 ]);
-    map.insert("ガーディアンアーマー", [ 4,2 ]);
-    map.insert("ディヴィニティアーマー", [ 4,2 ]);
-    map.insert("アルティメットフレーム", [ 4,2 ]);
-    map.insert("セレスティアルアーマー", [ 10,4 ]);
-    map.insert("ハンターフィールド", [ 8,8 ]);
-    map.insert("レンジャーフィールド", [ 8,8 ]);
-    map.insert("フォースフィールド", [ 8,8 ]);
-    map.insert("リバイバルガーメント", [ 5,10 ]);
-    map.insert("スピリットガーメント", [ 7,5 ]);
-    map.insert("匂う鎧", [ 85,85 ]);
-    map.insert("Ｄパーツ ver1.01", [ 10,7 ]);
-    map.insert("Ｄパーツ ver2.10", [ 10,8 ]);
-    map.insert("寄生防具「デ・ロル」", [ 0,0 ]);
-    map.insert("寄生防具「ネルガル」", [ 0,0 ]);
-    map.insert("寄生防具「ヴァジュラ」", [ 0,0 ]);
-    map.insert("センスプレート", [ 8,8 ]);
-    map.insert("グラビトンプレート", [ 8,0 ]);
-    map.insert("アトリビュートプレート", [ 8,8 ]);
-    map.insert("フロウウェンの鎧", [ 10,10 ]);
-    map.insert("カスタムフレーム ver.OO", [ 10,10 ]);
-    map.insert("ＤＢの鎧", [ 10,10 ]);
-    map.insert("ガードウェイブ", [ 50,20 ]);
-    map.insert("DFフィールド", [ 50,20 ]);
-    map.insert("ルミナスフィールド", [ 50,20 ]);
-    map.insert("チューチューフィーバー", [ 0,0 ]);
-    map.insert("ラブハート", [ 50,20 ]);
-    map.insert("フレイムガーメント", [ 50,20 ]);
-    map.insert("細菌防具「ラフテリア」", [ 0,0 ]);
-    map.insert("ブライトネスサークル", [ 50,20 ]);
-    map.insert("オーラフィールド", [ 50,20 ]);
-    map.insert("エレクトロフレーム", [ 50,20 ]);
-    map.insert("セイクリッドクロス", [ 50,20 ]);
-    map.insert("スモーキングプレート", [ 50,20 ]);
-    map.insert("スターキュイラス", [ 30,0 ]);
-    map.insert("ブラックハウンドキュイラス", [ 0,0 ]);
-    map.insert("モーニングプレイヤー", [ 10,20 ]);
-    map.insert("黒糸威胴丸", [ 10,10 ]);
-    map.insert("赤糸威胴丸", [ 10,10 ]);
-    map.insert("黒糸威朱塗桶側二枚胴", [ 10,10 ]);
-    map.insert("紺糸威紫塗桶側二枚胴", [ 10,10 ]);
-    map.insert("汚染された救命胴衣", [ 0,0 ]);
-    map.insert("クロエのセーター", [ 0,0 ]);
-    map.insert("ウェディングドレス", [ 0,0 ]);
-    map.insert("ソニックチームアーマー", [ 0,0 ]);
-    map.insert("レッドコート", [ 10,10 ]);
-    map.insert("サーティーン", [ 8,8 ]);
-    map.insert("マザーガーブ", [ 15,5 ]);
-    map.insert("マザーガーブ+", [ 15,5 ]);
-    map.insert("トランスヴェスタイトプレート", [ 0,0 ]);
-    map.insert("スウィートプレート", [ 50,20 ]);
-    map.insert("イグニションクローク", [ 8,8 ]);
-    map.insert("カンギールクローク", [ 8,8 ]);
-    map.insert("テンペストクローク", [ 8,8 ]);
-    map.insert("カースクローク", [ 8,8 ]);
-    map.insert("セレクトクローク", [ 8,8 ]);
-    map.insert("スピリットキュイラス", [ 7,5 ]);
-    map.insert("リバイバルキュイラス", [ 5,10 ]);
-    map.insert("連盟親衛隊軍服", [ 12,0 ]);
-    map.insert("連盟親衛隊上等兵軍服", [ 14,0 ]);
-    map.insert("連盟親衛隊指揮官軍服", [ 16,0 ]);
-    map.insert("クリムゾンコート", [ 12,12 ]);
-    map.insert("空間機動歩兵３２分隊上等兵軍服", [ 12,8 ]);
-    map.insert("空間機動歩兵３２分隊中尉軍服", [ 18,16 ]);
-    map.insert("空間機動歩兵外套", [ 10,10 ]);
-    map.insert("空間機動歩兵中尉外套", [ 21,18 ]);
-    map.insert("ユニオンフィールド", [ 0,0 ]);
-    map.insert("浅黄絲威褄取鎧", [ 0,0 ]);
-    map.insert("ステルススーツ", [ 0,25 ]);
-    map
-}
-
-pub fn barriers_ja() -> HashMap<&'static str, [i32; 2]> {
-    let mut map = HashMap::new();
-    map.insert("バリア", [ 5,5 ]);
-    map.insert("シールド", [ 5,5 ]);
-    map.insert("コアシールド", [ 5,5 ]);
-    map.insert("ギガシールド", [ 5,5 ]);
-    map.insert("ソウルバリア", [ 5,5 ]);
-    map.insert("ハードシールド", [ 5,5 ]);
-    map.insert("ブレイブバリア", [ 5,5 ]);
-    map.insert("ソリッドシールド", [ 5,5 ]);
-    map.insert("フレイムバリア", [ 5,5 ]);
-    map.insert("プラズマバリア", [ 5,5 ]);
-    map.insert("フリーズバリア", [ 5,5 ]);
-    map.insert("サイキックバリア", [ 5,5 ]);
-    map.insert("ジェネラルシールド", [ 5,5 ]);
-    map.insert("プロテクトバリア", [ 5,5 ]);
-    map.insert("グロリアスシールド", [ 5,5 ]);
-    map.insert("インペリアルバリア", [ 5,5 ]);
-    map.insert("ガーディアンシールド", [ 5,5 ]);
-    map.insert("ディヴィニティバリア", [ 5,5 ]);
-    map.insert("アルティメットシールド", [ 5,5 ]);
-    map.insert("スピリチュアルシールド", [ 5,5 ]);
-    map.insert("セレスティアルシールド", [ 5,5 ]);
-    map.insert("インビジブルガード", [ 8,8 ]);
-    map.insert("セイクリッドガード", [ 8,8 ]);
-    map.insert("Ｓパーツ ver1.16", [ 8,8 ]);
-    map.insert("Ｓパーツ ver2.01", [ 7,7 ]);
-    map.insert("ライトリリーフ", [ 7,7 ]);
-    map.insert("デルセイバーの盾", [ 7,7 ]);
-    map.insert("フォースウォル", [ 10,10 ]);
-    map.insert("レンジャーウォル", [ 10,10 ]);
-    map.insert("ハンターウォル", [ 10,10 ]);
-    map.insert("アトリビュートウォル", [ 10,10 ]);
-    map.insert("シークレットギア", [ 10,10 ]);
-    map.insert("コンバットギア", [ 0,0 ]);
-    map.insert("リジェネレイトギア試", [ 7,7 ]);
-    map.insert("リジェネレイトギア", [ 7,7 ]);
-    map.insert("リジェネレイトギア改", [ 7,7 ]);
-    map.insert("フロウウェンの盾", [ 10,10 ]);
-    map.insert("カスタムバリア ver.OO", [ 10,10 ]);
-    map.insert("ＤＢの盾", [ 10,10 ]);
-    map.insert("レッドリング", [ 85,25 ]);
-    map.insert("トリポリックシールド", [ 50,15 ]);
-    map.insert("スタンドスティル", [ 50,15 ]);
-    map.insert("セーフティハート", [ 50,15 ]);
-    map.insert("カザミノコテ", [ 50,15 ]);
-    map.insert("四神盾「朱雀」", [ 0,0 ]);
-    map.insert("四神盾「玄武」", [ 0,0 ]);
-    map.insert("四神盾「白虎」", [ 0,0 ]);
-    map.insert("四神盾「青龍」", [ 0,0 ]);
-    map.insert("ハンターズシェル", [ 50,15 ]);
-    map.insert("リコのメガネ", [ 0,0 ]);
-    map.insert("リコのイヤリング", [ 85,25 ]);
-    map.insert("セキュアフット", [ 50,15 ]);
-    map.insert("レスタマージ", [ 5,5 ]);
-    map.insert("アンティマージ", [ 5,5 ]);
-    map.insert("シフタマージ", [ 5,5 ]);
-    map.insert("デバンドマージ", [ 5,5 ]);
-    map.insert("フォイエマージ", [ 5,5 ]);
-    map.insert("ギフォイエマージ", [ 5,5 ]);
-    map.insert("ラフォイエマージ", [ 5,5 ]);
-    map.insert("レッドマージ", [ 5,5 ]);
-    map.insert("バータマージ", [ 5,5 ]);
-    map.insert("ギバータマージ", [ 5,5 ]);
-    map.insert("ラバータマージ", [ 5,5 ]);
-    map.insert("ブルーマージ", [ 5,5 ]);
-    map.insert("ゾンデマージ", [ 5,5 ]);
-    map.insert("ギゾンデマージ", [ 5,5 ]);
-    map.insert("ラゾンデマージ", [ 5,5 ]);
-    map.insert("イエローマージ", [ 5,5 ]);
-    map.insert("リカバリバリア", [ 5,5 ]);
-    map.insert("アシストバリア", [ 5,5 ]);
-    map.insert("レッドバリア", [ 5,5 ]);
-    map.insert("ブルーバリア", [ 5,5 ]);
-    map.insert("イエローバリア", [ 5,5 ]);
-    map.insert("ウェポンズシールド金", [ 0,0 ]);
-    map.insert("ブラックギア", [ 5,5 ]);
-    map.insert("ＷＯＲＫＳガード", [ 5,5 ]);
-    map.insert("ラグオルリング", [ 0,0 ]);
-    map.insert("ブルーリング", [ 5,5 ]);
-    map.insert("グリーンリング", [ 5,5 ]);
-    map.insert("イエローリング", [ 5,5 ]);
-    map.insert("パープルリング", [ 5,5 ]);
-    map.insert("ホワイトリング", [ 5,5 ]);
-    map.insert("ブラックリング", [ 5,5 ]);
-    map.insert("ウェポンズシールド銀", [ 0,0 ]);
-    map.insert("ウェポンズシールド銅", [ 0,0 ]);
-    map.insert("ガラシア", [ 20,15 ]);
-    map.insert("トリポリックリフレクター", [ 50,15 ]);
-    map.insert("ストライカープラス", [ 10,5 ]);
-    map.insert("リジェネレイトギアB・P", [ 7,7 ]);
-    map.insert("ルピカ", [ 10,20 ]);
-    map.insert("八咫鏡", [ 20,25 ]);
-    map.insert("ウサミミ", [ 0,0 ]);
-    map.insert("ネコミミ", [ 0,0 ]);
-    map.insert("スリーシールズ", [ 3,3 ]);
-    map.insert("四神盾「黄龍」", [ 0,0 ]);
-    map.insert("DFの盾", [ 85,25 ]);
-    map.insert("淵ヨリ来ルモノ", [ 0,0 ]);
-    map.insert("デ・ロル・レ・シールド", [ 75,75 ]);
-    map.insert("ハニカムリフレクタ", [ 10,10 ]);
-    map.insert("イプシガード", [ 75,75 ]);
-    map.insert("円環天使", [ 0,0 ]);
-    map.insert("ユニオンガード", [ 0,0 ]);
-    map.insert("匂う盾", [ 75,75 ]);
-    map.insert("黒のガントレット", [ 0,0 ]);
-    map.insert("源平", [ 0,0 ]);
-    map.insert("アンチライトリング", [ 0,0 ]);
-    map.insert("アンチダークリング", [ 0,0 ]);
-    map.insert("レッドリング* - 青のペイント", [ 85,25 ]);
-    map.insert("レッドリング* - 緑のペイント", [ 85,25 ]);
-    map.insert("レッドリング* - 黄のペイント", [ 85,25 ]);
-    map.insert("レッドリング* - 紫のペイント", [ 85,25 ]);
-    map.insert("レッドリング* - 白のペイント", [ 85,25 ]);
-    map.insert("レッドリング* - 黒のペイント", [ 85,25 ]);
-    map.insert("レッドリング* - 黄緑のペイント", [ 85,25 ]);
-    map.insert("レッドリング* - 水色のペイント", [ 85,25 ]);
-    map.insert("レッドリング* - 漆黒のペイント", [ 85,25 ]);
-    map.insert("レッドリング* - 橙のペイント", [ 85,25 ]);
-    map.insert("レッドリング* - バラのペイント", [ 85,25 ]);
-    map.insert("レッドリング* - 群青のペイント", [ 85,25 ]);
-    map.insert("レッドリング* - スミレのペイント", [ 85,25 ]);
-    map.insert("レッドリング* - デルセイバーの盾のメッキ", [ 85,25 ]);
-    map.insert("レッドリング* - スタンドスティルのメッキ", [ 85,25 ]);
-    map.insert("レッドリング* - ハニカムリフレクターのメッキ", [ 85,25 ]);
-    map.insert("レッドリング* - イプシガードのメッキ", [ 85,25 ]);
-    map.insert("レッドリング* - セーフティハートのメッキ", [ 85,25 ]);
-    map.insert("レッドリング* - 円環天使のメッキ", [ 85,25 ]);
-    map.insert("レッドリング* - ウサミミのメッキ", [ 85,25 ]);
-    map.insert("レッドリング* - ネコミミのメッキ", [ 85,25 ]);
-    map.insert("レッドリング* - 淵ヨリ来ルモノのメッキ", [ 85,25 ]);
-    map
-}
-
 pub fn tech_codes_ja() -> HashMap<u8, &'static str> {
     let mut map = HashMap::new();
     map.insert(0x00, "フォイエ");
@@ -853,1791 +626,13 @@ pub fn tech_codes_ja() -> HashMap<u8, &'static str> {
     map
 }
 
-pub fn pbs_ja() -> HashMap<&'static str, [&'static str; 3]> {
+pub fn photon_blasts_ja() -> HashMap<u8, &'static str> {
     let mut map = HashMap::new();
-    map.insert("0000", ["", "", ""]);
-    map.insert("0001", ["ファーラ", "", ""]);
-    map.insert("0101", ["エストラ", "", ""]);
-    map.insert("0401", ["レイラ", "", ""]);
-    map.insert("1003", ["ファーラ", "ゴーラ", ""]);
-    map.insert("5007", ["ファーラ", "ゴーラ", "パイラ"]);
-    map.insert("D007", ["ファーラ", "ゴーラ", "マイラ＆ユーラ"]);
-    map.insert("1007", ["ファーラ", "ゴーラ", "エストラ"]);
-    map.insert("1803", ["ファーラ", "パイラ", ""]);
-    map.insert("5807", ["ファーラ", "パイラ", "ゴーラ"]);
-    map.insert("D807", ["ファーラ", "パイラ", "マイラ＆ユーラ"]);
-    map.insert("1807", ["ファーラ", "パイラ", "エストラ"]);
-    map.insert("2803", ["ファーラ", "マイラ＆ユーラ", ""]);
-    map.insert("6807", ["ファーラ", "マイラ＆ユーラ", "ゴーラ"]);
-    map.insert("A807", ["ファーラ", "マイラ＆ユーラ", "パイラ"]);
-    map.insert("2807", ["ファーラ", "マイラ＆ユーラ", "エストラ"]);
-    map.insert("1103", ["エストラ", "ゴーラ", ""]);
-    map.insert("5107", ["エストラ", "ゴーラ", "パイラ"]);
-    map.insert("D107", ["エストラ", "ゴーラ", "マイラ＆ユーラ"]);
-    map.insert("1107", ["エストラ", "ゴーラ", "ファーラ"]);
-    map.insert("1903", ["エストラ", "パイラ", ""]);
-    map.insert("5907", ["エストラ", "パイラ", "ゴーラ"]);
-    map.insert("D907", ["エストラ", "パイラ", "マイラ＆ユーラ"]);
-    map.insert("1907", ["エストラ", "パイラ", "ファーラ"]);
-    map.insert("2903", ["エストラ", "マイラ＆ユーラ", ""]);
-    map.insert("6907", ["エストラ", "マイラ＆ユーラ", "ゴーラ"]);
-    map.insert("A907", ["エストラ", "マイラ＆ユーラ", "パイラ"]);
-    map.insert("2907", ["エストラ", "マイラ＆ユーラ", "ファーラ"]);
-    map.insert("1403", ["レイラ", "ゴーラ", ""]);
-    map.insert("9407", ["レイラ", "ゴーラ", "パイラ"]);
-    map.insert("D407", ["レイラ", "ゴーラ", "マイラ＆ユーラ"]);
-    map.insert("1407", ["レイラ", "ゴーラ", "ファーラ"]);
-    map.insert("5407", ["レイラ", "ゴーラ", "エストラ"]);
-    map.insert("1C03", ["レイラ", "パイラ", ""]);
-    map.insert("9C07", ["レイラ", "パイラ", "ゴーラ"]);
-    map.insert("DC07", ["レイラ", "パイラ", "マイラ＆ユーラ"]);
-    map.insert("1C07", ["レイラ", "パイラ", "ファーラ"]);
-    map.insert("5C07", ["レイラ", "パイラ", "エストラ"]);
-    map.insert("2C03", ["レイラ", "マイラ＆ユーラ", ""]);
-    map.insert("AC07", ["レイラ", "マイラ＆ユーラ", "ゴーラ"]);
-    map.insert("EC07", ["レイラ", "マイラ＆ユーラ", "パイラ"]);
-    map.insert("2C07", ["レイラ", "マイラ＆ユーラ", "ファーラ"]);
-    map.insert("6C07", ["レイラ", "マイラ＆ユーラ", "エストラ"]);
+    map.insert(0x00, "ファーラ");
+    map.insert(0x01, "エストラ");
+    map.insert(0x02, "ゴーラ");
+    map.insert(0x03, "パイラ");
+    map.insert(0x04, "レイラ");
+    map.insert(0x05, "マイラ＆ユーラ");
     map
 }
-
-pub fn item_codes_ja() -> HashMap<u32, &'static str> {
-    let mut codes = HashMap::new();
-    codes.insert(0x000000, "セイバー");
-    codes.insert(0x000100, "セイバー");
-    codes.insert(0x000101, "ブランド");
-    codes.insert(0x000102, "バスター");
-    codes.insert(0x000103, "パラッシュ");
-    codes.insert(0x000104, "グラディウス");
-    codes.insert(0x000105, "ＤＢの剣");
-    codes.insert(0x000106, "カラドボルグ");
-    codes.insert(0x000107, "デュランダル");
-    codes.insert(0x000108, "ガラティーン");
-    codes.insert(0x000200, "ソード");
-    codes.insert(0x000201, "ギガッシュ");
-    codes.insert(0x000202, "ブレイカー");
-    codes.insert(0x000203, "クレイモア");
-    codes.insert(0x000204, "キャリバー");
-    codes.insert(0x000205, "フロウウェンの大剣");
-    codes.insert(0x000206, "ラストサバイバー");
-    codes.insert(0x000207, "ドラゴンスレイヤー");
-    codes.insert(0x000300, "ダガー");
-    codes.insert(0x000301, "ナイフ");
-    codes.insert(0x000302, "ブレイド");
-    codes.insert(0x000303, "エッジ");
-    codes.insert(0x000304, "リッパー");
-    codes.insert(0x000305, "ブレイドダンス");
-    codes.insert(0x000306, "ブラッディアート");
-    codes.insert(0x000307, "クロススケア");
-    codes.insert(0x000308, "ゼロディバイド");
-    codes.insert(0x000309, "ニレンカムイ");
-    codes.insert(0x000400, "パルチザン");
-    codes.insert(0x000401, "ハルベルト");
-    codes.insert(0x000402, "グレイブ");
-    codes.insert(0x000403, "バルディス");
-    codes.insert(0x000404, "グングニル");
-    codes.insert(0x000405, "ブリューナク");
-    codes.insert(0x000406, "ヴィジャヤ");
-    codes.insert(0x000407, "ガエボルグ");
-    codes.insert(0x000408, "アステロンベルト");
-    codes.insert(0x000500, "スライサー");
-    codes.insert(0x000501, "スピナー");
-    codes.insert(0x000502, "カッター");
-    codes.insert(0x000503, "ソーサー");
-    codes.insert(0x000504, "ディスカ");
-    codes.insert(0x000505, "暗殺者のスライサー");
-    codes.insert(0x000506, "解放者のディスカ");
-    codes.insert(0x000507, "勇者のディスカ");
-    codes.insert(0x000508, "イズマエラ");
-    codes.insert(0x000600, "ハンドガン");
-    codes.insert(0x000601, "オートガン");
-    codes.insert(0x000602, "ロックガン");
-    codes.insert(0x000603, "レールガン");
-    codes.insert(0x000604, "レイガン");
-    codes.insert(0x000605, "ヴァリスタ");
-    codes.insert(0x000606, "カスタムレイ ver.OO");
-    codes.insert(0x000607, "ブレイパス");
-    codes.insert(0x000608, "テンションブラスター");
-    codes.insert(0x000700, "ライフル");
-    codes.insert(0x000701, "スナイパー");
-    codes.insert(0x000702, "ブラスター");
-    codes.insert(0x000703, "ビーム");
-    codes.insert(0x000704, "レーザー");
-    codes.insert(0x000705, "ヴィスク－２３５Ｗ");
-    codes.insert(0x000706, "ウォルス－ＭＫ２");
-    codes.insert(0x000707, "ジャスティ－２３ＳＴ");
-    codes.insert(0x000708, "ライアーノフ303SNR");
-    codes.insert(0x000709, "ライアーノフ303SNR-1");
-    codes.insert(0x00070A, "ライアーノフ303SNR-2");
-    codes.insert(0x00070B, "ライアーノフ303SNR-3");
-    codes.insert(0x00070C, "ライアーノフ303SNR-4");
-    codes.insert(0x00070D, "ライアーノフ303SNR-5");
-    codes.insert(0x000800, "マシンガン");
-    codes.insert(0x000801, "アサルト");
-    codes.insert(0x000802, "リピーター");
-    codes.insert(0x000803, "ガトリング");
-    codes.insert(0x000804, "バルカン");
-    codes.insert(0x000805, "Ｍ＆Ａ６０ ヴァイス");
-    codes.insert(0x000806, "Ｈ＆Ｓ２５ ジャスティス");
-    codes.insert(0x000807, "Ｌ＆Ｋ１４ コンバット");
-    codes.insert(0x000900, "ショット");
-    codes.insert(0x000901, "スプレッド");
-    codes.insert(0x000902, "キャノン");
-    codes.insert(0x000903, "ランチャー");
-    codes.insert(0x000904, "アームズ");
-    codes.insert(0x000905, "クラッシュバレット");
-    codes.insert(0x000906, "メテオスマッシュ");
-    codes.insert(0x000907, "ファイナルインパクト");
-    codes.insert(0x000A00, "ケイン");
-    codes.insert(0x000A01, "スティック");
-    codes.insert(0x000A02, "メイス");
-    codes.insert(0x000A03, "クラブ");
-    codes.insert(0x000A04, "ラコニウムの杖");
-    codes.insert(0x000A05, "アダマンの杖");
-    codes.insert(0x000A06, "ズミウランの杖");
-    codes.insert(0x000A07, "ロリポップ");
-    codes.insert(0x000B00, "ロッド");
-    codes.insert(0x000B01, "ポール");
-    codes.insert(0x000B02, "パイラー");
-    codes.insert(0x000B03, "ストライカー");
-    codes.insert(0x000B04, "バトルバージ");
-    codes.insert(0x000B05, "ブレイブハンマー");
-    codes.insert(0x000B06, "アライブアクゥー");
-    codes.insert(0x000B07, "ワルキューレ");
-    codes.insert(0x000C00, "ウォンド");
-    codes.insert(0x000C01, "スタッフ");
-    codes.insert(0x000C02, "バトン");
-    codes.insert(0x000C03, "セプター");
-    codes.insert(0x000C04, "炎杖「アグニ」");
-    codes.insert(0x000C05, "氷杖「ダゴン」");
-    codes.insert(0x000C06, "雷杖「インドラ」");
-    codes.insert(0x000C07, "土杖「ブラウニー」");
-    codes.insert(0x000D00, "フォトンクロー");
-    codes.insert(0x000D01, "サイレンスクロー");
-    codes.insert(0x000D02, "ネイクロー(偽)");
-    codes.insert(0x000D03, "灼炎鳳爪");
-    codes.insert(0x000E00, "ダブルセイバー");
-    codes.insert(0x000E01, "スタッグカットラリ");
-    codes.insert(0x000E02, "ツインブランド");
-    codes.insert(0x000F00, "ブレイブナックル");
-    codes.insert(0x000F01, "アングルフィスト");
-    codes.insert(0x000F02, "ゴッドハンド");
-    codes.insert(0x000F03, "ソニックナックル");
-    codes.insert(0x000F04, "ログイン");
-    codes.insert(0x001000, "オロチアギト");
-    codes.insert(0x001001, "アギト[1975]");
-    codes.insert(0x001002, "アギト[1983]");
-    codes.insert(0x001003, "アギト[2001]");
-    codes.insert(0x001004, "アギト[1991]");
-    codes.insert(0x001005, "アギト[1977]");
-    codes.insert(0x001006, "アギト[1980]");
-    codes.insert(0x001007, "雷切");
-    codes.insert(0x001100, "ソウルイーター");
-    codes.insert(0x001101, "ソウルバニッシュ");
-    codes.insert(0x001200, "スプレッドニードル");
-    codes.insert(0x001300, "ホーリーレイ");
-    codes.insert(0x001400, "インフェルノバズーカ");
-    codes.insert(0x001401, "ランブリングメイ");
-    codes.insert(0x001402, "Ｌ＆Ｋ３８ コンバット");
-    codes.insert(0x001500, "フレイムビジット");
-    codes.insert(0x001501, "バーニングビジット");
-    codes.insert(0x001600, "秋子おばさんのフライパン");
-    codes.insert(0x001700, "ソーサラーの杖");
-    codes.insert(0x001800, "シノワビートブレイド");
-    codes.insert(0x001900, "パンアームズブレイド");
-    codes.insert(0x001A00, "デルセイバーの剣");
-    codes.insert(0x001B00, "ブリンガーライフル");
-    codes.insert(0x001C00, "エッグブラスター");
-    codes.insert(0x001D00, "サイコウォンド");
-    codes.insert(0x001E00, "ヘブンパニッシャー");
-    codes.insert(0x001F00, "ラヴィス＝カノン");
-    codes.insert(0x002000, "ヴィクターアクス");
-    codes.insert(0x002001, "ラコニウムアクス");
-    codes.insert(0x002100, "チェインソード");
-    codes.insert(0x002200, "カジューシース");
-    codes.insert(0x002201, "メルクリウスロッド");
-    codes.insert(0x002300, "スティングティップ");
-    codes.insert(0x002400, "マジカルピース");
-    codes.insert(0x002500, "テクニカルクローサー");
-    codes.insert(0x002600, "サプレストガン");
-    codes.insert(0x002700, "アンシエントセイバー");
-    codes.insert(0x002800, "ハリセン");
-    codes.insert(0x002900, "ヤミガラス");
-    codes.insert(0x002A00, "秋子おばさんの中華鍋");
-    codes.insert(0x002B00, "トイハンマー");
-    codes.insert(0x002C00, "エリュシオン");
-    codes.insert(0x002D00, "赤のセイバー");
-    codes.insert(0x002E00, "流星棍");
-    codes.insert(0x002F00, "ニョイボウ");
-    codes.insert(0x002F01, "蒼黒のニョイボウ");
-    codes.insert(0x003000, "ダブル＝カノン");
-    codes.insert(0x003001, "ジラソーレ");
-    codes.insert(0x003100, "芸の道");
-    codes.insert(0x003200, "ツミキリ");
-    codes.insert(0x003300, "封印ノダチ");
-    codes.insert(0x003400, "赤のソード");
-    codes.insert(0x003500, "クレイジーチューン");
-    codes.insert(0x003600, "ツインチャクラム");
-    codes.insert(0x003700, "秋子飯店の中華鍋");
-    codes.insert(0x003800, "ラヴィス＝ブレイド");
-    codes.insert(0x003900, "赤のダガー");
-    codes.insert(0x003A00, "マダムノヒガサ");
-    codes.insert(0x003B00, "マダムノアマガサ");
-    codes.insert(0x003C00, "インペリアルピック");
-    codes.insert(0x003D00, "バルディッシュ");
-    codes.insert(0x003E00, "赤のパルチザン");
-    codes.insert(0x003F00, "フライトカッター");
-    codes.insert(0x004000, "扇舞");
-    codes.insert(0x004100, "赤のスライサー");
-    codes.insert(0x004200, "短銃「ガルド」");
-    codes.insert(0x004201, "マスターレイブン");
-    codes.insert(0x004300, "短銃「ミラ」");
-    codes.insert(0x004301, "ラストスワン");
-    codes.insert(0x004400, "赤のハンドガン");
-    codes.insert(0x004500, "フローズンシューター");
-    codes.insert(0x004501, "スノウクイーン");
-    codes.insert(0x004600, "対アンドロイド用ライフル");
-    codes.insert(0x004700, "ロケットパンチ");
-    codes.insert(0x004800, "サンバマラカス");
-    codes.insert(0x004900, "ツインサイコガン");
-    codes.insert(0x004A00, "ドリルランチャー");
-    codes.insert(0x004B00, "ガルド・ミラ");
-    codes.insert(0x004B01, "デュアルバード");
-    codes.insert(0x004C00, "赤のマシンガン");
-    codes.insert(0x004D00, "ベルラキャノン");
-    codes.insert(0x004E00, "パンツァーファースト");
-    codes.insert(0x004E01, "アイアンファースト");
-    codes.insert(0x004F00, "サミットムーン");
-    codes.insert(0x005000, "ウィンドミル");
-    codes.insert(0x005100, "エビルカースト");
-    codes.insert(0x005200, "フラワーケイン");
-    codes.insert(0x005300, "ヒルデベアの杖");
-    codes.insert(0x005400, "ヒルデブルーの杖");
-    codes.insert(0x005500, "ラビットウォンド");
-    codes.insert(0x005600, "ハッパ");
-    codes.insert(0x005601, "ヤツデ");
-    codes.insert(0x005700, "デモニックフォーク");
-    codes.insert(0x005800, "チャオの杖");
-    codes.insert(0x005900, "ホウキ");
-    codes.insert(0x005A00, "モタブの予言書");
-    codes.insert(0x005B00, "神の吐息");
-    codes.insert(0x005C00, "トゥインクルスター");
-    codes.insert(0x005D00, "マショウセン");
-    codes.insert(0x005E00, "ツインブレイズ");
-    codes.insert(0x005F00, "マリナのカバン");
-    codes.insert(0x006000, "ドラゴンクロー");
-    codes.insert(0x006100, "パンサークロー");
-    codes.insert(0x006200, "シノワレッドブレイド");
-    codes.insert(0x006300, "バショウセン");
-    codes.insert(0x006400, "カメレオンサイズ");
-    codes.insert(0x006500, "ヤスミノコフ3000R");
-    codes.insert(0x006600, "アノライフル");
-    codes.insert(0x006700, "バランゾランチャー");
-    codes.insert(0x006800, "パクパクのエダ");
-    codes.insert(0x006900, "ハートオブポウム");
-    codes.insert(0x006A00, "ヤスミノコフ2000H");
-    codes.insert(0x006B00, "ヤスミノコフ7000V");
-    codes.insert(0x006C00, "ヤスミノコフ9000M");
-    codes.insert(0x006D00, "メーザービーム");
-    codes.insert(0x006D01, "パワーメーザー");
-    codes.insert(0x006E00, "ファミ通");
-    codes.insert(0x006E01, "ログイン");
-    codes.insert(0x006F00, "ハナタバ");
-    codes.insert(0x008900, "ムサシ");
-    codes.insert(0x008901, "ヤマト");
-    codes.insert(0x008902, "アスカ");
-    codes.insert(0x008903, "サンゲヤシャ");
-    codes.insert(0x008A00, "サンゲ");
-    codes.insert(0x008A01, "ヤシャ");
-    codes.insert(0x008A02, "カムイ");
-    codes.insert(0x008B00, "フォトンランチャー");
-    codes.insert(0x008B01, "ギルティライト");
-    codes.insert(0x008B02, "レッドスコルピオ");
-    codes.insert(0x008B03, "フォノンメーザー");
-    codes.insert(0x008C00, "タリス");
-    codes.insert(0x008C01, "マフ");
-    codes.insert(0x008C02, "ヒトガタ");
-    codes.insert(0x008C03, "ヒトガタ「瑚式」");
-    codes.insert(0x008C04, "クナイ");
-    codes.insert(0x008D00, "Nug2000バズーカ");
-    codes.insert(0x008E00, "ベリルナックルType0");
-    codes.insert(0x008E01, "ベリルナックルType1");
-    codes.insert(0x008F00, "フロウウェンの大剣[3060]");
-    codes.insert(0x008F01, "フロウウェンの大剣[3064]");
-    codes.insert(0x008F02, "フロウウェンの大剣[3067]");
-    codes.insert(0x008F03, "フロウウェンの大剣[3073]");
-    codes.insert(0x008F04, "フロウウェンの大剣[3077]");
-    codes.insert(0x008F05, "フロウウェンの大剣[3082]");
-    codes.insert(0x008F06, "フロウウェンの大剣[3083]");
-    codes.insert(0x008F07, "フロウウェンの大剣[3084]");
-    codes.insert(0x008F08, "フロウウェンの大剣[3079]");
-    codes.insert(0x009000, "ＤＢの剣[3062]");
-    codes.insert(0x009001, "ＤＢの剣[3067]");
-    codes.insert(0x009002, "ＤＢの剣[3069/クリス社]");
-    codes.insert(0x009003, "ＤＢの剣[3064]");
-    codes.insert(0x009004, "ＤＢの剣[3069/トラト社]");
-    codes.insert(0x009005, "ＤＢの剣[3073]");
-    codes.insert(0x009006, "ＤＢの剣[3070]");
-    codes.insert(0x009007, "ＤＢの剣[3075]");
-    codes.insert(0x009008, "ＤＢの剣[3077]");
-    codes.insert(0x009100, "ギ・グー・バズーカ");
-    codes.insert(0x009200, "ガディアンナ");
-    codes.insert(0x009300, "ヴィリディアカード");
-    codes.insert(0x009301, "グリーニルカード");
-    codes.insert(0x009302, "スカイリーカード");
-    codes.insert(0x009303, "ブルーフルカード");
-    codes.insert(0x009304, "パープルナムカード");
-    codes.insert(0x009305, "ピンカルカード");
-    codes.insert(0x009306, "レッドリアカード");
-    codes.insert(0x009307, "オランカード");
-    codes.insert(0x009308, "イエローブーズカード");
-    codes.insert(0x009309, "ホワイティルカード");
-    codes.insert(0x009400, "モーニンググローリー");
-    codes.insert(0x009500, "迅雷");
-    codes.insert(0x009600, "ガルウインド");
-    codes.insert(0x009700, "ザンバ");
-    codes.insert(0x009800, "ファルクロー");
-    codes.insert(0x009900, "エンジェルハープ");
-    codes.insert(0x009A00, "デモリションコメット");
-    codes.insert(0x009B00, "ネイクロー(真)");
-    codes.insert(0x009C00, "レインボーバトン");
-    codes.insert(0x009D00, "ダークフロウ");
-    codes.insert(0x009E00, "ダークメテオ");
-    codes.insert(0x009F00, "ダークブリッジ");
-    codes.insert(0x00A000, "ツインアサシンセイバー");
-    codes.insert(0x00A100, "ラッピーファン");
-    codes.insert(0x00A200, "ブーマクロー");
-    codes.insert(0x00A201, "ゴブーマクロー");
-    codes.insert(0x00A202, "ジゴブーマクロー");
-    codes.insert(0x00A300, "ルビーバレット");
-    codes.insert(0x00A400, "アモーレローズ");
-    codes.insert(0x00AA00, "狂信者のスライサー");
-    codes.insert(0x00AB00, "ラムダージャン");
-    codes.insert(0x00AC00, "エクスキャリバー");
-    codes.insert(0x00AD00, "ラジュ・デ・フォドル");
-    codes.insert(0x00AD01, "ラジュ・デ・グラス");
-    codes.insert(0x00AD02, "ラジュ・ダム");
-    codes.insert(0x00AD03, "ラジュ・デ・フェ");
-    codes.insert(0x00AE00, "デイジーチェイン");
-    codes.insert(0x00AF00, "オフェリセーズ");
-    codes.insert(0x00B000, "ミルマルト");
-    codes.insert(0x00B100, "レオニア");
-    codes.insert(0x00B200, "オーナー指揮刀");
-    codes.insert(0x00B300, "ヴィヴィアン");
-    codes.insert(0x00B400, "草薙");
-    codes.insert(0x00B500, "セイクリッドダスター");
-    codes.insert(0x00B600, "紅蓮");
-    codes.insert(0x00B700, "青蓮");
-    codes.insert(0x00B800, "自在");
-    codes.insert(0x00B900, "フランベルジュ");
-    codes.insert(0x00BA00, "雲長");
-    codes.insert(0x00BB00, "蛇矛");
-    codes.insert(0x00BC00, "フラップジャックフラッパー");
-    codes.insert(0x00BD00, "月牙産");
-    codes.insert(0x00BE00, "神氷鉄");
-    codes.insert(0x00BF00, "ヘブンストライカー");
-    codes.insert(0x00C000, "カノンルージュ");
-    codes.insert(0x00C100, "メテオルージュ");
-    codes.insert(0x00C200, "ソルフェリーノ");
-    codes.insert(0x00C300, "クレイオー");
-    codes.insert(0x00C400, "セイレーングラスハンマー");
-    codes.insert(0x00C500, "グライドディバイン");
-    codes.insert(0x00C600, "七支刀");
-    codes.insert(0x00C700, "村雨");
-    codes.insert(0x00C800, "デイライトスカー");
-    codes.insert(0x00C900, "十戒石版");
-    codes.insert(0x00CA00, "PSO5周年記念謝恩刀");
-    codes.insert(0x00CB00, "総督恩賜パラソル");
-    codes.insert(0x00CC00, "秋子おばさんの中華包丁");
-    codes.insert(0x00CD00, "種子島");
-    codes.insert(0x00CE00, "高枝切鋏");
-    codes.insert(0x00CF00, "ナイスショット");
-    codes.insert(0x00D000, "UNKNOWN3");
-    codes.insert(0x00D100, "UNKNOWN4");
-    codes.insert(0x00D200, "アノバズーカ");
-    codes.insert(0x00D300, "シンセサイザー");
-    codes.insert(0x00D400, "竹やり");
-    codes.insert(0x00D500, "寛永通宝");
-    codes.insert(0x00D600, "十手");
-    codes.insert(0x00D700, "虫取り網");
-    codes.insert(0x00D800, "注射器");
-    codes.insert(0x00D900, "羽子板");
-    codes.insert(0x00DA00, "ラケット");
-    codes.insert(0x00DB00, "金鎚");
-    codes.insert(0x00DC00, "すごい花束");
-    codes.insert(0x00DD00, "TypeSA/SABER");
-    codes.insert(0x00DE00, "TypeN-SL/SABER");
-    codes.insert(0x00DE01, "TypeN-SL/SLICER");
-    codes.insert(0x00DE02, "TypeN-SL/CLAW");
-    codes.insert(0x00DE03, "TypeN-SL/J-SWORD");
-    codes.insert(0x00DF00, "TypeJS/SABER");
-    codes.insert(0x00DF01, "TypeJS/SLICER");
-    codes.insert(0x00DF02, "TypeJS/J-SWORD");
-    codes.insert(0x00E000, "TypeSW/SWORD");
-    codes.insert(0x00E001, "TypeSW/SLICER");
-    codes.insert(0x00E002, "TypeSW/J-SWORD");
-    codes.insert(0x00E100, "TypeRO/SWORD");
-    codes.insert(0x00E101, "TypeRO/HELBERT");
-    codes.insert(0x00E102, "TypeRO/ROD");
-    codes.insert(0x00E200, "TypeBL/BLADE");
-    codes.insert(0x00E300, "TypeKN/BLADE");
-    codes.insert(0x00E301, "TypeKN/CLAW");
-    codes.insert(0x00E400, "TypeHA/HELBERT");
-    codes.insert(0x00E401, "TypeHA/ROD");
-    codes.insert(0x00E500, "TypeDS/D.SABER");
-    codes.insert(0x00E501, "TypeDS/ROD");
-    codes.insert(0x00E502, "TypeDS/WAND");
-    codes.insert(0x00E600, "TypeCL/CLAW");
-    codes.insert(0x00E700, "TypeSS/SWORDS");
-    codes.insert(0x00E800, "TypeGU/HANDGUN");
-    codes.insert(0x00E801, "TypeGU/MECHGUN");
-    codes.insert(0x00E900, "TypeRI/RIFLE");
-    codes.insert(0x00EA00, "TypeME/MECHGUN");
-    codes.insert(0x00EB00, "TypeSH/SHOT");
-    codes.insert(0x00EC00, "TypeWA/WAND");
-    codes.insert(0x00ED00, "????");
-    codes.insert(0x010100, "フレーム");
-    codes.insert(0x010101, "アーマー");
-    codes.insert(0x010102, "サイアーマー");
-    codes.insert(0x010103, "ギガフレーム");
-    codes.insert(0x010104, "ソウルフレーム");
-    codes.insert(0x010105, "クロスアーマー");
-    codes.insert(0x010106, "ソリッドフレーム");
-    codes.insert(0x010107, "ブレイブアーマー");
-    codes.insert(0x010108, "ハイパーフレーム");
-    codes.insert(0x010109, "グランドアーマー");
-    codes.insert(0x01010A, "ショックフレーム");
-    codes.insert(0x01010B, "キングスフレーム");
-    codes.insert(0x01010C, "ドラゴンフレーム");
-    codes.insert(0x01010D, "アブソーブアーマー");
-    codes.insert(0x01010E, "プロテクトフレーム");
-    codes.insert(0x01010F, "ジェネラルアーマー");
-    codes.insert(0x010110, "パーフェクトフレーム");
-    codes.insert(0x010111, "ヴァリアントフレーム");
-    codes.insert(0x010112, "インペリアルアーマー");
-    codes.insert(0x010113, "ホーリネスアーマー");
-    codes.insert(0x010114, "ガーディアンアーマー");
-    codes.insert(0x010115, "ディヴィニティアーマー");
-    codes.insert(0x010116, "アルティメットフレーム");
-    codes.insert(0x010117, "セレスティアルアーマー");
-    codes.insert(0x010118, "ハンターフィールド");
-    codes.insert(0x010119, "レンジャーフィールド");
-    codes.insert(0x01011A, "フォースフィールド");
-    codes.insert(0x01011B, "リバイバルガーメント");
-    codes.insert(0x01011C, "スピリットガーメント");
-    codes.insert(0x01011D, "匂う鎧");
-    codes.insert(0x01011E, "Ｄパーツ ver1.01");
-    codes.insert(0x01011F, "Ｄパーツ ver2.10");
-    codes.insert(0x010120, "寄生防具「デ・ロル」");
-    codes.insert(0x010121, "寄生防具「ネルガル」");
-    codes.insert(0x010122, "寄生防具「ヴァジュラ」");
-    codes.insert(0x010123, "センスプレート");
-    codes.insert(0x010124, "グラビトンプレート");
-    codes.insert(0x010125, "アトリビュートプレート");
-    codes.insert(0x010126, "フロウウェンの鎧");
-    codes.insert(0x010127, "カスタムフレーム ver.OO");
-    codes.insert(0x010128, "ＤＢの鎧");
-    codes.insert(0x010129, "ガードウェイブ");
-    codes.insert(0x01012A, "DFフィールド");
-    codes.insert(0x01012B, "ルミナスフィールド");
-    codes.insert(0x01012C, "チューチューフィーバー");
-    codes.insert(0x01012D, "ラブハート");
-    codes.insert(0x01012E, "フレイムガーメント");
-    codes.insert(0x01012F, "細菌防具「ラフテリア」");
-    codes.insert(0x010130, "ブライトネスサークル");
-    codes.insert(0x010131, "オーラフィールド");
-    codes.insert(0x010132, "エレクトロフレーム");
-    codes.insert(0x010133, "セイクリッドクロス");
-    codes.insert(0x010134, "スモーキングプレート");
-    codes.insert(0x010135, "スターキュイラス");
-    codes.insert(0x010136, "ブラックハウンドキュイラス");
-    codes.insert(0x010137, "モーニングプレイヤー");
-    codes.insert(0x010138, "黒糸威胴丸");
-    codes.insert(0x010139, "赤糸威胴丸");
-    codes.insert(0x01013A, "黒糸威朱塗桶側二枚胴");
-    codes.insert(0x01013B, "紺糸威紫塗桶側二枚胴");
-    codes.insert(0x01013C, "汚染された救命胴衣");
-    codes.insert(0x01013D, "クロエのセーター");
-    codes.insert(0x01013E, "ウェディングドレス");
-    codes.insert(0x01013F, "ソニックチームアーマー");
-    codes.insert(0x010140, "レッドコート");
-    codes.insert(0x010141, "サーティーン");
-    codes.insert(0x010142, "マザーガーブ");
-    codes.insert(0x010143, "マザーガーブ+");
-    codes.insert(0x010144, "トランスヴェスタイトプレート");
-    codes.insert(0x010145, "スウィートプレート");
-    codes.insert(0x010146, "イグニションクローク");
-    codes.insert(0x010147, "カンギールクローク");
-    codes.insert(0x010148, "テンペストクローク");
-    codes.insert(0x010149, "カースクローク");
-    codes.insert(0x01014A, "セレクトクローク");
-    codes.insert(0x01014B, "スピリットキュイラス");
-    codes.insert(0x01014C, "リバイバルキュイラス");
-    codes.insert(0x01014D, "連盟親衛隊軍服");
-    codes.insert(0x01014E, "連盟親衛隊上等兵軍服");
-    codes.insert(0x01014F, "連盟親衛隊指揮官軍服");
-    codes.insert(0x010150, "クリムゾンコート");
-    codes.insert(0x010151, "空間機動歩兵３２分隊上等兵軍服");
-    codes.insert(0x010152, "空間機動歩兵３２分隊中尉軍服");
-    codes.insert(0x010153, "空間機動歩兵外套");
-    codes.insert(0x010154, "空間機動歩兵中尉外套");
-    codes.insert(0x010155, "ユニオンフィールド");
-    codes.insert(0x010156, "浅黄絲威褄取鎧");
-    codes.insert(0x010157, "ステルススーツ");
-    codes.insert(0x010158, "????");
-    codes.insert(0x010200, "バリア");
-    codes.insert(0x010201, "シールド");
-    codes.insert(0x010202, "コアシールド");
-    codes.insert(0x010203, "ギガシールド");
-    codes.insert(0x010204, "ソウルバリア");
-    codes.insert(0x010205, "ハードシールド");
-    codes.insert(0x010206, "ブレイブバリア");
-    codes.insert(0x010207, "ソリッドシールド");
-    codes.insert(0x010208, "フレイムバリア");
-    codes.insert(0x010209, "プラズマバリア");
-    codes.insert(0x01020A, "フリーズバリア");
-    codes.insert(0x01020B, "サイキックバリア");
-    codes.insert(0x01020C, "ジェネラルシールド");
-    codes.insert(0x01020D, "プロテクトバリア");
-    codes.insert(0x01020E, "グロリアスシールド");
-    codes.insert(0x01020F, "インペリアルバリア");
-    codes.insert(0x010210, "ガーディアンシールド");
-    codes.insert(0x010211, "ディヴィニティバリア");
-    codes.insert(0x010212, "アルティメットシールド");
-    codes.insert(0x010213, "スピリチュアルシールド");
-    codes.insert(0x010214, "セレスティアルシールド");
-    codes.insert(0x010215, "インビジブルガード");
-    codes.insert(0x010216, "セイクリッドガード");
-    codes.insert(0x010217, "Ｓパーツ ver1.16");
-    codes.insert(0x010218, "Ｓパーツ ver2.01");
-    codes.insert(0x010219, "ライトリリーフ");
-    codes.insert(0x01021A, "デルセイバーの盾");
-    codes.insert(0x01021B, "フォースウォル");
-    codes.insert(0x01021C, "レンジャーウォル");
-    codes.insert(0x01021D, "ハンターウォル");
-    codes.insert(0x01021E, "アトリビュートウォル");
-    codes.insert(0x01021F, "シークレットギア");
-    codes.insert(0x010220, "コンバットギア");
-    codes.insert(0x010221, "リジェネレイトギア試");
-    codes.insert(0x010222, "リジェネレイトギア");
-    codes.insert(0x010223, "リジェネレイトギア改");
-    codes.insert(0x010224, "フロウウェンの盾");
-    codes.insert(0x010225, "カスタムバリア ver.OO");
-    codes.insert(0x010226, "ＤＢの盾");
-    codes.insert(0x010227, "レッドリング");
-    codes.insert(0x010228, "トリポリックシールド");
-    codes.insert(0x010229, "スタンドスティル");
-    codes.insert(0x01022A, "セーフティハート");
-    codes.insert(0x01022B, "カザミノコテ");
-    codes.insert(0x01022C, "四神盾「朱雀」");
-    codes.insert(0x01022D, "四神盾「玄武」");
-    codes.insert(0x01022E, "四神盾「白虎」");
-    codes.insert(0x01022F, "四神盾「青龍」");
-    codes.insert(0x010230, "ハンターズシェル");
-    codes.insert(0x010231, "リコのメガネ");
-    codes.insert(0x010232, "リコのイヤリング");
-    codes.insert(0x010233, "ブルーリング");
-    codes.insert(0x010234, "イエローリング");
-    codes.insert(0x010235, "セキュアフット");
-    codes.insert(0x010236, "パープルリング");
-    codes.insert(0x010237, "グリーンリング");
-    codes.insert(0x010238, "（バリア）");
-    codes.insert(0x010239, "（バリア）");
-    codes.insert(0x01023A, "レスタマージ");
-    codes.insert(0x01023B, "アンティマージ");
-    codes.insert(0x01023C, "シフタマージ");
-    codes.insert(0x01023D, "デバンドマージ");
-    codes.insert(0x01023E, "フォイエマージ");
-    codes.insert(0x01023F, "ギフォイエマージ");
-    codes.insert(0x010240, "ラフォイエマージ");
-    codes.insert(0x010241, "レッドマージ");
-    codes.insert(0x010242, "バータマージ");
-    codes.insert(0x010243, "ギバータマージ");
-    codes.insert(0x010244, "ラバータマージ");
-    codes.insert(0x010245, "ブルーマージ");
-    codes.insert(0x010246, "ゾンデマージ");
-    codes.insert(0x010247, "ギゾンデマージ");
-    codes.insert(0x010248, "ラゾンデマージ");
-    codes.insert(0x010249, "イエローマージ");
-    codes.insert(0x01024A, "リカバリバリア");
-    codes.insert(0x01024B, "アシストバリア");
-    codes.insert(0x01024C, "レッドバリア");
-    codes.insert(0x01024D, "ブルーバリア");
-    codes.insert(0x01024E, "イエローバリア");
-    codes.insert(0x01024F, "ウェポンズシールド金");
-    codes.insert(0x010250, "ブラックギア");
-    codes.insert(0x010251, "ＷＯＲＫＳガード");
-    codes.insert(0x010252, "ラグオルリング");
-    codes.insert(0x010253, "ブルーリング");
-    codes.insert(0x010254, "ブルーリング");
-    codes.insert(0x010255, "ブルーリング");
-    codes.insert(0x010256, "ブルーリング");
-    codes.insert(0x010257, "ブルーリング");
-    codes.insert(0x010258, "ブルーリング");
-    codes.insert(0x010259, "ブルーリング");
-    codes.insert(0x01025A, "ブルーリング");
-    codes.insert(0x01025B, "グリーンリング");
-    codes.insert(0x01025C, "グリーンリング");
-    codes.insert(0x01025D, "グリーンリング");
-    codes.insert(0x01025E, "グリーンリング");
-    codes.insert(0x01025F, "グリーンリング");
-    codes.insert(0x010260, "グリーンリング");
-    codes.insert(0x010261, "グリーンリング");
-    codes.insert(0x010262, "グリーンリング");
-    codes.insert(0x010263, "イエローリング");
-    codes.insert(0x010264, "イエローリング");
-    codes.insert(0x010265, "イエローリング");
-    codes.insert(0x010266, "イエローリング");
-    codes.insert(0x010267, "イエローリング");
-    codes.insert(0x010268, "イエローリング");
-    codes.insert(0x010269, "イエローリング");
-    codes.insert(0x01026A, "イエローリング");
-    codes.insert(0x01026B, "パープルリング");
-    codes.insert(0x01026C, "パープルリング");
-    codes.insert(0x01026D, "パープルリング");
-    codes.insert(0x01026E, "パープルリング");
-    codes.insert(0x01026F, "パープルリング");
-    codes.insert(0x010270, "パープルリング");
-    codes.insert(0x010271, "パープルリング");
-    codes.insert(0x010272, "パープルリング");
-    codes.insert(0x010273, "ホワイトリング");
-    codes.insert(0x010274, "ホワイトリング");
-    codes.insert(0x010275, "ホワイトリング");
-    codes.insert(0x010276, "ホワイトリング");
-    codes.insert(0x010277, "ホワイトリング");
-    codes.insert(0x010278, "ホワイトリング");
-    codes.insert(0x010279, "ホワイトリング");
-    codes.insert(0x01027A, "ホワイトリング");
-    codes.insert(0x01027B, "ブラックリング");
-    codes.insert(0x01027C, "ブラックリング");
-    codes.insert(0x01027D, "ブラックリング");
-    codes.insert(0x01027E, "ブラックリング");
-    codes.insert(0x01027F, "ブラックリング");
-    codes.insert(0x010280, "ブラックリング");
-    codes.insert(0x010281, "ブラックリング");
-    codes.insert(0x010282, "ブラックリング");
-    codes.insert(0x010283, "ウェポンズシールド銀");
-    codes.insert(0x010284, "ウェポンズシールド銅");
-    codes.insert(0x010285, "ガラシア");
-    codes.insert(0x010286, "トリポリックリフレクター");
-    codes.insert(0x010287, "ストライカープラス");
-    codes.insert(0x010288, "リジェネレイトギアB・P");
-    codes.insert(0x010289, "ルピカ");
-    codes.insert(0x01028A, "八咫鏡");
-    codes.insert(0x01028B, "ウサミミ");
-    codes.insert(0x01028C, "ネコミミ");
-    codes.insert(0x01028D, "スリーシールズ");
-    codes.insert(0x01028E, "四神盾「黄龍」");
-    codes.insert(0x01028F, "DFの盾");
-    codes.insert(0x010290, "淵ヨリ来ルモノ");
-    codes.insert(0x010291, "デ・ロル・レ・シールド");
-    codes.insert(0x010292, "ハニカムリフレクタ");
-    codes.insert(0x010293, "イプシガード");
-    codes.insert(0x010294, "円環天使");
-    codes.insert(0x010295, "ユニオンガード");
-    codes.insert(0x010296, "ユニオンガード");
-    codes.insert(0x010297, "ユニオンガード");
-    codes.insert(0x010298, "ユニオンガード");
-    codes.insert(0x010299, "匂う盾");
-    codes.insert(0x01029A, "黒のガントレット");
-    codes.insert(0x01029B, "源平");
-    codes.insert(0x01029C, "源平");
-    codes.insert(0x01029D, "源平");
-    codes.insert(0x01029E, "源平");
-    codes.insert(0x01029F, "源平");
-    codes.insert(0x0102A0, "源平");
-    codes.insert(0x0102A1, "源平");
-    codes.insert(0x0102A2, "源平");
-    codes.insert(0x0102A3, "源平");
-    codes.insert(0x0102A4, "源平");
-    codes.insert(0x0102A5, "????");
-    codes.insert(0x010300, "ナイト/パワー");
-    codes.insert(0x010301, "ジェネラル/パワー");
-    codes.insert(0x010302, "オウガ/パワー");
-    codes.insert(0x010303, "ゴッド/パワー");
-    codes.insert(0x010304, "プリースト/マインド");
-    codes.insert(0x010305, "ジェネラル/マインド");
-    codes.insert(0x010306, "エンジェル/マインド");
-    codes.insert(0x010307, "ゴッド/マインド");
-    codes.insert(0x010308, "マークスマン/アーム");
-    codes.insert(0x010309, "ジェネラル/アーム");
-    codes.insert(0x01030A, "エルフ/アーム");
-    codes.insert(0x01030B, "ゴッド/アーム");
-    codes.insert(0x01030C, "シーフ/レグス");
-    codes.insert(0x01030D, "ジェネラル/レグス");
-    codes.insert(0x01030E, "エルフ/レグス");
-    codes.insert(0x01030F, "ゴッド/レグス");
-    codes.insert(0x010310, "ディガー/ＨＰ");
-    codes.insert(0x010311, "ジェネラル/ＨＰ");
-    codes.insert(0x010312, "ドラゴン/ＨＰ");
-    codes.insert(0x010313, "ゴッド/ＨＰ");
-    codes.insert(0x010314, "マジシャン/ＴＰ");
-    codes.insert(0x010315, "ジェネラル/ＴＰ");
-    codes.insert(0x010316, "エンジェル/ＴＰ");
-    codes.insert(0x010317, "ゴッド/ＴＰ");
-    codes.insert(0x010318, "ウォリアー/ボディ");
-    codes.insert(0x010319, "ジェネラル/ボディ");
-    codes.insert(0x01031A, "メタル/ボディ");
-    codes.insert(0x01031B, "ゴッド/ボディ");
-    codes.insert(0x01031C, "エンジェル/ラック");
-    codes.insert(0x01031D, "ゴッド/ラック");
-    codes.insert(0x01031E, "マスター/アビリティ");
-    codes.insert(0x01031F, "ヒーロー/アビリティ");
-    codes.insert(0x010320, "ゴッド/アビリティ");
-    codes.insert(0x010321, "レジスト/ファイア");
-    codes.insert(0x010322, "レジスト/フレイム");
-    codes.insert(0x010323, "レジスト/バーニング");
-    codes.insert(0x010324, "レジスト/コールド");
-    codes.insert(0x010325, "レジスト/フリーズ");
-    codes.insert(0x010326, "レジスト/ブリザード");
-    codes.insert(0x010327, "レジスト/ショック");
-    codes.insert(0x010328, "レジスト/サンダー");
-    codes.insert(0x010329, "レジスト/ストーム");
-    codes.insert(0x01032A, "レジスト/ライト");
-    codes.insert(0x01032B, "レジスト/セイント");
-    codes.insert(0x01032C, "レジスト/ホーリー");
-    codes.insert(0x01032D, "レジスト/ダーク");
-    codes.insert(0x01032E, "レジスト/エビル");
-    codes.insert(0x01032F, "レジスト/エビル");
-    codes.insert(0x010330, "オール/レジスト");
-    codes.insert(0x010331, "スーパー/レジスト");
-    codes.insert(0x010332, "パーフェクト/レジスト");
-    codes.insert(0x010333, "ＨＰ/リストレイト");
-    codes.insert(0x010334, "ＨＰ/ジェエレイト");
-    codes.insert(0x010335, "ＨＰ/リバイバル");
-    codes.insert(0x010336, "ＴＰ/リストレイト");
-    codes.insert(0x010337, "ＴＰ/ジェネレイト");
-    codes.insert(0x010338, "ＴＰ/リバイバル");
-    codes.insert(0x010339, "ＰＢ/アンプリファー");
-    codes.insert(0x01033A, "ＰＢ/ジェネレイト");
-    codes.insert(0x01033B, "ＰＢ/クリエイト");
-    codes.insert(0x01033C, "ウィザード/テクニック");
-    codes.insert(0x01033D, "デビル/テクニック");
-    codes.insert(0x01033E, "ゴッド/テクニック");
-    codes.insert(0x01033F, "ジェネラル/バトル");
-    codes.insert(0x010340, "デビル/バトル");
-    codes.insert(0x010341, "ゴッド/バトル");
-    codes.insert(0x010342, "キュア/ポイズン");
-    codes.insert(0x010343, "キュア/パラライズ");
-    codes.insert(0x010344, "キュア/スロー");
-    codes.insert(0x010345, "キュア/コンフューズ");
-    codes.insert(0x010346, "キュア/フリーズ");
-    codes.insert(0x010347, "キュア/ショック");
-    codes.insert(0x010348, "八坂瓊曲玉");
-    codes.insert(0x010349, "Ｖ１０１");
-    codes.insert(0x01034A, "Ｖ５０１");
-    codes.insert(0x01034B, "Ｖ５０２");
-    codes.insert(0x01034C, "Ｖ８０１");
-    codes.insert(0x01034D, "リミッター");
-    codes.insert(0x01034E, "アデプト");
-    codes.insert(0x01034F, "剣士の心得");
-    codes.insert(0x010350, "剣聖の証");
-    codes.insert(0x010351, "スマートリンク");
-    codes.insert(0x010352, "ディバインプロテクション");
-    codes.insert(0x010353, "ヘヴンリー/バトル");
-    codes.insert(0x010354, "ヘヴンリー/パワー");
-    codes.insert(0x010355, "ヘヴンリー/マインド");
-    codes.insert(0x010356, "ヘヴンリー/アーム");
-    codes.insert(0x010357, "ヘヴンリー/レグス");
-    codes.insert(0x010358, "ヘヴンリー/ボディ");
-    codes.insert(0x010359, "ヘヴンリー/ラック");
-    codes.insert(0x01035A, "ヘヴンリー/アビリティ");
-    codes.insert(0x01035B, "センチュリオン/アビリティ");
-    codes.insert(0x01035C, "友達の輪");
-    codes.insert(0x01035D, "ヘヴンリー/ＨＰ");
-    codes.insert(0x01035E, "ヘヴンリー/ＴＰ");
-    codes.insert(0x01035F, "ヘヴンリー/レジスト");
-    codes.insert(0x010360, "ヘヴンリー/テクニック");
-    codes.insert(0x010361, "ＨＰ/リザレクション");
-    codes.insert(0x010362, "ＴＰ/リザレクション");
-    codes.insert(0x010363, "ＰＢ/インクリーズ");
-    codes.insert(0x010364, "????");
-    codes.insert(0x020000, "マグ");
-    codes.insert(0x020100, "ヴァルナ");
-    codes.insert(0x020200, "ミトラ");
-    codes.insert(0x020300, "スーリヤ");
-    codes.insert(0x020400, "ヴァーユ");
-    codes.insert(0x020500, "ヴァラーハ");
-    codes.insert(0x020600, "カーマ");
-    codes.insert(0x020700, "ウシャス");
-    codes.insert(0x020800, "アプサラス");
-    codes.insert(0x020900, "クマーラ");
-    codes.insert(0x020A00, "カイタバ");
-    codes.insert(0x020B00, "タパス");
-    codes.insert(0x020C00, "ヴァラーハ");
-    codes.insert(0x020D00, "カルキ");
-    codes.insert(0x020E00, "ルドラ");
-    codes.insert(0x020F00, "マルト");
-    codes.insert(0x021000, "ヤクシャ");
-    codes.insert(0x021100, "シーター");
-    codes.insert(0x021200, "ガルダ");
-    codes.insert(0x021300, "ナンディン");
-    codes.insert(0x021400, "アシヴィン");
-    codes.insert(0x021500, "リヴ");
-    codes.insert(0x021600, "ソーマ");
-    codes.insert(0x021700, "イラー");
-    codes.insert(0x021800, "ドゥルガー");
-    codes.insert(0x021900, "ヴリトラ");
-    codes.insert(0x021A00, "ナムチ");
-    codes.insert(0x021B00, "シュムバ");
-    codes.insert(0x021C00, "ナーガ");
-    codes.insert(0x021D00, "ピトリ");
-    codes.insert(0x021E00, "カバンダ");
-    codes.insert(0x021F00, "ラーヴァナ");
-    codes.insert(0x022000, "マーリーチャ");
-    codes.insert(0x022100, "ソニチ");
-    codes.insert(0x022200, "プレタ");
-    codes.insert(0x022300, "アンダカ");
-    codes.insert(0x022400, "バーナ");
-    codes.insert(0x022500, "ナラカ");
-    codes.insert(0x022600, "マドゥ");
-    codes.insert(0x022700, "チュレル");
-    codes.insert(0x022800, "オモチャオ");
-    codes.insert(0x022900, "オパオパ");
-    codes.insert(0x022A00, "ピアン");
-    codes.insert(0x022B00, "チャオ");
-    codes.insert(0x022C00, "チュウチュウ");
-    codes.insert(0x022D00, "カプカプ");
-    codes.insert(0x022E00, "天使の羽");
-    codes.insert(0x022F00, "悪魔の羽");
-    codes.insert(0x023000, "エレノア");
-    codes.insert(0x023100, "マーク3");
-    codes.insert(0x023200, "マスターシステム");
-    codes.insert(0x023300, "メガドライブ");
-    codes.insert(0x023400, "セガサターン");
-    codes.insert(0x023500, "ドリームキャスト");
-    codes.insert(0x023600, "ハンバーガー");
-    codes.insert(0x023700, "パンサーテイル");
-    codes.insert(0x023800, "悪魔のシッポ");
-    codes.insert(0x023900, "デーヴァ");
-    codes.insert(0x023A00, "ラティ");
-    codes.insert(0x023B00, "サヴィトリ");
-    codes.insert(0x023C00, "ルクミン");
-    codes.insert(0x023D00, "プーシャン");
-    codes.insert(0x023E00, "ディヴァリー");
-    codes.insert(0x023F00, "シャト");
-    codes.insert(0x024000, "ピーマ");
-    codes.insert(0x024100, "ニドラー");
-    codes.insert(0x024200, "キョンシー");
-    codes.insert(0x024300, "（マグ）");
-    codes.insert(0x024400, "テルジス");
-    codes.insert(0x024500, "ストライカーユニット");
-    codes.insert(0x024600, "パイオニア");
-    codes.insert(0x024700, "プヨ");
-    codes.insert(0x024800, "モロ");
-    codes.insert(0x024900, "ラッピー");
-    codes.insert(0x024A00, "Ｙａｈｏｏ！");
-    codes.insert(0x024B00, "ガエルギエル");
-    codes.insert(0x024C00, "アガスティア");
-    codes.insert(0x024D00, "マグ0503");
-    codes.insert(0x024E00, "マグ0504");
-    codes.insert(0x024F00, "マグ0505");
-    codes.insert(0x025000, "マグ0506");
-    codes.insert(0x025100, "マグ0507");
-    codes.insert(0x025200, "????");
-    codes.insert(0x030000, "モノメイト");
-    codes.insert(0x030001, "ディメイト");
-    codes.insert(0x030002, "トリメイト");
-    codes.insert(0x030100, "モノフルイド");
-    codes.insert(0x030101, "ディフルイド");
-    codes.insert(0x030102, "トリフルイド");
-    codes.insert(0x030300, "ソルアトマイザー");
-    codes.insert(0x030400, "ムーンアトマイザー");
-    codes.insert(0x030500, "スターアトマイザー");
-    codes.insert(0x030600, "アンティドート");
-    codes.insert(0x030601, "アンティパラライズ");
-    codes.insert(0x030700, "テレパイプ");
-    codes.insert(0x030800, "トラップビジョン");
-    codes.insert(0x030900, "スケープドール");
-    codes.insert(0x030A00, "モノグラインダー");
-    codes.insert(0x030A01, "ディグラインダー");
-    codes.insert(0x030A02, "トリグラインダー");
-    codes.insert(0x030B00, "パワーマテリアル");
-    codes.insert(0x030B01, "マインドマテリアル");
-    codes.insert(0x030B02, "イベイドマテリアル");
-    codes.insert(0x030B03, "ＨＰマテリアル");
-    codes.insert(0x030B04, "ＴＰマテリアル");
-    codes.insert(0x030B05, "ディフェンスマテリアル");
-    codes.insert(0x030B06, "ラックマテリアル");
-    codes.insert(0x030C00, "マグ細胞５０２");
-    codes.insert(0x030C01, "マグ細胞２１３");
-    codes.insert(0x030C02, "オモチャオのパーツ");
-    codes.insert(0x030C03, "オパオパの心");
-    codes.insert(0x030C04, "ピアンの心");
-    codes.insert(0x030C05, "チャオの心");
-    codes.insert(0x030D00, "ソーサラーの右腕");
-    codes.insert(0x030D01, "シノワビードの両手");
-    codes.insert(0x030D02, "パンアームズの両手");
-    codes.insert(0x030D03, "デルセイバーの右手");
-    codes.insert(0x030D04, "ブリンガーの右手");
-    codes.insert(0x030D05, "デルセイバーの左手");
-    codes.insert(0x030D06, "シノワレッドの両手");
-    codes.insert(0x030D07, "ドラゴンのツメ");
-    codes.insert(0x030D08, "ヒルデベアの頭");
-    codes.insert(0x030D09, "ヒルデブルーの頭");
-    codes.insert(0x030D0A, "バランゾの部品");
-    codes.insert(0x030D0B, "ベルラの右手");
-    codes.insert(0x030D0C, "ギ・グーの腹部");
-    codes.insert(0x030D0D, "シノワベリルの両手");
-    codes.insert(0x030D0E, "グラスアサッシンの両手");
-    codes.insert(0x030D0F, "ブーマの右腕");
-    codes.insert(0x030D10, "ゴブーマの右腕");
-    codes.insert(0x030D11, "ジゴブーマの右腕");
-    codes.insert(0x030D12, "ガルグリフォンのハネ");
-    codes.insert(0x030D13, "ラッピーのハネ");
-    codes.insert(0x030D14, "イプシロンの外装");
-    codes.insert(0x030D15, "デ・ロル・レの殻");
-    codes.insert(0x030E00, "ベリルフォトン");
-    codes.insert(0x030E01, "侵食遺伝子「フロウ」");
-    codes.insert(0x030E02, "魔石「イリティスタ」");
-    codes.insert(0x030E03, "蒼黒石");
-    codes.insert(0x030E04, "シンセスタ");
-    codes.insert(0x030E05, "魔神水");
-    codes.insert(0x030E06, "Ｄ型寄生細胞");
-    codes.insert(0x030E07, "魔石「ハートキー」");
-    codes.insert(0x030E08, "輝石「ムーラ」");
-    codes.insert(0x030E09, "スターアンプ");
-    codes.insert(0x030E0A, "真瑚経書");
-    codes.insert(0x030E0B, "チュウチュウの心");
-    codes.insert(0x030E0C, "エッグパーツ");
-    codes.insert(0x030E0D, "天使の心");
-    codes.insert(0x030E0E, "悪魔の心");
-    codes.insert(0x030E0F, "ハンバーガーキット");
-    codes.insert(0x030E10, "パンサースピリット");
-    codes.insert(0x030E11, "マーク3キット");
-    codes.insert(0x030E12, "マスターキット");
-    codes.insert(0x030E13, "メガキット");
-    codes.insert(0x030E14, "サターンキット");
-    codes.insert(0x030E15, "ドリームキット");
-    codes.insert(0x030E16, "レスタアンプ");
-    codes.insert(0x030E17, "アンティアンプ");
-    codes.insert(0x030E18, "シフタアンプ");
-    codes.insert(0x030E19, "デバンドアンプ");
-    codes.insert(0x030E1A, "フォイエアンプ");
-    codes.insert(0x030E1B, "ギフォイエアンプ");
-    codes.insert(0x030E1C, "ラフォイエアンプ");
-    codes.insert(0x030E1D, "バータアンプ");
-    codes.insert(0x030E1E, "ギバータアンプ");
-    codes.insert(0x030E1F, "ラバータアンプ");
-    codes.insert(0x030E20, "ゾンデアンプ");
-    codes.insert(0x030E21, "ギゾンデアンプ");
-    codes.insert(0x030E22, "ラゾンデアンプ");
-    codes.insert(0x030E23, "レッドアンプ");
-    codes.insert(0x030E24, "ブルーアンプ");
-    codes.insert(0x030E25, "イエローアンプ");
-    codes.insert(0x030E26, "カプカプの心");
-    codes.insert(0x030E27, "フォトンブースター");
-    codes.insert(0x030F00, "アドスロット");
-    codes.insert(0x031000, "フォトンドロップ");
-    codes.insert(0x031001, "フォトンスフィア");
-    codes.insert(0x031002, "フォトンクリスタル");
-    codes.insert(0x031003, "ヒミツチケット");
-    codes.insert(0x031004, "フォトンチケット");
-    codes.insert(0x031100, "四天 壱の巻");
-    codes.insert(0x031101, "四天 弐の巻");
-    codes.insert(0x031102, "四天 参の巻");
-    codes.insert(0x031200, "ウェポンズバッヂ銅");
-    codes.insert(0x031201, "ウェポンズバッヂ銀");
-    codes.insert(0x031202, "ウェポンズバッヂ金");
-    codes.insert(0x031203, "ウェポンズバッヂクリスタル");
-    codes.insert(0x031204, "ウェポンズバッヂ鉄");
-    codes.insert(0x031205, "ウェポンズバッヂアルミ");
-    codes.insert(0x031206, "ウェポンズバッヂ皮");
-    codes.insert(0x031207, "ウェポンズバッヂ骨");
-    codes.insert(0x031208, "感謝状");
-    codes.insert(0x031209, "アイテム引換券");
-    codes.insert(0x03120A, "バレンタインチョコ");
-    codes.insert(0x03120B, "年賀状");
-    codes.insert(0x03120C, "クリスマスカード");
-    codes.insert(0x03120D, "誕生日カード");
-    codes.insert(0x03120E, "ソニックチームの証");
-    codes.insert(0x03120F, "トクベツチケット");
-    codes.insert(0x031210, "花束");
-    codes.insert(0x031211, "ケーキ");
-    codes.insert(0x031212, "アクセサリー");
-    codes.insert(0x031213, "中社長の名刺");
-    codes.insert(0x031300, "プレゼント");
-    codes.insert(0x031400, "チョコレート");
-    codes.insert(0x031401, "キャンディ");
-    codes.insert(0x031402, "ケーキ");
-    codes.insert(0x031403, "ウェポンズバッヂ銀");
-    codes.insert(0x031404, "ウェポンズバッヂ金");
-    codes.insert(0x031405, "ウェポンズバッヂクリスタル");
-    codes.insert(0x031406, "ウェポンズバッヂ鉄");
-    codes.insert(0x031407, "ウェポンズバッヂアルミ");
-    codes.insert(0x031408, "ウェポンズバッヂ皮");
-    codes.insert(0x031409, "ウェポンズバッヂ骨");
-    codes.insert(0x03140A, "ブーケ");
-    codes.insert(0x03140B, "調合薬");
-    codes.insert(0x031500, "クリスマスプレゼント");
-    codes.insert(0x031501, "イースターエッグ");
-    codes.insert(0x031502, "カボチャ");
-    codes.insert(0x031600, "ＤＩＳＫ Ｖｏｌ．１");
-    codes.insert(0x031601, "ＤＩＳＫ Ｖｏｌ．２");
-    codes.insert(0x031602, "ＤＩＳＫ Ｖｏｌ．３");
-    codes.insert(0x031603, "ＤＩＳＫ Ｖｏｌ．４");
-    codes.insert(0x031604, "ＤＩＳＫ Ｖｏｌ．５");
-    codes.insert(0x031605, "ＤＩＳＫ Ｖｏｌ．６");
-    codes.insert(0x031606, "ＤＩＳＫ Ｖｏｌ．７");
-    codes.insert(0x031607, "ＤＩＳＫ「ＨＥＡＲＴ ２ ＨＥＡＲＴ」");
-    codes.insert(0x031608, "ＤＩＳＫ「ＳＴＲＡＮＧＥ ＢＬＵＥ」");
-    codes.insert(0x031609, "ＤＩＳＫ「ＲＥＵＮＩＯＮ ＳＹＳＴＥＭ」");
-    codes.insert(0x03160A, "ＤＩＳＫ「ＰＩＮＮＡＣＬＥＳ」");
-    codes.insert(0x03160B, "ＤＩＳＫ「宇宙船内の戦い」");
-    codes.insert(0x031700, "ハンターズレポート（未開封）");
-    codes.insert(0x031701, "ハンターズレポート（優）");
-    codes.insert(0x031702, "ハンターズレポート（良）");
-    codes.insert(0x031703, "ハンターズレポート（可）");
-    codes.insert(0x031704, "ハンターズレポート（不）");
-    codes.insert(0x031800, "位牌");
-    codes.insert(0x031801, "UNKNOWN2");
-    codes.insert(0x031802, "龍の鱗");
-    codes.insert(0x031803, "ヘブンストライカーの塗膜");
-    codes.insert(0x031804, "パイオニアのパーツ");
-    codes.insert(0x031805, "アミティのメモ");
-    codes.insert(0x031806, "モロ星人の心");
-    codes.insert(0x031807, "ラッピーのくちばし");
-    codes.insert(0x031808, "Ｙａｈｏｏ！エンジン");
-    codes.insert(0x031809, "Ｄフォトンコア");
-    codes.insert(0x03180A, "リベルタユニット");
-    codes.insert(0x03180B, "ステルスキット");
-    codes.insert(0x03180C, "マグキット");
-    codes.insert(0x03180D, "ヴァルナキット");
-    codes.insert(0x03180E, "カルキキット");
-    codes.insert(0x03180F, "ヴリトラキット");
-    codes.insert(0x031900, "チームポイント５００");
-    codes.insert(0x031901, "チームポイント１０００");
-    codes.insert(0x031902, "チームポイント５０００");
-    codes.insert(0x031903, "チームポイント１００００");
-    codes.insert(0x031A00, "????");
-    codes.insert(0x040000, "Meseta");
-    codes.insert(0x050000, "Foie Lv1");
-    codes.insert(0x050001, "Foie Lv2");
-    codes.insert(0x050002, "Foie Lv3");
-    codes.insert(0x050003, "Foie Lv4");
-    codes.insert(0x050004, "Foie Lv5");
-    codes.insert(0x050005, "Foie Lv6");
-    codes.insert(0x050006, "Foie Lv7");
-    codes.insert(0x050007, "Foie Lv8");
-    codes.insert(0x050008, "Foie Lv9");
-    codes.insert(0x050009, "Foie Lv10");
-    codes.insert(0x05000A, "Foie Lv11");
-    codes.insert(0x05000B, "Foie Lv12");
-    codes.insert(0x05000C, "Foie Lv13");
-    codes.insert(0x05000D, "Foie Lv14");
-    codes.insert(0x05000E, "Foie Lv15");
-    codes.insert(0x05000F, "Foie Lv16");
-    codes.insert(0x050010, "Foie Lv17");
-    codes.insert(0x050011, "Foie Lv18");
-    codes.insert(0x050012, "Foie Lv19");
-    codes.insert(0x050013, "Foie Lv20");
-    codes.insert(0x050014, "Foie Lv21");
-    codes.insert(0x050015, "Foie Lv22");
-    codes.insert(0x050016, "Foie Lv23");
-    codes.insert(0x050017, "Foie Lv24");
-    codes.insert(0x050018, "Foie Lv25");
-    codes.insert(0x050019, "Foie Lv26");
-    codes.insert(0x05001A, "Foie Lv27");
-    codes.insert(0x05001B, "Foie Lv28");
-    codes.insert(0x05001C, "Foie Lv29");
-    codes.insert(0x05001D, "Foie Lv30");
-    codes.insert(0x050100, "Gifoie Lv1");
-    codes.insert(0x050101, "Gifoie Lv2");
-    codes.insert(0x050102, "Gifoie Lv3");
-    codes.insert(0x050103, "Gifoie Lv4");
-    codes.insert(0x050104, "Gifoie Lv5");
-    codes.insert(0x050105, "Gifoie Lv6");
-    codes.insert(0x050106, "Gifoie Lv7");
-    codes.insert(0x050107, "Gifoie Lv8");
-    codes.insert(0x050108, "Gifoie Lv9");
-    codes.insert(0x050109, "Gifoie Lv10");
-    codes.insert(0x05010A, "Gifoie Lv11");
-    codes.insert(0x05010B, "Gifoie Lv12");
-    codes.insert(0x05010C, "Gifoie Lv13");
-    codes.insert(0x05010D, "Gifoie Lv14");
-    codes.insert(0x05010E, "Gifoie Lv15");
-    codes.insert(0x05010F, "Gifoie Lv16");
-    codes.insert(0x050110, "Gifoie Lv17");
-    codes.insert(0x050111, "Gifoie Lv18");
-    codes.insert(0x050112, "Gifoie Lv19");
-    codes.insert(0x050113, "Gifoie Lv20");
-    codes.insert(0x050114, "Gifoie Lv21");
-    codes.insert(0x050115, "Gifoie Lv22");
-    codes.insert(0x050116, "Gifoie Lv23");
-    codes.insert(0x050117, "Gifoie Lv24");
-    codes.insert(0x050118, "Gifoie Lv25");
-    codes.insert(0x050119, "Gifoie Lv26");
-    codes.insert(0x05011A, "Gifoie Lv27");
-    codes.insert(0x05011B, "Gifoie Lv28");
-    codes.insert(0x05011C, "Gifoie Lv29");
-    codes.insert(0x05011D, "Gifoie Lv30");
-    codes.insert(0x050200, "Rafoie Lv1");
-    codes.insert(0x050201, "Rafoie Lv2");
-    codes.insert(0x050202, "Rafoie Lv3");
-    codes.insert(0x050203, "Rafoie Lv4");
-    codes.insert(0x050204, "Rafoie Lv5");
-    codes.insert(0x050205, "Rafoie Lv6");
-    codes.insert(0x050206, "Rafoie Lv7");
-    codes.insert(0x050207, "Rafoie Lv8");
-    codes.insert(0x050208, "Rafoie Lv9");
-    codes.insert(0x050209, "Rafoie Lv10");
-    codes.insert(0x05020A, "Rafoie Lv11");
-    codes.insert(0x05020B, "Rafoie Lv12");
-    codes.insert(0x05020C, "Rafoie Lv13");
-    codes.insert(0x05020D, "Rafoie Lv14");
-    codes.insert(0x05020E, "Rafoie Lv15");
-    codes.insert(0x05020F, "Rafoie Lv16");
-    codes.insert(0x050210, "Rafoie Lv17");
-    codes.insert(0x050211, "Rafoie Lv18");
-    codes.insert(0x050212, "Rafoie Lv19");
-    codes.insert(0x050213, "Rafoie Lv20");
-    codes.insert(0x050214, "Rafoie Lv21");
-    codes.insert(0x050215, "Rafoie Lv22");
-    codes.insert(0x050216, "Rafoie Lv23");
-    codes.insert(0x050217, "Rafoie Lv24");
-    codes.insert(0x050218, "Rafoie Lv25");
-    codes.insert(0x050219, "Rafoie Lv26");
-    codes.insert(0x05021A, "Rafoie Lv27");
-    codes.insert(0x05021B, "Rafoie Lv28");
-    codes.insert(0x05021C, "Rafoie Lv29");
-    codes.insert(0x05021D, "Rafoie Lv30");
-    codes.insert(0x050300, "Barta Lv1");
-    codes.insert(0x050301, "Barta Lv2");
-    codes.insert(0x050302, "Barta Lv3");
-    codes.insert(0x050303, "Barta Lv4");
-    codes.insert(0x050304, "Barta Lv5");
-    codes.insert(0x050305, "Barta Lv6");
-    codes.insert(0x050306, "Barta Lv7");
-    codes.insert(0x050307, "Barta Lv8");
-    codes.insert(0x050308, "Barta Lv9");
-    codes.insert(0x050309, "Barta Lv10");
-    codes.insert(0x05030A, "Barta Lv11");
-    codes.insert(0x05030B, "Barta Lv12");
-    codes.insert(0x05030C, "Barta Lv13");
-    codes.insert(0x05030D, "Barta Lv14");
-    codes.insert(0x05030E, "Barta Lv15");
-    codes.insert(0x05030F, "Barta Lv16");
-    codes.insert(0x050310, "Barta Lv17");
-    codes.insert(0x050311, "Barta Lv18");
-    codes.insert(0x050312, "Barta Lv19");
-    codes.insert(0x050313, "Barta Lv20");
-    codes.insert(0x050314, "Barta Lv21");
-    codes.insert(0x050315, "Barta Lv22");
-    codes.insert(0x050316, "Barta Lv23");
-    codes.insert(0x050317, "Barta Lv24");
-    codes.insert(0x050318, "Barta Lv25");
-    codes.insert(0x050319, "Barta Lv26");
-    codes.insert(0x05031A, "Barta Lv27");
-    codes.insert(0x05031B, "Barta Lv28");
-    codes.insert(0x05031C, "Barta Lv29");
-    codes.insert(0x05031D, "Barta Lv30");
-    codes.insert(0x050400, "Gibarta Lv1");
-    codes.insert(0x050401, "Gibarta Lv2");
-    codes.insert(0x050402, "Gibarta Lv3");
-    codes.insert(0x050403, "Gibarta Lv4");
-    codes.insert(0x050404, "Gibarta Lv5");
-    codes.insert(0x050405, "Gibarta Lv6");
-    codes.insert(0x050406, "Gibarta Lv7");
-    codes.insert(0x050407, "Gibarta Lv8");
-    codes.insert(0x050408, "Gibarta Lv9");
-    codes.insert(0x050409, "Gibarta Lv10");
-    codes.insert(0x05040A, "Gibarta Lv11");
-    codes.insert(0x05040B, "Gibarta Lv12");
-    codes.insert(0x05040C, "Gibarta Lv13");
-    codes.insert(0x05040D, "Gibarta Lv14");
-    codes.insert(0x05040E, "Gibarta Lv15");
-    codes.insert(0x05040F, "Gibarta Lv16");
-    codes.insert(0x050410, "Gibarta Lv17");
-    codes.insert(0x050411, "Gibarta Lv18");
-    codes.insert(0x050412, "Gibarta Lv19");
-    codes.insert(0x050413, "Gibarta Lv20");
-    codes.insert(0x050414, "Gibarta Lv21");
-    codes.insert(0x050415, "Gibarta Lv22");
-    codes.insert(0x050416, "Gibarta Lv23");
-    codes.insert(0x050417, "Gibarta Lv24");
-    codes.insert(0x050418, "Gibarta Lv25");
-    codes.insert(0x050419, "Gibarta Lv26");
-    codes.insert(0x05041A, "Gibarta Lv27");
-    codes.insert(0x05041B, "Gibarta Lv28");
-    codes.insert(0x05041C, "Gibarta Lv29");
-    codes.insert(0x05041D, "Gibarta Lv30");
-    codes.insert(0x050500, "Rabarta Lv1");
-    codes.insert(0x050501, "Rabarta Lv2");
-    codes.insert(0x050502, "Rabarta Lv3");
-    codes.insert(0x050503, "Rabarta Lv4");
-    codes.insert(0x050504, "Rabarta Lv5");
-    codes.insert(0x050505, "Rabarta Lv6");
-    codes.insert(0x050506, "Rabarta Lv7");
-    codes.insert(0x050507, "Rabarta Lv8");
-    codes.insert(0x050508, "Rabarta Lv9");
-    codes.insert(0x050509, "Rabarta Lv10");
-    codes.insert(0x05050A, "Rabarta Lv11");
-    codes.insert(0x05050B, "Rabarta Lv12");
-    codes.insert(0x05050C, "Rabarta Lv13");
-    codes.insert(0x05050D, "Rabarta Lv14");
-    codes.insert(0x05050E, "Rabarta Lv15");
-    codes.insert(0x05050F, "Rabarta Lv16");
-    codes.insert(0x050510, "Rabarta Lv17");
-    codes.insert(0x050511, "Rabarta Lv18");
-    codes.insert(0x050512, "Rabarta Lv19");
-    codes.insert(0x050513, "Rabarta Lv20");
-    codes.insert(0x050514, "Rabarta Lv21");
-    codes.insert(0x050515, "Rabarta Lv22");
-    codes.insert(0x050516, "Rabarta Lv23");
-    codes.insert(0x050517, "Rabarta Lv24");
-    codes.insert(0x050518, "Rabarta Lv25");
-    codes.insert(0x050519, "Rabarta Lv26");
-    codes.insert(0x05051A, "Rabarta Lv27");
-    codes.insert(0x05051B, "Rabarta Lv28");
-    codes.insert(0x05051C, "Rabarta Lv29");
-    codes.insert(0x05051D, "Rabarta Lv30");
-    codes.insert(0x050600, "Zonde Lv1");
-    codes.insert(0x050601, "Zonde Lv2");
-    codes.insert(0x050602, "Zonde Lv3");
-    codes.insert(0x050603, "Zonde Lv4");
-    codes.insert(0x050604, "Zonde Lv5");
-    codes.insert(0x050605, "Zonde Lv6");
-    codes.insert(0x050606, "Zonde Lv7");
-    codes.insert(0x050607, "Zonde Lv8");
-    codes.insert(0x050608, "Zonde Lv9");
-    codes.insert(0x050609, "Zonde Lv10");
-    codes.insert(0x05060A, "Zonde Lv11");
-    codes.insert(0x05060B, "Zonde Lv12");
-    codes.insert(0x05060C, "Zonde Lv13");
-    codes.insert(0x05060D, "Zonde Lv14");
-    codes.insert(0x05060E, "Zonde Lv15");
-    codes.insert(0x05060F, "Zonde Lv16");
-    codes.insert(0x050610, "Zonde Lv17");
-    codes.insert(0x050611, "Zonde Lv18");
-    codes.insert(0x050612, "Zonde Lv19");
-    codes.insert(0x050613, "Zonde Lv20");
-    codes.insert(0x050614, "Zonde Lv21");
-    codes.insert(0x050615, "Zonde Lv22");
-    codes.insert(0x050616, "Zonde Lv23");
-    codes.insert(0x050617, "Zonde Lv24");
-    codes.insert(0x050618, "Zonde Lv25");
-    codes.insert(0x050619, "Zonde Lv26");
-    codes.insert(0x05061A, "Zonde Lv27");
-    codes.insert(0x05061B, "Zonde Lv28");
-    codes.insert(0x05061C, "Zonde Lv29");
-    codes.insert(0x05061D, "Zonde Lv30");
-    codes.insert(0x050700, "Gizonde Lv1");
-    codes.insert(0x050701, "Gizonde Lv2");
-    codes.insert(0x050702, "Gizonde Lv3");
-    codes.insert(0x050703, "Gizonde Lv4");
-    codes.insert(0x050704, "Gizonde Lv5");
-    codes.insert(0x050705, "Gizonde Lv6");
-    codes.insert(0x050706, "Gizonde Lv7");
-    codes.insert(0x050707, "Gizonde Lv8");
-    codes.insert(0x050708, "Gizonde Lv9");
-    codes.insert(0x050709, "Gizonde Lv10");
-    codes.insert(0x05070A, "Gizonde Lv11");
-    codes.insert(0x05070B, "Gizonde Lv12");
-    codes.insert(0x05070C, "Gizonde Lv13");
-    codes.insert(0x05070D, "Gizonde Lv14");
-    codes.insert(0x05070E, "Gizonde Lv15");
-    codes.insert(0x05070F, "Gizonde Lv16");
-    codes.insert(0x050710, "Gizonde Lv17");
-    codes.insert(0x050711, "Gizonde Lv18");
-    codes.insert(0x050712, "Gizonde Lv19");
-    codes.insert(0x050713, "Gizonde Lv20");
-    codes.insert(0x050714, "Gizonde Lv21");
-    codes.insert(0x050715, "Gizonde Lv22");
-    codes.insert(0x050716, "Gizonde Lv23");
-    codes.insert(0x050717, "Gizonde Lv24");
-    codes.insert(0x050718, "Gizonde Lv25");
-    codes.insert(0x050719, "Gizonde Lv26");
-    codes.insert(0x05071A, "Gizonde Lv27");
-    codes.insert(0x05071B, "Gizonde Lv28");
-    codes.insert(0x05071C, "Gizonde Lv29");
-    codes.insert(0x05071D, "Gizonde Lv30");
-    codes.insert(0x050800, "Razonde Lv1");
-    codes.insert(0x050801, "Razonde Lv2");
-    codes.insert(0x050802, "Razonde Lv3");
-    codes.insert(0x050803, "Razonde Lv4");
-    codes.insert(0x050804, "Razonde Lv5");
-    codes.insert(0x050805, "Razonde Lv6");
-    codes.insert(0x050806, "Razonde Lv7");
-    codes.insert(0x050807, "Razonde Lv8");
-    codes.insert(0x050808, "Razonde Lv9");
-    codes.insert(0x050809, "Razonde Lv10");
-    codes.insert(0x05080A, "Razonde Lv11");
-    codes.insert(0x05080B, "Razonde Lv12");
-    codes.insert(0x05080C, "Razonde Lv13");
-    codes.insert(0x05080D, "Razonde Lv14");
-    codes.insert(0x05080E, "Razonde Lv15");
-    codes.insert(0x05080F, "Razonde Lv16");
-    codes.insert(0x050810, "Razonde Lv17");
-    codes.insert(0x050811, "Razonde Lv18");
-    codes.insert(0x050812, "Razonde Lv19");
-    codes.insert(0x050813, "Razonde Lv20");
-    codes.insert(0x050814, "Razonde Lv21");
-    codes.insert(0x050815, "Razonde Lv22");
-    codes.insert(0x050816, "Razonde Lv23");
-    codes.insert(0x050817, "Razonde Lv24");
-    codes.insert(0x050818, "Razonde Lv25");
-    codes.insert(0x050819, "Razonde Lv26");
-    codes.insert(0x05081A, "Razonde Lv27");
-    codes.insert(0x05081B, "Razonde Lv28");
-    codes.insert(0x05081C, "Razonde Lv29");
-    codes.insert(0x05081D, "Razonde Lv30");
-    codes.insert(0x050900, "Grants Lv1");
-    codes.insert(0x050901, "Grants Lv2");
-    codes.insert(0x050902, "Grants Lv3");
-    codes.insert(0x050903, "Grants Lv4");
-    codes.insert(0x050904, "Grants Lv5");
-    codes.insert(0x050905, "Grants Lv6");
-    codes.insert(0x050906, "Grants Lv7");
-    codes.insert(0x050907, "Grants Lv8");
-    codes.insert(0x050908, "Grants Lv9");
-    codes.insert(0x050909, "Grants Lv10");
-    codes.insert(0x05090A, "Grants Lv11");
-    codes.insert(0x05090B, "Grants Lv12");
-    codes.insert(0x05090C, "Grants Lv13");
-    codes.insert(0x05090D, "Grants Lv14");
-    codes.insert(0x05090E, "Grants Lv15");
-    codes.insert(0x05090F, "Grants Lv16");
-    codes.insert(0x050910, "Grants Lv17");
-    codes.insert(0x050911, "Grants Lv18");
-    codes.insert(0x050912, "Grants Lv19");
-    codes.insert(0x050913, "Grants Lv20");
-    codes.insert(0x050914, "Grants Lv21");
-    codes.insert(0x050915, "Grants Lv22");
-    codes.insert(0x050916, "Grants Lv23");
-    codes.insert(0x050917, "Grants Lv24");
-    codes.insert(0x050918, "Grants Lv25");
-    codes.insert(0x050919, "Grants Lv26");
-    codes.insert(0x05091A, "Grants Lv27");
-    codes.insert(0x05091B, "Grants Lv28");
-    codes.insert(0x05091C, "Grants Lv29");
-    codes.insert(0x05091D, "Grants Lv30");
-    codes.insert(0x050A00, "Deband Lv1");
-    codes.insert(0x050A01, "Deband Lv2");
-    codes.insert(0x050A02, "Deband Lv3");
-    codes.insert(0x050A03, "Deband Lv4");
-    codes.insert(0x050A04, "Deband Lv5");
-    codes.insert(0x050A05, "Deband Lv6");
-    codes.insert(0x050A06, "Deband Lv7");
-    codes.insert(0x050A07, "Deband Lv8");
-    codes.insert(0x050A08, "Deband Lv9");
-    codes.insert(0x050A09, "Deband Lv10");
-    codes.insert(0x050A0A, "Deband Lv11");
-    codes.insert(0x050A0B, "Deband Lv12");
-    codes.insert(0x050A0C, "Deband Lv13");
-    codes.insert(0x050A0D, "Deband Lv14");
-    codes.insert(0x050A0E, "Deband Lv15");
-    codes.insert(0x050A0F, "Deband Lv16");
-    codes.insert(0x050A10, "Deband Lv17");
-    codes.insert(0x050A11, "Deband Lv18");
-    codes.insert(0x050A12, "Deband Lv19");
-    codes.insert(0x050A13, "Deband Lv20");
-    codes.insert(0x050A14, "Deband Lv21");
-    codes.insert(0x050A15, "Deband Lv22");
-    codes.insert(0x050A16, "Deband Lv23");
-    codes.insert(0x050A17, "Deband Lv24");
-    codes.insert(0x050A18, "Deband Lv25");
-    codes.insert(0x050A19, "Deband Lv26");
-    codes.insert(0x050A1A, "Deband Lv27");
-    codes.insert(0x050A1B, "Deband Lv28");
-    codes.insert(0x050A1C, "Deband Lv29");
-    codes.insert(0x050A1D, "Deband Lv30");
-    codes.insert(0x050B00, "Jellen Lv1");
-    codes.insert(0x050B01, "Jellen Lv2");
-    codes.insert(0x050B02, "Jellen Lv3");
-    codes.insert(0x050B03, "Jellen Lv4");
-    codes.insert(0x050B04, "Jellen Lv5");
-    codes.insert(0x050B05, "Jellen Lv6");
-    codes.insert(0x050B06, "Jellen Lv7");
-    codes.insert(0x050B07, "Jellen Lv8");
-    codes.insert(0x050B08, "Jellen Lv9");
-    codes.insert(0x050B09, "Jellen Lv10");
-    codes.insert(0x050B0A, "Jellen Lv11");
-    codes.insert(0x050B0B, "Jellen Lv12");
-    codes.insert(0x050B0C, "Jellen Lv13");
-    codes.insert(0x050B0D, "Jellen Lv14");
-    codes.insert(0x050B0E, "Jellen Lv15");
-    codes.insert(0x050B0F, "Jellen Lv16");
-    codes.insert(0x050B10, "Jellen Lv17");
-    codes.insert(0x050B11, "Jellen Lv18");
-    codes.insert(0x050B12, "Jellen Lv19");
-    codes.insert(0x050B13, "Jellen Lv20");
-    codes.insert(0x050B14, "Jellen Lv21");
-    codes.insert(0x050B15, "Jellen Lv22");
-    codes.insert(0x050B16, "Jellen Lv23");
-    codes.insert(0x050B17, "Jellen Lv24");
-    codes.insert(0x050B18, "Jellen Lv25");
-    codes.insert(0x050B19, "Jellen Lv26");
-    codes.insert(0x050B1A, "Jellen Lv27");
-    codes.insert(0x050B1B, "Jellen Lv28");
-    codes.insert(0x050B1C, "Jellen Lv29");
-    codes.insert(0x050B1D, "Jellen Lv30");
-    codes.insert(0x050C00, "Zalure Lv1");
-    codes.insert(0x050C01, "Zalure Lv2");
-    codes.insert(0x050C02, "Zalure Lv3");
-    codes.insert(0x050C03, "Zalure Lv4");
-    codes.insert(0x050C04, "Zalure Lv5");
-    codes.insert(0x050C05, "Zalure Lv6");
-    codes.insert(0x050C06, "Zalure Lv7");
-    codes.insert(0x050C07, "Zalure Lv8");
-    codes.insert(0x050C08, "Zalure Lv9");
-    codes.insert(0x050C09, "Zalure Lv10");
-    codes.insert(0x050C0A, "Zalure Lv11");
-    codes.insert(0x050C0B, "Zalure Lv12");
-    codes.insert(0x050C0C, "Zalure Lv13");
-    codes.insert(0x050C0D, "Zalure Lv14");
-    codes.insert(0x050C0E, "Zalure Lv15");
-    codes.insert(0x050C0F, "Zalure Lv16");
-    codes.insert(0x050C10, "Zalure Lv17");
-    codes.insert(0x050C11, "Zalure Lv18");
-    codes.insert(0x050C12, "Zalure Lv19");
-    codes.insert(0x050C13, "Zalure Lv20");
-    codes.insert(0x050C14, "Zalure Lv21");
-    codes.insert(0x050C15, "Zalure Lv22");
-    codes.insert(0x050C16, "Zalure Lv23");
-    codes.insert(0x050C17, "Zalure Lv24");
-    codes.insert(0x050C18, "Zalure Lv25");
-    codes.insert(0x050C19, "Zalure Lv26");
-    codes.insert(0x050C1A, "Zalure Lv27");
-    codes.insert(0x050C1B, "Zalure Lv28");
-    codes.insert(0x050C1C, "Zalure Lv29");
-    codes.insert(0x050C1D, "Zalure Lv30");
-    codes.insert(0x050D00, "Shifta Lv1");
-    codes.insert(0x050D01, "Shifta Lv2");
-    codes.insert(0x050D02, "Shifta Lv3");
-    codes.insert(0x050D03, "Shifta Lv4");
-    codes.insert(0x050D04, "Shifta Lv5");
-    codes.insert(0x050D05, "Shifta Lv6");
-    codes.insert(0x050D06, "Shifta Lv7");
-    codes.insert(0x050D07, "Shifta Lv8");
-    codes.insert(0x050D08, "Shifta Lv9");
-    codes.insert(0x050D09, "Shifta Lv10");
-    codes.insert(0x050D0A, "Shifta Lv11");
-    codes.insert(0x050D0B, "Shifta Lv12");
-    codes.insert(0x050D0C, "Shifta Lv13");
-    codes.insert(0x050D0D, "Shifta Lv14");
-    codes.insert(0x050D0E, "Shifta Lv15");
-    codes.insert(0x050D0F, "Shifta Lv16");
-    codes.insert(0x050D10, "Shifta Lv17");
-    codes.insert(0x050D11, "Shifta Lv18");
-    codes.insert(0x050D12, "Shifta Lv19");
-    codes.insert(0x050D13, "Shifta Lv20");
-    codes.insert(0x050D14, "Shifta Lv21");
-    codes.insert(0x050D15, "Shifta Lv22");
-    codes.insert(0x050D16, "Shifta Lv23");
-    codes.insert(0x050D17, "Shifta Lv24");
-    codes.insert(0x050D18, "Shifta Lv25");
-    codes.insert(0x050D19, "Shifta Lv26");
-    codes.insert(0x050D1A, "Shifta Lv27");
-    codes.insert(0x050D1B, "Shifta Lv28");
-    codes.insert(0x050D1C, "Shifta Lv29");
-    codes.insert(0x050D1D, "Shifta Lv30");
-    codes.insert(0x050E00, "Ryuker Lv1");
-    codes.insert(0x050F00, "Resta Lv1");
-    codes.insert(0x050F01, "Resta Lv2");
-    codes.insert(0x050F02, "Resta Lv3");
-    codes.insert(0x050F03, "Resta Lv4");
-    codes.insert(0x050F04, "Resta Lv5");
-    codes.insert(0x050F05, "Resta Lv6");
-    codes.insert(0x050F06, "Resta Lv7");
-    codes.insert(0x050F07, "Resta Lv8");
-    codes.insert(0x050F08, "Resta Lv9");
-    codes.insert(0x050F09, "Resta Lv10");
-    codes.insert(0x050F0A, "Resta Lv11");
-    codes.insert(0x050F0B, "Resta Lv12");
-    codes.insert(0x050F0C, "Resta Lv13");
-    codes.insert(0x050F0D, "Resta Lv14");
-    codes.insert(0x050F0E, "Resta Lv15");
-    codes.insert(0x050F0F, "Resta Lv16");
-    codes.insert(0x050F10, "Resta Lv17");
-    codes.insert(0x050F11, "Resta Lv18");
-    codes.insert(0x050F12, "Resta Lv19");
-    codes.insert(0x050F13, "Resta Lv20");
-    codes.insert(0x050F14, "Resta Lv21");
-    codes.insert(0x050F15, "Resta Lv22");
-    codes.insert(0x050F16, "Resta Lv23");
-    codes.insert(0x050F17, "Resta Lv24");
-    codes.insert(0x050F18, "Resta Lv25");
-    codes.insert(0x050F19, "Resta Lv26");
-    codes.insert(0x050F1A, "Resta Lv27");
-    codes.insert(0x050F1B, "Resta Lv28");
-    codes.insert(0x050F1C, "Resta Lv29");
-    codes.insert(0x050F1D, "Resta Lv30");
-    codes.insert(0x051000, "Anti Lv1");
-    codes.insert(0x051001, "Anti Lv2");
-    codes.insert(0x051002, "Anti Lv3");
-    codes.insert(0x051003, "Anti Lv4");
-    codes.insert(0x051004, "Anti Lv5");
-    codes.insert(0x051005, "Anti Lv6");
-    codes.insert(0x051006, "Anti Lv7");
-    codes.insert(0x051100, "Reverser Lv1");
-    codes.insert(0x051200, "Megid Lv1");
-    codes.insert(0x051201, "Megid Lv2");
-    codes.insert(0x051202, "Megid Lv3");
-    codes.insert(0x051203, "Megid Lv4");
-    codes.insert(0x051204, "Megid Lv5");
-    codes.insert(0x051205, "Megid Lv6");
-    codes.insert(0x051206, "Megid Lv7");
-    codes.insert(0x051207, "Megid Lv8");
-    codes.insert(0x051208, "Megid Lv9");
-    codes.insert(0x051209, "Megid Lv10");
-    codes.insert(0x05120A, "Megid Lv11");
-    codes.insert(0x05120B, "Megid Lv12");
-    codes.insert(0x05120C, "Megid Lv13");
-    codes.insert(0x05120D, "Megid Lv14");
-    codes.insert(0x05120E, "Megid Lv15");
-    codes.insert(0x05120F, "Megid Lv16");
-    codes.insert(0x051210, "Megid Lv17");
-    codes.insert(0x051211, "Megid Lv18");
-    codes.insert(0x051212, "Megid Lv19");
-    codes.insert(0x051213, "Megid Lv20");
-    codes.insert(0x051214, "Megid Lv21");
-    codes.insert(0x051215, "Megid Lv22");
-    codes.insert(0x051216, "Megid Lv23");
-    codes.insert(0x051217, "Megid Lv24");
-    codes.insert(0x051218, "Megid Lv25");
-    codes.insert(0x051219, "Megid Lv26");
-    codes.insert(0x05121A, "Megid Lv27");
-    codes.insert(0x05121B, "Megid Lv28");
-    codes.insert(0x05121C, "Megid Lv29");
-    codes.insert(0x05121D, "Megid Lv30");
-    // Ephinea Origina);
-    codes.insert(0x00040C, "アステロンベルト* - バショウセン");
-    codes.insert(0x00040D, "ヴィジャヤ* - バショウセン");
-    codes.insert(0x00040E, "ヴィジャヤ* - カメレオンサイズ");
-    codes.insert(0x00040F, "ヴィジャヤ* - ソウルバニッシュ");
-    codes.insert(0x000410, "アステロンベルト* - カメレオンサイズ");
-    codes.insert(0x000411, "アステロンベルト* - ソウルバニッシュ");
-    codes.insert(0x00050C, "勇者のディスカ* - 解放者のディスカ");
-    codes.insert(0x00050D, "勇者のディスカ* - イズマエラ");
-    codes.insert(0x000808, "Ｍ＆Ａ６０ ヴァイス* - ヤスミノコフ9000M");
-    codes.insert(0x00080C, "Ｍ＆Ａ６０ ヴァイス* - サンバマラカス");
-    codes.insert(0x001201, "スプレッドニードル* - ライアーノフ303SNR");
-    codes.insert(0x001B01, "ブリンガーライフル* - ライアーノフ303SNR");
-    codes.insert(0x001B02, "ブリンガーライフル* - エッグブラスター");
-    codes.insert(0x001B03, "ブリンガーライフル* - エンジェルハープ");
-    codes.insert(0x001D01, "サイコウォンド* - ラビットウォンド");
-    codes.insert(0x001D02, "サイコウォンド* - ソーサラーの杖");
-    codes.insert(0x001E01, "ヘブンパニッシャー* - テンションブラスター");
-    codes.insert(0x001E02, "ヘブンパニッシャー* - サプレストガン");
-    codes.insert(0x001E03, "ヘブンパニッシャー* - ルビーバレット");
-    codes.insert(0x002101, "チェインソード* - デイジーチェイン");
-    codes.insert(0x002102, "チェインソード* - クレイジーチューン");
-    codes.insert(0x002401, "マジカルピース* - 神の吐息");
-    codes.insert(0x002D01, "赤のセイバー* - ＤＢの剣");
-    codes.insert(0x002D02, "赤のセイバー* - アンシエントセイバー");
-    codes.insert(0x002D03, "赤のセイバー* - デルセイバーの剣");
-    codes.insert(0x002D04, "赤のセイバー* - フランベルジュ");
-    codes.insert(0x003201, "ツミキリ* - デイジーチェイン");
-    codes.insert(0x003202, "ツミキリ* - クレイジーチューン");
-    codes.insert(0x003203, "ツミキリ* - ロリポップ");
-    codes.insert(0x003204, "ツミキリ* - ラコニウムアクス");
-    codes.insert(0x003401, "赤のソード* - デイジーチェイン");
-    codes.insert(0x003402, "赤のソード* - クレイジーチューン");
-    codes.insert(0x004502, "フローズンシューター* - ライアーノフ303SNR");
-    codes.insert(0x004503, "フローズンシューター* - エッグブラスター");
-    codes.insert(0x004504, "スノウクイーン* - エッグブラスター");
-    codes.insert(0x004505, "フローズンシューター* - エンジェルハープ");
-    codes.insert(0x004506, "スノウクイーン* - エンジェルハープ");
-    codes.insert(0x004507, "スノウクイーン* - ライアーノフ303SNR");
-    codes.insert(0x004B02, "ガルド・ミラ* - ヤスミノコフ9000M");
-    codes.insert(0x004B03, "デュアルバード* - ヤスミノコフ9000M");
-    codes.insert(0x004B04, "ガルド・ミラ* - サンバマラカス");
-    codes.insert(0x004B05, "デュアルバード* - サンバマラカス");
-    codes.insert(0x004F01, "サミットムーン* - ロリポップ");
-    codes.insert(0x005801, "チャオの杖* - ラビットウォンド");
-    codes.insert(0x005802, "チャオの杖* - ソーサラーの杖");
-    codes.insert(0x005A01, "モタブの予言書* - 神の吐息");
-    codes.insert(0x008F09, "フロウウェンの大剣[3084]* - デイジーチェイン");
-    codes.insert(0x008F0A, "フロウウェンの大剣[3084]* - クレイジーチューン");
-    codes.insert(0x009701, "ザンバ* - デイジーチェイン");
-    codes.insert(0x009702, "ザンバ* - クレイジーチューン");
-    codes.insert(0x009703, "ザンバ* - ラコニウムアクス");
-    codes.insert(0x009C01, "レインボーバトン* - 解放者のディスカ");
-    codes.insert(0x009C02, "レインボーバトン* - イズマエラ");
-    codes.insert(0x009D01, "ダークフロウ* - デイジーチェイン");
-    codes.insert(0x009D02, "ダークフロウ* - クレイジーチューン");
-    codes.insert(0x009D03, "ダークフロウ* - アンシエントセイバー");
-    codes.insert(0x009D04, "ダークフロウ* - ラコニウムアクス");
-    codes.insert(0x009D05, "ダークフロウ* - デイジーチェイン (Blue and Green)");
-    codes.insert(0x009D06, "ダークフロウ* - デイジーチェイン (Rose)");
-    codes.insert(0x009D07, "ダークフロウ* - デイジーチェイン (Chartreuse)");
-    codes.insert(0x009D08, "ダークフロウ* - デイジーチェイン (Mauve)");
-    codes.insert(0x009D09, "ダークフロウ* - デイジーチェイン (Cyan)");
-    codes.insert(0x009D0A, "ダークフロウ* - デイジーチェイン (Orange)");
-    codes.insert(0x009D0B, "ダークフロウ* - デイジーチェイン (White)");
-    codes.insert(0x009D0C, "ダークフロウ* - デイジーチェイン (Green)");
-    codes.insert(0x009D0D, "ダークフロウ* - デイジーチェイン (Blue)");
-    codes.insert(0x009D0E, "ダークフロウ* - デイジーチェイン (Purple)");
-    codes.insert(0x009D0F, "ダークフロウ* - デイジーチェイン (Red)");
-    codes.insert(0x00AA01, "狂信者のスライサー* - 解放者のディスカ");
-    codes.insert(0x00AA02, "狂信者のスライサー* - イズマエラ");
-    codes.insert(0x00AC01, "エクスキャリバー* - ロリポップ");
-    codes.insert(0x00AC02, "エクスキャリバー* - ＤＢの剣");
-    codes.insert(0x00AC03, "エクスキャリバー* - アンシエントセイバー");
-    codes.insert(0x00AC04, "エクスキャリバー* - デルセイバーの剣");
-    codes.insert(0x00AC05, "エクスキャリバー* - フランベルジュ (Blue)");
-    codes.insert(0x00AC06, "エクスキャリバー* - フランベルジュ (Purple)");
-    codes.insert(0x00AC07, "エクスキャリバー* - フランベルジュ (Red)");
-    codes.insert(0x00AC08, "エクスキャリバー* - フランベルジュ (Yellow)");
-    codes.insert(0x00AC09, "エクスキャリバー* - フランベルジュ (Blue and Green)");
-    codes.insert(0x00AC0A, "エクスキャリバー* - フランベルジュ (Rose)");
-    codes.insert(0x00AC0B, "エクスキャリバー* - フランベルジュ (Chartreuse)");
-    codes.insert(0x00AC0C, "エクスキャリバー* - フランベルジュ (Mauve)");
-    codes.insert(0x00AC0D, "エクスキャリバー* - フランベルジュ (Cyan)");
-    codes.insert(0x00AC0E, "エクスキャリバー* - フランベルジュ (Orange)");
-    codes.insert(0x00AC0F, "エクスキャリバー* - フランベルジュ (White)");
-    codes.insert(0x00AC10, "エクスキャリバー* - フランベルジュ (Green)");
-    codes.insert(0x00B001, "ミルマルト* - ヤスミノコフ9000M");
-    codes.insert(0x00B002, "ミルマルト* - サンバマラカス");
-    codes.insert(0x00BA01, "雲長* - バショウセン");
-    codes.insert(0x00BA02, "雲長* - カメレオンサイズ");
-    codes.insert(0x00BA03, "雲長* - ソウルバニッシュ");
-    codes.insert(0x00BF01, "ヘブンストライカー* - テンションブラスター");
-    codes.insert(0x00BF02, "ヘブンストライカー* - サプレストガン");
-    codes.insert(0x00BF03, "ヘブンストライカー* - ルビーバレット");
-    codes.insert(0x00BF04, "ヘブンストライカー* - サプレストガン (Blue)");
-    codes.insert(0x00BF05, "ヘブンストライカー* - サプレストガン (Purple)");
-    codes.insert(0x00BF06, "ヘブンストライカー* - サプレストガン (Red)");
-    codes.insert(0x00BF07, "ヘブンストライカー* - サプレストガン (Yellow)");
-    codes.insert(0x00BF08, "ヘブンストライカー* - サプレストガン (Blue and Green)");
-    codes.insert(0x00BF09, "ヘブンストライカー* - サプレストガン (Rose)");
-    codes.insert(0x00BF0A, "ヘブンストライカー* - サプレストガン (Chartreuse)");
-    codes.insert(0x00BF0B, "ヘブンストライカー* - サプレストガン (Mauve)");
-    codes.insert(0x00BF0C, "ヘブンストライカー* - サプレストガン (Cyan)");
-    codes.insert(0x00BF0D, "ヘブンストライカー* - サプレストガン (Orange)");
-    codes.insert(0x00BF0E, "ヘブンストライカー* - サプレストガン (White)");
-    codes.insert(0x00C501, "グライドディバイン* - ラビットウォンド");
-    codes.insert(0x00C502, "グライドディバイン* - ソーサラーの杖");
-    codes.insert(0x010253, "レッドリング* - 青のペイント");
-    codes.insert(0x01025B, "レッドリング* - 緑のペイント");
-    codes.insert(0x010263, "レッドリング* - 黄のペイント");
-    codes.insert(0x01026B, "レッドリング* - 紫のペイント");
-    codes.insert(0x010273, "アンチダークリング");
-    codes.insert(0x010274, "レッドリング* - 白のペイント");
-    codes.insert(0x01027B, "アンチライトリング");
-    codes.insert(0x01027C, "レッドリング* - 黒のペイント");
-    codes.insert(0x0102A5, "レッドリング* - 黄緑のペイント");
-    codes.insert(0x0102A6, "レッドリング* - 水色のペイント");
-    codes.insert(0x0102A7, "レッドリング* - 漆黒のペイント");
-    codes.insert(0x0102A8, "レッドリング* - 橙のペイント");
-    codes.insert(0x0102A9, "レッドリング* - バラのペイント");
-    codes.insert(0x0102AA, "レッドリング* - 群青のペイント");
-    codes.insert(0x0102AB, "レッドリング* - スミレのペイント");
-    codes.insert(0x0102AC, "レッドリング* - デルセイバーの盾のメッキ");
-    codes.insert(0x0102AD, "レッドリング* - スタンドスティルのメッキ");
-    codes.insert(0x0102AE, "レッドリング* - ハニカムリフレクターのメッキ");
-    codes.insert(0x0102AF, "レッドリング* - イプシガードのメッキ");
-    codes.insert(0x0102B0, "レッドリング* - セーフティハートのメッキ");
-    codes.insert(0x0102B1, "レッドリング* - 円環天使のメッキ");
-    codes.insert(0x0102B2, "レッドリング* - ウサミミのメッキ");
-    codes.insert(0x0102B3, "レッドリング* - ネコミミのメッキ");
-    codes.insert(0x0102B4, "レッドリング* - 淵ヨリ来ルモノのメッキ");
-    codes.insert(0x024D00, "ステルス");
-    codes.insert(0x024E00, "マグ*");
-    codes.insert(0x024F00, "ヴァルナ*");
-    codes.insert(0x025000, "カルキ*");
-    codes.insert(0x025100, "ヴリトラ*");
-    codes.insert(0x030E28, "デイジーチェインの心");
-    codes.insert(0x030E29, "クレイジーチューンの心");
-    codes.insert(0x030E2A, "ライアーノフ303SNRの心");
-    codes.insert(0x030E2B, "ヤスミノコフ9000Mの心");
-    codes.insert(0x030E2C, "ラビットウォンドの心");
-    codes.insert(0x030E2D, "ソーサラーの杖の心");
-    codes.insert(0x030E2E, "中和剤");
-    codes.insert(0x030E2F, "青のペイント");
-    codes.insert(0x030E30, "緑のペイント");
-    codes.insert(0x030E31, "黄のペイント");
-    codes.insert(0x030E32, "紫のペイント");
-    codes.insert(0x030E33, "白のペイント");
-    codes.insert(0x030E34, "黒のペイント");
-    codes.insert(0x030E35, "赤のペイント");
-    codes.insert(0x030E36, "サプレストガンの心");
-    codes.insert(0x030E37, "テンションブラスターの心");
-    codes.insert(0x030E38, "サンバマラカスの心");
-    codes.insert(0x030E39, "ロリポップの心");
-    codes.insert(0x030E3A, "ＤＢの剣の心");
-    codes.insert(0x030E3B, "バショウセンの心");
-    codes.insert(0x030E3C, "解放者のディスカの心");
-    codes.insert(0x030E3D, "イズマエラの心");
-    codes.insert(0x030E3E, "神の吐息の心");
-    codes.insert(0x030E3F, "エンジェルハープの心");
-    codes.insert(0x030E40, "ルビーバレットの心");
-    codes.insert(0x030E41, "アンシエントセイバーの心");
-    codes.insert(0x030E42, "ラコニウムアクスの心");
-    codes.insert(0x030E43, "デルセイバーの剣の心");
-    codes.insert(0x030E44, "エッグブラスターの心");
-    codes.insert(0x030E45, "フォトンフィルター");
-    codes.insert(0x030E46, "フランベルジュの心");
-    codes.insert(0x030E47, "カメレオンサイズの心");
-    codes.insert(0x030E48, "ソウルバニッシュの心");
-    codes.insert(0x030E49, "黄緑のペイント");
-    codes.insert(0x030E4A, "水色のペイント");
-    codes.insert(0x030E4B, "漆黒のペイント");
-    codes.insert(0x030E4C, "橙のペイント");
-    codes.insert(0x030E4D, "バラのペイント");
-    codes.insert(0x030E4E, "群青のペイント");
-    codes.insert(0x030E4F, "スミレのペイント");
-    codes.insert(0x030E50, "デルセイバーの盾のメッキ");
-    codes.insert(0x030E51, "スタンドスティルのメッキ");
-    codes.insert(0x030E52, "ハニカムリフレクターのメッキ");
-    codes.insert(0x030E53, "イプシガードのメッキ");
-    codes.insert(0x030E54, "セーフティハートのメッキ");
-    codes.insert(0x030E55, "円環天使のメッキ");
-    codes.insert(0x030E56, "ウサミミのメッキ");
-    codes.insert(0x030E57, "ネコミミのメッキ");
-    codes.insert(0x030E58, "淵ヨリ来ルモノのメッキ");
-    codes.insert(0x031005, "イベントエッグ");
-    codes.insert(0x031006, "１周年バッジ銅");
-    codes.insert(0x031007, "１周年バッジ銀");
-    codes.insert(0x031008, "１周年バッジ金");
-    codes.insert(0x031009, "１周年バッジプラチナ");
-    codes.insert(0x03100A, "２周年バッジ銅");
-    codes.insert(0x03100B, "２周年バッジ銀");
-    codes.insert(0x03100C, "２周年バッジ金");
-    codes.insert(0x03100D, "２周年バッジプラチナ");
-    codes.insert(0x031010, "３周年バッジ銅");
-    codes.insert(0x031011, "３周年バッジ銀");
-    codes.insert(0x031012, "３周年バッジ金");
-    codes.insert(0x031013, "３周年バッジプラチナ");
-    codes.insert(0x031014, "フォトンのカタマリ");
-    codes.insert(0x031015, "４周年バッジ銅");
-    codes.insert(0x031016, "４周年バッジ銀");
-    codes.insert(0x031017, "４周年バッジ金");
-    codes.insert(0x031018, "４周年バッジプラチナ");
-    codes.insert(0x031019, "アニバーサリーバッジ銅");
-    codes.insert(0x03101A, "アニバーサリーバッジ銀");
-    codes.insert(0x03101B, "アニバーサリーバッジ金");
-    codes.insert(0x03101C, "アニバーサリーバッジプラチナ");
-    codes.insert(0x03100E, "ハロウィーンのクッキー");
-    codes.insert(0x03100F, "石炭");
-    codes.insert(0x03160C, "ＤＩＳＫ「ＧＥＴ　ＩＴ　ＵＰ」");
-    codes.insert(0x03160D, "ＤＩＳＫ「ＦＬＩＧＨＴ」");
-    codes.insert(0x03160E, "ＤＩＳＫ「ＳＰＡＣＥ　ＨＡＲＲＩＥＲ」");
-    codes.insert(0x03160F, "ＤＩＳＫ「ＤＥＡＴＨＷＡＴＣＨ」");
-    codes.insert(0x031610, "ＤＩＳＫ「ＢＡＹＯＮＥＴＴＡ」");
-    codes.insert(0x031611, "ＤＩＳＫ「ＰＵＹＯＰＵＹＯ」");
-    codes.insert(0x031612, "ＤＩＳＫ「ＲＨＹＴＨＭ＆ＢＡＬＡＮＣＥ」");
-    codes.insert(0x031613, "ＤＩＳＫ「ＴＨＥ　ＰＡＲＴＹ」");
-    codes.insert(0x031614, "ＤＩＳＫ「ＡＲＭＡＤＡ　ＢＡＴＴＬＥ」");
-    codes.insert(0x031615, "Disk Vol.22 \"Back 2 Back\"");
-    codes.insert(0x031616, "Disk Vol.23 \"The Strange Fruits\"");
-    codes.insert(0x031617, "Disk Vol.24 \"The Whims of Fate\"");
-    codes.insert(0x031618, "Disk Vol.25 \"Last Impression\"");
-    codes.insert(0x031705, "Viridia バッヂ");
-    codes.insert(0x031706, "Greenill バッヂ");
-    codes.insert(0x031707, "Skyly バッヂ");
-    codes.insert(0x031708, "Bluefull バッヂ");
-    codes.insert(0x031709, "Purplenum バッヂ");
-    codes.insert(0x03170A, "Pinkal バッヂ");
-    codes.insert(0x03170B, "Redria バッヂ");
-    codes.insert(0x03170C, "Oran バッヂ");
-    codes.insert(0x03170D, "Yellowboze バッヂ");
-    codes.insert(0x03170E, "Whitill バッヂ");
-    codes.insert(0x03180B, "ステルスキット");
-    codes.insert(0x03180C, "マグキット");
-    codes.insert(0x03180D, "ヴァルナキット");
-    codes.insert(0x03180E, "カルキキット");
-    codes.insert(0x03180F, "ヴリトラキット");
-    codes.insert(0x031810, "ＹＮ－０１１７のハート");
-    codes
-}
\ No newline at end of file
@@ -0,0 +1,111 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::collections::HashMap;
+use serde::Deserialize;
+use crate::parser::error::ParseError;
+
+pub const ITEM_TABLE_VERSION: u32 = 1;
+const MAX_ITEM_CODE: u32 = 0xFFFFFF;
+
+#[derive(Debug, Deserialize)]
+struct ItemTableFile {
+    version: u32,
+    lang: String,
+    items: HashMap<String, String>,
+}
+
+/// Where item name tables are read from: the data files bundled with the app and an
+/// optional directory of user overrides in the app data dir.
+#[derive(Debug, Clone, Default)]
+pub struct ItemTablePaths {
+    pub bundled: Option<PathBuf>,
+    pub overrides: Option<PathBuf>,
+}
+
+impl ItemTablePaths {
+    pub fn from_app(app_handle: &tauri::AppHandle) -> Self {
+        let resolver = app_handle.path_resolver();
+
+        ItemTablePaths {
+            bundled: resolver.resolve_resource("data"),
+            overrides: resolver.app_data_dir().map(|dir| dir.join("data")),
+        }
+    }
+}
+
+fn table_filename(lang: &str) -> String {
+    format!("item_codes.{}.json", lang.to_lowercase())
+}
+
+pub fn bad_table(path: &Path, reason: String) -> ParseError {
+    ParseError::BadItemTable { path: path.display().to_string(), reason }
+}
+
+/// Parses a `0x`-prefixed hex key, rejecting codes above `max`.
+pub fn parse_code(code: &str, max: u32, path: &Path) -> Result<u32, ParseError> {
+    code
+        .strip_prefix("0x")
+        .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+        .filter(|&value| value <= max)
+        .ok_or_else(|| bad_table(path, format!("bad code \"{}\"", code)))
+}
+
+/// Files that are layered on top of the English base for a language: the bundled
+/// translation first, then the user's override.
+pub fn layer_paths(filename: &str, lang: &str, paths: &ItemTablePaths) -> Vec<PathBuf> {
+    let mut layers = Vec::new();
+
+    if lang != "EN" {
+        if let Some(dir) = &paths.bundled {
+            layers.push(dir.join(filename));
+        }
+    }
+
+    if let Some(dir) = &paths.overrides {
+        layers.push(dir.join(filename));
+    }
+
+    layers
+}
+
+fn read_table(path: &Path, lang: &str) -> Result<HashMap<u32, String>, ParseError> {
+    let contents = fs::read_to_string(path).map_err(|error| bad_table(path, error.to_string()))?;
+    let table: ItemTableFile = serde_json::from_str(&contents).map_err(|error| bad_table(path, error.to_string()))?;
+
+    if table.version != ITEM_TABLE_VERSION {
+        return Err(bad_table(path, format!("version {} is not supported, expected {}", table.version, ITEM_TABLE_VERSION)));
+    }
+
+    if table.lang != lang {
+        return Err(bad_table(path, format!("table is for {}, expected {}", table.lang, lang)));
+    }
+
+    let mut items = HashMap::new();
+
+    for (code, name) in table.items {
+        items.insert(parse_code(&code, MAX_ITEM_CODE, path)?, name);
+    }
+
+    Ok(items)
+}
+
+/// Loads the bundled English item names, then applies the bundled table and the user's
+/// override file for the language on top, so names missing from a translation stay English.
+pub fn load_item_codes(lang: &str, paths: &ItemTablePaths) -> Result<HashMap<u32, String>, ParseError> {
+    let base = table_filename("EN");
+    let bundled = paths
+        .bundled
+        .as_ref()
+        .map(|dir| dir.join(&base))
+        .ok_or(ParseError::MissingItemTable { filename: base })?;
+
+    let mut items = read_table(&bundled, "EN")?;
+
+    for path in layer_paths(&table_filename(lang), lang, paths) {
+        if path.exists() {
+            items.extend(read_table(&path, lang)?);
+        }
+    }
+
+    Ok(items)
+}
@@ -0,0 +1,182 @@
+use std::fs;
+use std::path::Path;
+use std::collections::HashMap;
+use serde::{Serialize, Deserialize};
+use crate::config::config::Config;
+use crate::config::quests::quest_table;
+use crate::config::item_tables::{ItemTablePaths, ITEM_TABLE_VERSION, bad_table, parse_code, layer_paths};
+use crate::parser::error::ParseError;
+
+const MAX_ITEM_CODE: u32 = 0xFFFFFF;
+const MAX_BYTE_CODE: u32 = 0xFF;
+const MAX_QUEST_FLAG: u32 = 0x3FF;
+
+/// A language pack as stored on disk. Every section is optional, and byte or item code keys
+/// are written as `0x`-prefixed hex, quests by their clear flag. Class and section ID names are
+/// keyed by their English name.
+#[derive(Debug, Deserialize)]
+struct LanguagePackFile {
+    version: u32,
+    lang: String,
+    #[serde(default)]
+    meseta: Option<String>,
+    #[serde(default)]
+    weapon_specials: HashMap<String, String>,
+    #[serde(default)]
+    rare_weapon_specials: HashMap<String, String>,
+    #[serde(default)]
+    srank_specials: HashMap<String, String>,
+    #[serde(default)]
+    photon_blasts: HashMap<String, String>,
+    #[serde(default)]
+    mag_colors: HashMap<String, String>,
+    #[serde(default)]
+    techs: HashMap<String, String>,
+    #[serde(default)]
+    classes: HashMap<String, String>,
+    #[serde(default)]
+    section_ids: HashMap<String, String>,
+    #[serde(default)]
+    quests: HashMap<String, String>,
+}
+
+/// Translations a language pack provides on top of the built-in tables.
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct LanguagePack {
+    pub meseta: Option<String>,
+    pub weapon_specials: HashMap<u8, String>,
+    pub rare_weapon_specials: HashMap<u32, String>,
+    pub srank_specials: HashMap<u8, String>,
+    pub photon_blasts: HashMap<u8, String>,
+    pub mag_colors: HashMap<u8, String>,
+    pub techs: HashMap<u8, String>,
+    pub classes: HashMap<String, String>,
+    pub section_ids: HashMap<String, String>,
+    pub quests: HashMap<u16, String>,
+}
+
+impl LanguagePack {
+    fn extend(&mut self, other: LanguagePack) {
+        if other.meseta.is_some() {
+            self.meseta = other.meseta;
+        }
+
+        self.weapon_specials.extend(other.weapon_specials);
+        self.rare_weapon_specials.extend(other.rare_weapon_specials);
+        self.srank_specials.extend(other.srank_specials);
+        self.photon_blasts.extend(other.photon_blasts);
+        self.mag_colors.extend(other.mag_colors);
+        self.techs.extend(other.techs);
+        self.classes.extend(other.classes);
+        self.section_ids.extend(other.section_ids);
+        self.quests.extend(other.quests);
+    }
+}
+
+pub fn pack_filename(lang: &str) -> String {
+    format!("lang.{}.json", lang.to_lowercase())
+}
+
+fn byte_keyed(map: HashMap<String, String>, path: &Path) -> Result<HashMap<u8, String>, ParseError> {
+    map.into_iter()
+        .map(|(code, name)| Ok((parse_code(&code, MAX_BYTE_CODE, path)? as u8, name)))
+        .collect()
+}
+
+fn name_keyed(map: HashMap<String, String>, known: Vec<&'static str>, path: &Path) -> Result<HashMap<String, String>, ParseError> {
+    for key in map.keys() {
+        if !known.contains(&key.as_str()) {
+            return Err(bad_table(path, format!("unknown name \"{}\"", key)));
+        }
+    }
+
+    Ok(map)
+}
+
+fn quest_keyed(map: HashMap<String, String>, path: &Path) -> Result<HashMap<u16, String>, ParseError> {
+    let quests = quest_table();
+
+    map.into_iter()
+        .map(|(code, name)| {
+            let flag = parse_code(&code, MAX_QUEST_FLAG, path)? as u16;
+            if !quests.contains_key(&flag) {
+                return Err(bad_table(path, format!("unknown quest flag \"{}\"", code)));
+            }
+
+            Ok((flag, name))
+        })
+        .collect()
+}
+
+fn read_pack(path: &Path, lang: &str) -> Result<LanguagePack, ParseError> {
+    let contents = fs::read_to_string(path).map_err(|error| bad_table(path, error.to_string()))?;
+    let pack: LanguagePackFile = serde_json::from_str(&contents).map_err(|error| bad_table(path, error.to_string()))?;
+
+    if pack.version != ITEM_TABLE_VERSION {
+        return Err(bad_table(path, format!("version {} is not supported, expected {}", pack.version, ITEM_TABLE_VERSION)));
+    }
+
+    if pack.lang != lang {
+        return Err(bad_table(path, format!("pack is for {}, expected {}", pack.lang, lang)));
+    }
+
+    let rare_weapon_specials = pack.rare_weapon_specials
+        .into_iter()
+        .map(|(code, name)| Ok((parse_code(&code, MAX_ITEM_CODE, path)?, name)))
+        .collect::<Result<HashMap<u32, String>, ParseError>>()?;
+
+    Ok(LanguagePack {
+        meseta: pack.meseta,
+        weapon_specials: byte_keyed(pack.weapon_specials, path)?,
+        rare_weapon_specials,
+        srank_specials: byte_keyed(pack.srank_specials, path)?,
+        photon_blasts: byte_keyed(pack.photon_blasts, path)?,
+        mag_colors: byte_keyed(pack.mag_colors, path)?,
+        techs: byte_keyed(pack.techs, path)?,
+        classes: name_keyed(pack.classes, Config::classes().into_values().collect(), path)?,
+        section_ids: name_keyed(pack.section_ids, Config::section_ids().into_values().collect(), path)?,
+        quests: quest_keyed(pack.quests, path)?,
+    })
+}
+
+/// Loads the bundled pack for a locale code, then the user's override for it. Languages
+/// without a pack get an empty one, which leaves the built-in tables untouched.
+pub fn load_language_pack(lang: &str, paths: &ItemTablePaths) -> Result<LanguagePack, ParseError> {
+    let mut pack = LanguagePack::default();
+
+    for path in layer_paths(&pack_filename(lang), lang, paths) {
+        if path.exists() {
+            pack.extend(read_pack(&path, lang)?);
+        }
+    }
+
+    Ok(pack)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn override_pack(name: &str, contents: &str) -> ItemTablePaths {
+        let dir = std::env::temp_dir().join(format!("psobb-language-pack-{}", name));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join(pack_filename("DE")), contents).unwrap();
+
+        ItemTablePaths { bundled: None, overrides: Some(dir) }
+    }
+
+    #[test]
+    fn quests_are_translated_by_clear_flag() {
+        let paths = override_pack("quests", r#"{"version": 1, "lang": "DE", "quests": {"0x0065": "Metall-Magnitude"}}"#);
+
+        let pack = load_language_pack("DE", &paths).unwrap();
+        assert_eq!(pack.quests.get(&0x65).map(String::as_str), Some("Metall-Magnitude"));
+    }
+
+    #[test]
+    fn unknown_quest_flags_are_rejected() {
+        let paths = override_pack("unknown-quest", r#"{"version": 1, "lang": "DE", "quests": {"0x0040": "Unbekannt"}}"#);
+
+        assert!(matches!(load_language_pack("DE", &paths), Err(ParseError::BadItemTable { .. })));
+    }
+}
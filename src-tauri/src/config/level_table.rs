@@ -0,0 +1,34 @@
+/// Total experience needed to reach each level, starting at level 1. Every class levels on
+/// the same curve; only the stat gains per level differ.
+pub const EXPERIENCE_TABLE: [u32; 200] = [
+    0, 50, 210, 500, 910, 1480, 2200, 3100, 4180, 5470,
+    6990, 8740, 10750, 13050, 15650, 18580, 21850, 25510, 29570, 34070,
+    39030, 44500, 50500, 57070, 64250, 72090, 80620, 89900, 99970, 110880,
+    122700, 135470, 149250, 164130, 180150, 197390, 215930, 235850, 257230, 280160,
+    304730, 331040, 359200, 389310, 421480, 455850, 492530, 531660, 573370, 617830,
+    665180, 715580, 769210, 826250, 886880, 951320, 1019760, 1092420, 1169550, 1251370,
+    1338150, 1430150, 1527660, 1630970, 1740380, 1856230, 1978850, 2108610, 2245870, 2391030,
+    2544510, 2706730, 2878150, 3059250, 3250520, 3452490, 3665710, 3890750, 4128200, 4378720,
+    4642940, 4921570, 5215330, 5524980, 5851320, 6195180, 6557420, 6938980, 7340790, 7763860,
+    8209250, 8678040, 9171390, 9690500, 10236610, 10811060, 11415220, 12050520, 12718470, 13420660,
+    14158720, 14934380, 15749450, 16605810, 17505430, 18450370, 19442800, 20484960, 21579220, 22728030,
+    23933960, 25199720, 26528110, 27922080, 29384690, 30919160, 32528830, 34217220, 35987990, 37844960,
+    39792130, 41833670, 43973940, 46217500, 48569110, 51033740, 53616560, 56323010, 59158740, 62129650,
+    65241920, 68501990, 71916570, 75492700, 79237690, 83159190, 87265200, 91564040, 96064410, 100775390,
+    105706450, 110867480, 116268800, 121921170, 127835820, 134024480, 140499370, 147273250, 154359430, 161771800,
+    169524840, 177633650, 186114010, 194982350, 204255810, 213952270, 224090400, 234689630, 245770240, 257353400,
+    269461160, 282116510, 295343430, 309166930, 323613070, 338709040, 354483160, 370964990, 388185290, 406176190,
+    424971140, 444605010, 465114160, 486536490, 508911480, 532280290, 556685820, 582172780, 608787750, 636579290,
+    665597970, 695896530, 727529880, 760555260, 795032320, 831023170, 868592550, 907807920, 948739520, 991460550,
+    1036047270, 1082579110, 1131138810, 1181812560, 1234690150, 1289865080, 1347434750, 1407500610, 1470168320, 1535547910,
+];
+
+pub const MAX_LEVEL: u8 = 200;
+
+pub fn experience_for_level(level: u8) -> Option<u32> {
+    if level == 0 {
+        return None;
+    }
+
+    EXPERIENCE_TABLE.get(level as usize - 1).copied()
+}
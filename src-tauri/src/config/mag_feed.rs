@@ -0,0 +1,105 @@
+use std::collections::HashMap;
+
+/// Tools a mag can be fed, by item code.
+pub const FEED_ITEMS: [u32; 11] = [
+    0x030000, // Monomate
+    0x030001, // Dimate
+    0x030002, // Trimate
+    0x030100, // Monofluid
+    0x030101, // Difluid
+    0x030102, // Trifluid
+    0x030600, // Antidote
+    0x030601, // Antiparalysis
+    0x030300, // Sol Atomizer
+    0x030400, // Moon Atomizer
+    0x030500, // Star Atomizer
+];
+
+/// Change from one feeding as (sync, iq, def, pow, dex, mind), stats in hundredths of a level.
+pub type FeedResult = (i8, i8, i16, i16, i16, i16);
+
+/// Feed results per table, in `FEED_ITEMS` order. Tables 5 to 7 aren't filled in yet.
+pub fn feed_tables() -> HashMap<u8, [FeedResult; 11]> {
+    let mut map = HashMap::new();
+    map.insert(0, [
+        (3, 3, 5, 40, 5, 0),
+        (3, 3, 10, 45, 5, 0),
+        (4, 4, 15, 50, 10, 0),
+        (3, 3, 5, 0, 5, 40),
+        (3, 3, 10, 0, 5, 45),
+        (4, 4, 15, 0, 10, 50),
+        (3, 3, 5, 10, 40, 0),
+        (3, 3, 5, 0, 44, 10),
+        (4, 1, 15, 30, 15, 25),
+        (4, 1, 15, 25, 15, 30),
+        (6, 5, 25, 25, 25, 25),
+    ]);
+    map.insert(1, [
+        (0, 0, 5, 10, 0, -1),
+        (2, 1, 6, 15, 3, -3),
+        (3, 2, 12, 21, 4, -7),
+        (0, 0, 5, 0, 0, 8),
+        (2, 1, 7, 0, 3, 13),
+        (3, 2, 7, 0, 7, 15),
+        (0, 1, 0, 5, 15, 0),
+        (2, 0, 3, 0, 20, 3),
+        (3, 2, 12, 5, 7, 8),
+        (3, 2, 12, 5, 8, 7),
+        (4, 2, 15, 8, 8, 8),
+    ]);
+    map.insert(2, [
+        (0, -1, 1, 13, 0, -5),
+        (2, 0, 3, 18, 0, -7),
+        (3, 1, 4, 25, 0, -10),
+        (0, -1, 1, -5, 0, 13),
+        (2, 0, 3, -7, 0, 18),
+        (3, 1, 4, -10, 0, 25),
+        (0, -1, 1, 0, 13, -5),
+        (2, 0, 3, -5, 18, 0),
+        (3, 2, 7, 7, 7, 7),
+        (3, 2, 7, 7, 7, 7),
+        (4, 3, 10, 10, 10, 10),
+    ]);
+    map.insert(3, [
+        (0, -1, 0, 8, 2, -5),
+        (2, 0, 2, 12, 3, -7),
+        (3, 1, 3, 17, 3, -10),
+        (0, -1, 0, -5, 2, 8),
+        (2, 0, 2, -7, 3, 12),
+        (3, 1, 3, -10, 3, 17),
+        (0, -1, 0, 2, 8, -5),
+        (2, 0, 2, -5, 12, 2),
+        (3, 2, 5, 5, 5, 5),
+        (3, 2, 5, 5, 5, 5),
+        (4, 3, 7, 7, 7, 7),
+    ]);
+    map.insert(4, [
+        (0, -1, 0, 5, 1, -3),
+        (1, 0, 1, 8, 2, -5),
+        (2, 1, 2, 11, 2, -7),
+        (0, -1, 0, -3, 1, 5),
+        (1, 0, 1, -5, 2, 8),
+        (2, 1, 2, -7, 2, 11),
+        (0, -1, 0, 1, 5, -3),
+        (1, 0, 1, -3, 8, 1),
+        (2, 2, 3, 3, 3, 3),
+        (2, 2, 3, 3, 3, 3),
+        (3, 3, 5, 5, 5, 5),
+    ]);
+    map
+}
+
+/// Feed table each mag uses, by mag id, as listed in the mag entries of ItemPMT.prs.
+/// Mags past Churel (0x27) are the rare forms, which all share table 6.
+pub fn feed_table(mag_id: u8) -> u8 {
+    match mag_id {
+        0x00 => 0,
+        0x01 | 0x0D | 0x19 => 1,
+        0x0E | 0x0F | 0x14 | 0x1A | 0x1B => 2,
+        0x02 | 0x03 | 0x0B => 3,
+        0x04..=0x0A | 0x0C => 4,
+        0x10..=0x13 | 0x15..=0x18 => 5,
+        0x1C..=0x22 | 0x24 => 7,
+        _ => 6,
+    }
+}
@@ -0,0 +1,35 @@
+use std::collections::HashMap;
+
+/// Quest clear flags and the (episode, quest name) they belong to. The nth Episode 1
+/// one-person quest, in the order the game lists them, sets flag 0x63 + 2n; these are the
+/// flags Tethealla's ship server checks in `qflag_ep1solo`. Other quests have no known
+/// flags yet, so they aren't listed rather than guessed.
+pub fn quest_table() -> HashMap<u16, (u8, &'static str)> {
+    let mut map = HashMap::new();
+    map.insert(0x0065, (1, "Magnitude of Metal"));
+    map.insert(0x0067, (1, "Claiming a Stake"));
+    map.insert(0x0069, (1, "The Value of Money"));
+    map.insert(0x006B, (1, "Battle Training"));
+    map.insert(0x006D, (1, "Journalistic Pursuit"));
+    map.insert(0x006F, (1, "The Fake in Yellow"));
+    map.insert(0x0071, (1, "Native Research"));
+    map.insert(0x0073, (1, "Forest of Sorrow"));
+    map.insert(0x0075, (1, "Gran Squall"));
+    map.insert(0x0077, (1, "Addicting Food"));
+    map.insert(0x0079, (1, "The Lost Bride"));
+    map.insert(0x007B, (1, "Waterfall Tears"));
+    map.insert(0x007D, (1, "Black Paper"));
+    map.insert(0x007F, (1, "Secret Delivery"));
+    map.insert(0x0081, (1, "Soul of a Blacksmith"));
+    map.insert(0x0083, (1, "Letter from Lionel"));
+    map.insert(0x0085, (1, "The Grave's Butler"));
+    map.insert(0x0087, (1, "Knowing One's Heart"));
+    map.insert(0x0089, (1, "The Retired Hunter"));
+    map.insert(0x008B, (1, "Dr. Osto's Research"));
+    map.insert(0x008D, (1, "The Unsealed Door"));
+    map.insert(0x008F, (1, "Soul of Steel"));
+    map.insert(0x0091, (1, "Doc's Secret Plan"));
+    map.insert(0x0093, (1, "Seek My Master"));
+    map.insert(0x0095, (1, "From the Depths"));
+    map
+}
@@ -0,0 +1,36 @@
+use std::collections::HashMap;
+use serde::{Serialize, Deserialize};
+use crate::config::ephinea_items::{ephinea_items, ServerItemRow};
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+pub enum ServerProfile {
+    Ephinea,
+    VanillaBB,
+}
+
+impl ServerProfile {
+    /// Picks a profile from the server name stored with the account (the save filename prefix).
+    pub fn from_server(server: &str) -> Self {
+        let server = server.to_lowercase();
+
+        if server.contains("ephinea") {
+            ServerProfile::Ephinea
+        } else {
+            ServerProfile::VanillaBB
+        }
+    }
+
+    /// Item codes the server reuses or adds on top of the vanilla tables. Codes missing
+    /// from here are always looked up in the vanilla tables.
+    pub fn items(&self) -> HashMap<u32, ServerItemRow> {
+        match self {
+            ServerProfile::Ephinea => ephinea_items(),
+            ServerProfile::VanillaBB => HashMap::new(),
+        }
+    }
+
+    /// Only Ephinea keeps separate classic mode characters and banks.
+    pub fn has_classic_mode(&self) -> bool {
+        matches!(self, ServerProfile::Ephinea)
+    }
+}
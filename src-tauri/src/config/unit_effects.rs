@@ -0,0 +1,94 @@
+use std::collections::HashMap;
+
+/// Stat bonuses (atp, mst, ata, evp, dfp, lck, hp, tp), resistances (efr, eic, eth, elt, edk),
+/// the bonus change per +/- modifier step and a description of any effect that isn't a stat.
+pub type UnitEffectRow = ([i16; 8], [i16; 5], i16, &'static str);
+
+pub fn unit_effects() -> HashMap<u32, UnitEffectRow> {
+    let mut map = HashMap::new();
+    map.insert(0x010300, ([10, 0, 0, 0, 0, 0, 0, 0], [0, 0, 0, 0, 0], 2, ""));
+    map.insert(0x010301, ([15, 0, 0, 0, 0, 0, 0, 0], [0, 0, 0, 0, 0], 2, ""));
+    map.insert(0x010302, ([25, 0, 0, 0, 0, 0, 0, 0], [0, 0, 0, 0, 0], 2, ""));
+    map.insert(0x010303, ([30, 0, 0, 0, 0, 0, 0, 0], [0, 0, 0, 0, 0], 2, ""));
+    map.insert(0x010304, ([0, 10, 0, 0, 0, 0, 0, 0], [0, 0, 0, 0, 0], 2, ""));
+    map.insert(0x010305, ([0, 15, 0, 0, 0, 0, 0, 0], [0, 0, 0, 0, 0], 2, ""));
+    map.insert(0x010306, ([0, 25, 0, 0, 0, 0, 0, 0], [0, 0, 0, 0, 0], 2, ""));
+    map.insert(0x010307, ([0, 30, 0, 0, 0, 0, 0, 0], [0, 0, 0, 0, 0], 2, ""));
+    map.insert(0x010308, ([0, 0, 10, 0, 0, 0, 0, 0], [0, 0, 0, 0, 0], 2, ""));
+    map.insert(0x010309, ([0, 0, 15, 0, 0, 0, 0, 0], [0, 0, 0, 0, 0], 2, ""));
+    map.insert(0x01030A, ([0, 0, 25, 0, 0, 0, 0, 0], [0, 0, 0, 0, 0], 2, ""));
+    map.insert(0x01030B, ([0, 0, 30, 0, 0, 0, 0, 0], [0, 0, 0, 0, 0], 2, ""));
+    map.insert(0x01030C, ([0, 0, 0, 10, 0, 0, 0, 0], [0, 0, 0, 0, 0], 2, ""));
+    map.insert(0x01030D, ([0, 0, 0, 15, 0, 0, 0, 0], [0, 0, 0, 0, 0], 2, ""));
+    map.insert(0x01030E, ([0, 0, 0, 25, 0, 0, 0, 0], [0, 0, 0, 0, 0], 2, ""));
+    map.insert(0x01030F, ([0, 0, 0, 30, 0, 0, 0, 0], [0, 0, 0, 0, 0], 2, ""));
+    map.insert(0x010310, ([0, 0, 0, 0, 0, 0, 15, 0], [0, 0, 0, 0, 0], 5, ""));
+    map.insert(0x010311, ([0, 0, 0, 0, 0, 0, 30, 0], [0, 0, 0, 0, 0], 5, ""));
+    map.insert(0x010312, ([0, 0, 0, 0, 0, 0, 45, 0], [0, 0, 0, 0, 0], 5, ""));
+    map.insert(0x010313, ([0, 0, 0, 0, 0, 0, 60, 0], [0, 0, 0, 0, 0], 5, ""));
+    map.insert(0x010314, ([0, 0, 0, 0, 0, 0, 0, 15], [0, 0, 0, 0, 0], 5, ""));
+    map.insert(0x010315, ([0, 0, 0, 0, 0, 0, 0, 30], [0, 0, 0, 0, 0], 5, ""));
+    map.insert(0x010316, ([0, 0, 0, 0, 0, 0, 0, 45], [0, 0, 0, 0, 0], 5, ""));
+    map.insert(0x010317, ([0, 0, 0, 0, 0, 0, 0, 60], [0, 0, 0, 0, 0], 5, ""));
+    map.insert(0x010318, ([0, 0, 0, 0, 10, 0, 0, 0], [0, 0, 0, 0, 0], 2, ""));
+    map.insert(0x010319, ([0, 0, 0, 0, 15, 0, 0, 0], [0, 0, 0, 0, 0], 2, ""));
+    map.insert(0x01031A, ([0, 0, 0, 0, 25, 0, 0, 0], [0, 0, 0, 0, 0], 2, ""));
+    map.insert(0x01031B, ([0, 0, 0, 0, 30, 0, 0, 0], [0, 0, 0, 0, 0], 2, ""));
+    map.insert(0x01031C, ([0, 0, 0, 0, 0, 10, 0, 0], [0, 0, 0, 0, 0], 2, ""));
+    map.insert(0x01031D, ([0, 0, 0, 0, 0, 20, 0, 0], [0, 0, 0, 0, 0], 2, ""));
+    map.insert(0x01031E, ([5, 5, 5, 5, 5, 5, 0, 0], [0, 0, 0, 0, 0], 1, ""));
+    map.insert(0x01031F, ([10, 10, 10, 10, 10, 10, 0, 0], [0, 0, 0, 0, 0], 1, ""));
+    map.insert(0x010320, ([15, 15, 15, 15, 15, 15, 0, 0], [0, 0, 0, 0, 0], 1, ""));
+    map.insert(0x010321, ([0, 0, 0, 0, 0, 0, 0, 0], [10, 0, 0, 0, 0], 2, ""));
+    map.insert(0x010322, ([0, 0, 0, 0, 0, 0, 0, 0], [25, 0, 0, 0, 0], 2, ""));
+    map.insert(0x010323, ([0, 0, 0, 0, 0, 0, 0, 0], [40, 0, 0, 0, 0], 2, ""));
+    map.insert(0x010324, ([0, 0, 0, 0, 0, 0, 0, 0], [0, 10, 0, 0, 0], 2, ""));
+    map.insert(0x010325, ([0, 0, 0, 0, 0, 0, 0, 0], [0, 25, 0, 0, 0], 2, ""));
+    map.insert(0x010326, ([0, 0, 0, 0, 0, 0, 0, 0], [0, 40, 0, 0, 0], 2, ""));
+    map.insert(0x010327, ([0, 0, 0, 0, 0, 0, 0, 0], [0, 0, 10, 0, 0], 2, ""));
+    map.insert(0x010328, ([0, 0, 0, 0, 0, 0, 0, 0], [0, 0, 25, 0, 0], 2, ""));
+    map.insert(0x010329, ([0, 0, 0, 0, 0, 0, 0, 0], [0, 0, 40, 0, 0], 2, ""));
+    map.insert(0x01032A, ([0, 0, 0, 0, 0, 0, 0, 0], [0, 0, 0, 10, 0], 2, ""));
+    map.insert(0x01032B, ([0, 0, 0, 0, 0, 0, 0, 0], [0, 0, 0, 25, 0], 2, ""));
+    map.insert(0x01032C, ([0, 0, 0, 0, 0, 0, 0, 0], [0, 0, 0, 40, 0], 2, ""));
+    map.insert(0x01032D, ([0, 0, 0, 0, 0, 0, 0, 0], [0, 0, 0, 0, 10], 2, ""));
+    map.insert(0x01032E, ([0, 0, 0, 0, 0, 0, 0, 0], [0, 0, 0, 0, 25], 2, ""));
+    map.insert(0x01032F, ([0, 0, 0, 0, 0, 0, 0, 0], [0, 0, 0, 0, 40], 2, ""));
+    map.insert(0x010330, ([0, 0, 0, 0, 0, 0, 0, 0], [10, 10, 10, 10, 10], 2, ""));
+    map.insert(0x010331, ([0, 0, 0, 0, 0, 0, 0, 0], [25, 25, 25, 25, 25], 2, ""));
+    map.insert(0x010332, ([0, 0, 0, 0, 0, 0, 0, 0], [40, 40, 40, 40, 40], 2, ""));
+    map.insert(0x010333, ([0, 0, 0, 0, 0, 0, 0, 0], [0, 0, 0, 0, 0], 0, "Slowly restores HP"));
+    map.insert(0x010334, ([0, 0, 0, 0, 0, 0, 0, 0], [0, 0, 0, 0, 0], 0, "Restores HP"));
+    map.insert(0x010335, ([0, 0, 0, 0, 0, 0, 0, 0], [0, 0, 0, 0, 0], 0, "Quickly restores HP"));
+    map.insert(0x010336, ([0, 0, 0, 0, 0, 0, 0, 0], [0, 0, 0, 0, 0], 0, "Slowly restores TP"));
+    map.insert(0x010337, ([0, 0, 0, 0, 0, 0, 0, 0], [0, 0, 0, 0, 0], 0, "Restores TP"));
+    map.insert(0x010338, ([0, 0, 0, 0, 0, 0, 0, 0], [0, 0, 0, 0, 0], 0, "Quickly restores TP"));
+    map.insert(0x010339, ([0, 0, 0, 0, 0, 0, 0, 0], [0, 0, 0, 0, 0], 0, "Photon blast gauge fills faster"));
+    map.insert(0x01033A, ([0, 0, 0, 0, 0, 0, 0, 0], [0, 0, 0, 0, 0], 0, "Photon blast gauge fills faster"));
+    map.insert(0x01033B, ([0, 0, 0, 0, 0, 0, 0, 0], [0, 0, 0, 0, 0], 0, "Photon blast gauge fills much faster"));
+    map.insert(0x01033C, ([0, 0, 0, 0, 0, 0, 0, 0], [0, 0, 0, 0, 0], 0, "Technique level +1"));
+    map.insert(0x01033D, ([0, 0, 0, 0, 0, 0, 0, 0], [0, 0, 0, 0, 0], 0, "Technique level +1, TP cost up"));
+    map.insert(0x01033E, ([0, 0, 0, 0, 0, 0, 0, 0], [0, 0, 0, 0, 0], 0, "Technique level +2"));
+    map.insert(0x01033F, ([10, 0, 10, 10, 10, 0, 0, 0], [0, 0, 0, 0, 0], 1, ""));
+    map.insert(0x010340, ([15, 0, 15, 15, 15, 0, 0, 0], [0, 0, 0, 0, 0], 1, ""));
+    map.insert(0x010341, ([20, 0, 20, 20, 20, 0, 0, 0], [0, 0, 0, 0, 0], 1, ""));
+    map.insert(0x010342, ([0, 0, 0, 0, 0, 0, 0, 0], [0, 0, 0, 0, 0], 0, "Prevents poison"));
+    map.insert(0x010343, ([0, 0, 0, 0, 0, 0, 0, 0], [0, 0, 0, 0, 0], 0, "Prevents paralysis"));
+    map.insert(0x010344, ([0, 0, 0, 0, 0, 0, 0, 0], [0, 0, 0, 0, 0], 0, "Prevents slow"));
+    map.insert(0x010345, ([0, 0, 0, 0, 0, 0, 0, 0], [0, 0, 0, 0, 0], 0, "Prevents confusion"));
+    map.insert(0x010346, ([0, 0, 0, 0, 0, 0, 0, 0], [0, 0, 0, 0, 0], 0, "Prevents freeze"));
+    map.insert(0x010347, ([0, 0, 0, 0, 0, 0, 0, 0], [0, 0, 0, 0, 0], 0, "Prevents shock"));
+    map.insert(0x010353, ([30, 0, 30, 30, 30, 0, 0, 0], [0, 0, 0, 0, 0], 1, ""));
+    map.insert(0x010354, ([35, 0, 0, 0, 0, 0, 0, 0], [0, 0, 0, 0, 0], 2, ""));
+    map.insert(0x010355, ([0, 35, 0, 0, 0, 0, 0, 0], [0, 0, 0, 0, 0], 2, ""));
+    map.insert(0x010356, ([0, 0, 35, 0, 0, 0, 0, 0], [0, 0, 0, 0, 0], 2, ""));
+    map.insert(0x010357, ([0, 0, 0, 35, 0, 0, 0, 0], [0, 0, 0, 0, 0], 2, ""));
+    map.insert(0x010358, ([0, 0, 0, 0, 35, 0, 0, 0], [0, 0, 0, 0, 0], 2, ""));
+    map.insert(0x010359, ([0, 0, 0, 0, 0, 25, 0, 0], [0, 0, 0, 0, 0], 2, ""));
+    map.insert(0x01035A, ([20, 20, 20, 20, 20, 20, 0, 0], [0, 0, 0, 0, 0], 1, ""));
+    map.insert(0x01035D, ([0, 0, 0, 0, 0, 0, 100, 0], [0, 0, 0, 0, 0], 5, ""));
+    map.insert(0x01035E, ([0, 0, 0, 0, 0, 0, 0, 100], [0, 0, 0, 0, 0], 5, ""));
+    map.insert(0x01035F, ([0, 0, 0, 0, 0, 0, 0, 0], [50, 50, 50, 50, 50], 2, ""));
+    map.insert(0x010360, ([0, 0, 0, 0, 0, 0, 0, 0], [0, 0, 0, 0, 0], 0, "Technique level +3"));
+    map
+}
@@ -0,0 +1,147 @@
+use serde::{Serialize, Deserialize};
+use crate::lib::db::WeaponReference;
+use crate::parser::types::Attribute;
+
+pub const ATP_PER_GRIND: u32 = 2;
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct AtpRange {
+    pub min: u32,
+    pub max: u32,
+}
+
+/// Average ATP against one enemy category, with the weapon's attribute bonus applied.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct CategoryDamage {
+    pub category: String,
+    pub percent: i8,
+    pub current: f32,
+    pub max_grind: f32,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct WeaponAtp {
+    pub weapon_id: i64,
+    pub name: String,
+    pub grind: u8,
+    pub max_grind: u8,
+    pub current: AtpRange,
+    pub max_grind_atp: AtpRange,
+    pub ata: u8,
+    pub hit: i8,
+    pub categories: Vec<CategoryDamage>,
+}
+
+/// Weapon ATP at a grind, without the character's stats or attributes.
+pub fn atp_at_grind(reference: &WeaponReference, grind: u8) -> AtpRange {
+    let bonus = grind as u32 * ATP_PER_GRIND;
+
+    AtpRange {
+        min: reference.min_atp + bonus,
+        max: reference.max_atp + bonus,
+    }
+}
+
+fn expected_damage(atp: &AtpRange, percent: i8) -> f32 {
+    let average = (atp.min + atp.max) as f32 / 2.0;
+    average * (100.0 + percent as f32) / 100.0
+}
+
+/// Effective ATP of a weapon at its grind and fully ground, and the average ATP it brings
+/// against each enemy category. Grinds past the reference maximum are counted as they are,
+/// since some servers allow them.
+pub fn weapon_atp(weapon_id: i64, name: String, reference: &WeaponReference, grind: u8, attribute: &Attribute) -> WeaponAtp {
+    let max_grind = reference.max_grind.max(grind);
+    let current = atp_at_grind(reference, grind);
+    let max_grind_atp = atp_at_grind(reference, max_grind);
+
+    let categories = [
+        ("NATIVE", attribute.native),
+        ("A_BEAST", attribute.a_beast),
+        ("MACHINE", attribute.machine),
+        ("DARK", attribute.dark),
+    ]
+    .iter()
+    .map(|&(category, percent)| CategoryDamage {
+        category: String::from(category),
+        percent,
+        current: expected_damage(&current, percent),
+        max_grind: expected_damage(&max_grind_atp, percent),
+    })
+    .collect();
+
+    WeaponAtp {
+        weapon_id,
+        name,
+        grind,
+        max_grind,
+        current,
+        max_grind_atp,
+        ata: reference.ata,
+        hit: attribute.hit,
+        categories,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn saber() -> WeaponReference {
+        WeaponReference {
+            min_atp: 40,
+            max_atp: 55,
+            max_grind: 35,
+            ata: 30,
+            requirement_atp: 30,
+            requirement_ata: 0,
+            requirement_mst: 0,
+            classes: Vec::new(),
+        }
+    }
+
+    fn attribute(native: i8, dark: i8) -> Attribute {
+        Attribute { native, a_beast: 0, machine: 0, dark, hit: 15 }
+    }
+
+    #[test]
+    fn grinds_add_two_atp_each() {
+        let atp = atp_at_grind(&saber(), 0);
+        assert_eq!((atp.min, atp.max), (40, 55));
+
+        let atp = atp_at_grind(&saber(), 10);
+        assert_eq!((atp.min, atp.max), (60, 75));
+    }
+
+    #[test]
+    fn saber_at_its_grind_and_fully_ground() {
+        let atp = weapon_atp(1, String::from("Saber"), &saber(), 5, &attribute(0, 0));
+
+        assert_eq!((atp.current.min, atp.current.max), (50, 65));
+        assert_eq!(atp.max_grind, 35);
+        // Matches the reference total ATP at max grind.
+        assert_eq!((atp.max_grind_atp.min, atp.max_grind_atp.max), (110, 125));
+        assert_eq!((atp.ata, atp.hit), (30, 15));
+    }
+
+    #[test]
+    fn attributes_scale_average_atp_per_category() {
+        let atp = weapon_atp(1, String::from("Saber"), &saber(), 5, &attribute(50, -10));
+        let category = |name: &str| atp.categories.iter().find(|category| category.category == name).unwrap();
+
+        // Average of 50-65 is 57.5.
+        assert_eq!(category("NATIVE").current, 86.25);
+        assert_eq!(category("DARK").current, 51.75);
+        assert_eq!(category("MACHINE").current, 57.5);
+        assert_eq!(category("NATIVE").max_grind, 176.25);
+    }
+
+    #[test]
+    fn grinds_past_the_reference_maximum_count() {
+        let atp = weapon_atp(1, String::from("Saber"), &saber(), 40, &attribute(0, 0));
+
+        assert_eq!(atp.max_grind, 40);
+        assert_eq!(atp.current.min, 120);
+        assert_eq!(atp.max_grind_atp.min, 120);
+    }
+}
@@ -0,0 +1,291 @@
+use serde::{Serialize, Deserialize};
+use crate::lib::atp::{AtpRange, ATP_PER_GRIND, atp_at_grind};
+use crate::lib::db::WeaponReference;
+use crate::parser::item::{armor_reference, unit_effect};
+use crate::parser::types::{CharacterStats, Item};
+
+// Each mag level adds these to the character's stats, DEX in half points of ATA. These are
+// the bonuses the game's status screen adds for an equipped mag, so a 5/145/50/0 mag is the
+// familiar +290 ATP.
+const MAG_ATP_PER_POW: u32 = 2;
+const MAG_DFP_PER_DEF: i32 = 2;
+const MAG_MST_PER_MIND: i32 = 2;
+const MAG_ATA_PER_DEX: f32 = 0.5;
+
+/// Gear worn in each equipment slot. Units only count up to the frame's slot count.
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct Loadout {
+    pub weapon: Option<Item>,
+    pub frame: Option<Item>,
+    pub barrier: Option<Item>,
+    pub units: Vec<Item>,
+    pub mag: Option<Item>,
+}
+
+impl Loadout {
+    /// Puts an item into its slot, replacing whatever was there. Units are added to the
+    /// list, and items that can't be equipped are ignored.
+    pub fn equip(&mut self, item: Item) {
+        match item {
+            Item::Weapon { .. } | Item::SRankWeapon { .. } => self.weapon = Some(item),
+            Item::Frame { .. } => self.frame = Some(item),
+            Item::Barrier { .. } => self.barrier = Some(item),
+            Item::Unit { .. } => self.units.push(item),
+            Item::Mag { .. } => self.mag = Some(item),
+            Item::Tech { .. } | Item::Tool { .. } | Item::Other { .. } | Item::Meseta { .. } => (),
+        }
+    }
+}
+
+/// Final stats of a character with a loadout. `unknown` names equipped items the reference
+/// data has no stats for, so only their grind or additions are counted, and `unslotted`
+/// names units that didn't fit into the frame.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct BuildStats {
+    pub atp: AtpRange,
+    pub dfp: i32,
+    pub mst: i32,
+    pub ata: f32,
+    pub evp: i32,
+    pub lck: i32,
+    pub unknown: Vec<String>,
+    pub unslotted: Vec<String>,
+}
+
+fn item_code(item_data: &str) -> Option<u32> {
+    item_data.get(0..6).and_then(|code| u32::from_str_radix(code, 16).ok())
+}
+
+fn item_name(item: &Item) -> String {
+    match item {
+          Item::Weapon { name, .. }
+        | Item::Frame { name, .. }
+        | Item::Barrier { name, .. }
+        | Item::Unit { name, .. }
+        | Item::Mag { name, .. }
+        | Item::Tech { name, .. }
+        | Item::SRankWeapon { name, .. }
+        | Item::Tool { name, .. }
+        | Item::Other { name, .. }
+        | Item::Meseta { name, .. } => name.clone(),
+    }
+}
+
+/// Adds the weapon, armor, units and mag of a loadout to the character's base stats.
+/// `weapon_reference` is the reference row for the loadout's weapon, the same one
+/// `atp::weapon_atp` works from, so both calculators agree on weapon ATP.
+pub fn evaluate_build(stats: &CharacterStats, loadout: &Loadout, weapon_reference: Option<&WeaponReference>) -> BuildStats {
+    let mut build = BuildStats {
+        atp: AtpRange { min: stats.atp as u32, max: stats.atp as u32 },
+        dfp: stats.dfp as i32,
+        mst: stats.mst as i32,
+        ata: stats.ata as f32,
+        evp: stats.evp as i32,
+        lck: stats.lck as i32,
+        unknown: Vec::new(),
+        unslotted: Vec::new(),
+    };
+
+    match &loadout.weapon {
+        Some(Item::Weapon { name, grind, attribute, .. }) => {
+            match weapon_reference {
+                Some(reference) => {
+                    let atp = atp_at_grind(reference, *grind);
+                    build.atp.min += atp.min;
+                    build.atp.max += atp.max;
+                    build.ata += reference.ata as f32;
+                },
+                None => {
+                    let bonus = *grind as u32 * ATP_PER_GRIND;
+                    build.atp.min += bonus;
+                    build.atp.max += bonus;
+                    build.unknown.push(name.clone());
+                },
+            }
+
+            build.ata += attribute.hit as f32;
+        },
+        Some(Item::SRankWeapon { name, grind, .. }) => {
+            let bonus = *grind as u32 * ATP_PER_GRIND;
+            build.atp.min += bonus;
+            build.atp.max += bonus;
+            build.unknown.push(name.clone());
+        },
+        _ => (),
+    }
+
+    let mut unit_slots = 0;
+
+    for armor in [&loadout.frame, &loadout.barrier].iter().copied().flatten() {
+        let (name, item_data, addition) = match armor {
+            Item::Frame { name, item_data, addition, slot, .. } => {
+                unit_slots = *slot as usize;
+                (name, item_data, addition)
+            },
+            Item::Barrier { name, item_data, addition, .. } => (name, item_data, addition),
+            _ => continue,
+        };
+
+        build.dfp += addition.dfp;
+        build.evp += addition.evp;
+
+        match item_code(item_data).and_then(armor_reference).and_then(|reference| reference.base) {
+            Some(base) => {
+                build.dfp += base.dfp as i32;
+                build.evp += base.evp as i32;
+            },
+            None => build.unknown.push(name.clone()),
+        }
+    }
+
+    for (index, unit) in loadout.units.iter().enumerate() {
+        if index >= unit_slots {
+            build.unslotted.push(item_name(unit));
+            continue;
+        }
+
+        if let Item::Unit { name, item_data, modifier, .. } = unit {
+            match item_code(item_data).and_then(|code| unit_effect(code, *modifier)) {
+                Some(effect) => {
+                    build.atp.min = (build.atp.min as i32 + effect.atp as i32).max(0) as u32;
+                    build.atp.max = (build.atp.max as i32 + effect.atp as i32).max(0) as u32;
+                    build.dfp += effect.dfp as i32;
+                    build.mst += effect.mst as i32;
+                    build.ata += effect.ata as f32;
+                    build.evp += effect.evp as i32;
+                    build.lck += effect.lck as i32;
+                },
+                None => build.unknown.push(name.clone()),
+            }
+        }
+    }
+
+    if let Some(Item::Mag { stats, .. }) = &loadout.mag {
+        build.atp.min += stats.pow as u32 * MAG_ATP_PER_POW;
+        build.atp.max += stats.pow as u32 * MAG_ATP_PER_POW;
+        build.dfp += stats.def as i32 * MAG_DFP_PER_DEF;
+        build.mst += stats.mind as i32 * MAG_MST_PER_MIND;
+        build.ata += stats.dex as f32 * MAG_ATA_PER_DEX;
+    }
+
+    build
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::fixtures;
+    use crate::parser::item::new_item;
+
+    fn item(bytes: &[u8]) -> Item {
+        let mut item_data = bytes.to_vec();
+        item_data.resize(24, 0);
+        let item_code = (item_data[0] as u32) << 16 | (item_data[1] as u32) << 8 | item_data[2] as u32;
+
+        new_item(item_data, item_code, fixtures::config()).unwrap().item.unwrap()
+    }
+
+    fn stats() -> CharacterStats {
+        CharacterStats { atp: 100, mst: 50, evp: 80, hp: 200, dfp: 60, ata: 90, lck: 10 }
+    }
+
+    fn saber_reference() -> WeaponReference {
+        WeaponReference {
+            min_atp: 40,
+            max_atp: 55,
+            max_grind: 35,
+            ata: 30,
+            requirement_atp: 30,
+            requirement_ata: 0,
+            requirement_mst: 0,
+            classes: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn base_stats_without_gear() {
+        let build = evaluate_build(&stats(), &Loadout::default(), None);
+
+        assert_eq!((build.atp.min, build.atp.max), (100, 100));
+        assert_eq!((build.dfp, build.mst, build.evp, build.lck), (60, 50, 80, 10));
+        assert_eq!(build.ata, 90.0);
+    }
+
+    #[test]
+    fn ground_weapon_matches_the_atp_calculator() {
+        let mut loadout = Loadout::default();
+        loadout.equip(item(&[0x00, 0x01, 0x00, 5]));
+        let reference = saber_reference();
+
+        let build = evaluate_build(&stats(), &loadout, Some(&reference));
+        let weapon = atp_at_grind(&reference, 5);
+
+        // Saber +5: 40-55 ATP and 2 ATP per grind.
+        assert_eq!((build.atp.min, build.atp.max), (150, 165));
+        assert_eq!((build.atp.min - 100, build.atp.max - 100), (weapon.min, weapon.max));
+        assert_eq!(build.ata, 120.0);
+        assert!(build.unknown.is_empty());
+    }
+
+    #[test]
+    fn weapon_without_reference_only_counts_grind() {
+        let mut loadout = Loadout::default();
+        loadout.equip(item(&[0x00, 0x01, 0x00, 5]));
+
+        let build = evaluate_build(&stats(), &loadout, None);
+        assert_eq!((build.atp.min, build.atp.max), (110, 110));
+        assert_eq!(build.unknown, vec![String::from("Saber")]);
+    }
+
+    #[test]
+    fn units_only_fill_frame_slots() {
+        let mut loadout = Loadout::default();
+        // Frame with one slot and +2 DFP, then two Knight/Power units (+10 ATP each).
+        loadout.equip(item(&[0x01, 0x01, 0x00, 0x00, 0x00, 1, 2]));
+        loadout.equip(item(&[0x01, 0x03, 0x00]));
+        loadout.equip(item(&[0x01, 0x03, 0x00]));
+
+        let build = evaluate_build(&stats(), &loadout, None);
+        assert_eq!(build.atp.min, 110);
+        assert_eq!(build.dfp, 62);
+        assert_eq!(build.unslotted.len(), 1);
+        // The plain Frame has no base stats in the armor data yet.
+        assert_eq!(build.unknown.len(), 1);
+    }
+
+    fn mag_with_levels(levels: [u16; 4]) -> Item {
+        // DEF, POW, DEX, MIND, stored as levels times 100.
+        let mut mag = vec![0x02, 0x00, 0x05, 0x00];
+        for level in levels {
+            mag.extend_from_slice(&(level * 100).to_le_bytes());
+        }
+        mag.resize(19, 0);
+        mag.push(0x01);
+        item(&mag)
+    }
+
+    #[test]
+    fn mag_levels_add_to_stats() {
+        let mut loadout = Loadout::default();
+        loadout.equip(mag_with_levels([10, 20, 5, 3]));
+
+        let build = evaluate_build(&stats(), &loadout, None);
+        assert_eq!(build.atp.min, 140);
+        assert_eq!(build.dfp, 80);
+        assert_eq!(build.mst, 56);
+        assert_eq!(build.ata, 92.5);
+    }
+
+    #[test]
+    fn level_200_attack_mag() {
+        let mut loadout = Loadout::default();
+        loadout.equip(mag_with_levels([5, 145, 50, 0]));
+
+        let build = evaluate_build(&stats(), &loadout, None);
+        let base = evaluate_build(&stats(), &Loadout::default(), None);
+        assert_eq!(build.atp.min - base.atp.min, 290);
+        assert_eq!(build.dfp - base.dfp, 10);
+        assert_eq!(build.mst - base.mst, 0);
+        assert_eq!(build.ata - base.ata, 25.0);
+    }
+}
@@ -1,12 +1,33 @@
 use serde::{Serialize, Deserialize};
-use rusqlite::{Connection, Result as SqlResult, params};
+use rusqlite::{Connection, OptionalExtension, Result as SqlResult, params};
+use std::collections::HashMap;
+use std::collections::hash_map::DefaultHasher;
 use std::fs;
+use std::hash::{Hash, Hasher};
 use std::io::Read;
 use std::path::Path;
 use crate::util::Util;
 use crate::config::config::Config;
 use crate::command::db::{SqlError, CharacterData};
-use crate::parser::types::{Item, WrappedItem};
+use crate::lib::weapon_db::{Weapon, weapon_info};
+use crate::parser::types::{
+    Item,
+    WrappedItem,
+    CharacterStats,
+    CharacterAppearance,
+    MaterialUsage,
+    StoryProgress,
+    EpisodeProgress,
+    QuestStatus,
+    ChallengeRecords,
+    LearnedTech,
+    UnitEffect,
+    BattleRecords,
+    ChallengeTitle,
+    Inventory
+};
+use crate::parser::character::{experience_progress, material_headroom};
+use crate::parser::error::ParseError;
 use crate::parser::item::{
     weapon,
     frame,
@@ -18,112 +39,126 @@ use crate::parser::item::{
     s_rank_weapon,
     meseta,
     new_item,
+    photon_blast_names,
+    unit_effect,
     set_meseta
 };
 
-pub fn insert_item(conn: &Connection, item: &WrappedItem, account_id: i64, character_id: i64, storage_type: String, account_type: &String, lang: &String) -> Result<(), SqlError> {
+pub fn insert_item(conn: &Connection, item: &WrappedItem, account_id: i64, character_id: i64, storage_type: String, account_type: &String, config: &Config) -> Result<(), SqlError> {
+    let lang = config.lang.clone().unwrap_or_default();
+
     if let Some(item_type) = &item.item {
         match item_type {
-            Item::Weapon { name, type_, item_data, special, special_code, grind, attribute, tekked, rare } => {
+            Item::Weapon { name, type_, item_data, special, special_code, grind, attribute, tekked, rare, kill_counter, wrapped } => {
                 conn.execute(
                     "INSERT INTO weapon
                     (
                         account_id, character_id, storage_type, name, type, item_data, special,
                         special_code, grind, native, a_beast, machine, dark, hit, tekked, rare, 
-                        account_type, lang
+                        account_type, lang, kills, kills_remaining, wrapped
                     )
-                    VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9,?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18)",
+                    VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9,?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18, ?19, ?20, ?21)",
                     params![
                         account_id, character_id, storage_type, name, type_, item_data, special, special_code,
                         grind, attribute.native, attribute.a_beast, attribute.machine, attribute.dark,
-                        attribute.hit, tekked, rare, account_type, lang
+                        attribute.hit, tekked, rare, account_type, lang,
+                        kill_counter.as_ref().map(|counter| counter.kills),
+                        kill_counter.as_ref().map(|counter| counter.kills_remaining), wrapped
                     ]
                 )?;
             }
-            Item::Frame { name, type_, item_data, slot, addition, max_addition } => {
+            Item::Frame { name, type_, item_data, slot, addition, max_addition, wrapped } => {
                 conn.execute(
                     "INSERT INTO frame
                     (
                         account_id, character_id, storage_type, name, type, item_data, slot, dfp,
-                        evp, max_dfp, max_evp, account_type, lang
+                        evp, max_dfp, max_evp, account_type, lang, wrapped
                     )
-                    VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13)",
+                    VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14)",
                     params![
                         account_id, character_id, storage_type, name, type_, item_data, slot, addition.dfp,
-                        addition.evp, max_addition.dfp, max_addition.evp, account_type, lang
+                        addition.evp, max_addition.dfp, max_addition.evp, account_type, lang, wrapped
                     ]
                 )?;
             }
-            Item::Barrier { name, type_, item_data, addition, max_addition } => {
+            Item::Barrier { name, type_, item_data, addition, max_addition, wrapped } => {
                 conn.execute(
                     "INSERT INTO barrier
                     (
                         account_id, character_id, storage_type, name, type, dfp, evp, max_dfp, max_evp,
-                        item_data, account_type, lang
+                        item_data, account_type, lang, wrapped
                     )
-                     VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12)",
+                     VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13)",
                      params![
                         account_id, character_id, storage_type, name, type_, addition.dfp, addition.evp,
-                        max_addition.dfp, max_addition.evp, item_data, account_type, lang
+                        max_addition.dfp, max_addition.evp, item_data, account_type, lang, wrapped
                     ]
                 )?;
             }
-            Item::Unit { name, type_, item_data } => {
+            Item::Unit { name, type_, item_data, modifier, kill_counter, wrapped } => {
                 conn.execute(
-                    "INSERT INTO unit (account_id, character_id, storage_type, name, type, item_data, account_type, lang)
-                     VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
-                     params![account_id, character_id, storage_type, name, type_, item_data, account_type, lang]
+                    "INSERT INTO unit (
+                        account_id, character_id, storage_type, name, type, item_data, account_type, lang, modifier,
+                        kills, kills_remaining, wrapped
+                     )
+                     VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12)",
+                     params![
+                        account_id, character_id, storage_type, name, type_, item_data, account_type, lang, modifier,
+                        kill_counter.as_ref().map(|counter| counter.kills),
+                        kill_counter.as_ref().map(|counter| counter.kills_remaining), wrapped
+                     ]
                 )?;
             }
-            Item::Mag { name, type_, item_data, level, sync, iq, color, rgb, stats, pbs } => {
+            Item::Mag { name, type_, item_data, level, sync, iq, color, rgb, stats, photon_blasts, evolution, wrapped, .. } => {
                 conn.execute(
                     "INSERT INTO mag
                     (
                         account_id, character_id, storage_type, name, type, level, sync, iq, color,
-                        rgb, def, pow, dex, mind, pbs, item_data, account_type, lang
+                        rgb, def, pow, dex, mind, pbs, item_data, account_type, lang, evolution_stage, affinity, wrapped
                     )
-                     VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18)",
+                     VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18, ?19, ?20, ?21)",
                      params![
                         account_id, character_id, storage_type, name, type_, level, sync, iq, color, rgb,
-                        stats.def, stats.pow, stats.dex, stats.mind, pbs.join(","), item_data, account_type, lang
+                        stats.def, stats.pow, stats.dex, stats.mind, photon_blast_names(photon_blasts, config).join(","),
+                        item_data, account_type, lang, evolution.stage, evolution.affinity.map(|affinity| affinity.as_str()), wrapped
                     ]
                 )?;
             }
-            Item::Tech { name, type_, item_data, level } => {
+            Item::Tech { name, type_, item_data, level, wrapped } => {
                 conn.execute(
-                    "INSERT INTO tech (account_id, character_id, storage_type, name, type, level, item_data, account_type, lang)
-                     VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
-                     params![account_id, character_id, storage_type, name, type_, level, item_data, account_type, lang]
+                    "INSERT INTO tech (account_id, character_id, storage_type, name, type, level, item_data, account_type, lang, wrapped)
+                     VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)",
+                     params![account_id, character_id, storage_type, name, type_, level, item_data, account_type, lang, wrapped]
                 )?;
             }
-            Item::SRankWeapon { name, type_, item_data, grind, special, special_code } => {
+            Item::SRankWeapon { name, type_, item_data, grind, special, special_code, wrapped } => {
                 conn.execute(
                     "INSERT INTO srank_weapon (
-                        account_id, character_id, storage_type, name, type, grind, special, special_code, item_data, account_type, lang
+                        account_id, character_id, storage_type, name, type, grind, special, special_code, item_data, account_type, lang, wrapped
                      )
-                     VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)",
-                     params![account_id, character_id, storage_type, name, type_, grind, special, special_code, item_data, account_type, lang]
+                     VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12)",
+                     params![account_id, character_id, storage_type, name, type_, grind, special, special_code, item_data, account_type, lang, wrapped]
                 )?;
             }
-            Item::Tool { name, type_, item_data, number } => {
+            Item::Tool { name, type_, item_data, number, wrapped } => {
                 conn.execute(
-                    "INSERT INTO tool (account_id, character_id, storage_type, name, type, number, item_data, account_type, lang)
-                     VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
-                     params![account_id, character_id, storage_type, name, type_, number, item_data, account_type, lang]
+                    "INSERT INTO tool (account_id, character_id, storage_type, name, type, number, item_data, account_type, lang, wrapped)
+                     VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)",
+                     params![account_id, character_id, storage_type, name, type_, number, item_data, account_type, lang, wrapped]
                 )?;
             }
-            Item::Meseta { name, type_, amount } => {
+            Item::Meseta { name, type_, amount, .. } => {
                 conn.execute(
                     "INSERT INTO meseta (account_id, character_id, storage_type, name, type, amount, account_type, lang)
                      VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
                      params![account_id, character_id, storage_type, name, type_, amount, account_type, lang]
                 )?;
             }
-            Item::Other { name, type_, item_data, number } => {
+            Item::Other { name, type_, item_data, number, wrapped } => {
                 conn.execute(
-                    "INSERT INTO other (account_id, character_id, storage_type, name, type, number, item_data, account_type, lang)
-                     VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
-                     params![account_id, character_id, storage_type, name, type_, number, item_data, account_type, lang]
+                    "INSERT INTO other (account_id, character_id, storage_type, name, type, number, item_data, account_type, lang, wrapped)
+                     VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)",
+                     params![account_id, character_id, storage_type, name, type_, number, item_data, account_type, lang, wrapped]
                 )?;
             }
         }
@@ -134,6 +169,42 @@ pub fn insert_item(conn: &Connection, item: &WrappedItem, account_id: i64, chara
     Ok(())
 }
 
+/// Base stats and requirements of a weapon from `weapon_reference`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct WeaponReference {
+    pub min_atp: u32,
+    pub max_atp: u32,
+    pub max_grind: u8,
+    pub ata: u8,
+    pub requirement_atp: u32,
+    pub requirement_ata: u32,
+    pub requirement_mst: u32,
+    pub classes: Vec<String>,
+}
+
+/// Reads the joined `weapon_reference` columns starting at `start`, if the weapon has a row.
+pub fn weapon_reference(row: &rusqlite::Row, start: usize) -> SqlResult<Option<WeaponReference>> {
+    let min_atp: Option<u32> = row.get(start)?;
+
+    match min_atp {
+        Some(min_atp) => {
+            let classes: String = row.get(start + 7)?;
+
+            Ok(Some(WeaponReference {
+                min_atp,
+                max_atp: row.get(start + 1)?,
+                max_grind: row.get(start + 2)?,
+                ata: row.get(start + 3)?,
+                requirement_atp: row.get(start + 4)?,
+                requirement_ata: row.get(start + 5)?,
+                requirement_mst: row.get(start + 6)?,
+                classes: classes.split(',').filter(|class| !class.is_empty()).map(String::from).collect(),
+            }))
+        },
+        None => Ok(None),
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub enum DBItem {
     Weapon {
@@ -155,7 +226,11 @@ pub enum DBItem {
         rare: bool,
         item_data: String,
         account_type: String,
-        lang: String
+        lang: String,
+        kills: Option<u16>,
+        kills_remaining: Option<u16>,
+        wrapped: bool,
+        reference: Option<WeaponReference>
     },
     SRankWeapon {
         id: i64,
@@ -169,7 +244,8 @@ pub enum DBItem {
         special_code: String,
         item_data: String,
         account_type: String,
-        lang: String
+        lang: String,
+        wrapped: bool
     },
     Frame {
         id: i64,
@@ -185,7 +261,8 @@ pub enum DBItem {
         max_evp: u8,
         item_data: String,
         account_type: String,
-        lang: String
+        lang: String,
+        wrapped: bool
     },
     Barrier {
         id: i64,
@@ -200,7 +277,8 @@ pub enum DBItem {
         max_evp: u8,
         item_data: String,
         account_type: String,
-        lang: String
+        lang: String,
+        wrapped: bool
     },
     Unit {
         id: i64,
@@ -211,7 +289,12 @@ pub enum DBItem {
         name: String,
         item_data: String,
         account_type: String,
-        lang: String
+        lang: String,
+        modifier: i8,
+        effect: Option<UnitEffect>,
+        kills: Option<u16>,
+        kills_remaining: Option<u16>,
+        wrapped: bool
     },
     Mag {
         id: i64,
@@ -232,7 +315,10 @@ pub enum DBItem {
         pbs: String,
         item_data: String,
         account_type: String,
-        lang: String
+        lang: String,
+        evolution_stage: u8,
+        affinity: Option<String>,
+        wrapped: bool
     },
     Tech {
         id: i64,
@@ -244,7 +330,8 @@ pub enum DBItem {
         level: u8,
         item_data: String,
         account_type: String,
-        lang: String
+        lang: String,
+        wrapped: bool
     },
     Tool {
         id: i64,
@@ -256,7 +343,8 @@ pub enum DBItem {
         number: u8,
         item_data: String,
         account_type: String,
-        lang: String
+        lang: String,
+        wrapped: bool
     },
     Other {
         id: i64,
@@ -268,7 +356,8 @@ pub enum DBItem {
         number: u32,
         item_data: String,
         account_type: String,
-        lang: String
+        lang: String,
+        wrapped: bool
     },
     Meseta {
         id: i64,
@@ -283,10 +372,82 @@ pub enum DBItem {
     }
 }
 
+// Hash of the built-in weapon data in item code order. A different toolchain may hash
+// differently, which only costs one extra refill.
+fn weapon_reference_hash(weapons: &HashMap<u32, Weapon>) -> String {
+    let mut codes: Vec<&u32> = weapons.keys().collect();
+    codes.sort();
+
+    let mut hasher = DefaultHasher::new();
+    for code in codes {
+        (code, &weapons[code]).hash(&mut hasher);
+    }
+
+    format!("{:016x}", hasher.finish())
+}
+
+/// Refills `weapon_reference` from the built-in weapon data whenever the data's hash differs
+/// from the one stored in `reference_version`, so a new release that changes any weapon
+/// replaces the old rows on startup.
+pub fn insert_weapon_reference(conn: &Connection) -> Result<(), SqlError> {
+    let weapons = weapon_info();
+    let hash = weapon_reference_hash(&weapons);
+    let stored: Option<String> = conn.query_row(
+        "SELECT hash FROM reference_version WHERE name = 'weapon_reference'",
+        [],
+        |row| row.get(0),
+    ).optional()?;
+
+    if stored.as_deref() == Some(hash.as_str()) {
+        return Ok(());
+    }
+
+    conn.execute("DELETE FROM weapon_reference", [])?;
+
+    let abbreviations = Config::class_abbreviations();
+    let mut stmt = conn.prepare(
+        "INSERT INTO weapon_reference
+        (
+            _type, weapon_type, name, hex_code, description, rarity, maxstack, teampoints, grind, ata,
+            min_atp, max_atp, special, targets, classes, notes, total_min_atp, total_max_atp,
+            requirement_atp, requirement_ata, requirement_mst
+        )
+        VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18, ?19, ?20, ?21)"
+    )?;
+
+    for weapon in weapons.values() {
+        let classes: Vec<&str> = weapon.classes
+            .iter()
+            .map(|class| *abbreviations.get(class).unwrap_or(class))
+            .collect();
+
+        stmt.execute(params![
+            weapon._type, weapon.weapon_type, weapon.name, weapon.hex_code, weapon.description,
+            weapon.rarity, weapon.maxstack, weapon.teampoints, weapon.grind, weapon.ata,
+            weapon.min_atp, weapon.max_atp, weapon.special, weapon.targets, classes.join(","),
+            weapon.notes, weapon.total_min_atp, weapon.total_max_atp,
+            weapon.requirement_atp, weapon.requirement_ata, weapon.requirement_mst
+        ])?;
+    }
+
+    conn.execute(
+        "INSERT OR REPLACE INTO reference_version (name, hash) VALUES ('weapon_reference', ?1)",
+        [hash],
+    )?;
+
+    Ok(())
+}
+
 pub fn get_items(conn: &Connection, account_id: i64, character_id: i64, lang: &String) -> Result<Vec<DBItem>, SqlError> {
     let mut items = Vec::new();
 
-    let mut wep_stmt = conn.prepare("SELECT * FROM weapon WHERE account_id = ?1 AND character_id = ?2 AND lang = ?3")?;
+    let mut wep_stmt = conn.prepare(
+        "SELECT w.*, r.min_atp, r.max_atp, r.grind, r.ata, r.requirement_atp, r.requirement_ata,
+            r.requirement_mst, r.classes
+        FROM weapon w
+        LEFT JOIN weapon_reference r ON r.hex_code = substr(w.item_data, 1, 6)
+        WHERE w.account_id = ?1 AND w.character_id = ?2 AND w.lang = ?3"
+    )?;
     let wep_itr = wep_stmt.query_map(params![account_id, character_id, lang], |row| {
         Ok(DBItem::Weapon {
             id: row.get(0)?,
@@ -307,7 +468,11 @@ pub fn get_items(conn: &Connection, account_id: i64, character_id: i64, lang: &S
             rare: row.get(15)?,
             item_data: row.get(16)?,
             account_type: row.get(17)?,
-            lang: row.get(18)?
+            lang: row.get(18)?,
+            kills: row.get(19)?,
+            kills_remaining: row.get(20)?,
+            wrapped: row.get(21)?,
+            reference: weapon_reference(row, 22)?
         })
     })?;
 
@@ -329,7 +494,8 @@ pub fn get_items(conn: &Connection, account_id: i64, character_id: i64, lang: &S
             special_code: row.get(8)?,
             item_data: row.get(9)?,
             account_type: row.get(10)?,
-            lang: row.get(11)?
+            lang: row.get(11)?,
+            wrapped: row.get(12)?
         })
     })?;
 
@@ -353,7 +519,8 @@ pub fn get_items(conn: &Connection, account_id: i64, character_id: i64, lang: &S
             max_evp: row.get(10)?,
             item_data: row.get(11)?,
             account_type: row.get(12)?,
-            lang: row.get(13)?
+            lang: row.get(13)?,
+            wrapped: row.get(14)?
         })
     })?;
 
@@ -376,7 +543,8 @@ pub fn get_items(conn: &Connection, account_id: i64, character_id: i64, lang: &S
             max_evp: row.get(9)?,
             item_data: row.get(10)?,
             account_type: row.get(11)?,
-            lang: row.get(12)?
+            lang: row.get(12)?,
+            wrapped: row.get(13)?
         })
     })?;
 
@@ -386,6 +554,10 @@ pub fn get_items(conn: &Connection, account_id: i64, character_id: i64, lang: &S
 
     let mut unit_stmt = conn.prepare("SELECT * FROM unit WHERE account_id = ?1 AND character_id = ?2 AND lang = ?3")?;
     let unit_itr = unit_stmt.query_map(params![account_id, character_id, lang], |row| {
+        let item_data: String = row.get(6)?;
+        let item_code = Util::binary_array_to_int(&Util::hex_string_to_array(&item_data)[0..3]);
+        let modifier: i8 = row.get(9)?;
+
         Ok(DBItem::Unit {
             id: row.get(0)?,
             account_id: row.get(1)?,
@@ -395,7 +567,12 @@ pub fn get_items(conn: &Connection, account_id: i64, character_id: i64, lang: &S
             name: row.get(5)?,
             item_data: row.get(6)?,
             account_type: row.get(7)?,
-            lang: row.get(8)?
+            lang: row.get(8)?,
+            modifier: modifier,
+            effect: unit_effect(item_code, modifier),
+            kills: row.get(10)?,
+            kills_remaining: row.get(11)?,
+            wrapped: row.get(12)?
         })
     })?;
 
@@ -424,7 +601,10 @@ pub fn get_items(conn: &Connection, account_id: i64, character_id: i64, lang: &S
             pbs: row.get(15)?,
             item_data: row.get(16)?,
             account_type: row.get(17)?,
-            lang: row.get(18)?
+            lang: row.get(18)?,
+            evolution_stage: row.get(19)?,
+            affinity: row.get(20)?,
+            wrapped: row.get(21)?
         })
     })?;
 
@@ -444,7 +624,8 @@ pub fn get_items(conn: &Connection, account_id: i64, character_id: i64, lang: &S
             level: row.get(6)?,
             item_data: row.get(7)?,
             account_type: row.get(8)?,
-            lang: row.get(9)?
+            lang: row.get(9)?,
+            wrapped: row.get(10)?
         })
     })?;
 
@@ -464,7 +645,8 @@ pub fn get_items(conn: &Connection, account_id: i64, character_id: i64, lang: &S
             number: row.get(6)?,
             item_data: row.get(7)?,
             account_type: row.get(8)?,
-            lang: row.get(9)?
+            lang: row.get(9)?,
+            wrapped: row.get(10)?
         })
     })?;
 
@@ -484,7 +666,8 @@ pub fn get_items(conn: &Connection, account_id: i64, character_id: i64, lang: &S
             number: row.get(6)?,
             item_data: row.get(7)?,
             account_type: row.get(8)?,
-            lang: row.get(9)?
+            lang: row.get(9)?,
+            wrapped: row.get(10)?
         })
     })?;
 
@@ -514,13 +697,227 @@ pub fn get_items(conn: &Connection, account_id: i64, character_id: i64, lang: &S
     Ok(items)
 }
 
+pub fn insert_appearance(conn: &Connection, character_id: i64, appearance: &CharacterAppearance) -> Result<(), SqlError> {
+    conn.execute(
+        "INSERT INTO character_appearance
+        (
+            character_id, name_color, costume, skin, face, head, hair,
+            hair_r, hair_g, hair_b, proportion_x, proportion_y
+        )
+        VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12)",
+        params![
+            character_id, appearance.name_color, appearance.costume, appearance.skin,
+            appearance.face, appearance.head, appearance.hair, appearance.hair_color[0],
+            appearance.hair_color[1], appearance.hair_color[2], appearance.proportion_x,
+            appearance.proportion_y
+        ]
+    )?;
+
+    Ok(())
+}
+
+pub fn get_appearance(conn: &Connection, character_id: i64) -> Result<CharacterAppearance, SqlError> {
+    let mut stmt = conn.prepare(
+        "SELECT name_color, costume, skin, face, head, hair, hair_r, hair_g, hair_b, proportion_x, proportion_y
+        FROM character_appearance
+        WHERE character_id = ?1"
+    )?;
+    let mut appearance_itr = stmt.query_map([character_id], |row| {
+        Ok(CharacterAppearance {
+            name_color: row.get(0)?,
+            costume: row.get(1)?,
+            skin: row.get(2)?,
+            face: row.get(3)?,
+            head: row.get(4)?,
+            hair: row.get(5)?,
+            hair_color: [row.get(6)?, row.get(7)?, row.get(8)?],
+            proportion_x: row.get(9)?,
+            proportion_y: row.get(10)?
+        })
+    })?;
+
+    Ok(appearance_itr.next().transpose()?.unwrap_or_default())
+}
+
+pub fn insert_story_progress(conn: &Connection, character_id: i64, story_progress: &StoryProgress) -> Result<(), SqlError> {
+    let episodes = [
+        (1, &story_progress.ep1),
+        (2, &story_progress.ep2),
+        (4, &story_progress.ep4),
+    ];
+
+    for (episode, progress) in episodes {
+        conn.execute(
+            "INSERT INTO story_progress (character_id, episode, stages, normal, hard, very_hard, ultimate)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+            params![
+                character_id, episode, progress.stages, progress.normal,
+                progress.hard, progress.very_hard, progress.ultimate
+            ]
+        )?;
+    }
+
+    Ok(())
+}
+
+pub fn insert_quest_log(conn: &Connection, character_id: i64, quest_log: &Vec<QuestStatus>) -> Result<(), SqlError> {
+    for quest in quest_log {
+        for (difficulty, completed) in quest.completed.iter().enumerate() {
+            conn.execute(
+                "INSERT INTO quest_log (character_id, episode, flag, difficulty, completed)
+                 VALUES (?1, ?2, ?3, ?4, ?5)",
+                params![character_id, quest.episode, quest.flag, difficulty, completed]
+            )?;
+        }
+    }
+
+    Ok(())
+}
+
+pub fn insert_learned_techs(conn: &Connection, character_id: i64, techniques: &Vec<LearnedTech>) -> Result<(), SqlError> {
+    for technique in techniques {
+        conn.execute(
+            "INSERT INTO learned_tech (character_id, tech, level)
+             VALUES (?1, ?2, ?3)",
+            params![character_id, technique.tech, technique.level]
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Stores the item data of the equipped inventory items. Item data doesn't change with the
+/// language, so the rows hold for every translation of the character's items.
+pub fn insert_equipped_items(conn: &Connection, character_id: i64, inventory: &Inventory, equipped: &[usize]) -> Result<(), SqlError> {
+    for &index in equipped {
+        let item_data = inventory
+            .get(index)
+            .and_then(|wrapped| wrapped.item.as_ref())
+            .and_then(|item| item.item_data());
+
+        if let Some(item_data) = item_data {
+            conn.execute(
+                "INSERT INTO equipped_item (character_id, item_data)
+                 VALUES (?1, ?2)",
+                params![character_id, item_data]
+            )?;
+        }
+    }
+
+    Ok(())
+}
+
+pub fn insert_challenge_records(conn: &Connection, character_id: i64, challenge: &ChallengeRecords) -> Result<(), SqlError> {
+    let episodes = [(1, &challenge.ep1), (2, &challenge.ep2)];
+
+    for (episode, stages) in episodes {
+        for record in stages {
+            conn.execute(
+                "INSERT INTO challenge_record (character_id, episode, stage, time)
+                 VALUES (?1, ?2, ?3, ?4)",
+                params![character_id, episode, record.stage, record.time]
+            )?;
+        }
+    }
+
+    conn.execute(
+        "INSERT INTO challenge_title (character_id, rank, title_color)
+         VALUES (?1, ?2, ?3)",
+        params![character_id, challenge.rank, challenge.title_color]
+    )?;
+
+    let battle = &challenge.battle;
+    conn.execute(
+        "INSERT INTO battle_record (character_id, first, second, third, fourth, disconnects)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+        params![character_id, battle.first, battle.second, battle.third, battle.fourth, battle.disconnects]
+    )?;
+
+    Ok(())
+}
+
+pub fn get_battle_records(conn: &Connection, character_id: i64) -> Result<Option<BattleRecords>, SqlError> {
+    let mut stmt = conn.prepare(
+        "SELECT first, second, third, fourth, disconnects
+        FROM battle_record
+        WHERE character_id = ?1"
+    )?;
+    let mut battle_itr = stmt.query_map([character_id], |row| {
+        Ok(BattleRecords {
+            first: row.get(0)?,
+            second: row.get(1)?,
+            third: row.get(2)?,
+            fourth: row.get(3)?,
+            disconnects: row.get(4)?
+        })
+    })?;
+
+    Ok(battle_itr.next().transpose()?)
+}
+
+pub fn get_challenge_title(conn: &Connection, character_id: i64) -> Result<Option<ChallengeTitle>, SqlError> {
+    let mut stmt = conn.prepare(
+        "SELECT rank, title_color
+        FROM challenge_title
+        WHERE character_id = ?1"
+    )?;
+    let mut title_itr = stmt.query_map([character_id], |row| {
+        Ok(ChallengeTitle {
+            rank: row.get(0)?,
+            title_color: row.get(1)?
+        })
+    })?;
+
+    Ok(title_itr.next().transpose()?)
+}
+
+pub fn get_story_progress(conn: &Connection, character_id: i64) -> Result<StoryProgress, SqlError> {
+    let mut story_progress = StoryProgress::default();
+    let mut stmt = conn.prepare(
+        "SELECT episode, stages, normal, hard, very_hard, ultimate
+        FROM story_progress
+        WHERE character_id = ?1"
+    )?;
+    let progress_itr = stmt.query_map([character_id], |row| {
+        Ok((
+            row.get::<_, u8>(0)?,
+            EpisodeProgress {
+                stages: row.get(1)?,
+                normal: row.get(2)?,
+                hard: row.get(3)?,
+                very_hard: row.get(4)?,
+                ultimate: row.get(5)?
+            }
+        ))
+    })?;
+
+    for progress in progress_itr {
+        let (episode, progress) = progress?;
+
+        match episode {
+            1 => story_progress.ep1 = progress,
+            2 => story_progress.ep2 = progress,
+            4 => story_progress.ep4 = progress,
+            _ => return Err(SqlError::DatabaseError(format!("Unknown episode {} in story progress", episode))),
+        }
+    }
+
+    Ok(story_progress)
+}
+
+// Lets lookups inside a row closure fail the query instead of panicking.
+fn row_error(error: SqlError) -> rusqlite::Error {
+    rusqlite::Error::ToSqlConversionFailure(Box::new(error))
+}
+
 pub fn get_character_data(conn: &Connection, account_id: i64, lang: &String) -> Result<Vec<CharacterData>, SqlError> {
     let mut characters = Vec::new();
     let mut stmt = conn.prepare(
         "SELECT
             id, account_id, slot, mode, guild_card, name, class,
-            section_id, level, experience, ep1_progress, ep2_progress,
-            image
+            section_id, level, experience, image, atp, mst, evp, hp, dfp, ata, lck,
+            power_materials, mind_materials, evade_materials, def_materials,
+            luck_materials, hp_materials, tp_materials
         FROM
         character
         WHERE account_id = ?1;"
@@ -530,7 +927,7 @@ pub fn get_character_data(conn: &Connection, account_id: i64, lang: &String) ->
         [account_id],
         |row| {
             let character_id: i64 = row.get(0)?;
-            let items: Vec<DBItem> = get_items(conn, account_id, character_id, lang).expect("get_items error");
+            let items: Vec<DBItem> = get_items(conn, account_id, character_id, lang).map_err(row_error)?;
             let mut inventory = Vec::new();
             let mut bank = Vec::new();
 
@@ -555,6 +952,21 @@ pub fn get_character_data(conn: &Connection, account_id: i64, lang: &String) ->
                 }
             }
 
+            let level: u8 = row.get(8)?;
+            let experience: u32 = row.get(9)?;
+            let class: String = row.get(6)?;
+            let materials = MaterialUsage {
+                power: row.get(18)?,
+                mind: row.get(19)?,
+                evade: row.get(20)?,
+                def: row.get(21)?,
+                luck: row.get(22)?,
+                hp: row.get(23)?,
+                tp: row.get(24)?
+            };
+            let story_progress = get_story_progress(conn, character_id).map_err(row_error)?;
+            let appearance = get_appearance(conn, character_id).map_err(row_error)?;
+
             Ok(CharacterData {
                 id: row.get(0)?,
                 account_id: row.get(1)?,
@@ -562,13 +974,25 @@ pub fn get_character_data(conn: &Connection, account_id: i64, lang: &String) ->
                 mode: row.get(3)?,
                 guild_card: row.get(4)?,
                 name: row.get(5)?,
-                class: row.get(6)?,
+                material_headroom: material_headroom(&class, &materials),
+                materials: materials,
+                class: class,
                 section_id: row.get(7)?,
-                level: row.get(8)?,
-                experience: row.get(9)?,
-                ep1_progress: row.get(10)?,
-                ep2_progress: row.get(11)?,
-                image: row.get::<_, Option<Vec<u8>>>(12)?,
+                level: level,
+                experience: experience as u64,
+                experience_progress: experience_progress(level, experience),
+                appearance: appearance,
+                story_progress: story_progress,
+                stats: CharacterStats {
+                    atp: row.get(11)?,
+                    mst: row.get(12)?,
+                    evp: row.get(13)?,
+                    hp: row.get(14)?,
+                    dfp: row.get(15)?,
+                    ata: row.get(16)?,
+                    lck: row.get(17)?
+                },
+                image: row.get::<_, Option<Vec<u8>>>(10)?,
                 inventory: inventory,
                 bank: bank
             })
@@ -582,7 +1006,7 @@ pub fn get_character_data(conn: &Connection, account_id: i64, lang: &String) ->
     Ok(characters)
 }
 
-fn translate_item(item_data: &String, config: &Config) -> WrappedItem {
+pub fn translate_item(item_data: &String, config: &Config) -> Result<WrappedItem, ParseError> {
     let _item_data = Util::hex_string_to_array(&item_data);
     let item_code = Util::binary_array_to_int(&_item_data[0..3]);
     let item_hex_code = Util::binary_array_to_hex(&_item_data[0..3]);
@@ -609,11 +1033,106 @@ pub fn translate_items(conn: &Connection, account_id: i64, character_id: i64, it
             | DBItem::Mag { item_data, account_type, .. }
             | DBItem::Tech { item_data, account_type, .. }
             | DBItem::Tool { item_data, account_type, .. }
-            | DBItem::Other { item_data, account_type, .. } => (translate_item(item_data, &config), account_type),
+            | DBItem::Other { item_data, account_type, .. } => (translate_item(item_data, &config)?, account_type),
             DBItem::Meseta { amount, account_type, .. } => (translate_meseta(*amount, &config), account_type),
         };
-        insert_item(conn, &item_data, account_id, character_id, storage_type.clone(), &account_type, &config.lang.clone().unwrap());
+        insert_item(conn, &item_data, account_id, character_id, storage_type.clone(), account_type, &config)?;
     }
 
     Ok(())
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn reference_db() -> Connection {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute(
+            "CREATE TABLE weapon_reference (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                _type TEXT NOT NULL,
+                weapon_type TEXT NOT NULL,
+                name TEXT NOT NULL,
+                hex_code TEXT NOT NULL,
+                description TEXT NOT NULL,
+                rarity INTEGER NOT NULL,
+                maxstack INTEGER NOT NULL,
+                teampoints INTEGER NOT NULL,
+                grind INTEGER NOT NULL,
+                ata INTEGER NOT NULL,
+                min_atp INTEGER NOT NULL,
+                max_atp INTEGER NOT NULL,
+                special TEXT NOT NULL,
+                targets INTEGER NOT NULL,
+                classes TEXT NOT NULL,
+                notes TEXT NOT NULL,
+                total_min_atp INTEGER NOT NULL,
+                total_max_atp INTEGER NOT NULL,
+                requirement_atp INTEGER NOT NULL,
+                requirement_ata INTEGER NOT NULL,
+                requirement_mst INTEGER NOT NULL
+            )",
+            [],
+        ).unwrap();
+        conn.execute("CREATE TABLE reference_version (name TEXT PRIMARY KEY, hash TEXT NOT NULL)", []).unwrap();
+
+        conn
+    }
+
+    fn saber_min_atp(conn: &Connection) -> u32 {
+        conn.query_row("SELECT min_atp FROM weapon_reference WHERE hex_code = '000100'", [], |row| row.get(0)).unwrap()
+    }
+
+    fn lookup(conn: &Connection, item_data: &str) -> Option<WeaponReference> {
+        conn.query_row(
+            "SELECT min_atp, max_atp, grind, ata, requirement_atp, requirement_ata, requirement_mst, classes
+            FROM weapon_reference WHERE hex_code = substr(?1, 1, 6)",
+            [item_data],
+            |row| weapon_reference(row, 0),
+        ).optional().unwrap().flatten()
+    }
+
+    #[test]
+    fn reference_is_filled_once() {
+        let conn = reference_db();
+        insert_weapon_reference(&conn).unwrap();
+        insert_weapon_reference(&conn).unwrap();
+
+        let count: usize = conn.query_row("SELECT COUNT(*) FROM weapon_reference", [], |row| row.get(0)).unwrap();
+        assert_eq!(count, weapon_info().len());
+        assert_eq!(count, 977);
+    }
+
+    #[test]
+    fn reference_is_refilled_when_the_data_changes() {
+        let conn = reference_db();
+        insert_weapon_reference(&conn).unwrap();
+        conn.execute("UPDATE weapon_reference SET min_atp = 0 WHERE hex_code = '000100'", []).unwrap();
+
+        // Same data version, the rows are left alone.
+        insert_weapon_reference(&conn).unwrap();
+        assert_eq!(saber_min_atp(&conn), 0);
+
+        // Same row count but an older version, as after a release that fixed a stat.
+        conn.execute("UPDATE reference_version SET hash = 'older' WHERE name = 'weapon_reference'", []).unwrap();
+        insert_weapon_reference(&conn).unwrap();
+        assert_eq!(saber_min_atp(&conn), 40);
+    }
+
+    #[test]
+    fn weapons_join_their_reference_by_item_code() {
+        let conn = reference_db();
+        insert_weapon_reference(&conn).unwrap();
+
+        let saber = lookup(&conn, "000100050000000000000000").unwrap();
+        assert_eq!((saber.min_atp, saber.max_atp, saber.max_grind, saber.ata), (40, 55, 35, 30));
+        assert_eq!(saber.requirement_atp, 30);
+        // Class names are stored in full so they compare against the character's class.
+        assert_eq!(saber.classes.len(), 12);
+        assert!(saber.classes.contains(&String::from("FOnewearl")));
+        assert!(saber.classes.contains(&String::from("HUcast")));
+
+        assert!(lookup(&conn, "00FFFF000000000000000000").is_none());
+    }
+}
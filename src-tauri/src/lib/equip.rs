@@ -0,0 +1,343 @@
+use serde::{Serialize, Deserialize};
+use crate::config::config::Config;
+use crate::lib::db::DBItem;
+use crate::lib::mag::class_line;
+use crate::lib::weapon_db::weapon_info;
+use crate::parser::character::tech_level_cap;
+use crate::parser::item::armor_reference;
+use crate::parser::types::MagClass;
+
+/// What the equip checks need to know about a character.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct EquipCharacter {
+    pub character_id: i64,
+    pub name: String,
+    pub class: String,
+    pub level: u8,
+    pub atp: u32,
+    pub ata: u32,
+    pub mst: u32,
+}
+
+/// `unknown` lists the characters the reference data can't rule in or out, because the item
+/// has no class list or class line to check against. They are not in `eligible`.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ItemEligibility {
+    pub item_id: i64,
+    pub item_type: String,
+    pub name: String,
+    pub character_id: i64,
+    pub storage_type: String,
+    pub eligible: Vec<EquipCharacter>,
+    pub unknown: Vec<EquipCharacter>,
+}
+
+fn item_code(item_data: &str) -> Option<u32> {
+    item_data.get(0..6).and_then(|code| u32::from_str_radix(code, 16).ok())
+}
+
+fn restriction(item_data: &str) -> Option<MagClass> {
+    item_code(item_data).and_then(|code| Config::equip_restrictions().get(&code).copied())
+}
+
+// Class check against an armor or unit's class line, `None` when it has no known restriction.
+fn class_allowed(item_data: &str, class: &str) -> Option<bool> {
+    restriction(item_data).map(|line| class_line(class) == line)
+}
+
+// A failed requirement rules the character out even when the class check is unknown.
+fn unless_failed(class_ok: Option<bool>, requirements_met: bool) -> Option<bool> {
+    if requirements_met { class_ok } else { Some(false) }
+}
+
+fn armor_allowed(item_data: &str, character: &EquipCharacter) -> Option<bool> {
+    let level_requirement = item_code(item_data)
+        .and_then(armor_reference)
+        .and_then(|reference| reference.level_requirement);
+    let level_ok = !matches!(level_requirement, Some(level) if character.level < level);
+
+    unless_failed(class_allowed(item_data, &character.class), level_ok)
+}
+
+// S-rank weapon types 0x70 to 0x7B are the S-rank versions of Saber through Wand (0x01 to
+// 0x0C) and follow the same class rules. The later types have no vanilla counterpart here.
+fn srank_classes(item_data: &str) -> Option<Vec<String>> {
+    let srank_type = (item_code(item_data)? >> 8) & 0xFF;
+    if !(0x70..=0x7B).contains(&srank_type) {
+        return None;
+    }
+
+    let abbreviations = Config::class_abbreviations();
+    let base = weapon_info().remove(&((srank_type - 0x6F) << 8))?;
+
+    Some(base.classes
+        .iter()
+        .map(|class| String::from(*abbreviations.get(class).unwrap_or(class)))
+        .collect())
+}
+
+/// Whether a character can equip or use an item, `None` when the data to decide is missing.
+/// Weapons are checked against the reference classes and stat requirements, S-rank weapons
+/// against the classes of their weapon type, armor against its level requirement and class
+/// line, tech disks against the class's technique level cap. Every class can equip any mag.
+/// A failed stat or level requirement is still a `Some(false)` without class data.
+pub fn can_equip(item: &DBItem, character: &EquipCharacter) -> Option<bool> {
+    match item {
+        DBItem::Weapon { reference, .. } => {
+            let reference = reference.as_ref()?;
+            let class_ok = if reference.classes.is_empty() {
+                None
+            } else {
+                Some(reference.classes.contains(&character.class))
+            };
+            let requirements_met = character.atp >= reference.requirement_atp
+                && character.ata >= reference.requirement_ata
+                && character.mst >= reference.requirement_mst;
+
+            unless_failed(class_ok, requirements_met)
+        },
+        DBItem::SRankWeapon { item_data, .. } => Some(srank_classes(item_data)?.contains(&character.class)),
+        DBItem::Mag { .. } => Some(true),
+        DBItem::Frame { item_data, .. } | DBItem::Barrier { item_data, .. } => armor_allowed(item_data, character),
+        DBItem::Unit { item_data, .. } => class_allowed(item_data, &character.class),
+        DBItem::Tech { item_data, level, .. } => {
+            let tech = item_data.get(8..10).and_then(|tech| u8::from_str_radix(tech, 16).ok())?;
+            Some(*level <= tech_level_cap(&character.class, tech))
+        },
+        DBItem::Tool { .. } | DBItem::Other { .. } | DBItem::Meseta { .. } => Some(false),
+    }
+}
+
+/// Lists, for every equippable item, the characters on the account that can use it.
+pub fn equip_compatibility(items: &[DBItem], characters: &[EquipCharacter]) -> Vec<ItemEligibility> {
+    items
+        .iter()
+        .filter_map(|item| {
+            let (item_id, item_type, name, character_id, storage_type) = match item {
+                DBItem::Weapon { id, name, character_id, storage_type, .. } => (id, "WEAPON", name, character_id, storage_type),
+                DBItem::SRankWeapon { id, name, character_id, storage_type, .. } => (id, "SRANK_WEAPON", name, character_id, storage_type),
+                DBItem::Frame { id, name, character_id, storage_type, .. } => (id, "FRAME", name, character_id, storage_type),
+                DBItem::Barrier { id, name, character_id, storage_type, .. } => (id, "BARRIER", name, character_id, storage_type),
+                DBItem::Unit { id, name, character_id, storage_type, .. } => (id, "UNIT", name, character_id, storage_type),
+                DBItem::Mag { id, name, character_id, storage_type, .. } => (id, "MAG", name, character_id, storage_type),
+                DBItem::Tech { id, name, character_id, storage_type, .. } => (id, "TECH", name, character_id, storage_type),
+                DBItem::Tool { .. } | DBItem::Other { .. } | DBItem::Meseta { .. } => return None,
+            };
+
+            let (eligible, unknown) = characters
+                .iter()
+                .filter(|character| can_equip(item, character) != Some(false))
+                .cloned()
+                .partition(|character| can_equip(item, character).is_some());
+
+            Some(ItemEligibility {
+                item_id: *item_id,
+                item_type: String::from(item_type),
+                name: name.clone(),
+                character_id: *character_id,
+                storage_type: storage_type.clone(),
+                eligible,
+                unknown,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lib::db::WeaponReference;
+
+    fn character(class: &str, level: u8, atp: u32) -> EquipCharacter {
+        EquipCharacter {
+            character_id: 1,
+            name: String::from(class),
+            class: String::from(class),
+            level,
+            atp,
+            ata: 100,
+            mst: 100,
+        }
+    }
+
+    fn weapon(classes: &[&str], requirement_atp: u32) -> DBItem {
+        DBItem::Weapon {
+            id: 1,
+            account_id: 1,
+            character_id: 0,
+            storage_type: String::from("SHARED_BANK"),
+            type_: 1,
+            name: String::from("Hammer"),
+            special: String::new(),
+            special_code: String::new(),
+            grind: 0,
+            native: 0,
+            a_beast: 0,
+            machine: 0,
+            dark: 0,
+            hit: 0,
+            tekked: true,
+            rare: true,
+            item_data: String::from("00DB00000000000000000000"),
+            account_type: String::from("NORMAL"),
+            lang: String::from("EN"),
+            kills: None,
+            kills_remaining: None,
+            wrapped: false,
+            reference: Some(WeaponReference {
+                min_atp: 120,
+                max_atp: 185,
+                max_grind: 0,
+                ata: 35,
+                requirement_atp,
+                requirement_ata: 0,
+                requirement_mst: 0,
+                classes: classes.iter().map(|class| String::from(*class)).collect(),
+            }),
+        }
+    }
+
+    fn tech(item_data: &str, level: u8) -> DBItem {
+        DBItem::Tech {
+            id: 2,
+            account_id: 1,
+            character_id: 0,
+            storage_type: String::from("BANK"),
+            type_: 6,
+            name: String::from("Disk"),
+            level,
+            item_data: String::from(item_data),
+            account_type: String::from("NORMAL"),
+            lang: String::from("EN"),
+            wrapped: false,
+        }
+    }
+
+    fn srank(item_data: &str) -> DBItem {
+        DBItem::SRankWeapon {
+            id: 4,
+            account_id: 1,
+            character_id: 0,
+            storage_type: String::from("BANK"),
+            type_: 8,
+            name: String::from("S-Rank"),
+            grind: 0,
+            special: String::new(),
+            special_code: String::new(),
+            item_data: String::from(item_data),
+            account_type: String::from("NORMAL"),
+            lang: String::from("EN"),
+            wrapped: false,
+        }
+    }
+
+    fn mag() -> DBItem {
+        DBItem::Mag {
+            id: 5,
+            account_id: 1,
+            character_id: 0,
+            storage_type: String::from("BANK"),
+            type_: 5,
+            name: String::from("Mag"),
+            level: 5,
+            sync: 0,
+            iq: 0,
+            color: String::new(),
+            rgb: String::new(),
+            def: 5,
+            pow: 0,
+            dex: 0,
+            mind: 0,
+            pbs: String::new(),
+            item_data: String::from("020005000000000000000000"),
+            account_type: String::from("NORMAL"),
+            lang: String::from("EN"),
+            evolution_stage: 0,
+            affinity: None,
+            wrapped: false,
+        }
+    }
+
+    fn unit(item_data: &str) -> DBItem {
+        DBItem::Unit {
+            id: 3,
+            account_id: 1,
+            character_id: 0,
+            storage_type: String::from("BANK"),
+            type_: 4,
+            name: String::from("Unit"),
+            item_data: String::from(item_data),
+            account_type: String::from("NORMAL"),
+            lang: String::from("EN"),
+            modifier: 0,
+            effect: None,
+            kills: None,
+            kills_remaining: None,
+            wrapped: false,
+        }
+    }
+
+    #[test]
+    fn weapon_classes_and_requirements() {
+        let saber = weapon(&["HUmar", "HUcast"], 0);
+        assert_eq!(can_equip(&saber, &character("HUcast", 1, 0)), Some(true));
+        assert_eq!(can_equip(&saber, &character("FOmar", 1, 0)), Some(false));
+
+        let heavy = weapon(&["HUcast"], 129);
+        assert_eq!(can_equip(&heavy, &character("HUcast", 1, 128)), Some(false));
+        assert_eq!(can_equip(&heavy, &character("HUcast", 1, 129)), Some(true));
+    }
+
+    #[test]
+    fn weapons_without_class_data_are_unknown() {
+        let hammer = weapon(&[], 129);
+        assert_eq!(can_equip(&hammer, &character("FOnewearl", 1, 200)), None);
+        // Too weak is known regardless of class.
+        assert_eq!(can_equip(&hammer, &character("FOnewearl", 1, 100)), Some(false));
+
+        let result = equip_compatibility(&[hammer], &[character("HUcast", 1, 200), character("FOmar", 1, 100)]);
+        assert!(result[0].eligible.is_empty());
+        assert_eq!(result[0].unknown.len(), 1);
+        assert_eq!(result[0].unknown[0].class, "HUcast");
+    }
+
+    #[test]
+    fn class_line_restrictions() {
+        let smartlink = unit("010351000000");
+        assert_eq!(can_equip(&smartlink, &character("RAmar", 1, 0)), Some(true));
+        assert_eq!(can_equip(&smartlink, &character("FOmar", 1, 0)), Some(false));
+        // Knight/Power has no class line in the data.
+        assert_eq!(can_equip(&unit("010300000000"), &character("FOmar", 1, 0)), None);
+    }
+
+    #[test]
+    fn srank_weapons_follow_their_weapon_type() {
+        let srank_saber = srank("007000000000000000000000");
+        assert_eq!(can_equip(&srank_saber, &character("HUcast", 1, 0)), Some(true));
+        assert_eq!(can_equip(&srank_saber, &character("FOnewearl", 1, 0)), Some(true));
+
+        let srank_rifle = srank("007600000000000000000000");
+        assert_eq!(can_equip(&srank_rifle, &character("RAmarl", 1, 0)), Some(true));
+        assert_eq!(can_equip(&srank_rifle, &character("FOmar", 1, 0)), Some(false));
+
+        let srank_wand = srank("007B00000000000000000000");
+        assert_eq!(can_equip(&srank_wand, &character("HUcast", 1, 0)), Some(false));
+
+        assert_eq!(can_equip(&srank("008200000000000000000000"), &character("HUmar", 1, 0)), None);
+    }
+
+    #[test]
+    fn every_class_can_equip_a_mag() {
+        for class in ["HUcast", "RAmarl", "FOnewm"] {
+            assert_eq!(can_equip(&mag(), &character(class, 1, 0)), Some(true));
+        }
+    }
+
+    #[test]
+    fn tech_disks_respect_level_caps() {
+        // Grants is forces only, HUmar Foie stops at 15.
+        assert_eq!(can_equip(&tech("0300000009", 1), &character("HUmar", 50, 0)), Some(false));
+        assert_eq!(can_equip(&tech("0300000000", 15), &character("HUmar", 50, 0)), Some(true));
+        assert_eq!(can_equip(&tech("0300000000", 16), &character("HUmar", 50, 0)), Some(false));
+    }
+}
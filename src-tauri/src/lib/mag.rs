@@ -0,0 +1,347 @@
+use serde::{Serialize, Deserialize};
+use crate::config::config::Config;
+use crate::config::mag_feed::{FEED_ITEMS, FeedResult, feed_tables, feed_table};
+use crate::parser::types::{MagClass, MagStats};
+
+const MAX_LEVEL: u16 = 200;
+const MAX_IQ: i16 = 200;
+const MAX_SYNC: i16 = 120;
+const MAX_FEEDINGS: usize = 5000;
+
+/// A mag as the simulator sees it. Stats are kept in hundredths of a level, the way
+/// they're stored in the item.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct MagState {
+    pub mag_id: u8,
+    pub stage: u8,
+    pub sync: u8,
+    pub iq: u8,
+    pub def: u16,
+    pub pow: u16,
+    pub dex: u16,
+    pub mind: u16,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct PredictedEvolution {
+    pub level: u16,
+    pub stage: u8,
+    pub mag_id: Option<u8>,
+    pub affinity: Option<MagClass>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct FeedStock {
+    pub item_code: u32,
+    pub name: String,
+    pub number: u32,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct FeedStep {
+    pub item_code: u32,
+    pub name: String,
+    pub count: u32,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct FeedingPlan {
+    pub steps: Vec<FeedStep>,
+    pub result: MagStats,
+    pub reached: bool,
+    pub evolutions: Vec<PredictedEvolution>,
+}
+
+impl MagState {
+    pub fn from_item_data(item_data: &[u8]) -> Self {
+        let mag_id = item_data[1];
+        let stat = |offset: usize| u16::from_le_bytes([item_data[offset], item_data[offset + 1]]);
+        let stage = Config::mag_evolutions()
+            .get(&mag_id)
+            .map(|(stage, _)| *stage)
+            .unwrap_or(if mag_id >= 0x28 { 4 } else { 3 });
+
+        MagState {
+            mag_id,
+            stage,
+            sync: item_data[16],
+            iq: item_data[17],
+            def: stat(4),
+            pow: stat(6),
+            dex: stat(8),
+            mind: stat(10),
+        }
+    }
+
+    pub fn level(&self) -> u16 {
+        (self.def / 100) + (self.pow / 100) + (self.dex / 100) + (self.mind / 100)
+    }
+
+    pub fn stats(&self) -> MagStats {
+        MagStats {
+            def: self.def / 100,
+            pow: self.pow / 100,
+            dex: self.dex / 100,
+            mind: self.mind / 100,
+        }
+    }
+}
+
+pub fn class_line(class: &str) -> MagClass {
+    match class.get(0..2).map(|prefix| prefix.to_uppercase()).as_deref() {
+        Some("RA") => MagClass::Ranger,
+        Some("FO") => MagClass::Force,
+        _ => MagClass::Hunter,
+    }
+}
+
+// Level 35 forms follow the highest of pow, dex and mind, with ties going to the first.
+fn level_35_form(state: &MagState, class: MagClass) -> u8 {
+    let forms = match class {
+        MagClass::Hunter => [0x0E, 0x0F, 0x04],
+        MagClass::Ranger => [0x02, 0x03, 0x0B],
+        MagClass::Force => [0x1A, 0x1B, 0x14],
+    };
+
+    if state.pow >= state.dex && state.pow >= state.mind {
+        forms[0]
+    } else if state.dex >= state.mind {
+        forms[1]
+    } else {
+        forms[2]
+    }
+}
+
+/// Predicts the next evolution of a mag fed by a character of `class`. The level 10 and 35
+/// forms are named, level 50+ forms also depend on section ID and stat ratios so only their
+/// level and stage are given.
+pub fn next_evolution(state: &MagState, class: &str) -> Option<PredictedEvolution> {
+    let class = class_line(class);
+
+    match state.stage {
+        0 => Some(PredictedEvolution {
+            level: 10,
+            stage: 1,
+            mag_id: Some(match class {
+                MagClass::Hunter => 0x01,
+                MagClass::Ranger => 0x0D,
+                MagClass::Force => 0x19,
+            }),
+            affinity: Some(class),
+        }),
+        1 => Some(PredictedEvolution {
+            level: 35,
+            stage: 2,
+            mag_id: Some(level_35_form(state, class)),
+            affinity: Some(class),
+        }),
+        2 => Some(PredictedEvolution { level: 50, stage: 3, mag_id: None, affinity: None }),
+        3 if state.level() < MAX_LEVEL => Some(PredictedEvolution {
+            level: (state.level() / 5 + 1) * 5,
+            stage: 3,
+            mag_id: None,
+            affinity: None,
+        }),
+        _ => None,
+    }
+}
+
+fn apply_stat(value: u16, change: i16, level: u16) -> u16 {
+    let changed = (value as i32 + change as i32).max(0) as u16;
+
+    // Stats stop growing once the mag is at the level cap.
+    if level >= MAX_LEVEL && changed / 100 > value / 100 {
+        value
+    } else {
+        changed
+    }
+}
+
+/// Feeds one item and evolves the mag if it reached an evolution level.
+pub fn feed(state: &MagState, result: &FeedResult, class: &str) -> (MagState, Option<PredictedEvolution>) {
+    let (sync, iq, def, pow, dex, mind) = *result;
+    let level = state.level();
+    let mut fed = MagState {
+        sync: (state.sync as i16 + sync as i16).clamp(0, MAX_SYNC) as u8,
+        iq: (state.iq as i16 + iq as i16).clamp(0, MAX_IQ) as u8,
+        def: apply_stat(state.def, def, level),
+        pow: apply_stat(state.pow, pow, level),
+        dex: apply_stat(state.dex, dex, level),
+        mind: apply_stat(state.mind, mind, level),
+        ..state.clone()
+    };
+
+    let evolution = next_evolution(state, class)
+        .filter(|evolution| level < evolution.level && fed.level() >= evolution.level);
+
+    if let Some(evolution) = &evolution {
+        fed.stage = evolution.stage;
+        if let Some(mag_id) = evolution.mag_id {
+            fed.mag_id = mag_id;
+        }
+    }
+
+    (fed, evolution)
+}
+
+// How far each stat is from its target level, in hundredths of a level.
+fn distance(state: &MagState, target: &MagStats) -> u32 {
+    [(state.def, target.def), (state.pow, target.pow), (state.dex, target.dex), (state.mind, target.mind)]
+        .iter()
+        .map(|&(value, target)| {
+            let low = target as u32 * 100;
+            let high = low + 99;
+            let value = value as u32;
+
+            if value < low { low - value } else { value.saturating_sub(high) }
+        })
+        .sum()
+}
+
+/// Plans a feeding order that brings the mag as close as possible to `target` using only
+/// the tools in `stock`. Each step feeds whichever tool gets closest to the target, and
+/// planning stops once the target is reached, no tool helps anymore, or the mag's feed
+/// table isn't known (level 50+ forms can't be predicted, so neither can their table).
+pub fn plan_feeding(state: &MagState, class: &str, target: &MagStats, stock: &[FeedStock]) -> FeedingPlan {
+    let tables = feed_tables();
+    let mut remaining: Vec<u32> = stock.iter().map(|tool| tool.number).collect();
+    let mut state = state.clone();
+    let mut steps: Vec<FeedStep> = Vec::new();
+    let mut evolutions = Vec::new();
+    let mut table_id = Some(feed_table(state.mag_id));
+
+    for _ in 0..MAX_FEEDINGS {
+        let current = distance(&state, target);
+        if current == 0 {
+            break;
+        }
+
+        let table = match table_id.and_then(|id| tables.get(&id)) {
+            Some(table) => table,
+            None => break,
+        };
+        let best = stock
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| remaining[*i] > 0)
+            .filter_map(|(i, tool)| {
+                let slot = FEED_ITEMS.iter().position(|&code| code == tool.item_code)?;
+                let (fed, evolution) = feed(&state, &table[slot], class);
+                Some((distance(&fed, target), i, fed, evolution))
+            })
+            .min_by_key(|(distance, i, _, _)| (*distance, *i));
+
+        let (i, fed, evolution) = match best {
+            Some((next, i, fed, evolution)) if next < current => (i, fed, evolution),
+            _ => break,
+        };
+
+        // Level 50+ form changes stay within stage 3, only stage changes go into the plan.
+        remaining[i] -= 1;
+        if let Some(evolution) = &evolution {
+            table_id = evolution.mag_id.map(feed_table);
+        }
+        evolutions.extend(evolution.filter(|evolution| evolution.stage != state.stage));
+        state = fed;
+
+        match steps.last_mut() {
+            Some(step) if step.item_code == stock[i].item_code => step.count += 1,
+            _ => steps.push(FeedStep { item_code: stock[i].item_code, name: stock[i].name.clone(), count: 1 }),
+        }
+    }
+
+    FeedingPlan {
+        reached: distance(&state, target) == 0,
+        result: state.stats(),
+        steps,
+        evolutions,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mag(mag_id: u8, stage: u8, levels: [u16; 4]) -> MagState {
+        MagState {
+            mag_id,
+            stage,
+            sync: 0,
+            iq: 0,
+            def: levels[0] * 100,
+            pow: levels[1] * 100,
+            dex: levels[2] * 100,
+            mind: levels[3] * 100,
+        }
+    }
+
+    #[test]
+    fn classes_map_to_their_line() {
+        assert_eq!(class_line("HUcast"), MagClass::Hunter);
+        assert_eq!(class_line("RAmarl"), MagClass::Ranger);
+        assert_eq!(class_line("FOnewearl"), MagClass::Force);
+    }
+
+    #[test]
+    fn level_10_form_follows_the_class() {
+        let fresh = mag(0x00, 0, [5, 0, 0, 0]);
+
+        let varuna = next_evolution(&fresh, "HUmar").unwrap();
+        assert_eq!((varuna.level, varuna.stage, varuna.mag_id), (10, 1, Some(0x01)));
+        assert_eq!(next_evolution(&fresh, "RAcast").unwrap().mag_id, Some(0x0D));
+        assert_eq!(next_evolution(&fresh, "FOmarl").unwrap().mag_id, Some(0x19));
+    }
+
+    #[test]
+    fn level_35_form_follows_the_highest_stat() {
+        assert_eq!(next_evolution(&mag(0x01, 1, [5, 20, 5, 0]), "HUmar").unwrap().mag_id, Some(0x0E));
+        assert_eq!(next_evolution(&mag(0x01, 1, [5, 5, 20, 0]), "HUmar").unwrap().mag_id, Some(0x0F));
+        assert_eq!(next_evolution(&mag(0x01, 1, [5, 5, 0, 20]), "HUmar").unwrap().mag_id, Some(0x04));
+        // Ties go to pow, then dex.
+        assert_eq!(next_evolution(&mag(0x19, 1, [5, 10, 10, 0]), "FOmar").unwrap().mag_id, Some(0x1A));
+        assert_eq!(next_evolution(&mag(0x0D, 1, [5, 0, 10, 10]), "RAmar").unwrap().mag_id, Some(0x03));
+    }
+
+    #[test]
+    fn later_evolutions_only_give_the_level() {
+        let level_50 = next_evolution(&mag(0x0E, 2, [5, 30, 5, 0]), "HUmar").unwrap();
+        assert_eq!((level_50.level, level_50.stage, level_50.mag_id), (50, 3, None));
+
+        assert_eq!(next_evolution(&mag(0x28, 3, [5, 47, 0, 0]), "HUmar").unwrap().level, 55);
+        assert!(next_evolution(&mag(0x28, 3, [5, 195, 0, 0]), "HUmar").is_none());
+    }
+
+    #[test]
+    fn feeding_past_level_10_evolves() {
+        let (fed, evolution) = feed(&mag(0x00, 0, [5, 4, 0, 0]), &(0, 0, 0, 100, 0, 0), "RAmarl");
+
+        assert_eq!(fed.level(), 10);
+        assert_eq!((fed.stage, fed.mag_id), (1, 0x0D));
+        assert_eq!(evolution.unwrap().affinity, Some(MagClass::Ranger));
+    }
+
+    #[test]
+    fn stage_comes_from_the_mag_id() {
+        let mut item_data = vec![0x02, 0x0E, 0x05, 0x00];
+        item_data.resize(20, 0);
+
+        assert_eq!(MagState::from_item_data(&item_data).stage, 2);
+    }
+
+    #[test]
+    fn feed_table_follows_the_mag_id() {
+        // Vayu and Tapas are both level 35 forms, but feed from different tables.
+        assert_eq!(feed_table(0x04), 4);
+        assert_eq!(feed_table(0x0B), 3);
+        assert_eq!(feed_table(0x0E), 2);
+        assert_eq!(feed_table(0x28), 6);
+
+        // A Monomate gives Vayu 0.05 pow from table 4, table 2 would give 0.13 and level it up.
+        let vayu = MagState { pow: 3090, ..mag(0x04, 2, [5, 0, 0, 0]) };
+        let stock = [FeedStock { item_code: 0x030000, name: String::from("Monomate"), number: 1 }];
+        let plan = plan_feeding(&vayu, "HUmar", &MagStats { def: 5, pow: 31, dex: 0, mind: 0 }, &stock);
+        assert_eq!(plan.steps.len(), 1);
+        assert_eq!(plan.result.pow, 30);
+        assert!(!plan.reached);
+    }
+}
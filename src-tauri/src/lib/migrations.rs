@@ -0,0 +1,243 @@
+use rusqlite::{Connection, Result as SqlResult};
+
+/// Schema changes for databases created by older versions, stored in `PRAGMA user_version`.
+/// Databases from before versioning are version 0. Migration `n` takes a database from
+/// version `n - 1` to `n`, so new ones are only ever appended.
+const MIGRATIONS: &[&str] = &[
+    // 1: base stats on characters.
+    "ALTER TABLE character ADD COLUMN atp INTEGER NOT NULL DEFAULT 0;
+    ALTER TABLE character ADD COLUMN mst INTEGER NOT NULL DEFAULT 0;
+    ALTER TABLE character ADD COLUMN evp INTEGER NOT NULL DEFAULT 0;
+    ALTER TABLE character ADD COLUMN hp INTEGER NOT NULL DEFAULT 0;
+    ALTER TABLE character ADD COLUMN dfp INTEGER NOT NULL DEFAULT 0;
+    ALTER TABLE character ADD COLUMN ata INTEGER NOT NULL DEFAULT 0;
+    ALTER TABLE character ADD COLUMN lck INTEGER NOT NULL DEFAULT 0;",
+    // 2: material usage on characters.
+    "ALTER TABLE character ADD COLUMN power_materials INTEGER NOT NULL DEFAULT 0;
+    ALTER TABLE character ADD COLUMN mind_materials INTEGER NOT NULL DEFAULT 0;
+    ALTER TABLE character ADD COLUMN evade_materials INTEGER NOT NULL DEFAULT 0;
+    ALTER TABLE character ADD COLUMN def_materials INTEGER NOT NULL DEFAULT 0;
+    ALTER TABLE character ADD COLUMN luck_materials INTEGER NOT NULL DEFAULT 0;
+    ALTER TABLE character ADD COLUMN hp_materials INTEGER NOT NULL DEFAULT 0;
+    ALTER TABLE character ADD COLUMN tp_materials INTEGER NOT NULL DEFAULT 0;",
+    // 3: story progress moved to its own table. The old episode progress columns are kept
+    // for existing characters until the flag-based progress is checked against real saves,
+    // but SQLite can't drop their NOT NULL in place, so the character table is rebuilt.
+    "CREATE TABLE character_rebuilt (
+        id INTEGER PRIMARY KEY AUTOINCREMENT,
+        account_id INTEGER NOT NULL,
+        slot INTEGER NOT NULL UNIQUE,
+        mode TEXT NOT NULL,
+        guild_card INTEGER NOT NULL,
+        name TEXT NOT NULL,
+        class TEXT NOT NULL,
+        section_id TEXT NOT NULL,
+        level INTEGER NOT NULL,
+        experience INTEGER NOT NULL,
+        ep1_progress TEXT DEFAULT NULL,
+        ep2_progress TEXT DEFAULT NULL,
+        image BLOB DEFAULT NULL,
+        atp INTEGER NOT NULL DEFAULT 0,
+        mst INTEGER NOT NULL DEFAULT 0,
+        evp INTEGER NOT NULL DEFAULT 0,
+        hp INTEGER NOT NULL DEFAULT 0,
+        dfp INTEGER NOT NULL DEFAULT 0,
+        ata INTEGER NOT NULL DEFAULT 0,
+        lck INTEGER NOT NULL DEFAULT 0,
+        power_materials INTEGER NOT NULL DEFAULT 0,
+        mind_materials INTEGER NOT NULL DEFAULT 0,
+        evade_materials INTEGER NOT NULL DEFAULT 0,
+        def_materials INTEGER NOT NULL DEFAULT 0,
+        luck_materials INTEGER NOT NULL DEFAULT 0,
+        hp_materials INTEGER NOT NULL DEFAULT 0,
+        tp_materials INTEGER NOT NULL DEFAULT 0
+    );
+    INSERT INTO character_rebuilt
+    SELECT
+        id, account_id, slot, mode, guild_card, name, class, section_id, level, experience,
+        ep1_progress, ep2_progress, image,
+        atp, mst, evp, hp, dfp, ata, lck,
+        power_materials, mind_materials, evade_materials, def_materials,
+        luck_materials, hp_materials, tp_materials
+    FROM character;
+    DROP TABLE character;
+    ALTER TABLE character_rebuilt RENAME TO character;",
+    // 4: mag evolution stage and class affinity.
+    "ALTER TABLE mag ADD COLUMN evolution_stage INTEGER NOT NULL DEFAULT 0;
+    ALTER TABLE mag ADD COLUMN affinity TEXT DEFAULT NULL;",
+    // 5: unit +/- modifiers.
+    "ALTER TABLE unit ADD COLUMN modifier INTEGER NOT NULL DEFAULT 0;",
+    // 6: kill counters on weapons and units.
+    "ALTER TABLE weapon ADD COLUMN kills INTEGER DEFAULT NULL;
+    ALTER TABLE weapon ADD COLUMN kills_remaining INTEGER DEFAULT NULL;
+    ALTER TABLE unit ADD COLUMN kills INTEGER DEFAULT NULL;
+    ALTER TABLE unit ADD COLUMN kills_remaining INTEGER DEFAULT NULL;",
+    // 7: wrapped presents.
+    "ALTER TABLE weapon ADD COLUMN wrapped INTEGER NOT NULL DEFAULT 0 CHECK(wrapped IN (0, 1));
+    ALTER TABLE srank_weapon ADD COLUMN wrapped INTEGER NOT NULL DEFAULT 0 CHECK(wrapped IN (0, 1));
+    ALTER TABLE frame ADD COLUMN wrapped INTEGER NOT NULL DEFAULT 0 CHECK(wrapped IN (0, 1));
+    ALTER TABLE barrier ADD COLUMN wrapped INTEGER NOT NULL DEFAULT 0 CHECK(wrapped IN (0, 1));
+    ALTER TABLE unit ADD COLUMN wrapped INTEGER NOT NULL DEFAULT 0 CHECK(wrapped IN (0, 1));
+    ALTER TABLE mag ADD COLUMN wrapped INTEGER NOT NULL DEFAULT 0 CHECK(wrapped IN (0, 1));
+    ALTER TABLE tech ADD COLUMN wrapped INTEGER NOT NULL DEFAULT 0 CHECK(wrapped IN (0, 1));
+    ALTER TABLE tool ADD COLUMN wrapped INTEGER NOT NULL DEFAULT 0 CHECK(wrapped IN (0, 1));
+    ALTER TABLE other ADD COLUMN wrapped INTEGER NOT NULL DEFAULT 0 CHECK(wrapped IN (0, 1));",
+    // 8: versions of the reference tables filled from built-in data.
+    "CREATE TABLE IF NOT EXISTS reference_version (
+        name TEXT PRIMARY KEY,
+        hash TEXT NOT NULL
+    );",
+];
+
+pub const SCHEMA_VERSION: usize = MIGRATIONS.len();
+
+/// Whether the database predates this run, as opposed to being created by it.
+pub fn has_schema(conn: &Connection) -> SqlResult<bool> {
+    conn.query_row(
+        "SELECT COUNT(*) FROM sqlite_master WHERE type = 'table' AND name = 'character'",
+        [],
+        |row| row.get::<_, u32>(0).map(|count| count > 0),
+    )
+}
+
+/// Brings an existing database up to `SCHEMA_VERSION`. New databases are created with the
+/// current schema, so they only get the version stamped.
+pub fn migrate(conn: &Connection, existing: bool) -> SqlResult<()> {
+    if existing {
+        let version: usize = conn.pragma_query_value(None, "user_version", |row| row.get(0))?;
+
+        for migration in MIGRATIONS.iter().skip(version) {
+            conn.execute_batch(migration)?;
+        }
+    }
+
+    conn.pragma_update(None, "user_version", SCHEMA_VERSION)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The character table and item tables as the first released version created them,
+    // trimmed to the columns the migrations care about.
+    const VERSION_0: &str = "
+        CREATE TABLE character (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            account_id INTEGER NOT NULL,
+            slot INTEGER NOT NULL UNIQUE,
+            mode TEXT NOT NULL,
+            guild_card INTEGER NOT NULL,
+            name TEXT NOT NULL,
+            class TEXT NOT NULL,
+            section_id TEXT NOT NULL,
+            level INTEGER NOT NULL,
+            experience INTEGER NOT NULL,
+            ep1_progress TEXT NOT NULL,
+            ep2_progress TEXT NOT NULL,
+            image BLOB DEFAULT NULL
+        );
+        INSERT INTO character (account_id, slot, mode, guild_card, name, class, section_id, level, experience, ep1_progress, ep2_progress)
+        VALUES (1, 0, 'NORMAL', 42004063, 'Zero', 'HUcast', 'Oran', 10, 1000, 'Stage 3 Cleared!', '');
+        CREATE TABLE weapon (id INTEGER PRIMARY KEY AUTOINCREMENT, item_data TEXT NOT NULL);
+        CREATE TABLE srank_weapon (id INTEGER PRIMARY KEY AUTOINCREMENT, item_data TEXT NOT NULL);
+        CREATE TABLE frame (id INTEGER PRIMARY KEY AUTOINCREMENT, item_data TEXT NOT NULL);
+        CREATE TABLE barrier (id INTEGER PRIMARY KEY AUTOINCREMENT, item_data TEXT NOT NULL);
+        CREATE TABLE unit (id INTEGER PRIMARY KEY AUTOINCREMENT, item_data TEXT NOT NULL);
+        CREATE TABLE mag (id INTEGER PRIMARY KEY AUTOINCREMENT, item_data TEXT NOT NULL);
+        CREATE TABLE tech (id INTEGER PRIMARY KEY AUTOINCREMENT, item_data TEXT NOT NULL);
+        CREATE TABLE tool (id INTEGER PRIMARY KEY AUTOINCREMENT, item_data TEXT NOT NULL);
+        CREATE TABLE other (id INTEGER PRIMARY KEY AUTOINCREMENT, item_data TEXT NOT NULL);
+    ";
+
+    fn columns(conn: &Connection, table: &str) -> Vec<String> {
+        let mut stmt = conn.prepare(&format!("PRAGMA table_info({})", table)).unwrap();
+        let columns = stmt.query_map([], |row| row.get(1)).unwrap();
+        columns.collect::<SqlResult<Vec<String>>>().unwrap()
+    }
+
+    fn version(conn: &Connection) -> usize {
+        conn.pragma_query_value(None, "user_version", |row| row.get(0)).unwrap()
+    }
+
+    #[test]
+    fn version_0_database_is_migrated() {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch(VERSION_0).unwrap();
+
+        assert!(has_schema(&conn).unwrap());
+        migrate(&conn, true).unwrap();
+
+        let character = columns(&conn, "character");
+        let added = [
+            "atp", "mst", "evp", "hp", "dfp", "ata", "lck",
+            "power_materials", "mind_materials", "evade_materials", "def_materials",
+            "luck_materials", "hp_materials", "tp_materials",
+        ];
+        for column in added {
+            assert!(character.iter().any(|name| name == column), "character.{} is missing", column);
+        }
+
+        let level: u8 = conn.query_row("SELECT level FROM character WHERE slot = 0", [], |row| row.get(0)).unwrap();
+        assert_eq!(level, 10);
+        assert_eq!(columns(&conn, "reference_version"), ["name", "hash"]);
+        assert_eq!(version(&conn), SCHEMA_VERSION);
+    }
+
+    #[test]
+    fn item_tables_get_the_new_columns() {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch(VERSION_0).unwrap();
+        migrate(&conn, true).unwrap();
+
+        let added = [
+            ("mag", "evolution_stage"), ("mag", "affinity"),
+            ("unit", "modifier"),
+            ("weapon", "kills"), ("weapon", "kills_remaining"), ("unit", "kills"), ("unit", "kills_remaining"),
+            ("weapon", "wrapped"), ("srank_weapon", "wrapped"), ("frame", "wrapped"),
+            ("barrier", "wrapped"), ("unit", "wrapped"), ("mag", "wrapped"),
+            ("tech", "wrapped"), ("tool", "wrapped"), ("other", "wrapped"),
+        ];
+        for (table, column) in added {
+            assert!(columns(&conn, table).iter().any(|name| name == column), "{}.{} is missing", table, column);
+        }
+    }
+
+    #[test]
+    fn characters_insert_without_episode_progress() {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch(VERSION_0).unwrap();
+        migrate(&conn, true).unwrap();
+
+        // Existing characters keep their old progress.
+        let progress: String = conn.query_row("SELECT ep1_progress FROM character WHERE slot = 0", [], |row| row.get(0)).unwrap();
+        assert_eq!(progress, "Stage 3 Cleared!");
+
+        conn.execute(
+            "INSERT INTO character (account_id, slot, mode, guild_card, name, class, section_id, level, experience)
+            VALUES (1, 1, 'NORMAL', 42004064, 'One', 'FOnewm', 'Skyly', 1, 0)",
+            [],
+        ).unwrap();
+        let count: u32 = conn.query_row("SELECT COUNT(*) FROM character", [], |row| row.get(0)).unwrap();
+        assert_eq!(count, 2);
+    }
+
+    #[test]
+    fn migrating_twice_changes_nothing() {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch(VERSION_0).unwrap();
+        migrate(&conn, true).unwrap();
+        let before = columns(&conn, "character");
+
+        migrate(&conn, true).unwrap();
+        assert_eq!(columns(&conn, "character"), before);
+    }
+
+    #[test]
+    fn new_database_is_stamped_with_the_current_version() {
+        let conn = Connection::open_in_memory().unwrap();
+
+        assert!(!has_schema(&conn).unwrap());
+        migrate(&conn, false).unwrap();
+        assert_eq!(version(&conn), SCHEMA_VERSION);
+    }
+}
@@ -0,0 +1,115 @@
+use std::fs;
+use std::path::Path;
+use crate::command::db::SqlError;
+use crate::parser::types::CharacterAppearance;
+
+const TINT_STRENGTH: u32 = 64;
+const EMBLEM_SCALE: u32 = 4;
+
+struct Image {
+    width: u32,
+    height: u32,
+    pixels: Vec<u8>,
+}
+
+fn decode(data: &[u8]) -> Result<Image, SqlError> {
+    let mut decoder = png::Decoder::new(data);
+    decoder.set_transformations(png::Transformations::normalize_to_color8());
+
+    let mut reader = decoder.read_info()?;
+    let mut buffer = vec![0; reader.output_buffer_size()];
+    let info = reader.next_frame(&mut buffer)?;
+    let bytes = &buffer[..info.buffer_size()];
+
+    let pixels = match info.color_type {
+        png::ColorType::Rgba => bytes.to_vec(),
+        png::ColorType::Rgb => bytes.chunks(3).flat_map(|p| [p[0], p[1], p[2], 0xFF]).collect(),
+        png::ColorType::GrayscaleAlpha => bytes.chunks(2).flat_map(|p| [p[0], p[0], p[0], p[1]]).collect(),
+        png::ColorType::Grayscale => bytes.iter().flat_map(|&p| [p, p, p, 0xFF]).collect(),
+        png::ColorType::Indexed => return Err(SqlError::ImageError(String::from("Unexpanded palette image"))),
+    };
+
+    Ok(Image { width: info.width, height: info.height, pixels })
+}
+
+fn encode(image: &Image) -> Result<Vec<u8>, SqlError> {
+    let mut png_data = Vec::new();
+    {
+        let mut encoder = png::Encoder::new(&mut png_data, image.width, image.height);
+        encoder.set_color(png::ColorType::Rgba);
+        encoder.set_depth(png::BitDepth::Eight);
+
+        let mut writer = encoder.write_header()?;
+        writer.write_image_data(&image.pixels)?;
+    }
+
+    Ok(png_data)
+}
+
+// Multiplies every pixel by the hair color, blended in at TINT_STRENGTH / 255.
+fn tint(image: &mut Image, color: [u8; 3]) {
+    for pixel in image.pixels.chunks_mut(4) {
+        for channel in 0..3 {
+            let value = pixel[channel] as u32;
+            let multiplied = value * color[channel] as u32 / 255;
+            pixel[channel] = ((value * (255 - TINT_STRENGTH) + multiplied * TINT_STRENGTH) / 255) as u8;
+        }
+    }
+}
+
+// Draws the section ID emblem, scaled down with nearest neighbour, in the bottom right corner.
+fn overlay_emblem(image: &mut Image, emblem: &Image) {
+    let size = image.width.min(image.height) / EMBLEM_SCALE;
+    let left = image.width - size;
+    let top = image.height - size;
+
+    for y in 0..size {
+        for x in 0..size {
+            let source_x = x * emblem.width / size;
+            let source_y = y * emblem.height / size;
+            let source = ((source_y * emblem.width + source_x) * 4) as usize;
+            let target = (((top + y) * image.width + left + x) * 4) as usize;
+            let alpha = emblem.pixels[source + 3] as u32;
+
+            for channel in 0..3 {
+                let over = emblem.pixels[source + channel] as u32;
+                let under = image.pixels[target + channel] as u32;
+                image.pixels[target + channel] = ((over * alpha + under * (255 - alpha)) / 255) as u8;
+            }
+        }
+    }
+}
+
+// Class names are matched ignoring case, the bundled Hunewearl icon is named HUnewearl.png.
+fn find_class_default(class_defaults: &Path, class: &str) -> Result<Vec<u8>, SqlError> {
+    let file_name = format!("{}.png", class);
+
+    for entry in fs::read_dir(class_defaults)? {
+        let path = entry?.path();
+        let matches = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .map_or(false, |name| name.eq_ignore_ascii_case(&file_name));
+
+        if matches {
+            return Ok(fs::read(path)?);
+        }
+    }
+
+    Err(SqlError::ImageError(format!("No class default image for {}", class)))
+}
+
+/// Renders a portrait PNG from the class default icon in `public_dir`, tinted with the
+/// character's hair color and marked with its section ID emblem when one is bundled.
+pub fn render_portrait(public_dir: &Path, class: &str, section_id: &str, appearance: &CharacterAppearance) -> Result<Vec<u8>, SqlError> {
+    let mut portrait = decode(&find_class_default(&public_dir.join("class_defaults"), class)?)?;
+
+    tint(&mut portrait, appearance.hair_color);
+
+    let emblem_path = public_dir.join(format!("{}.png", section_id.to_lowercase()));
+    if emblem_path.exists() {
+        overlay_emblem(&mut portrait, &decode(&fs::read(emblem_path)?)?);
+    }
+
+    encode(&portrait)
+}
@@ -1,28 +1,31 @@
-use std::collections::Hashmap;
+use std::collections::HashMap;
+use serde::Serialize;
 
-#[derive(Debug, Serialize, Deserialize)]
+/// Reference data for every weapon, keyed by item code. Class lists use the short class
+/// names (HUmr, FOnl, ...).
+#[derive(Debug, Serialize, Hash)]
 pub struct Weapon {
-    name: String,
-    hex_code: String,
-    description: String,
-    _type: String,
-    rarity: u8,
-    maxstack: u8,
-    teampoints: u16,
-    weapon_type: String,
-    grind: u8,
-    ata: u8,
-    min_atp: u32,
-    max_atp: u32,
-    special: String,
-    targets: u8,
-    classes: Vec<String>,
-    notes: String,
-    total_min_atp: u32,
-    total_max_atp: u32,
-    requirement_atp: u32,
-    requirement_ata: u32,
-    requirement_mst: u32
+    pub name: &'static str,
+    pub hex_code: &'static str,
+    pub description: &'static str,
+    pub _type: &'static str,
+    pub rarity: u8,
+    pub maxstack: u8,
+    pub teampoints: u16,
+    pub weapon_type: &'static str,
+    pub grind: u8,
+    pub ata: u8,
+    pub min_atp: u32,
+    pub max_atp: u32,
+    pub special: &'static str,
+    pub targets: u8,
+    pub classes: &'static [&'static str],
+    pub notes: &'static str,
+    pub total_min_atp: u32,
+    pub total_max_atp: u32,
+    pub requirement_atp: u32,
+    pub requirement_ata: u32,
+    pub requirement_mst: u32
 }
 
 pub fn weapon_info() -> HashMap<u32, Weapon> {
@@ -42,7 +45,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 55,
             special: "Variable",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 110,
             total_max_atp: 125,
@@ -65,7 +68,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 100,
             special: "Variable",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 144,
             total_max_atp: 164,
@@ -88,7 +91,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 160,
             special: "Variable",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 180,
             total_max_atp: 220,
@@ -111,7 +114,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 220,
             special: "Variable",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 222,
             total_max_atp: 272,
@@ -134,7 +137,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 280,
             special: "Variable",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 276,
             total_max_atp: 316,
@@ -157,7 +160,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 282,
             special: "None",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 152,
             total_max_atp: 282,
@@ -180,7 +183,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 250,
             special: "Burning",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 210,
             total_max_atp: 250,
@@ -203,7 +206,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 1,
             special: "None",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 1,
             total_max_atp: 1,
@@ -226,7 +229,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 124,
             special: "None",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 148,
             total_max_atp: 160,
@@ -249,7 +252,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 376,
             special: "Lords",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 374,
             total_max_atp: 450,
@@ -272,7 +275,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 1,
             special: "None",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 1,
             total_max_atp: 1,
@@ -295,7 +298,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 1,
             special: "None",
             targets: 10,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 1,
             total_max_atp: 1,
@@ -318,7 +321,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 123,
             special: "None",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 100,
             total_max_atp: 123,
@@ -341,7 +344,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 320,
             special: "Freeze",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 310,
             total_max_atp: 370,
@@ -364,7 +367,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 1,
             special: "None",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 1,
             total_max_atp: 1,
@@ -387,7 +390,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 544,
             special: "Kings",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "FOmr", "FOml", "FOnm"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "FOmr", "FOml", "FOnm"],
             notes: "",
             total_min_atp: 549,
             total_max_atp: 562,
@@ -410,7 +413,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 585,
             special: "TrapVision",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 560,
             total_max_atp: 585,
@@ -433,7 +436,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 250,
             special: "None",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 288,
             total_max_atp: 338,
@@ -456,7 +459,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 301,
             special: "None",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 325,
             total_max_atp: 337,
@@ -479,7 +482,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 143,
             special: "None",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 128,
             total_max_atp: 171,
@@ -502,7 +505,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 149,
             special: "None",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 172,
             total_max_atp: 191,
@@ -525,7 +528,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 202,
             special: "None",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 261,
             total_max_atp: 274,
@@ -548,7 +551,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 109,
             special: "None",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 151,
             total_max_atp: 167,
@@ -571,7 +574,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 231,
             special: "None",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 260,
             total_max_atp: 279,
@@ -594,7 +597,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 340,
             special: "None",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm"],
             notes: "",
             total_min_atp: 470,
             total_max_atp: 510,
@@ -617,7 +620,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 168,
             special: "None",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 158,
             total_max_atp: 186,
@@ -640,7 +643,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 350,
             special: "Storm",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 358,
             total_max_atp: 368,
@@ -663,7 +666,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 368,
             special: "None",
             targets: 1,
-            classes: ["HUmr", "HUnl", "RAml", "FOmr", "FOml", "FOnm"],
+            classes: &["HUmr", "HUnl", "RAml", "FOmr", "FOml", "FOnm"],
             notes: "",
             total_min_atp: 378,
             total_max_atp: 386,
@@ -686,7 +689,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 590,
             special: "Spirit",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl"],
             notes: "",
             total_min_atp: 635,
             total_max_atp: 650,
@@ -709,7 +712,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 575,
             special: "Demons",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl"],
             notes: "",
             total_min_atp: 624,
             total_max_atp: 639,
@@ -732,7 +735,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 465,
             special: "None",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm"],
             notes: "",
             total_min_atp: 500,
             total_max_atp: 535,
@@ -755,7 +758,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 489,
             special: "Fill",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 606,
             total_max_atp: 645,
@@ -778,7 +781,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 170,
             special: "Soul",
             targets: 1,
-            classes: ["FOmr", "FOml", "FOnm", "FOnl"],
+            classes: &["FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 230,
             total_max_atp: 230,
@@ -801,7 +804,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 666,
             special: "Megid",
             targets: 1,
-            classes: ["FOmr", "FOml", "FOnm", "FOnl"],
+            classes: &["FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 444,
             total_max_atp: 666,
@@ -824,7 +827,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 750,
             special: "Other",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl"],
             notes: "",
             total_min_atp: 730,
             total_max_atp: 750,
@@ -847,7 +850,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 950,
             special: "Berserk",
             targets: 3,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm"],
             notes: "",
             total_min_atp: 900,
             total_max_atp: 950,
@@ -870,7 +873,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 0,
             special: "Spirit",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml"],
             notes: "",
             total_min_atp: 18,
             total_max_atp: 18,
@@ -893,7 +896,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 120,
             special: "None",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 370,
             total_max_atp: 370,
@@ -916,7 +919,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 60,
             special: "Variable",
             targets: 10,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl"],
             notes: "",
             total_min_atp: 117,
             total_max_atp: 152,
@@ -939,7 +942,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 100,
             special: "Variable",
             targets: 10,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl"],
             notes: "",
             total_min_atp: 119,
             total_max_atp: 164,
@@ -962,7 +965,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 150,
             special: "Variable",
             targets: 10,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl"],
             notes: "",
             total_min_atp: 136,
             total_max_atp: 186,
@@ -985,7 +988,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 200,
             special: "Variable",
             targets: 10,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl"],
             notes: "",
             total_min_atp: 182,
             total_max_atp: 232,
@@ -1008,7 +1011,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 255,
             special: "Variable",
             targets: 10,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl"],
             notes: "",
             total_min_atp: 230,
             total_max_atp: 275,
@@ -1031,7 +1034,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 200,
             special: "None",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 1,
             total_max_atp: 200,
@@ -1054,7 +1057,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 255,
             special: "Chaos",
             targets: 10,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 200,
             total_max_atp: 255,
@@ -1077,7 +1080,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 410,
             special: "Chaos",
             targets: 10,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl"],
             notes: "",
             total_min_atp: 350,
             total_max_atp: 410,
@@ -1100,7 +1103,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 352,
             special: "Burning",
             targets: 10,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl"],
             notes: "",
             total_min_atp: 413,
             total_max_atp: 420,
@@ -1123,7 +1126,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 300,
             special: "None",
             targets: 10,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl"],
             notes: "",
             total_min_atp: 272,
             total_max_atp: 342,
@@ -1146,7 +1149,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 185,
             special: "Dark",
             targets: 10,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 120,
             total_max_atp: 185,
@@ -1169,7 +1172,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 412,
             special: "Havoc",
             targets: 10,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 255,
             total_max_atp: 412,
@@ -1192,7 +1195,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 321,
             special: "Fill",
             targets: 10,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl"],
             notes: "",
             total_min_atp: 337,
             total_max_atp: 383,
@@ -1215,7 +1218,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 525,
             special: "Gush",
             targets: 10,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl"],
             notes: "",
             total_min_atp: 530,
             total_max_atp: 555,
@@ -1238,7 +1241,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 243,
             special: "None",
             targets: 10,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl"],
             notes: "",
             total_min_atp: 159,
             total_max_atp: 267,
@@ -1261,7 +1264,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 248,
             special: "None",
             targets: 10,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl"],
             notes: "",
             total_min_atp: 196,
             total_max_atp: 280,
@@ -1284,7 +1287,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 256,
             special: "None",
             targets: 10,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl"],
             notes: "",
             total_min_atp: 242,
             total_max_atp: 298,
@@ -1307,7 +1310,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 262,
             special: "None",
             targets: 10,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl"],
             notes: "",
             total_min_atp: 265,
             total_max_atp: 330,
@@ -1330,7 +1333,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 255,
             special: "None",
             targets: 10,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl"],
             notes: "",
             total_min_atp: 185,
             total_max_atp: 319,
@@ -1353,7 +1356,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 300,
             special: "None",
             targets: 10,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl"],
             notes: "",
             total_min_atp: 308,
             total_max_atp: 318,
@@ -1376,7 +1379,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 273,
             special: "None",
             targets: 10,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl"],
             notes: "",
             total_min_atp: 235,
             total_max_atp: 297,
@@ -1399,7 +1402,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 283,
             special: "None",
             targets: 10,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl"],
             notes: "",
             total_min_atp: 283,
             total_max_atp: 305,
@@ -1422,7 +1425,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 320,
             special: "Spirit",
             targets: 10,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl"],
             notes: "",
             total_min_atp: 470,
             total_max_atp: 490,
@@ -1445,7 +1448,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 750,
             special: "Berserk",
             targets: 1,
-            classes: ["HUmr", "HUct", "HUcl"],
+            classes: &["HUmr", "HUct", "HUcl"],
             notes: "",
             total_min_atp: 750,
             total_max_atp: 800,
@@ -1468,7 +1471,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 611,
             special: "Seize",
             targets: 10,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl"],
             notes: "",
             total_min_atp: 504,
             total_max_atp: 715,
@@ -1491,7 +1494,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 420,
             special: "Fill",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 340,
             total_max_atp: 460,
@@ -1514,7 +1517,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 438,
             special: "Berserk",
             targets: 10,
-            classes: ["HUmr", "HUct"],
+            classes: &["HUmr", "HUct"],
             notes: "",
             total_min_atp: 386,
             total_max_atp: 514,
@@ -1537,7 +1540,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 900,
             special: "Other",
             targets: 10,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl"],
             notes: "",
             total_min_atp: 756,
             total_max_atp: 900,
@@ -1560,7 +1563,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 525,
             special: "Hell",
             targets: 10,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl"],
             notes: "",
             total_min_atp: 400,
             total_max_atp: 525,
@@ -1583,7 +1586,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 756,
             special: "Other",
             targets: 10,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl"],
             notes: "",
             total_min_atp: 800,
             total_max_atp: 856,
@@ -1606,7 +1609,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 150,
             special: "None",
             targets: 10,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 350,
             total_max_atp: 400,
@@ -1629,7 +1632,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 140,
             special: "None",
             targets: 10,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 350,
             total_max_atp: 390,
@@ -1652,7 +1655,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 150,
             special: "None",
             targets: 10,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl"],
             notes: "",
             total_min_atp: 400,
             total_max_atp: 400,
@@ -1675,7 +1678,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 40,
             special: "Variable",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl"],
             notes: "",
             total_min_atp: 155,
             total_max_atp: 170,
@@ -1698,7 +1701,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 70,
             special: "Variable",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl"],
             notes: "",
             total_min_atp: 150,
             total_max_atp: 170,
@@ -1721,7 +1724,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 100,
             special: "Variable",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl"],
             notes: "",
             total_min_atp: 150,
             total_max_atp: 170,
@@ -1744,7 +1747,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 130,
             special: "Variable",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl"],
             notes: "",
             total_min_atp: 155,
             total_max_atp: 180,
@@ -1767,7 +1770,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 160,
             special: "Variable",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl"],
             notes: "",
             total_min_atp: 155,
             total_max_atp: 190,
@@ -1790,7 +1793,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 180,
             special: "Seize",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl"],
             notes: "",
             total_min_atp: 170,
             total_max_atp: 240,
@@ -1813,7 +1816,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 175,
             special: "Devils",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl"],
             notes: "",
             total_min_atp: 204,
             total_max_atp: 259,
@@ -1836,7 +1839,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 220,
             special: "Storm",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl"],
             notes: "",
             total_min_atp: 191,
             total_max_atp: 276,
@@ -1859,7 +1862,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 170,
             special: "Charge",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 160,
             total_max_atp: 170,
@@ -1882,7 +1885,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 400,
             special: "Gush",
             targets: 1,
-            classes: ["HUnl", "HUcl", "RAml", "RAcl"],
+            classes: &["HUnl", "HUcl", "RAml", "RAcl"],
             notes: "",
             total_min_atp: 400,
             total_max_atp: 450,
@@ -1905,7 +1908,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 270,
             special: "Arrest",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 300,
             total_max_atp: 320,
@@ -1928,7 +1931,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 280,
             special: "Soul",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl"],
             notes: "",
             total_min_atp: 375,
             total_max_atp: 410,
@@ -1951,7 +1954,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 220,
             special: "Hell",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl"],
             notes: "",
             total_min_atp: 240,
             total_max_atp: 250,
@@ -1974,7 +1977,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 197,
             special: "Havoc",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl"],
             notes: "",
             total_min_atp: 228,
             total_max_atp: 267,
@@ -1997,7 +2000,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 350,
             special: "ShiftaDeband",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl"],
             notes: "",
             total_min_atp: 370,
             total_max_atp: 380,
@@ -2020,7 +2023,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 250,
             special: "Dark",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 285,
             total_max_atp: 290,
@@ -2043,7 +2046,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 300,
             special: "Blizzard",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 398,
             total_max_atp: 498,
@@ -2066,7 +2069,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 321,
             special: "Chaos",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl"],
             notes: "",
             total_min_atp: 350,
             total_max_atp: 351,
@@ -2089,7 +2092,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 550,
             special: "Berserk",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl"],
             notes: "",
             total_min_atp: 550,
             total_max_atp: 600,
@@ -2112,7 +2115,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 450,
             special: "Other",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl"],
             notes: "",
             total_min_atp: 380,
             total_max_atp: 450,
@@ -2135,7 +2138,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 650,
             special: "Zalure",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl"],
             notes: "",
             total_min_atp: 600,
             total_max_atp: 650,
@@ -2158,7 +2161,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "None",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl"],
             notes: "",
             total_min_atp: 190,
             total_max_atp: 190,
@@ -2181,7 +2184,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 40,
             special: "Variable",
             targets: 10,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl"],
             notes: "",
             total_min_atp: 100,
             total_max_atp: 110,
@@ -2204,7 +2207,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 75,
             special: "Variable",
             targets: 10,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl"],
             notes: "",
             total_min_atp: 125,
             total_max_atp: 135,
@@ -2227,7 +2230,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 110,
             special: "Variable",
             targets: 10,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl"],
             notes: "",
             total_min_atp: 145,
             total_max_atp: 160,
@@ -2250,7 +2253,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 145,
             special: "Variable",
             targets: 10,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl"],
             notes: "",
             total_min_atp: 170,
             total_max_atp: 185,
@@ -2273,7 +2276,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 180,
             special: "Variable",
             targets: 10,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl"],
             notes: "",
             total_min_atp: 170,
             total_max_atp: 200,
@@ -2296,7 +2299,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 255,
             special: "Spirit",
             targets: 10,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 2,
             total_max_atp: 255,
@@ -2319,7 +2322,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 182,
             special: "Soul",
             targets: 10,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl"],
             notes: "",
             total_min_atp: 180,
             total_max_atp: 212,
@@ -2342,7 +2345,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 1,
             special: "Lords",
             targets: 10,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 1,
             total_max_atp: 1,
@@ -2365,7 +2368,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 180,
             special: "Geist",
             targets: 10,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 80,
             total_max_atp: 180,
@@ -2388,7 +2391,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 220,
             special: "Freeze",
             targets: 10,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl"],
             notes: "",
             total_min_atp: 275,
             total_max_atp: 280,
@@ -2411,7 +2414,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 230,
             special: "None",
             targets: 10,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 190,
             total_max_atp: 230,
@@ -2434,7 +2437,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 200,
             special: "None",
             targets: 10,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 180,
             total_max_atp: 200,
@@ -2457,7 +2460,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 185,
             special: "Berserk",
             targets: 10,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 198,
             total_max_atp: 203,
@@ -2480,7 +2483,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 1,
             special: "None",
             targets: 10,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 1,
             total_max_atp: 1,
@@ -2503,7 +2506,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 200,
             special: "Charge",
             targets: 10,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 1,
             total_max_atp: 200,
@@ -2526,7 +2529,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 300,
             special: "Charge",
             targets: 10,
-            classes: ["HUnl", "HUcl", "RAml", "RAcl", "FOml", "FOml"],
+            classes: &["HUnl", "HUcl", "RAml", "RAcl", "FOml", "FOnl"],
             notes: "",
             total_min_atp: 250,
             total_max_atp: 300,
@@ -2549,7 +2552,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 220,
             special: "Other",
             targets: 10,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl"],
             notes: "",
             total_min_atp: 196,
             total_max_atp: 250,
@@ -2572,7 +2575,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 400,
             special: "Hell",
             targets: 10,
-            classes: ["HUmr", "HUct", "RAmr", "RAct", "FOmr"],
+            classes: &["HUmr", "HUct", "RAmr", "RAct", "FOmr"],
             notes: "",
             total_min_atp: 398,
             total_max_atp: 418,
@@ -2595,7 +2598,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 290,
             special: "Other",
             targets: 10,
-            classes: ["HUct", "HUcl"],
+            classes: &["HUct", "HUcl"],
             notes: "",
             total_min_atp: 320,
             total_max_atp: 340,
@@ -2618,7 +2621,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 300,
             special: "Devils",
             targets: 10,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl"],
             notes: "",
             total_min_atp: 298,
             total_max_atp: 318,
@@ -2641,7 +2644,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 280,
             special: "Berserk",
             targets: 10,
-            classes: ["HUnl", "HUcl", "RAml", "RAcl", "FOml", "FOnl"],
+            classes: &["HUnl", "HUcl", "RAml", "RAcl", "FOml", "FOnl"],
             notes: "",
             total_min_atp: 210,
             total_max_atp: 280,
@@ -2664,7 +2667,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 250,
             special: "None",
             targets: 10,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl"],
             notes: "",
             total_min_atp: 260,
             total_max_atp: 280,
@@ -2687,7 +2690,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 300,
             special: "Other",
             targets: 10,
-            classes: ["HUmr", "HUnl", "FOmr", "FOml", "FOnm", "FOnl"],
+            classes: &["HUmr", "HUnl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 283,
             total_max_atp: 318,
@@ -2710,7 +2713,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 295,
             special: "Freeze",
             targets: 10,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl"],
             notes: "",
             total_min_atp: 370,
             total_max_atp: 375,
@@ -2733,7 +2736,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 310,
             special: "Dark",
             targets: 10,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl"],
             notes: "",
             total_min_atp: 320,
             total_max_atp: 340,
@@ -2756,7 +2759,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 370,
             special: "Megid",
             targets: 10,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "FOmr", "FOml"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "FOmr", "FOml"],
             notes: "",
             total_min_atp: 368,
             total_max_atp: 388,
@@ -2779,7 +2782,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 350,
             special: "Berserk",
             targets: 10,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl"],
             notes: "",
             total_min_atp: 350,
             total_max_atp: 400,
@@ -2802,7 +2805,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 220,
             special: "Spirit",
             targets: 10,
-            classes: ["HUnl", "HUcl", "RAml", "RAcl", "FOml", "FOnl"],
+            classes: &["HUnl", "HUcl", "RAml", "RAcl", "FOml", "FOnl"],
             notes: "",
             total_min_atp: 215,
             total_max_atp: 220,
@@ -2825,7 +2828,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "None",
             targets: 10,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl"],
             notes: "",
             total_min_atp: 190,
             total_max_atp: 190,
@@ -2848,7 +2851,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "None",
             targets: 10,
-            classes: ["FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 170,
             total_max_atp: 170,
@@ -2871,7 +2874,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 15,
             special: "Variable",
             targets: 4,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 45,
             total_max_atp: 55,
@@ -2894,7 +2897,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 30,
             special: "Variable",
             targets: 4,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 50,
             total_max_atp: 70,
@@ -2917,7 +2920,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 55,
             special: "Variable",
             targets: 4,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 65,
             total_max_atp: 85,
@@ -2940,7 +2943,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 80,
             special: "Variable",
             targets: 4,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 90,
             total_max_atp: 110,
@@ -2963,7 +2966,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 105,
             special: "Variable",
             targets: 4,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 105,
             total_max_atp: 125,
@@ -2986,7 +2989,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 167,
             special: "Berserk",
             targets: 4,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 168,
             total_max_atp: 185,
@@ -3009,7 +3012,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 146,
             special: "Havoc",
             targets: 4,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 138,
             total_max_atp: 164,
@@ -3032,7 +3035,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 125,
             special: "Dark",
             targets: 4,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 144,
             total_max_atp: 149,
@@ -3055,7 +3058,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 260,
             special: "Devils",
             targets: 4,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl"],
             notes: "",
             total_min_atp: 268,
             total_max_atp: 278,
@@ -3078,7 +3081,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 200,
             special: "Lords",
             targets: 4,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 215,
             total_max_atp: 230,
@@ -3101,7 +3104,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 250,
             special: "Other",
             targets: 4,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 250,
             total_max_atp: 250,
@@ -3124,7 +3127,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 146,
             special: "Havoc",
             targets: 4,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 204,
             total_max_atp: 210,
@@ -3147,7 +3150,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 200,
             special: "Flame",
             targets: 4,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm"],
             notes: "",
             total_min_atp: 280,
             total_max_atp: 290,
@@ -3170,7 +3173,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 360,
             special: "Demons",
             targets: 3,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 400,
             total_max_atp: 420,
@@ -3193,7 +3196,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 320,
             special: "Chaos",
             targets: 4,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 348,
             total_max_atp: 368,
@@ -3216,7 +3219,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 150,
             special: "None",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 350,
             total_max_atp: 400,
@@ -3239,7 +3242,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 140,
             special: "None",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 350,
             total_max_atp: 390,
@@ -3262,7 +3265,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 130,
             special: "None",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 350,
             total_max_atp: 380,
@@ -3285,7 +3288,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 140,
             special: "None",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 390,
             total_max_atp: 390,
@@ -3308,7 +3311,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 152,
             special: "Mind",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 220,
             total_max_atp: 222,
@@ -3331,7 +3334,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 530,
             special: "Devils",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl"],
             notes: "",
             total_min_atp: 580,
             total_max_atp: 580,
@@ -3354,7 +3357,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 550,
             special: "Other",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 500,
             total_max_atp: 550,
@@ -3377,7 +3380,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 560,
             special: "Blizzard",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl"],
             notes: "",
             total_min_atp: 330,
             total_max_atp: 590,
@@ -3400,7 +3403,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 390,
             special: "Devils",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl"],
             notes: "",
             total_min_atp: 430,
             total_max_atp: 440,
@@ -3423,7 +3426,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 410,
             special: "Tempest",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl"],
             notes: "",
             total_min_atp: 490,
             total_max_atp: 530,
@@ -3446,7 +3449,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 235,
             special: "Havoc",
             targets: 1,
-            classes: ["HUmr", "HUct", "RAmr", "RAct"],
+            classes: &["HUmr", "HUct", "RAmr", "RAct"],
             notes: "",
             total_min_atp: 290,
             total_max_atp: 295,
@@ -3469,7 +3472,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 240,
             special: "Devils",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl"],
             notes: "",
             total_min_atp: 355,
             total_max_atp: 360,
@@ -3492,7 +3495,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 600,
             special: "Devils",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl"],
             notes: "",
             total_min_atp: 750,
             total_max_atp: 760,
@@ -3515,7 +3518,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 520,
             special: "Gifoie",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl"],
             notes: "",
             total_min_atp: 318,
             total_max_atp: 538,
@@ -3538,7 +3541,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 590,
             special: "Heart",
             targets: 1,
-            classes: ["HUnl", "HUcl", "RAml", "RAcl", "FOml", "FOml"],
+            classes: &["HUnl", "HUcl", "RAml", "RAcl", "FOml", "FOnl"],
             notes: "",
             total_min_atp: 670,
             total_max_atp: 690,
@@ -3561,7 +3564,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 650,
             special: "Other",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl"],
             notes: "",
             total_min_atp: 620,
             total_max_atp: 650,
@@ -3584,7 +3587,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 30,
             special: "None",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 280,
             total_max_atp: 280,
@@ -3607,7 +3610,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 30,
             special: "Devils",
             targets: 1,
-            classes: ["FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 260,
             total_max_atp: 280,
@@ -3630,7 +3633,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 30,
             special: "None",
             targets: 1,
-            classes: ["FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 260,
             total_max_atp: 280,
@@ -3653,7 +3656,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 300,
             special: "Drain",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 270,
             total_max_atp: 340,
@@ -3676,7 +3679,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 315,
             special: "None",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 330,
             total_max_atp: 345,
@@ -3699,7 +3702,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 280,
             special: "Chaos",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 180,
             total_max_atp: 280,
@@ -3722,7 +3725,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 580,
             special: "Foie",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl"],
             notes: "",
             total_min_atp: 620,
             total_max_atp: 650,
@@ -3745,7 +3748,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 500,
             special: "None",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl"],
             notes: "",
             total_min_atp: 560,
             total_max_atp: 610,
@@ -3768,7 +3771,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 345,
             special: "None",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl"],
             notes: "",
             total_min_atp: 400,
             total_max_atp: 415,
@@ -3791,7 +3794,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 715,
             special: "Chaos",
             targets: 1,
-            classes: ["HUnl"],
+            classes: &["HUnl"],
             notes: "",
             total_min_atp: 695,
             total_max_atp: 715,
@@ -3814,7 +3817,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 500,
             special: "None",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm"],
             notes: "",
             total_min_atp: 450,
             total_max_atp: 500,
@@ -3837,7 +3840,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 412,
             special: "Gush",
             targets: 1,
-            classes: ["HUnl"],
+            classes: &["HUnl"],
             notes: "",
             total_min_atp: 450,
             total_max_atp: 462,
@@ -3860,7 +3863,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 570,
             special: "Burning",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 570,
             total_max_atp: 600,
@@ -3883,7 +3886,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 680,
             special: "Tempest",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl"],
             notes: "",
             total_min_atp: 670,
             total_max_atp: 750,
@@ -3906,7 +3909,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 345,
             special: "Dark",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl"],
             notes: "",
             total_min_atp: 365,
             total_max_atp: 375,
@@ -3929,7 +3932,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 756,
             special: "Spirit",
             targets: 1,
-            classes: ["HUnl"],
+            classes: &["HUnl"],
             notes: "",
             total_min_atp: 756,
             total_max_atp: 756,
@@ -3952,7 +3955,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 170,
             special: "None",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 420,
             total_max_atp: 420,
@@ -3975,7 +3978,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 250,
             special: "Mind",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 230,
             total_max_atp: 250,
@@ -3998,7 +4001,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 310,
             special: "Seize",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 280,
             total_max_atp: 310,
@@ -4021,7 +4024,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 435,
             special: "Dim",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 420,
             total_max_atp: 435,
@@ -4044,7 +4047,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 400,
             special: "Berserk",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 1,
             total_max_atp: 400,
@@ -4067,7 +4070,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 460,
             special: "Dim",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 550,
             total_max_atp: 560,
@@ -4090,7 +4093,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 460,
             special: "Dim",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 535,
             total_max_atp: 540,
@@ -4113,7 +4116,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 500,
             special: "Dim",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 290,
             total_max_atp: 590,
@@ -4136,7 +4139,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 410,
             special: "Dim",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 418,
             total_max_atp: 428,
@@ -4159,7 +4162,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 430,
             special: "Dim",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 418,
             total_max_atp: 448,
@@ -4182,7 +4185,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 840,
             special: "Burning",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl"],
             notes: "",
             total_min_atp: 790,
             total_max_atp: 840,
@@ -4205,7 +4208,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 650,
             special: "Dark",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl"],
             notes: "",
             total_min_atp: 650,
             total_max_atp: 650,
@@ -4228,7 +4231,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 560,
             special: "Other",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 550,
             total_max_atp: 560,
@@ -4251,7 +4254,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 550,
             special: "Fill",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl"],
             notes: "",
             total_min_atp: 500,
             total_max_atp: 550,
@@ -4274,7 +4277,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 560,
             special: "Dim",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 500,
             total_max_atp: 560,
@@ -4297,7 +4300,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 870,
             special: "Blizzard",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl"],
             notes: "",
             total_min_atp: 820,
             total_max_atp: 870,
@@ -4320,7 +4323,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 650,
             special: "Hell",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl"],
             notes: "",
             total_min_atp: 580,
             total_max_atp: 650,
@@ -4343,7 +4346,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 480,
             special: "Dim",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl"],
             notes: "",
             total_min_atp: 460,
             total_max_atp: 480,
@@ -4366,7 +4369,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 550,
             special: "Fill",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl"],
             notes: "",
             total_min_atp: 500,
             total_max_atp: 550,
@@ -4389,7 +4392,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 800,
             special: "Other",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl"],
             notes: "",
             total_min_atp: 750,
             total_max_atp: 800,
@@ -4412,7 +4415,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 130,
             special: "None",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 380,
             total_max_atp: 380,
@@ -4435,7 +4438,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 140,
             special: "None",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 350,
             total_max_atp: 390,
@@ -4458,7 +4461,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 130,
             special: "None",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 350,
             total_max_atp: 380,
@@ -4481,7 +4484,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 350,
             special: "Berserk",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl"],
             notes: "",
             total_min_atp: 410,
             total_max_atp: 430,
@@ -4504,7 +4507,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 390,
             special: "Blizzard",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl"],
             notes: "",
             total_min_atp: 500,
             total_max_atp: 510,
@@ -4527,7 +4530,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 570,
             special: "Burning",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl"],
             notes: "",
             total_min_atp: 620,
             total_max_atp: 630,
@@ -4550,7 +4553,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 360,
             special: "Arrest",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 400,
             total_max_atp: 410,
@@ -4573,7 +4576,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 810,
             special: "Hell",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl"],
             notes: "",
             total_min_atp: 880,
             total_max_atp: 890,
@@ -4596,7 +4599,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 650,
             special: "Gush",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl"],
             notes: "",
             total_min_atp: 700,
             total_max_atp: 710,
@@ -4619,7 +4622,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 150,
             special: "None",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl"],
             notes: "",
             total_min_atp: 400,
             total_max_atp: 400,
@@ -4642,7 +4645,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 460,
             special: "Hold",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 440,
             total_max_atp: 520,
@@ -4665,7 +4668,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 310,
             special: "Kings",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 260,
             total_max_atp: 310,
@@ -4688,7 +4691,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 560,
             special: "Freeze",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm"],
             notes: "",
             total_min_atp: 560,
             total_max_atp: 660,
@@ -4711,7 +4714,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 705,
             special: "Kings",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl"],
             notes: "",
             total_min_atp: 645,
             total_max_atp: 765,
@@ -4734,7 +4737,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 730,
             special: "Blizzard",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl"],
             notes: "",
             total_min_atp: 710,
             total_max_atp: 780,
@@ -4757,7 +4760,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 200,
             special: "None",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl"],
             notes: "",
             total_min_atp: 430,
             total_max_atp: 450,
@@ -4780,7 +4783,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 220,
             special: "None",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 430,
             total_max_atp: 470,
@@ -4803,7 +4806,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 30,
             special: "Variable",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 170,
             total_max_atp: 180,
@@ -4826,7 +4829,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 65,
             special: "Variable",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 145,
             total_max_atp: 165,
@@ -4849,7 +4852,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 100,
             special: "Variable",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 150,
             total_max_atp: 170,
@@ -4872,7 +4875,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 140,
             special: "Variable",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 170,
             total_max_atp: 190,
@@ -4895,7 +4898,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 180,
             special: "Variable",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 180,
             total_max_atp: 210,
@@ -4918,7 +4921,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 230,
             special: "Storm",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 250,
             total_max_atp: 290,
@@ -4941,7 +4944,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 195,
             special: "Flame",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 205,
             total_max_atp: 235,
@@ -4964,7 +4967,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 210,
             special: "Seize",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 205,
             total_max_atp: 260,
@@ -4987,7 +4990,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 310,
             special: "Seize",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 280,
             total_max_atp: 310,
@@ -5010,7 +5013,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 420,
             special: "Chaos",
             targets: 1,
-            classes: ["RAmr", "RAml", "RAct", "RAcl"],
+            classes: &["RAmr", "RAml", "RAct", "RAcl"],
             notes: "",
             total_min_atp: 408,
             total_max_atp: 438,
@@ -5033,7 +5036,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 300,
             special: "Storm",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 400,
             total_max_atp: 400,
@@ -5056,7 +5059,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 350,
             special: "Burning",
             targets: 1,
-            classes: ["RAmr", "RAml", "RAct", "RAcl"],
+            classes: &["RAmr", "RAml", "RAct", "RAcl"],
             notes: "",
             total_min_atp: 350,
             total_max_atp: 350,
@@ -5079,7 +5082,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 270,
             special: "None",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 278,
             total_max_atp: 288,
@@ -5102,7 +5105,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 340,
             special: "None",
             targets: 1,
-            classes: ["RAmr", "RAml", "RAct", "RAcl"],
+            classes: &["RAmr", "RAml", "RAct", "RAcl"],
             notes: "",
             total_min_atp: 360,
             total_max_atp: 360,
@@ -5125,7 +5128,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 400,
             special: "Soul",
             targets: 1,
-            classes: ["RAmr", "RAct"],
+            classes: &["RAmr", "RAct"],
             notes: "",
             total_min_atp: 388,
             total_max_atp: 418,
@@ -5148,7 +5151,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 360,
             special: "Fill",
             targets: 1,
-            classes: ["RAml","RAcl"],
+            classes: &["RAml","RAcl"],
             notes: "",
             total_min_atp: 368,
             total_max_atp: 378,
@@ -5171,7 +5174,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 90,
             special: "Lords",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 98,
             total_max_atp: 108,
@@ -5194,7 +5197,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 380,
             special: "Masters",
             targets: 1,
-            classes: ["HUmr", "HUct", "RAmr", "RAct", "FOmr", "FOnm"],
+            classes: &["HUmr", "HUct", "RAmr", "RAct", "FOmr", "FOnm"],
             notes: "",
             total_min_atp: 368,
             total_max_atp: 398,
@@ -5217,7 +5220,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 330,
             special: "Other",
             targets: 1,
-            classes: ["RAmr", "RAml", "RAct", "RAcl"],
+            classes: &["RAmr", "RAml", "RAct", "RAcl"],
             notes: "",
             total_min_atp: 300,
             total_max_atp: 330,
@@ -5240,7 +5243,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 660,
             special: "Berserk",
             targets: 1,
-            classes: ["RAmr", "RAml", "RAct", "RAcl"],
+            classes: &["RAmr", "RAml", "RAct", "RAcl"],
             notes: "",
             total_min_atp: 590,
             total_max_atp: 700,
@@ -5263,7 +5266,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "None",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 200,
             total_max_atp: 200,
@@ -5286,7 +5289,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "None",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 190,
             total_max_atp: 190,
@@ -5309,7 +5312,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 50,
             special: "Variable",
             targets: 1,
-            classes: ["RAmr", "RAml", "RAct", "RAcl"],
+            classes: &["RAmr", "RAml", "RAct", "RAcl"],
             notes: "",
             total_min_atp: 140,
             total_max_atp: 180,
@@ -5332,7 +5335,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 90,
             special: "Variable",
             targets: 1,
-            classes: ["RAmr", "RAml", "RAct", "RAcl"],
+            classes: &["RAmr", "RAml", "RAct", "RAcl"],
             notes: "",
             total_min_atp: 160,
             total_max_atp: 200,
@@ -5355,7 +5358,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 130,
             special: "Variable",
             targets: 1,
-            classes: ["RAmr", "RAml", "RAct", "RAcl"],
+            classes: &["RAmr", "RAml", "RAct", "RAcl"],
             notes: "",
             total_min_atp: 190,
             total_max_atp: 220,
@@ -5378,7 +5381,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 170,
             special: "Variable",
             targets: 1,
-            classes: ["RAmr", "RAml", "RAct", "RAcl"],
+            classes: &["RAmr", "RAml", "RAct", "RAcl"],
             notes: "",
             total_min_atp: 210,
             total_max_atp: 240,
@@ -5401,7 +5404,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 210,
             special: "Variable",
             targets: 1,
-            classes: ["RAmr", "RAml", "RAct", "RAcl"],
+            classes: &["RAmr", "RAml", "RAct", "RAcl"],
             notes: "",
             total_min_atp: 250,
             total_max_atp: 260,
@@ -5424,7 +5427,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 200,
             special: "Pierce",
             targets: 1,
-            classes: ["HUct", "HUcl", "RAct", "RAcl"],
+            classes: &["HUct", "HUcl", "RAct", "RAcl"],
             notes: "",
             total_min_atp: 180,
             total_max_atp: 200,
@@ -5447,7 +5450,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 320,
             special: "Devils",
             targets: 1,
-            classes: ["RAmr", "RAml", "RAct", "RAcl"],
+            classes: &["RAmr", "RAml", "RAct", "RAcl"],
             notes: "",
             total_min_atp: 320,
             total_max_atp: 350,
@@ -5470,7 +5473,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 330,
             special: "None",
             targets: 1,
-            classes: ["RAmr", "RAml", "RAct", "RAcl"],
+            classes: &["RAmr", "RAml", "RAct", "RAcl"],
             notes: "",
             total_min_atp: 310,
             total_max_atp: 330,
@@ -5493,7 +5496,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 300,
             special: "None",
             targets: 1,
-            classes: ["RAmr", "RAml", "RAct", "RAcl"],
+            classes: &["RAmr", "RAml", "RAct", "RAcl"],
             notes: "",
             total_min_atp: 420,
             total_max_atp: 420,
@@ -5516,7 +5519,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 410,
             special: "None",
             targets: 1,
-            classes: ["RAmr", "RAml", "RAct", "RAcl"],
+            classes: &["RAmr", "RAml", "RAct", "RAcl"],
             notes: "",
             total_min_atp: 320,
             total_max_atp: 410,
@@ -5539,7 +5542,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 250,
             special: "Seize",
             targets: 1,
-            classes: ["RAmr", "RAml", "RAct", "RAcl"],
+            classes: &["RAmr", "RAml", "RAct", "RAcl"],
             notes: "",
             total_min_atp: 250,
             total_max_atp: 280,
@@ -5562,7 +5565,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 280,
             special: "Havoc",
             targets: 1,
-            classes: ["RAmr", "RAml", "RAct", "RAcl"],
+            classes: &["RAmr", "RAml", "RAct", "RAcl"],
             notes: "",
             total_min_atp: 278,
             total_max_atp: 298,
@@ -5585,7 +5588,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 380,
             special: "Seize",
             targets: 1,
-            classes: ["RAmr", "RAml", "RAct", "RAcl"],
+            classes: &["RAmr", "RAml", "RAct", "RAcl"],
             notes: "",
             total_min_atp: 380,
             total_max_atp: 410,
@@ -5608,7 +5611,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 350,
             special: "Bind",
             targets: 1,
-            classes: ["RAmr", "RAml", "RAct", "RAcl"],
+            classes: &["RAmr", "RAml", "RAct", "RAcl"],
             notes: "",
             total_min_atp: 348,
             total_max_atp: 368,
@@ -5631,7 +5634,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 370,
             special: "Demons",
             targets: 1,
-            classes: ["RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
+            classes: &["RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 348,
             total_max_atp: 388,
@@ -5654,7 +5657,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 330,
             special: "Flame",
             targets: 1,
-            classes: ["RAmr", "RAml", "RAct", "RAcl"],
+            classes: &["RAmr", "RAml", "RAct", "RAcl"],
             notes: "",
             total_min_atp: 300,
             total_max_atp: 330,
@@ -5677,7 +5680,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 250,
             special: "Other",
             targets: 1,
-            classes: ["RAmr", "RAml", "RAct", "RAcl"],
+            classes: &["RAmr", "RAml", "RAct", "RAcl"],
             notes: "",
             total_min_atp: 258,
             total_max_atp: 268,
@@ -5700,7 +5703,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 300,
             special: "Arrest",
             targets: 1,
-            classes: ["HUmr", "HUnl", "RAml", "FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["HUmr", "HUnl", "RAml", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 370,
             total_max_atp: 380,
@@ -5723,7 +5726,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 400,
             special: "None",
             targets: 1,
-            classes: ["RAmr", "RAml", "RAct", "RAcl"],
+            classes: &["RAmr", "RAml", "RAct", "RAcl"],
             notes: "",
             total_min_atp: 450,
             total_max_atp: 500,
@@ -5746,7 +5749,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 400,
             special: "None",
             targets: 1,
-            classes: ["RAmr", "RAml", "RAct", "RAcl"],
+            classes: &["RAmr", "RAml", "RAct", "RAcl"],
             notes: "",
             total_min_atp: 200,
             total_max_atp: 450,
@@ -5769,7 +5772,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 500,
             special: "None",
             targets: 1,
-            classes: ["RAmr", "RAml", "RAct", "RAcl"],
+            classes: &["RAmr", "RAml", "RAct", "RAcl"],
             notes: "",
             total_min_atp: 530,
             total_max_atp: 530,
@@ -5792,7 +5795,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 450,
             special: "None",
             targets: 1,
-            classes: ["RAmr", "RAml", "RAct", "RAcl"],
+            classes: &["RAmr", "RAml", "RAct", "RAcl"],
             notes: "",
             total_min_atp: 470,
             total_max_atp: 570,
@@ -5815,7 +5818,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 110,
             special: "Seize",
             targets: 5,
-            classes: ["RAmr", "RAml", "RAct", "RAcl"],
+            classes: &["RAmr", "RAml", "RAct", "RAcl"],
             notes: "",
             total_min_atp: 81,
             total_max_atp: 190,
@@ -5838,7 +5841,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 400,
             special: "None",
             targets: 1,
-            classes: ["RAmr", "RAml", "RAct", "RAcl"],
+            classes: &["RAmr", "RAml", "RAct", "RAcl"],
             notes: "",
             total_min_atp: 490,
             total_max_atp: 520,
@@ -5861,7 +5864,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 450,
             special: "None",
             targets: 1,
-            classes: ["RAmr", "RAml", "RAct", "RAcl"],
+            classes: &["RAmr", "RAml", "RAct", "RAcl"],
             notes: "",
             total_min_atp: 420,
             total_max_atp: 500,
@@ -5884,7 +5887,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 320,
             special: "Chaos",
             targets: 1,
-            classes: ["RAml", "RAcl", "FOml", "FOnl"],
+            classes: &["RAml", "RAcl", "FOml", "FOnl"],
             notes: "",
             total_min_atp: 300,
             total_max_atp: 320,
@@ -5907,7 +5910,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 550,
             special: "Dark",
             targets: 1,
-            classes: ["RAmr", "RAml", "RAct", "RAcl"],
+            classes: &["RAmr", "RAml", "RAct", "RAcl"],
             notes: "",
             total_min_atp: 550,
             total_max_atp: 550,
@@ -5930,7 +5933,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 350,
             special: "Other",
             targets: 1,
-            classes: ["RAmr", "RAml", "RAct", "RAcl"],
+            classes: &["RAmr", "RAml", "RAct", "RAcl"],
             notes: "",
             total_min_atp: 366,
             total_max_atp: 386,
@@ -5953,7 +5956,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "None",
             targets: 1,
-            classes: ["RAmr", "RAml", "RAct", "RAcl"],
+            classes: &["RAmr", "RAml", "RAct", "RAcl"],
             notes: "",
             total_min_atp: 210,
             total_max_atp: 210,
@@ -5976,7 +5979,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 4,
             special: "Variable",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 20,
             total_max_atp: 22,
@@ -5999,7 +6002,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 8,
             special: "Variable",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 23,
             total_max_atp: 26,
@@ -6022,7 +6025,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 12,
             special: "Variable",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 23,
             total_max_atp: 30,
@@ -6045,7 +6048,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 16,
             special: "Variable",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 23,
             total_max_atp: 34,
@@ -6068,7 +6071,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 20,
             special: "Variable",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 23,
             total_max_atp: 38,
@@ -6091,7 +6094,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 30,
             special: "Freeze",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 33,
             total_max_atp: 48,
@@ -6114,7 +6117,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 30,
             special: "Seize",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 55,
             total_max_atp: 70,
@@ -6137,7 +6140,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 25,
             special: "Berserk",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 33,
             total_max_atp: 43,
@@ -6160,7 +6163,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 300,
             special: "Burning",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl"],
             notes: "",
             total_min_atp: 150,
             total_max_atp: 400,
@@ -6183,7 +6186,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Havoc",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 5,
             total_max_atp: 10,
@@ -6206,7 +6209,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 185,
             special: "Variable",
             targets: 1,
-            classes: ["RAmr", "RAml", "RAct", "RAcl"],
+            classes: &["RAmr", "RAml", "RAct", "RAcl"],
             notes: "",
             total_min_atp: 193,
             total_max_atp: 203,
@@ -6229,7 +6232,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 50,
             special: "Havoc",
             targets: 1,
-            classes: ["RAmr", "RAml", "RAct", "RAcl"],
+            classes: &["RAmr", "RAml", "RAct", "RAcl"],
             notes: "",
             total_min_atp: 110,
             total_max_atp: 110,
@@ -6252,7 +6255,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 40,
             special: "Other",
             targets: 1,
-            classes: ["HUmr", "HUnl", "RAmr", "RAml"],
+            classes: &["HUmr", "HUnl", "RAmr", "RAml"],
             notes: "",
             total_min_atp: 35,
             total_max_atp: 40,
@@ -6275,7 +6278,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 80,
             special: "None",
             targets: 1,
-            classes: ["RAmr", "RAml", "RAct", "RAcl"],
+            classes: &["RAmr", "RAml", "RAct", "RAcl"],
             notes: "",
             total_min_atp: 60,
             total_max_atp: 100,
@@ -6298,7 +6301,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 210,
             special: "Kings",
             targets: 1,
-            classes: ["HUcl","RAmr", "RAml", "RAct", "RAcl"],
+            classes: &["HUcl","RAmr", "RAml", "RAct", "RAcl"],
             notes: "",
             total_min_atp: 242,
             total_max_atp: 252,
@@ -6321,7 +6324,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 200,
             special: "Gush",
             targets: 1,
-            classes: ["RAmr", "RAml", "RAct", "RAcl"],
+            classes: &["RAmr", "RAml", "RAct", "RAcl"],
             notes: "",
             total_min_atp: 198,
             total_max_atp: 218,
@@ -6344,7 +6347,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 220,
             special: "Other",
             targets: 1,
-            classes: ["RAmr", "RAml", "RAct", "RAcl"],
+            classes: &["RAmr", "RAml", "RAct", "RAcl"],
             notes: "",
             total_min_atp: 224,
             total_max_atp: 244,
@@ -6367,7 +6370,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "None",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 70,
             total_max_atp: 70,
@@ -6390,7 +6393,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 25,
             special: "Variable",
             targets: 5,
-            classes: ["RAmr", "RAml", "RAct", "RAcl"],
+            classes: &["RAmr", "RAml", "RAct", "RAcl"],
             notes: "",
             total_min_atp: 60,
             total_max_atp: 65,
@@ -6413,7 +6416,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 50,
             special: "Variable",
             targets: 5,
-            classes: ["RAmr", "RAml", "RAct", "RAcl"],
+            classes: &["RAmr", "RAml", "RAct", "RAcl"],
             notes: "",
             total_min_atp: 70,
             total_max_atp: 90,
@@ -6436,7 +6439,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 80,
             special: "Variable",
             targets: 5,
-            classes: ["RAmr", "RAml", "RAct", "RAcl"],
+            classes: &["RAmr", "RAml", "RAct", "RAcl"],
             notes: "",
             total_min_atp: 70,
             total_max_atp: 110,
@@ -6459,7 +6462,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 110,
             special: "Variable",
             targets: 5,
-            classes: ["RAmr", "RAml", "RAct", "RAcl"],
+            classes: &["RAmr", "RAml", "RAct", "RAcl"],
             notes: "",
             total_min_atp: 80,
             total_max_atp: 140,
@@ -6482,7 +6485,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 140,
             special: "Variable",
             targets: 5,
-            classes: ["RAmr", "RAml", "RAct", "RAcl"],
+            classes: &["RAmr", "RAml", "RAct", "RAcl"],
             notes: "",
             total_min_atp: 80,
             total_max_atp: 160,
@@ -6505,7 +6508,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 133,
             special: "Fill",
             targets: 5,
-            classes: ["RAmr", "RAml", "RAct", "RAcl"],
+            classes: &["RAmr", "RAml", "RAct", "RAcl"],
             notes: "",
             total_min_atp: 135,
             total_max_atp: 183,
@@ -6528,7 +6531,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 160,
             special: "Soul",
             targets: 5,
-            classes: ["RAmr", "RAml", "RAct", "RAcl"],
+            classes: &["RAmr", "RAml", "RAct", "RAcl"],
             notes: "",
             total_min_atp: 130,
             total_max_atp: 210,
@@ -6551,7 +6554,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 170,
             special: "Lords",
             targets: 5,
-            classes: ["RAmr", "RAml", "RAct", "RAcl"],
+            classes: &["RAmr", "RAml", "RAct", "RAcl"],
             notes: "",
             total_min_atp: 155,
             total_max_atp: 230,
@@ -6574,7 +6577,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 240,
             special: "Charge",
             targets: 4,
-            classes: ["RAmr", "RAml", "RAct", "RAcl"],
+            classes: &["RAmr", "RAml", "RAct", "RAcl"],
             notes: "",
             total_min_atp: 290,
             total_max_atp: 300,
@@ -6597,7 +6600,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 500,
             special: "Pierce",
             targets: 1,
-            classes: ["RAmr", "RAml", "RAct", "RAcl"],
+            classes: &["RAmr", "RAml", "RAct", "RAcl"],
             notes: "",
             total_min_atp: 440,
             total_max_atp: 540,
@@ -6620,7 +6623,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 450,
             special: "Havoc",
             targets: 1,
-            classes: ["RAmr", "RAml", "RAct", "RAcl"],
+            classes: &["RAmr", "RAml", "RAct", "RAcl"],
             notes: "",
             total_min_atp: 318,
             total_max_atp: 468,
@@ -6643,7 +6646,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 410,
             special: "Devils",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 230,
             total_max_atp: 410,
@@ -6666,7 +6669,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 580,
             special: "Chaos",
             targets: 1,
-            classes: ["RAct", "RAcl"],
+            classes: &["RAct", "RAcl"],
             notes: "",
             total_min_atp: 536,
             total_max_atp: 616,
@@ -6689,7 +6692,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 250,
             special: "Burning",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 200,
             total_max_atp: 300,
@@ -6712,7 +6715,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 230,
             special: "None",
             targets: 1,
-            classes: ["RAmr", "RAml", "RAct", "RAcl"],
+            classes: &["RAmr", "RAml", "RAct", "RAcl"],
             notes: "",
             total_min_atp: 270,
             total_max_atp: 270,
@@ -6735,7 +6738,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 500,
             special: "Chaos",
             targets: 1,
-            classes: ["RAct", "RAcl"],
+            classes: &["RAct", "RAcl"],
             notes: "",
             total_min_atp: 368,
             total_max_atp: 518,
@@ -6758,7 +6761,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 450,
             special: "Chaos",
             targets: 2,
-            classes: ["HUnl", "HUcl", "RAml", "RAcl", "FOml", "FOml"],
+            classes: &["HUnl", "HUcl", "RAml", "RAcl", "FOml", "FOnl"],
             notes: "",
             total_min_atp: 360,
             total_max_atp: 450,
@@ -6781,7 +6784,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 540,
             special: "Havoc",
             targets: 1,
-            classes: ["RAmr", "RAml", "RAct", "RAcl"],
+            classes: &["RAmr", "RAml", "RAct", "RAcl"],
             notes: "",
             total_min_atp: 376,
             total_max_atp: 576,
@@ -6804,7 +6807,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 280,
             special: "Other",
             targets: 5,
-            classes: ["RAmr", "RAml", "RAct", "RAcl"],
+            classes: &["RAmr", "RAml", "RAct", "RAcl"],
             notes: "",
             total_min_atp: 200,
             total_max_atp: 330,
@@ -6827,7 +6830,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 270,
             special: "None",
             targets: 10,
-            classes: ["RAmr", "RAml", "RAct", "RAcl"],
+            classes: &["RAmr", "RAml", "RAct", "RAcl"],
             notes: "",
             total_min_atp: 310,
             total_max_atp: 310,
@@ -6850,7 +6853,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "None",
             targets: 1,
-            classes: ["RAmr", "RAml", "RAct", "RAcl"],
+            classes: &["RAmr", "RAml", "RAct", "RAcl"],
             notes: "",
             total_min_atp: 130,
             total_max_atp: 130,
@@ -6873,7 +6876,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 370,
             special: "None",
             targets: 10,
-            classes: ["RAmr", "RAml", "RAct", "RAcl"],
+            classes: &["RAmr", "RAml", "RAct", "RAcl"],
             notes: "",
             total_min_atp: 380,
             total_max_atp: 400,
@@ -6896,7 +6899,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 315,
             special: "Demons",
             targets: 10,
-            classes: ["RAmr", "RAml", "RAct", "RAcl"],
+            classes: &["RAmr", "RAml", "RAct", "RAcl"],
             notes: "",
             total_min_atp: 265,
             total_max_atp: 315,
@@ -6919,7 +6922,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 220,
             special: "Hold",
             targets: 4,
-            classes: ["RAmr", "RAml", "RAct", "RAcl"],
+            classes: &["RAmr", "RAml", "RAct", "RAcl"],
             notes: "",
             total_min_atp: 228,
             total_max_atp: 238,
@@ -6942,7 +6945,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 750,
             special: "Chaos",
             targets: 10,
-            classes: ["RAmr", "RAml", "RAct", "RAcl"],
+            classes: &["RAmr", "RAml", "RAct", "RAcl"],
             notes: "",
             total_min_atp: 660,
             total_max_atp: 810,
@@ -6965,7 +6968,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 620,
             special: "Seize",
             targets: 10,
-            classes: ["RAmr", "RAml", "RAct", "RAcl"],
+            classes: &["RAmr", "RAml", "RAct", "RAcl"],
             notes: "",
             total_min_atp: 520,
             total_max_atp: 620,
@@ -6988,7 +6991,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 250,
             special: "Blizzard",
             targets: 4,
-            classes: ["RAmr", "RAml", "RAct", "RAcl"],
+            classes: &["RAmr", "RAml", "RAct", "RAcl"],
             notes: "",
             total_min_atp: 276,
             total_max_atp: 286,
@@ -7011,7 +7014,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 360,
             special: "Havoc",
             targets: 4,
-            classes: ["RAmr", "RAml", "RAct", "RAcl"],
+            classes: &["RAmr", "RAml", "RAct", "RAcl"],
             notes: "",
             total_min_atp: 422,
             total_max_atp: 432,
@@ -7034,7 +7037,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 300,
             special: "Burning",
             targets: 4,
-            classes: ["RAmr", "RAml", "RAct", "RAcl"],
+            classes: &["RAmr", "RAml", "RAct", "RAcl"],
             notes: "",
             total_min_atp: 380,
             total_max_atp: 400,
@@ -7057,7 +7060,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 660,
             special: "None",
             targets: 10,
-            classes: ["RAmr", "RAml", "RAct", "RAcl"],
+            classes: &["RAmr", "RAml", "RAct", "RAcl"],
             notes: "",
             total_min_atp: 464,
             total_max_atp: 724,
@@ -7080,7 +7083,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 30,
             special: "Variable",
             targets: 1,
-            classes: ["FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 135,
             total_max_atp: 140,
@@ -7103,7 +7106,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 60,
             special: "Variable",
             targets: 1,
-            classes: ["FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 130,
             total_max_atp: 140,
@@ -7126,7 +7129,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 90,
             special: "Variable",
             targets: 1,
-            classes: ["FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 140,
             total_max_atp: 140,
@@ -7149,7 +7152,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 120,
             special: "Variable",
             targets: 1,
-            classes: ["FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 130,
             total_max_atp: 140,
@@ -7172,7 +7175,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 150,
             special: "Flame",
             targets: 1,
-            classes: ["FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 158,
             total_max_atp: 168,
@@ -7195,7 +7198,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 170,
             special: "Freeze",
             targets: 1,
-            classes: ["FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 188,
             total_max_atp: 188,
@@ -7218,7 +7221,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 180,
             special: "Storm",
             targets: 1,
-            classes: ["FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 196,
             total_max_atp: 198,
@@ -7241,7 +7244,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 180,
             special: "None",
             targets: 1,
-            classes: ["FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 168,
             total_max_atp: 180,
@@ -7264,7 +7267,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 200,
             special: "Heart",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 174,
             total_max_atp: 224,
@@ -7287,7 +7290,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 690,
             special: "None",
             targets: 1,
-            classes: ["HUnl", "RAml", "FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["HUnl", "RAml", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 660,
             total_max_atp: 690,
@@ -7310,7 +7313,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 310,
             special: "Other",
             targets: 1,
-            classes: ["HUmr", "HUnl", "RAml", "FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["HUmr", "HUnl", "RAml", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 318,
             total_max_atp: 328,
@@ -7333,7 +7336,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 200,
             special: "ShiftaDeband",
             targets: 1,
-            classes: ["FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 150,
             total_max_atp: 200,
@@ -7356,7 +7359,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 280,
             special: "Tempest",
             targets: 1,
-            classes: ["FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 288,
             total_max_atp: 298,
@@ -7379,7 +7382,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 45,
             special: "Variable",
             targets: 1,
-            classes: ["FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 175,
             total_max_atp: 195,
@@ -7402,7 +7405,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 80,
             special: "Variable",
             targets: 1,
-            classes: ["FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 155,
             total_max_atp: 180,
@@ -7425,7 +7428,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 115,
             special: "Variable",
             targets: 1,
-            classes: ["FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 145,
             total_max_atp: 175,
@@ -7448,7 +7451,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 150,
             special: "Variable",
             targets: 1,
-            classes: ["FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 140,
             total_max_atp: 190,
@@ -7471,7 +7474,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 230,
             special: "Fill",
             targets: 1,
-            classes: ["FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 240,
             total_max_atp: 260,
@@ -7494,7 +7497,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 185,
             special: "Devils",
             targets: 1,
-            classes: ["FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 160,
             total_max_atp: 215,
@@ -7517,7 +7520,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 220,
             special: "Spirit",
             targets: 1,
-            classes: ["FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 200,
             total_max_atp: 250,
@@ -7540,7 +7543,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 230,
             special: "Havoc",
             targets: 1,
-            classes: ["FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 90,
             total_max_atp: 230,
@@ -7563,7 +7566,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 230,
             special: "Chaos",
             targets: 1,
-            classes: ["FOml", "FOnl"],
+            classes: &["FOml", "FOnl"],
             notes: "",
             total_min_atp: 120,
             total_max_atp: 230,
@@ -7586,7 +7589,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 267,
             special: "None",
             targets: 1,
-            classes: ["FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 240,
             total_max_atp: 267,
@@ -7609,7 +7612,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 360,
             special: "Foie",
             targets: 1,
-            classes: ["FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 360,
             total_max_atp: 360,
@@ -7632,7 +7635,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 340,
             special: "Kings",
             targets: 1,
-            classes: ["FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 325,
             total_max_atp: 340,
@@ -7655,7 +7658,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 280,
             special: "None",
             targets: 1,
-            classes: ["HUmr", "HUnl", "RAmr", "RAml"],
+            classes: &["HUmr", "HUnl", "RAmr", "RAml"],
             notes: "",
             total_min_atp: 219,
             total_max_atp: 280,
@@ -7678,7 +7681,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 400,
             special: "Anti",
             targets: 1,
-            classes: ["FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 185,
             total_max_atp: 400,
@@ -7701,7 +7704,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 230,
             special: "Foie",
             targets: 1,
-            classes: ["FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 238,
             total_max_atp: 248,
@@ -7724,7 +7727,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 300,
             special: "Gibarta",
             targets: 1,
-            classes: ["FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 308,
             total_max_atp: 318,
@@ -7747,7 +7750,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 280,
             special: "None",
             targets: 1,
-            classes: ["HUmr", "HUnl", "RAmr", "RAml", "FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["HUmr", "HUnl", "RAmr", "RAml", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 219,
             total_max_atp: 280,
@@ -7770,7 +7773,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 450,
             special: "Grants",
             targets: 1,
-            classes: ["FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 390,
             total_max_atp: 450,
@@ -7793,7 +7796,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 360,
             special: "Geist",
             targets: 1,
-            classes: ["FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 360,
             total_max_atp: 360,
@@ -7816,7 +7819,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 600,
             special: "None",
             targets: 1,
-            classes: ["HUnl", "HUcl", "RAml", "RAcl", "FOml", "FOml"],
+            classes: &["HUnl", "HUcl", "RAml", "RAcl", "FOml", "FOnl"],
             notes: "",
             total_min_atp: 666,
             total_max_atp: 666,
@@ -7839,7 +7842,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 400,
             special: "None",
             targets: 1,
-            classes: ["FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 185,
             total_max_atp: 400,
@@ -7862,7 +7865,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 400,
             special: "Gifoie",
             targets: 1,
-            classes: ["FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 390,
             total_max_atp: 400,
@@ -7885,7 +7888,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 325,
             special: "Arrest",
             targets: 1,
-            classes: ["FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 300,
             total_max_atp: 325,
@@ -7908,7 +7911,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 400,
             special: "Other",
             targets: 1,
-            classes: ["FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 395,
             total_max_atp: 400,
@@ -7931,7 +7934,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 80,
             special: "None",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl"],
             notes: "",
             total_min_atp: 210,
             total_max_atp: 280,
@@ -7954,7 +7957,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "None",
             targets: 1,
-            classes: ["FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 190,
             total_max_atp: 190,
@@ -7977,7 +7980,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 100,
             special: "None",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl"],
             notes: "",
             total_min_atp: 210,
             total_max_atp: 300,
@@ -8000,7 +8003,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Variable",
             targets: 1,
-            classes: ["FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 33,
             total_max_atp: 40,
@@ -8023,7 +8026,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 30,
             special: "Variable",
             targets: 1,
-            classes: ["FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 40,
             total_max_atp: 60,
@@ -8046,7 +8049,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 50,
             special: "Variable",
             targets: 1,
-            classes: ["FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 55,
             total_max_atp: 70,
@@ -8069,7 +8072,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 75,
             special: "Variable",
             targets: 1,
-            classes: ["FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 75,
             total_max_atp: 95,
@@ -8092,7 +8095,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 95,
             special: "Flame",
             targets: 1,
-            classes: ["FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 88,
             total_max_atp: 113,
@@ -8115,7 +8118,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 105,
             special: "Freeze",
             targets: 1,
-            classes: ["FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 91,
             total_max_atp: 123,
@@ -8138,7 +8141,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 120,
             special: "Storm",
             targets: 1,
-            classes: ["FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 123,
             total_max_atp: 138,
@@ -8161,7 +8164,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 1,
             special: "None",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 1,
             total_max_atp: 1,
@@ -8184,7 +8187,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 5,
             special: "None",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 5,
             total_max_atp: 5,
@@ -8207,7 +8210,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 180,
             special: "Havoc",
             targets: 1,
-            classes: ["HUnl", "RAml", "FOml", "FOnl"],
+            classes: &["HUnl", "RAml", "FOml", "FOnl"],
             notes: "",
             total_min_atp: 10,
             total_max_atp: 180,
@@ -8230,7 +8233,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 670,
             special: "Gush",
             targets: 1,
-            classes: ["FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 410,
             total_max_atp: 710,
@@ -8253,7 +8256,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 300,
             special: "Resta",
             targets: 1,
-            classes: ["FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 250,
             total_max_atp: 300,
@@ -8276,7 +8279,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 135,
             special: "Barta",
             targets: 1,
-            classes: ["FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 130,
             total_max_atp: 135,
@@ -8299,7 +8302,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 180,
             special: "Gizonde",
             targets: 1,
-            classes: ["FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 175,
             total_max_atp: 180,
@@ -8322,7 +8325,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 240,
             special: "Zonde",
             targets: 1,
-            classes: ["FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 240,
             total_max_atp: 240,
@@ -8345,7 +8348,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 150,
             special: "Other",
             targets: 1,
-            classes: ["FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 175,
             total_max_atp: 230,
@@ -8368,7 +8371,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 250,
             special: "JellenZalure",
             targets: 1,
-            classes: ["FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 90,
             total_max_atp: 250,
@@ -8391,7 +8394,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 175,
             special: "Tempest",
             targets: 1,
-            classes: ["FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 175,
             total_max_atp: 175,
@@ -8414,7 +8417,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "None",
             targets: 1,
-            classes: ["FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 130,
             total_max_atp: 130,
@@ -8437,7 +8440,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 1,
             special: "Charge",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 1,
             total_max_atp: 1,
@@ -8460,7 +8463,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 7,
             special: "None",
             targets: 1,
-            classes: ["HUmr", "HUnl", "RAmr", "RAml", "FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["HUmr", "HUnl", "RAmr", "RAml", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 5,
             total_max_atp: 7,
@@ -8483,7 +8486,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 175,
             special: "Arrest",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 95,
             total_max_atp: 175,
@@ -8506,7 +8509,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 175,
             special: "Kings",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 95,
             total_max_atp: 175,
@@ -8529,7 +8532,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 310,
             special: "Other",
             targets: 1,
-            classes: ["FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 300,
             total_max_atp: 340,
@@ -8552,7 +8555,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 280,
             special: "Spirit",
             targets: 1,
-            classes: ["FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 218,
             total_max_atp: 298,
@@ -8575,7 +8578,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 230,
             special: "Soul",
             targets: 1,
-            classes: ["FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 168,
             total_max_atp: 248,
@@ -8598,7 +8601,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 200,
             special: "Soul",
             targets: 1,
-            classes: ["FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 138,
             total_max_atp: 218,
@@ -8621,7 +8624,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 180,
             special: "Mind",
             targets: 1,
-            classes: ["FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 138,
             total_max_atp: 198,
@@ -8644,7 +8647,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 250,
             special: "None",
             targets: 1,
-            classes: ["FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 200,
             total_max_atp: 250,
@@ -8667,7 +8670,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 250,
             special: "None",
             targets: 1,
-            classes: ["FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 200,
             total_max_atp: 250,
@@ -8690,7 +8693,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 250,
             special: "None",
             targets: 1,
-            classes: ["FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 200,
             total_max_atp: 250,
@@ -8713,7 +8716,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 250,
             special: "None",
             targets: 1,
-            classes: ["FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 200,
             total_max_atp: 250,
@@ -8736,7 +8739,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 250,
             special: "None",
             targets: 1,
-            classes: ["FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 200,
             total_max_atp: 250,
@@ -8759,7 +8762,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 250,
             special: "None",
             targets: 1,
-            classes: ["FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 200,
             total_max_atp: 250,
@@ -8782,7 +8785,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 250,
             special: "None",
             targets: 1,
-            classes: ["FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 200,
             total_max_atp: 250,
@@ -8805,7 +8808,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 250,
             special: "None",
             targets: 1,
-            classes: ["FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 200,
             total_max_atp: 250,
@@ -8828,7 +8831,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 250,
             special: "None",
             targets: 1,
-            classes: ["FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 200,
             total_max_atp: 250,
@@ -8851,7 +8854,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 250,
             special: "None",
             targets: 1,
-            classes: ["FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 200,
             total_max_atp: 250,
@@ -8874,7 +8877,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 354,
             special: "Geist",
             targets: 1,
-            classes: ["FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 350,
             total_max_atp: 354,
@@ -8897,7 +8900,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 200,
             special: "Variable",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 700,
             total_max_atp: 700,
@@ -8920,7 +8923,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Variable",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 510,
             total_max_atp: 510,
@@ -8943,7 +8946,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Variable",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 410,
             total_max_atp: 410,
@@ -8966,7 +8969,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Variable",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 250,
             total_max_atp: 250,
@@ -8989,7 +8992,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Variable",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 310,
             total_max_atp: 310,
@@ -9012,7 +9015,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 180,
             special: "Variable",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 680,
             total_max_atp: 680,
@@ -9035,7 +9038,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Variable",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 410,
             total_max_atp: 410,
@@ -9058,7 +9061,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 150,
             special: "Variable",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 650,
             total_max_atp: 650,
@@ -9081,7 +9084,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 150,
             special: "Variable",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 650,
             total_max_atp: 650,
@@ -9104,7 +9107,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 25,
             special: "Variable",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 325,
             total_max_atp: 325,
@@ -9127,7 +9130,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 170,
             special: "Variable",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 670,
             total_max_atp: 670,
@@ -9150,7 +9153,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 250,
             special: "Variable",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 750,
             total_max_atp: 750,
@@ -9173,7 +9176,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Variable",
             targets: 4,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 370,
             total_max_atp: 370,
@@ -9196,7 +9199,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Variable",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 110,
             total_max_atp: 110,
@@ -9219,7 +9222,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Variable",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 250,
             total_max_atp: 250,
@@ -9242,7 +9245,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Variable",
             targets: 5,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 150,
             total_max_atp: 150,
@@ -9265,7 +9268,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Variable",
             targets: 10,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 410,
             total_max_atp: 410,
@@ -9288,7 +9291,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Other",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 110,
             total_max_atp: 110,
@@ -9311,7 +9314,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Variable",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 510,
             total_max_atp: 510,
@@ -9334,7 +9337,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Variable",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 450,
             total_max_atp: 450,
@@ -9357,7 +9360,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Variable",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 370,
             total_max_atp: 370,
@@ -9380,7 +9383,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 150,
             special: "Variable",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 650,
             total_max_atp: 650,
@@ -9403,7 +9406,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Variable",
             targets: 10,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 370,
             total_max_atp: 370,
@@ -9426,7 +9429,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Variable",
             targets: 5,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 260,
             total_max_atp: 260,
@@ -9449,7 +9452,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Variable",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 18,
             total_max_atp: 18,
@@ -9472,7 +9475,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 200,
             special: "Variable",
             targets: 10,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 700,
             total_max_atp: 700,
@@ -9495,7 +9498,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 180,
             special: "Variable",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 680,
             total_max_atp: 680,
@@ -9518,7 +9521,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 50,
             special: "Variable",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 550,
             total_max_atp: 550,
@@ -9541,7 +9544,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Variable",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 210,
             total_max_atp: 210,
@@ -9564,7 +9567,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Variable",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 250,
             total_max_atp: 250,
@@ -9587,7 +9590,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 0,
             special: "None",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 0,
             total_max_atp: 0,
@@ -9610,7 +9613,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 0,
             special: "None",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 0,
             total_max_atp: 0,
@@ -9633,7 +9636,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 0,
             special: "None",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 0,
             total_max_atp: 0,
@@ -9656,7 +9659,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 0,
             special: "None",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 0,
             total_max_atp: 0,
@@ -9679,7 +9682,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 0,
             special: "None",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 0,
             total_max_atp: 0,
@@ -9702,7 +9705,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 0,
             special: "None",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 0,
             total_max_atp: 0,
@@ -9725,7 +9728,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 0,
             special: "None",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 0,
             total_max_atp: 0,
@@ -9748,7 +9751,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 0,
             special: "None",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 0,
             total_max_atp: 0,
@@ -9771,7 +9774,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 0,
             special: "None",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 0,
             total_max_atp: 0,
@@ -9794,7 +9797,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 0,
             special: "None",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 0,
             total_max_atp: 0,
@@ -9817,7 +9820,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 0,
             special: "None",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 0,
             total_max_atp: 0,
@@ -9840,7 +9843,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 0,
             special: "None",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 0,
             total_max_atp: 0,
@@ -9863,7 +9866,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 0,
             special: "None",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 0,
             total_max_atp: 0,
@@ -9886,7 +9889,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 0,
             special: "None",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 0,
             total_max_atp: 0,
@@ -9909,7 +9912,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 0,
             special: "None",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 0,
             total_max_atp: 0,
@@ -9932,7 +9935,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 0,
             special: "None",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 0,
             total_max_atp: 0,
@@ -9955,7 +9958,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 0,
             special: "None",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 0,
             total_max_atp: 0,
@@ -9978,7 +9981,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 0,
             special: "None",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 0,
             total_max_atp: 0,
@@ -10001,7 +10004,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 0,
             special: "None",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 0,
             total_max_atp: 0,
@@ -10024,7 +10027,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 0,
             special: "None",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 0,
             total_max_atp: 0,
@@ -10047,7 +10050,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 0,
             special: "None",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 0,
             total_max_atp: 0,
@@ -10070,7 +10073,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 0,
             special: "None",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 0,
             total_max_atp: 0,
@@ -10093,7 +10096,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 0,
             special: "None",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 0,
             total_max_atp: 0,
@@ -10116,7 +10119,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 0,
             special: "None",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 0,
             total_max_atp: 0,
@@ -10139,7 +10142,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 0,
             special: "None",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 0,
             total_max_atp: 0,
@@ -10162,7 +10165,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 0,
             special: "None",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 0,
             total_max_atp: 0,
@@ -10185,7 +10188,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 0,
             special: "None",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 0,
             total_max_atp: 0,
@@ -10208,7 +10211,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 0,
             special: "None",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 0,
             total_max_atp: 0,
@@ -10231,7 +10234,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 0,
             special: "None",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 0,
             total_max_atp: 0,
@@ -10254,7 +10257,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 0,
             special: "None",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 0,
             total_max_atp: 0,
@@ -10277,7 +10280,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 0,
             special: "None",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 0,
             total_max_atp: 0,
@@ -10300,7 +10303,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 0,
             special: "None",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 0,
             total_max_atp: 0,
@@ -10323,7 +10326,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 0,
             special: "None",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 0,
             total_max_atp: 0,
@@ -10346,7 +10349,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 0,
             special: "None",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 0,
             total_max_atp: 0,
@@ -10369,7 +10372,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 0,
             special: "None",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 0,
             total_max_atp: 0,
@@ -10392,7 +10395,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 400,
             special: "Hell",
             targets: 10,
-            classes: ["HUmr", "HUct", "RAmr", "RAct", "FOmr"],
+            classes: &["HUmr", "HUct", "RAmr", "RAct", "FOmr"],
             notes: "",
             total_min_atp: 398,
             total_max_atp: 418,
@@ -10415,7 +10418,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 25,
             special: "Berserk",
             targets: 1,
-            classes: ["RAmr", "RAml", "RAct", "RAcl"],
+            classes: &["RAmr", "RAml", "RAct", "RAcl"],
             notes: "",
             total_min_atp: 33,
             total_max_atp: 43,
@@ -10438,7 +10441,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 25,
             special: "Berserk",
             targets: 1,
-            classes: ["RAmr", "RAml", "RAct", "RAcl"],
+            classes: &["RAmr", "RAml", "RAct", "RAcl"],
             notes: "",
             total_min_atp: 33,
             total_max_atp: 43,
@@ -10461,7 +10464,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 110,
             special: "Seize",
             targets: 5,
-            classes: ["RAmr", "RAml", "RAct", "RAcl"],
+            classes: &["RAmr", "RAml", "RAct", "RAcl"],
             notes: "",
             total_min_atp: 81,
             total_max_atp: 190,
@@ -10484,7 +10487,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 370,
             special: "Demons",
             targets: 1,
-            classes: ["RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
+            classes: &["RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 348,
             total_max_atp: 388,
@@ -10507,7 +10510,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 400,
             special: "Other",
             targets: 1,
-            classes: ["RAmr", "RAml", "RAct", "RAcl"],
+            classes: &["RAmr", "RAml", "RAct", "RAcl"],
             notes: "",
             total_min_atp: 395,
             total_max_atp: 400,
@@ -10530,7 +10533,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 400,
             special: "Other",
             targets: 1,
-            classes: ["RAmr", "RAml", "RAct", "RAcl"],
+            classes: &["RAmr", "RAml", "RAct", "RAcl"],
             notes: "",
             total_min_atp: 395,
             total_max_atp: 400,
@@ -10553,7 +10556,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 330,
             special: "Other",
             targets: 1,
-            classes: ["RAmr", "RAml", "RAct", "RAcl"],
+            classes: &["RAmr", "RAml", "RAct", "RAcl"],
             notes: "",
             total_min_atp: 300,
             total_max_atp: 330,
@@ -10576,7 +10579,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 330,
             special: "Other",
             targets: 1,
-            classes: ["RAmr", "RAml", "RAct", "RAcl"],
+            classes: &["RAmr", "RAml", "RAct", "RAcl"],
             notes: "",
             total_min_atp: 300,
             total_max_atp: 330,
@@ -10599,7 +10602,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 525,
             special: "Gush",
             targets: 10,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl"],
             notes: "",
             total_min_atp: 530,
             total_max_atp: 555,
@@ -10622,7 +10625,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 525,
             special: "Gush",
             targets: 10,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl"],
             notes: "",
             total_min_atp: 530,
             total_max_atp: 555,
@@ -10645,7 +10648,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 240,
             special: "Zonde",
             targets: 1,
-            classes: ["FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 240,
             total_max_atp: 240,
@@ -10668,7 +10671,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 489,
             special: "Fill",
             targets: 1,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 606,
             total_max_atp: 645,
@@ -10691,7 +10694,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 756,
             special: "Other",
             targets: 10,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl"],
             notes: "",
             total_min_atp: 800,
             total_max_atp: 856,
@@ -10714,7 +10717,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 756,
             special: "Other",
             targets: 10,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl"],
             notes: "",
             total_min_atp: 800,
             total_max_atp: 856,
@@ -10737,7 +10740,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 756,
             special: "Other",
             targets: 10,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl"],
             notes: "",
             total_min_atp: 800,
             total_max_atp: 856,
@@ -10760,7 +10763,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 611,
             special: "Seize",
             targets: 10,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl"],
             notes: "",
             total_min_atp: 504,
             total_max_atp: 715,
@@ -10783,7 +10786,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 611,
             special: "Seize",
             targets: 10,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl"],
             notes: "",
             total_min_atp: 504,
             total_max_atp: 715,
@@ -10806,7 +10809,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 250,
             special: "Other",
             targets: 1,
-            classes: ["RAmr", "RAml", "RAct", "RAcl", "FOmr"],
+            classes: &["RAmr", "RAml", "RAct", "RAcl", "FOmr"],
             notes: "",
             total_min_atp: 258,
             total_max_atp: 268,
@@ -10829,7 +10832,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 200,
             special: "Gush",
             targets: 1,
-            classes: ["RAmr", "RAml", "RAct", "RAcl", "FOmr"],
+            classes: &["RAmr", "RAml", "RAct", "RAcl", "FOmr"],
             notes: "",
             total_min_atp: 198,
             total_max_atp: 218,
@@ -10852,7 +10855,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 210,
             special: "Kings",
             targets: 1,
-            classes: ["HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr"],
+            classes: &["HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr"],
             notes: "",
             total_min_atp: 242,
             total_max_atp: 252,
@@ -10875,7 +10878,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 280,
             special: "Tempest",
             targets: 1,
-            classes: ["FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 288,
             total_max_atp: 298,
@@ -10898,7 +10901,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 325,
             special: "Arrest",
             targets: 1,
-            classes: ["FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 300,
             total_max_atp: 325,
@@ -10921,7 +10924,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 325,
             special: "Arrest",
             targets: 1,
-            classes: ["FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 300,
             total_max_atp: 325,
@@ -10944,7 +10947,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 250,
             special: "JellenZalure",
             targets: 1,
-            classes: ["FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 90,
             total_max_atp: 250,
@@ -10967,7 +10970,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 150,
             special: "Jellen",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 650,
             total_max_atp: 650,
@@ -10990,7 +10993,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 150,
             special: "Zalure",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 650,
             total_max_atp: 650,
@@ -11013,7 +11016,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 150,
             special: "HP_Regeneration",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 650,
             total_max_atp: 650,
@@ -11036,7 +11039,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 150,
             special: "TP_Regeneration",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 650,
             total_max_atp: 650,
@@ -11059,7 +11062,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 150,
             special: "Burning",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 650,
             total_max_atp: 650,
@@ -11082,7 +11085,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 150,
             special: "Tempest",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 650,
             total_max_atp: 650,
@@ -11105,7 +11108,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 150,
             special: "Blizzard",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 650,
             total_max_atp: 650,
@@ -11128,7 +11131,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 150,
             special: "Arrest",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 650,
             total_max_atp: 650,
@@ -11151,7 +11154,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 150,
             special: "Chaos",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 650,
             total_max_atp: 650,
@@ -11174,7 +11177,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 150,
             special: "Hell",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 650,
             total_max_atp: 650,
@@ -11197,7 +11200,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 150,
             special: "Spirit",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 650,
             total_max_atp: 650,
@@ -11220,7 +11223,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 150,
             special: "Berserk",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 650,
             total_max_atp: 650,
@@ -11243,7 +11246,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 150,
             special: "Demons",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 650,
             total_max_atp: 650,
@@ -11266,7 +11269,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 150,
             special: "Gush",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 650,
             total_max_atp: 650,
@@ -11289,7 +11292,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 150,
             special: "Geist",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 650,
             total_max_atp: 650,
@@ -11312,7 +11315,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 150,
             special: "Kings",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 650,
             total_max_atp: 650,
@@ -11335,7 +11338,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 200,
             special: "Jellen",
             targets: 10,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 700,
             total_max_atp: 700,
@@ -11358,7 +11361,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 200,
             special: "Zalure",
             targets: 10,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 700,
             total_max_atp: 700,
@@ -11381,7 +11384,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 200,
             special: "HP_Regeneration",
             targets: 10,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 700,
             total_max_atp: 700,
@@ -11404,7 +11407,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 200,
             special: "TP_Regeneration",
             targets: 10,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 700,
             total_max_atp: 700,
@@ -11427,7 +11430,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 200,
             special: "Burning",
             targets: 10,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 700,
             total_max_atp: 700,
@@ -11450,7 +11453,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 200,
             special: "Tempest",
             targets: 10,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 700,
             total_max_atp: 700,
@@ -11473,7 +11476,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 200,
             special: "Blizzard",
             targets: 10,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 700,
             total_max_atp: 700,
@@ -11496,7 +11499,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 200,
             special: "Arrest",
             targets: 10,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 700,
             total_max_atp: 700,
@@ -11519,7 +11522,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 200,
             special: "Chaos",
             targets: 10,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 700,
             total_max_atp: 700,
@@ -11542,7 +11545,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 200,
             special: "Hell",
             targets: 10,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 700,
             total_max_atp: 700,
@@ -11565,7 +11568,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 200,
             special: "Spirit",
             targets: 10,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 700,
             total_max_atp: 700,
@@ -11588,7 +11591,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 200,
             special: "Berserk",
             targets: 10,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 700,
             total_max_atp: 700,
@@ -11611,7 +11614,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 200,
             special: "Demons",
             targets: 10,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 700,
             total_max_atp: 700,
@@ -11634,7 +11637,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 200,
             special: "Gush",
             targets: 10,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 700,
             total_max_atp: 700,
@@ -11657,7 +11660,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 200,
             special: "Geist",
             targets: 10,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 700,
             total_max_atp: 700,
@@ -11680,7 +11683,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 200,
             special: "Kings",
             targets: 10,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 700,
             total_max_atp: 700,
@@ -11703,7 +11706,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Jellen",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 410,
             total_max_atp: 410,
@@ -11726,7 +11729,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Zalure",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 410,
             total_max_atp: 410,
@@ -11749,7 +11752,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "HP_Regeneration",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 410,
             total_max_atp: 410,
@@ -11772,7 +11775,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "TP_Regeneration",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 410,
             total_max_atp: 410,
@@ -11795,7 +11798,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Burning",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 410,
             total_max_atp: 410,
@@ -11818,7 +11821,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Tempest",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 410,
             total_max_atp: 410,
@@ -11841,7 +11844,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Blizzard",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 410,
             total_max_atp: 410,
@@ -11864,7 +11867,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Arrest",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 410,
             total_max_atp: 410,
@@ -11887,7 +11890,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Chaos",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 410,
             total_max_atp: 410,
@@ -11910,7 +11913,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Hell",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 410,
             total_max_atp: 410,
@@ -11933,7 +11936,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Spirit",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 410,
             total_max_atp: 410,
@@ -11956,7 +11959,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Berserk",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 410,
             total_max_atp: 410,
@@ -11979,7 +11982,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Demons",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 410,
             total_max_atp: 410,
@@ -12002,7 +12005,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Gush",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 410,
             total_max_atp: 410,
@@ -12025,7 +12028,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Geist",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 410,
             total_max_atp: 410,
@@ -12048,7 +12051,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Kings",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 410,
             total_max_atp: 410,
@@ -12071,7 +12074,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Jellen",
             targets: 10,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 410,
             total_max_atp: 410,
@@ -12094,7 +12097,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Zalure",
             targets: 10,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 410,
             total_max_atp: 410,
@@ -12117,7 +12120,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "HP_Regeneration",
             targets: 10,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 410,
             total_max_atp: 410,
@@ -12140,7 +12143,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "TP_Regeneration",
             targets: 10,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 410,
             total_max_atp: 410,
@@ -12163,7 +12166,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Burning",
             targets: 10,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 410,
             total_max_atp: 410,
@@ -12186,7 +12189,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Tempest",
             targets: 10,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 410,
             total_max_atp: 410,
@@ -12209,7 +12212,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Blizzard",
             targets: 10,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 410,
             total_max_atp: 410,
@@ -12232,7 +12235,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Arrest",
             targets: 10,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 410,
             total_max_atp: 410,
@@ -12255,7 +12258,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Chaos",
             targets: 10,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 410,
             total_max_atp: 410,
@@ -12278,7 +12281,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Hell",
             targets: 10,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 410,
             total_max_atp: 410,
@@ -12301,7 +12304,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Spirit",
             targets: 10,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 410,
             total_max_atp: 410,
@@ -12324,7 +12327,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Berserk",
             targets: 10,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 410,
             total_max_atp: 410,
@@ -12347,7 +12350,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Demons",
             targets: 10,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 410,
             total_max_atp: 410,
@@ -12370,7 +12373,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Gush",
             targets: 10,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 410,
             total_max_atp: 410,
@@ -12393,7 +12396,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Geist",
             targets: 10,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 410,
             total_max_atp: 410,
@@ -12416,7 +12419,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Kings",
             targets: 10,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 410,
             total_max_atp: 410,
@@ -12439,7 +12442,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Jellen",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 18,
             total_max_atp: 18,
@@ -12462,7 +12465,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Zalure",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 18,
             total_max_atp: 18,
@@ -12485,7 +12488,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "HP_Regeneration",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 18,
             total_max_atp: 18,
@@ -12508,7 +12511,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "TP_Regeneration",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 18,
             total_max_atp: 18,
@@ -12531,7 +12534,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Burning",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 18,
             total_max_atp: 18,
@@ -12554,7 +12557,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Tempest",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 18,
             total_max_atp: 18,
@@ -12577,7 +12580,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Blizzard",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 18,
             total_max_atp: 18,
@@ -12600,7 +12603,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Arrest",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 18,
             total_max_atp: 18,
@@ -12623,7 +12626,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Chaos",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 18,
             total_max_atp: 18,
@@ -12646,7 +12649,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Hell",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 18,
             total_max_atp: 18,
@@ -12669,7 +12672,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Spirit",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 18,
             total_max_atp: 18,
@@ -12692,7 +12695,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Berserk",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 18,
             total_max_atp: 18,
@@ -12715,7 +12718,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Demons",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 18,
             total_max_atp: 18,
@@ -12738,7 +12741,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Gush",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 18,
             total_max_atp: 18,
@@ -12761,7 +12764,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Geist",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 18,
             total_max_atp: 18,
@@ -12784,7 +12787,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Kings",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 18,
             total_max_atp: 18,
@@ -12807,7 +12810,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Jellen",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 410,
             total_max_atp: 410,
@@ -12830,7 +12833,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Zalure",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 410,
             total_max_atp: 410,
@@ -12853,7 +12856,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "HP_Regeneration",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 410,
             total_max_atp: 410,
@@ -12876,7 +12879,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "TP_Regeneration",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 410,
             total_max_atp: 410,
@@ -12899,7 +12902,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Burning",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 410,
             total_max_atp: 410,
@@ -12922,7 +12925,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Tempest",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 410,
             total_max_atp: 410,
@@ -12945,7 +12948,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Blizzard",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 410,
             total_max_atp: 410,
@@ -12968,7 +12971,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Arrest",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 410,
             total_max_atp: 410,
@@ -12991,7 +12994,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Chaos",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 410,
             total_max_atp: 410,
@@ -13014,7 +13017,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Hell",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 410,
             total_max_atp: 410,
@@ -13037,7 +13040,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Spirit",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 410,
             total_max_atp: 410,
@@ -13060,7 +13063,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Berserk",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 410,
             total_max_atp: 410,
@@ -13083,7 +13086,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Demons",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 410,
             total_max_atp: 410,
@@ -13106,7 +13109,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Gush",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 410,
             total_max_atp: 410,
@@ -13129,7 +13132,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Geist",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 410,
             total_max_atp: 410,
@@ -13152,7 +13155,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Kings",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 410,
             total_max_atp: 410,
@@ -13175,7 +13178,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Jellen",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 450,
             total_max_atp: 450,
@@ -13198,7 +13201,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Zalure",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 450,
             total_max_atp: 450,
@@ -13221,7 +13224,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "HP_Regeneration",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 450,
             total_max_atp: 450,
@@ -13244,7 +13247,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "TP_Regeneration",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 450,
             total_max_atp: 450,
@@ -13267,7 +13270,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Burning",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 450,
             total_max_atp: 450,
@@ -13290,7 +13293,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Tempest",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 450,
             total_max_atp: 450,
@@ -13313,7 +13316,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Blizzard",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 450,
             total_max_atp: 450,
@@ -13336,7 +13339,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Arrest",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 450,
             total_max_atp: 450,
@@ -13359,7 +13362,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Chaos",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 450,
             total_max_atp: 450,
@@ -13382,7 +13385,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Hell",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 450,
             total_max_atp: 450,
@@ -13405,7 +13408,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Spirit",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 450,
             total_max_atp: 450,
@@ -13428,7 +13431,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Berserk",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 450,
             total_max_atp: 450,
@@ -13451,7 +13454,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Demons",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 450,
             total_max_atp: 450,
@@ -13474,7 +13477,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Gush",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 450,
             total_max_atp: 450,
@@ -13497,7 +13500,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Geist",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 450,
             total_max_atp: 450,
@@ -13520,7 +13523,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Kings",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 450,
             total_max_atp: 450,
@@ -13543,7 +13546,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Jellen",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 110,
             total_max_atp: 110,
@@ -13566,7 +13569,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Zalure",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 110,
             total_max_atp: 110,
@@ -13589,7 +13592,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "HP_Regeneration",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 110,
             total_max_atp: 110,
@@ -13612,7 +13615,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "TP_Regeneration",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 110,
             total_max_atp: 110,
@@ -13635,7 +13638,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Burning",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 110,
             total_max_atp: 110,
@@ -13658,7 +13661,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Tempest",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 110,
             total_max_atp: 110,
@@ -13681,7 +13684,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Blizzard",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 110,
             total_max_atp: 110,
@@ -13704,7 +13707,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Arrest",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 110,
             total_max_atp: 110,
@@ -13727,7 +13730,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Chaos",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 110,
             total_max_atp: 110,
@@ -13750,7 +13753,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Hell",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 110,
             total_max_atp: 110,
@@ -13773,7 +13776,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Spirit",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 110,
             total_max_atp: 110,
@@ -13796,7 +13799,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Berserk",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 110,
             total_max_atp: 110,
@@ -13819,7 +13822,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Demons",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 110,
             total_max_atp: 110,
@@ -13842,7 +13845,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Gush",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 110,
             total_max_atp: 110,
@@ -13865,7 +13868,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Geist",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 110,
             total_max_atp: 110,
@@ -13888,7 +13891,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Kings",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 110,
             total_max_atp: 110,
@@ -13911,7 +13914,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Jellen",
             targets: 5,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 260,
             total_max_atp: 260,
@@ -13934,7 +13937,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Zalure",
             targets: 5,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 260,
             total_max_atp: 260,
@@ -13957,7 +13960,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "HP_Regeneration",
             targets: 5,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 260,
             total_max_atp: 260,
@@ -13980,7 +13983,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "TP_Regeneration",
             targets: 5,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 260,
             total_max_atp: 260,
@@ -14003,7 +14006,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Burning",
             targets: 5,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 260,
             total_max_atp: 260,
@@ -14026,7 +14029,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Tempest",
             targets: 5,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 260,
             total_max_atp: 260,
@@ -14049,7 +14052,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Blizzard",
             targets: 5,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 260,
             total_max_atp: 260,
@@ -14072,7 +14075,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Arrest",
             targets: 5,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 260,
             total_max_atp: 260,
@@ -14095,7 +14098,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Chaos",
             targets: 5,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 260,
             total_max_atp: 260,
@@ -14118,7 +14121,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Hell",
             targets: 5,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 260,
             total_max_atp: 260,
@@ -14141,7 +14144,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Spirit",
             targets: 5,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 260,
             total_max_atp: 260,
@@ -14164,7 +14167,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Berserk",
             targets: 5,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 260,
             total_max_atp: 260,
@@ -14187,7 +14190,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Demons",
             targets: 5,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 260,
             total_max_atp: 260,
@@ -14210,7 +14213,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Gush",
             targets: 5,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 260,
             total_max_atp: 260,
@@ -14233,7 +14236,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Geist",
             targets: 5,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 260,
             total_max_atp: 260,
@@ -14256,7 +14259,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Kings",
             targets: 5,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 260,
             total_max_atp: 260,
@@ -14279,7 +14282,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Jellen",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 250,
             total_max_atp: 250,
@@ -14302,7 +14305,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Zalure",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 250,
             total_max_atp: 250,
@@ -14325,7 +14328,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "HP_Regeneration",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 250,
             total_max_atp: 250,
@@ -14348,7 +14351,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "TP_Regeneration",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 250,
             total_max_atp: 250,
@@ -14371,7 +14374,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Burning",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 250,
             total_max_atp: 250,
@@ -14394,7 +14397,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Tempest",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 250,
             total_max_atp: 250,
@@ -14417,7 +14420,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Blizzard",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 250,
             total_max_atp: 250,
@@ -14440,7 +14443,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Arrest",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 250,
             total_max_atp: 250,
@@ -14463,7 +14466,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Chaos",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 250,
             total_max_atp: 250,
@@ -14486,7 +14489,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Hell",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 250,
             total_max_atp: 250,
@@ -14509,7 +14512,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Spirit",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 250,
             total_max_atp: 250,
@@ -14532,7 +14535,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Berserk",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 250,
             total_max_atp: 250,
@@ -14555,7 +14558,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Demons",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 250,
             total_max_atp: 250,
@@ -14578,7 +14581,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Gush",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 250,
             total_max_atp: 250,
@@ -14601,7 +14604,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Geist",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 250,
             total_max_atp: 250,
@@ -14624,7 +14627,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Kings",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 250,
             total_max_atp: 250,
@@ -14647,7 +14650,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Jellen",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 370,
             total_max_atp: 370,
@@ -14670,7 +14673,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Zalure",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 370,
             total_max_atp: 370,
@@ -14693,7 +14696,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "HP_Regeneration",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 370,
             total_max_atp: 370,
@@ -14716,7 +14719,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "TP_Regeneration",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 370,
             total_max_atp: 370,
@@ -14739,7 +14742,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Burning",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 370,
             total_max_atp: 370,
@@ -14762,7 +14765,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Tempest",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 370,
             total_max_atp: 370,
@@ -14785,7 +14788,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Blizzard",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 370,
             total_max_atp: 370,
@@ -14808,7 +14811,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Arrest",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 370,
             total_max_atp: 370,
@@ -14831,7 +14834,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Chaos",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 370,
             total_max_atp: 370,
@@ -14854,7 +14857,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Hell",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 370,
             total_max_atp: 370,
@@ -14877,7 +14880,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Spirit",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 370,
             total_max_atp: 370,
@@ -14900,7 +14903,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Berserk",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 370,
             total_max_atp: 370,
@@ -14923,7 +14926,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Demons",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 370,
             total_max_atp: 370,
@@ -14946,7 +14949,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Gush",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 370,
             total_max_atp: 370,
@@ -14969,7 +14972,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Geist",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 370,
             total_max_atp: 370,
@@ -14992,7 +14995,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Kings",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 370,
             total_max_atp: 370,
@@ -15015,7 +15018,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Jellen",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 210,
             total_max_atp: 210,
@@ -15038,7 +15041,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Zalure",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 210,
             total_max_atp: 210,
@@ -15061,7 +15064,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "HP_Regeneration",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 210,
             total_max_atp: 210,
@@ -15084,7 +15087,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "TP_Regeneration",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 210,
             total_max_atp: 210,
@@ -15107,7 +15110,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Burning",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 210,
             total_max_atp: 210,
@@ -15130,7 +15133,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Tempest",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 210,
             total_max_atp: 210,
@@ -15153,7 +15156,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Blizzard",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 210,
             total_max_atp: 210,
@@ -15176,7 +15179,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Arrest",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 210,
             total_max_atp: 210,
@@ -15199,7 +15202,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Chaos",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 210,
             total_max_atp: 210,
@@ -15222,7 +15225,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Hell",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 210,
             total_max_atp: 210,
@@ -15245,7 +15248,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Spirit",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 210,
             total_max_atp: 210,
@@ -15268,7 +15271,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Berserk",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 210,
             total_max_atp: 210,
@@ -15291,7 +15294,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Demons",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 210,
             total_max_atp: 210,
@@ -15314,7 +15317,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Gush",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 210,
             total_max_atp: 210,
@@ -15337,7 +15340,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Geist",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 210,
             total_max_atp: 210,
@@ -15360,7 +15363,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Kings",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 210,
             total_max_atp: 210,
@@ -15383,7 +15386,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 50,
             special: "Jellen",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 550,
             total_max_atp: 550,
@@ -15406,7 +15409,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 50,
             special: "Zalure",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 550,
             total_max_atp: 550,
@@ -15429,7 +15432,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 50,
             special: "HP_Regeneration",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 550,
             total_max_atp: 550,
@@ -15452,7 +15455,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 50,
             special: "TP_Regeneration",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 550,
             total_max_atp: 550,
@@ -15475,7 +15478,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 50,
             special: "Burning",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 550,
             total_max_atp: 550,
@@ -15498,7 +15501,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 50,
             special: "Tempest",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 550,
             total_max_atp: 550,
@@ -15521,7 +15524,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 50,
             special: "Blizzard",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 550,
             total_max_atp: 550,
@@ -15544,7 +15547,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 50,
             special: "Arrest",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 550,
             total_max_atp: 550,
@@ -15567,7 +15570,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 50,
             special: "Chaos",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 550,
             total_max_atp: 550,
@@ -15590,7 +15593,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 50,
             special: "Hell",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 550,
             total_max_atp: 550,
@@ -15613,7 +15616,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 50,
             special: "Spirit",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 550,
             total_max_atp: 550,
@@ -15636,7 +15639,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 50,
             special: "Berserk",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 550,
             total_max_atp: 550,
@@ -15659,7 +15662,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 50,
             special: "Demons",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 550,
             total_max_atp: 550,
@@ -15682,7 +15685,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 50,
             special: "Gush",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 550,
             total_max_atp: 550,
@@ -15705,7 +15708,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 50,
             special: "Geist",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 550,
             total_max_atp: 550,
@@ -15728,7 +15731,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 50,
             special: "Kings",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 550,
             total_max_atp: 550,
@@ -15751,7 +15754,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 180,
             special: "Jellen",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 680,
             total_max_atp: 680,
@@ -15774,7 +15777,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 180,
             special: "Zalure",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 680,
             total_max_atp: 680,
@@ -15797,7 +15800,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 180,
             special: "HP_Regeneration",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 680,
             total_max_atp: 680,
@@ -15820,7 +15823,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 180,
             special: "TP_Regeneration",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 680,
             total_max_atp: 680,
@@ -15843,7 +15846,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 180,
             special: "Burning",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 680,
             total_max_atp: 680,
@@ -15866,7 +15869,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 180,
             special: "Tempest",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 680,
             total_max_atp: 680,
@@ -15889,7 +15892,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 180,
             special: "Blizzard",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 680,
             total_max_atp: 680,
@@ -15912,7 +15915,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 180,
             special: "Arrest",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 680,
             total_max_atp: 680,
@@ -15935,7 +15938,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 180,
             special: "Chaos",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 680,
             total_max_atp: 680,
@@ -15958,7 +15961,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 180,
             special: "Hell",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 680,
             total_max_atp: 680,
@@ -15981,7 +15984,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 180,
             special: "Spirit",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 680,
             total_max_atp: 680,
@@ -16004,7 +16007,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 180,
             special: "Berserk",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 680,
             total_max_atp: 680,
@@ -16027,7 +16030,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 180,
             special: "Demons",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 680,
             total_max_atp: 680,
@@ -16050,7 +16053,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 180,
             special: "Gush",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 680,
             total_max_atp: 680,
@@ -16073,7 +16076,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 180,
             special: "Geist",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 680,
             total_max_atp: 680,
@@ -16096,7 +16099,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 180,
             special: "Kings",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 680,
             total_max_atp: 680,
@@ -16119,7 +16122,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Jellen",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 510,
             total_max_atp: 510,
@@ -16142,7 +16145,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Zalure",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 510,
             total_max_atp: 510,
@@ -16165,7 +16168,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "HP_Regeneration",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 510,
             total_max_atp: 510,
@@ -16188,7 +16191,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "TP_Regeneration",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 510,
             total_max_atp: 510,
@@ -16211,7 +16214,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Burning",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 510,
             total_max_atp: 510,
@@ -16234,7 +16237,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Tempest",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 510,
             total_max_atp: 510,
@@ -16257,7 +16260,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Blizzard",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 510,
             total_max_atp: 510,
@@ -16280,7 +16283,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Arrest",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 510,
             total_max_atp: 510,
@@ -16303,7 +16306,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Chaos",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 510,
             total_max_atp: 510,
@@ -16326,7 +16329,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Hell",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 510,
             total_max_atp: 510,
@@ -16349,7 +16352,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Spirit",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 510,
             total_max_atp: 510,
@@ -16372,7 +16375,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Berserk",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 510,
             total_max_atp: 510,
@@ -16395,7 +16398,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Demons",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 510,
             total_max_atp: 510,
@@ -16418,7 +16421,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Gush",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 510,
             total_max_atp: 510,
@@ -16441,7 +16444,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Geist",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 510,
             total_max_atp: 510,
@@ -16464,7 +16467,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Kings",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 510,
             total_max_atp: 510,
@@ -16487,7 +16490,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Jellen",
             targets: 5,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 150,
             total_max_atp: 150,
@@ -16510,7 +16513,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Zalure",
             targets: 5,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 150,
             total_max_atp: 150,
@@ -16533,7 +16536,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "HP_Regeneration",
             targets: 5,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 150,
             total_max_atp: 150,
@@ -16556,7 +16559,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "TP_Regeneration",
             targets: 5,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 150,
             total_max_atp: 150,
@@ -16579,7 +16582,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Burning",
             targets: 5,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 150,
             total_max_atp: 150,
@@ -16602,7 +16605,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Tempest",
             targets: 5,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 150,
             total_max_atp: 150,
@@ -16625,7 +16628,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Blizzard",
             targets: 5,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 150,
             total_max_atp: 150,
@@ -16648,7 +16651,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Arrest",
             targets: 5,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 150,
             total_max_atp: 150,
@@ -16671,7 +16674,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Chaos",
             targets: 5,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 150,
             total_max_atp: 150,
@@ -16694,7 +16697,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Hell",
             targets: 5,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 150,
             total_max_atp: 150,
@@ -16717,7 +16720,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Spirit",
             targets: 5,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 150,
             total_max_atp: 150,
@@ -16740,7 +16743,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Berserk",
             targets: 5,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 150,
             total_max_atp: 150,
@@ -16763,7 +16766,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Demons",
             targets: 5,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 150,
             total_max_atp: 150,
@@ -16786,7 +16789,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Gush",
             targets: 5,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 150,
             total_max_atp: 150,
@@ -16809,7 +16812,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Geist",
             targets: 5,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 150,
             total_max_atp: 150,
@@ -16832,7 +16835,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Kings",
             targets: 5,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 150,
             total_max_atp: 150,
@@ -16855,7 +16858,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Jellen",
             targets: 10,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 370,
             total_max_atp: 370,
@@ -16878,7 +16881,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Zalure",
             targets: 10,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 370,
             total_max_atp: 370,
@@ -16901,7 +16904,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "HP_Regeneration",
             targets: 10,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 370,
             total_max_atp: 370,
@@ -16924,7 +16927,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "TP_Regeneration",
             targets: 10,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 370,
             total_max_atp: 370,
@@ -16947,7 +16950,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Burning",
             targets: 10,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 370,
             total_max_atp: 370,
@@ -16970,7 +16973,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Tempest",
             targets: 10,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 370,
             total_max_atp: 370,
@@ -16993,7 +16996,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Blizzard",
             targets: 10,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 370,
             total_max_atp: 370,
@@ -17016,7 +17019,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Arrest",
             targets: 10,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 370,
             total_max_atp: 370,
@@ -17039,7 +17042,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Chaos",
             targets: 10,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 370,
             total_max_atp: 370,
@@ -17062,7 +17065,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Hell",
             targets: 10,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 370,
             total_max_atp: 370,
@@ -17085,7 +17088,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Spirit",
             targets: 10,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 370,
             total_max_atp: 370,
@@ -17108,7 +17111,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Berserk",
             targets: 10,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 370,
             total_max_atp: 370,
@@ -17131,7 +17134,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Demons",
             targets: 10,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 370,
             total_max_atp: 370,
@@ -17154,7 +17157,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Gush",
             targets: 10,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 370,
             total_max_atp: 370,
@@ -17177,7 +17180,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Geist",
             targets: 10,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 370,
             total_max_atp: 370,
@@ -17200,7 +17203,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Kings",
             targets: 10,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 370,
             total_max_atp: 370,
@@ -17223,7 +17226,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 150,
             special: "Jellen",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 650,
             total_max_atp: 650,
@@ -17246,7 +17249,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 150,
             special: "Zalure",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 650,
             total_max_atp: 650,
@@ -17269,7 +17272,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 150,
             special: "HP_Regeneration",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 650,
             total_max_atp: 650,
@@ -17292,7 +17295,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 150,
             special: "TP_Regeneration",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 650,
             total_max_atp: 650,
@@ -17315,7 +17318,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 150,
             special: "Burning",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 650,
             total_max_atp: 650,
@@ -17338,7 +17341,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 150,
             special: "Tempest",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 650,
             total_max_atp: 650,
@@ -17361,7 +17364,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 150,
             special: "Blizzard",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 650,
             total_max_atp: 650,
@@ -17384,7 +17387,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 150,
             special: "Arrest",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 650,
             total_max_atp: 650,
@@ -17407,7 +17410,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 150,
             special: "Chaos",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 650,
             total_max_atp: 650,
@@ -17430,7 +17433,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 150,
             special: "Hell",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 650,
             total_max_atp: 650,
@@ -17453,7 +17456,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 150,
             special: "Spirit",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 650,
             total_max_atp: 650,
@@ -17476,7 +17479,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 150,
             special: "Berserk",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 650,
             total_max_atp: 650,
@@ -17499,7 +17502,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 150,
             special: "Demons",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 650,
             total_max_atp: 650,
@@ -17522,7 +17525,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 150,
             special: "Gush",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 650,
             total_max_atp: 650,
@@ -17545,7 +17548,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 150,
             special: "Geist",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 650,
             total_max_atp: 650,
@@ -17568,7 +17571,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 150,
             special: "Kings",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 650,
             total_max_atp: 650,
@@ -17591,7 +17594,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Jellen",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 250,
             total_max_atp: 250,
@@ -17614,7 +17617,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Zalure",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 250,
             total_max_atp: 250,
@@ -17637,7 +17640,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "HP_Regeneration",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 250,
             total_max_atp: 250,
@@ -17660,7 +17663,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "TP_Regeneration",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 250,
             total_max_atp: 250,
@@ -17683,7 +17686,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Burning",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 250,
             total_max_atp: 250,
@@ -17706,7 +17709,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Tempest",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 250,
             total_max_atp: 250,
@@ -17729,7 +17732,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Blizzard",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 250,
             total_max_atp: 250,
@@ -17752,7 +17755,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Arrest",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 250,
             total_max_atp: 250,
@@ -17775,7 +17778,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Chaos",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 250,
             total_max_atp: 250,
@@ -17798,7 +17801,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Hell",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 250,
             total_max_atp: 250,
@@ -17821,7 +17824,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Spirit",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 250,
             total_max_atp: 250,
@@ -17844,7 +17847,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Berserk",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 250,
             total_max_atp: 250,
@@ -17867,7 +17870,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Demons",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 250,
             total_max_atp: 250,
@@ -17890,7 +17893,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Gush",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 250,
             total_max_atp: 250,
@@ -17913,7 +17916,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Geist",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 250,
             total_max_atp: 250,
@@ -17936,7 +17939,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Kings",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 250,
             total_max_atp: 250,
@@ -17959,7 +17962,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Jellen",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 110,
             total_max_atp: 110,
@@ -17982,7 +17985,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Zalure",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 110,
             total_max_atp: 110,
@@ -18005,7 +18008,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "HP_Regeneration",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 110,
             total_max_atp: 110,
@@ -18028,7 +18031,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "TP_Regeneration",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 110,
             total_max_atp: 110,
@@ -18051,7 +18054,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Burning",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 110,
             total_max_atp: 110,
@@ -18074,7 +18077,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Tempest",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 110,
             total_max_atp: 110,
@@ -18097,7 +18100,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Blizzard",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 110,
             total_max_atp: 110,
@@ -18120,7 +18123,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Arrest",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 110,
             total_max_atp: 110,
@@ -18143,7 +18146,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Chaos",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 110,
             total_max_atp: 110,
@@ -18166,7 +18169,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Hell",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 110,
             total_max_atp: 110,
@@ -18189,7 +18192,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Spirit",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 110,
             total_max_atp: 110,
@@ -18212,7 +18215,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Berserk",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 110,
             total_max_atp: 110,
@@ -18235,7 +18238,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Demons",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 110,
             total_max_atp: 110,
@@ -18258,7 +18261,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Gush",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 110,
             total_max_atp: 110,
@@ -18281,7 +18284,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Geist",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 110,
             total_max_atp: 110,
@@ -18304,7 +18307,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Kings",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 110,
             total_max_atp: 110,
@@ -18327,7 +18330,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Jellen",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 510,
             total_max_atp: 510,
@@ -18350,7 +18353,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Zalure",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 510,
             total_max_atp: 510,
@@ -18373,7 +18376,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "HP_Regeneration",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 510,
             total_max_atp: 510,
@@ -18396,7 +18399,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "TP_Regeneration",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 510,
             total_max_atp: 510,
@@ -18419,7 +18422,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Burning",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 510,
             total_max_atp: 510,
@@ -18442,7 +18445,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Tempest",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 510,
             total_max_atp: 510,
@@ -18465,7 +18468,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Blizzard",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 510,
             total_max_atp: 510,
@@ -18488,7 +18491,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Arrest",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 510,
             total_max_atp: 510,
@@ -18511,7 +18514,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Chaos",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 510,
             total_max_atp: 510,
@@ -18534,7 +18537,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Hell",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 510,
             total_max_atp: 510,
@@ -18557,7 +18560,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Spirit",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 510,
             total_max_atp: 510,
@@ -18580,7 +18583,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Berserk",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 510,
             total_max_atp: 510,
@@ -18603,7 +18606,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Demons",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 510,
             total_max_atp: 510,
@@ -18626,7 +18629,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Gush",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 510,
             total_max_atp: 510,
@@ -18649,7 +18652,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Geist",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 510,
             total_max_atp: 510,
@@ -18672,7 +18675,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Kings",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 510,
             total_max_atp: 510,
@@ -18695,7 +18698,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Jellen",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 250,
             total_max_atp: 250,
@@ -18718,7 +18721,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Zalure",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 250,
             total_max_atp: 250,
@@ -18741,7 +18744,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "HP_Regeneration",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 250,
             total_max_atp: 250,
@@ -18764,7 +18767,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "TP_Regeneration",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 250,
             total_max_atp: 250,
@@ -18787,7 +18790,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Burning",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 250,
             total_max_atp: 250,
@@ -18810,7 +18813,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Tempest",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 250,
             total_max_atp: 250,
@@ -18833,7 +18836,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Blizzard",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 250,
             total_max_atp: 250,
@@ -18856,7 +18859,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Arrest",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 250,
             total_max_atp: 250,
@@ -18879,7 +18882,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Chaos",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 250,
             total_max_atp: 250,
@@ -18902,7 +18905,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Hell",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 250,
             total_max_atp: 250,
@@ -18925,7 +18928,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Spirit",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 250,
             total_max_atp: 250,
@@ -18948,7 +18951,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Berserk",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 250,
             total_max_atp: 250,
@@ -18971,7 +18974,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Demons",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 250,
             total_max_atp: 250,
@@ -18994,7 +18997,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Gush",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 250,
             total_max_atp: 250,
@@ -19017,7 +19020,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Geist",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 250,
             total_max_atp: 250,
@@ -19040,7 +19043,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Kings",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 250,
             total_max_atp: 250,
@@ -19063,7 +19066,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 150,
             special: "Jellen",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 650,
             total_max_atp: 650,
@@ -19086,7 +19089,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 150,
             special: "Zalure",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 650,
             total_max_atp: 650,
@@ -19109,7 +19112,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 150,
             special: "HP_Regeneration",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 650,
             total_max_atp: 650,
@@ -19132,7 +19135,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 150,
             special: "TP_Regeneration",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 650,
             total_max_atp: 650,
@@ -19155,7 +19158,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 150,
             special: "Burning",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 650,
             total_max_atp: 650,
@@ -19178,7 +19181,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 150,
             special: "Tempest",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 650,
             total_max_atp: 650,
@@ -19201,7 +19204,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 150,
             special: "Blizzard",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 650,
             total_max_atp: 650,
@@ -19224,7 +19227,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 150,
             special: "Arrest",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 650,
             total_max_atp: 650,
@@ -19247,7 +19250,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 150,
             special: "Chaos",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 650,
             total_max_atp: 650,
@@ -19270,7 +19273,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 150,
             special: "Hell",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 650,
             total_max_atp: 650,
@@ -19293,7 +19296,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 150,
             special: "Spirit",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 650,
             total_max_atp: 650,
@@ -19316,7 +19319,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 150,
             special: "Berserk",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 650,
             total_max_atp: 650,
@@ -19339,7 +19342,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 150,
             special: "Demons",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 650,
             total_max_atp: 650,
@@ -19362,7 +19365,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 150,
             special: "Gush",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 650,
             total_max_atp: 650,
@@ -19385,7 +19388,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 150,
             special: "Geist",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 650,
             total_max_atp: 650,
@@ -19408,7 +19411,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 150,
             special: "Kings",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 650,
             total_max_atp: 650,
@@ -19431,7 +19434,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 170,
             special: "Jellen",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 670,
             total_max_atp: 670,
@@ -19454,7 +19457,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 170,
             special: "Zalure",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 670,
             total_max_atp: 670,
@@ -19477,7 +19480,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 170,
             special: "HP_Regeneration",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 670,
             total_max_atp: 670,
@@ -19500,7 +19503,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 170,
             special: "TP_Regeneration",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 670,
             total_max_atp: 670,
@@ -19523,7 +19526,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 170,
             special: "Burning",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 670,
             total_max_atp: 670,
@@ -19546,7 +19549,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 170,
             special: "Tempest",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 670,
             total_max_atp: 670,
@@ -19569,7 +19572,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 170,
             special: "Blizzard",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 670,
             total_max_atp: 670,
@@ -19592,7 +19595,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 170,
             special: "Arrest",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 670,
             total_max_atp: 670,
@@ -19615,7 +19618,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 170,
             special: "Chaos",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 670,
             total_max_atp: 670,
@@ -19638,7 +19641,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 170,
             special: "Hell",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 670,
             total_max_atp: 670,
@@ -19661,7 +19664,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 170,
             special: "Spirit",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 670,
             total_max_atp: 670,
@@ -19684,7 +19687,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 170,
             special: "Berserk",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 670,
             total_max_atp: 670,
@@ -19707,7 +19710,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 170,
             special: "Demons",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 670,
             total_max_atp: 670,
@@ -19730,7 +19733,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 170,
             special: "Gush",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 670,
             total_max_atp: 670,
@@ -19753,7 +19756,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 170,
             special: "Geist",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 670,
             total_max_atp: 670,
@@ -19776,7 +19779,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 170,
             special: "Kings",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 670,
             total_max_atp: 670,
@@ -19799,7 +19802,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 25,
             special: "Jellen",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 325,
             total_max_atp: 325,
@@ -19822,7 +19825,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 25,
             special: "Zalure",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 325,
             total_max_atp: 325,
@@ -19845,7 +19848,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 25,
             special: "HP_Regeneration",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 325,
             total_max_atp: 325,
@@ -19868,7 +19871,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 25,
             special: "TP_Regeneration",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 325,
             total_max_atp: 325,
@@ -19891,7 +19894,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 25,
             special: "Burning",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 325,
             total_max_atp: 325,
@@ -19914,7 +19917,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 25,
             special: "Tempest",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 325,
             total_max_atp: 325,
@@ -19937,7 +19940,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 25,
             special: "Blizzard",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 325,
             total_max_atp: 325,
@@ -19960,7 +19963,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 25,
             special: "Arrest",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 325,
             total_max_atp: 325,
@@ -19983,7 +19986,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 25,
             special: "Chaos",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 325,
             total_max_atp: 325,
@@ -20006,7 +20009,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 25,
             special: "Hell",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 325,
             total_max_atp: 325,
@@ -20029,7 +20032,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 25,
             special: "Spirit",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 325,
             total_max_atp: 325,
@@ -20052,7 +20055,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 25,
             special: "Berserk",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 325,
             total_max_atp: 325,
@@ -20075,7 +20078,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 25,
             special: "Demons",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 325,
             total_max_atp: 325,
@@ -20098,7 +20101,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 25,
             special: "Gush",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 325,
             total_max_atp: 325,
@@ -20121,7 +20124,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 25,
             special: "Geist",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 325,
             total_max_atp: 325,
@@ -20144,7 +20147,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 25,
             special: "Kings",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 325,
             total_max_atp: 325,
@@ -20167,7 +20170,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 320,
             special: "Spirit",
             targets: 10,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl"],
             notes: "",
             total_min_atp: 470,
             total_max_atp: 490,
@@ -20190,7 +20193,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 320,
             special: "Spirit",
             targets: 10,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl"],
             notes: "",
             total_min_atp: 470,
             total_max_atp: 490,
@@ -20213,7 +20216,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 438,
             special: "Berserk",
             targets: 10,
-            classes: ["HUmr", "HUct"],
+            classes: &["HUmr", "HUct"],
             notes: "",
             total_min_atp: 386,
             total_max_atp: 514,
@@ -20236,7 +20239,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 438,
             special: "Berserk",
             targets: 10,
-            classes: ["HUmr", "HUct"],
+            classes: &["HUmr", "HUct"],
             notes: "",
             total_min_atp: 386,
             total_max_atp: 514,
@@ -20259,7 +20262,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 320,
             special: "Chaos",
             targets: 4,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 348,
             total_max_atp: 368,
@@ -20282,7 +20285,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 320,
             special: "Chaos",
             targets: 4,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 348,
             total_max_atp: 368,
@@ -20305,7 +20308,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 900,
             special: "Other",
             targets: 10,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl"],
             notes: "",
             total_min_atp: 756,
             total_max_atp: 900,
@@ -20328,7 +20331,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 900,
             special: "Other",
             targets: 10,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl"],
             notes: "",
             total_min_atp: 756,
             total_max_atp: 900,
@@ -20351,7 +20354,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 180,
             special: "Jellen",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 680,
             total_max_atp: 680,
@@ -20374,7 +20377,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 180,
             special: "Zalure",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 680,
             total_max_atp: 680,
@@ -20397,7 +20400,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 180,
             special: "HP_Regeneration",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 680,
             total_max_atp: 680,
@@ -20420,7 +20423,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 180,
             special: "TP_Regeneration",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 680,
             total_max_atp: 680,
@@ -20443,7 +20446,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 180,
             special: "Burning",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 680,
             total_max_atp: 680,
@@ -20466,7 +20469,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 180,
             special: "Tempest",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 680,
             total_max_atp: 680,
@@ -20489,7 +20492,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 180,
             special: "Blizzard",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 680,
             total_max_atp: 680,
@@ -20512,7 +20515,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 180,
             special: "Arrest",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 680,
             total_max_atp: 680,
@@ -20535,7 +20538,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 180,
             special: "Chaos",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 680,
             total_max_atp: 680,
@@ -20558,7 +20561,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 180,
             special: "Hell",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 680,
             total_max_atp: 680,
@@ -20581,7 +20584,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 180,
             special: "Spirit",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 680,
             total_max_atp: 680,
@@ -20604,7 +20607,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 180,
             special: "Berserk",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 680,
             total_max_atp: 680,
@@ -20627,7 +20630,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 180,
             special: "Demons",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 680,
             total_max_atp: 680,
@@ -20650,7 +20653,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 180,
             special: "Gush",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 680,
             total_max_atp: 680,
@@ -20673,7 +20676,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 180,
             special: "Geist",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 680,
             total_max_atp: 680,
@@ -20696,7 +20699,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 180,
             special: "Kings",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 680,
             total_max_atp: 680,
@@ -20719,7 +20722,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Jellen",
             targets: 4,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 370,
             total_max_atp: 370,
@@ -20742,7 +20745,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Zalure",
             targets: 4,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 370,
             total_max_atp: 370,
@@ -20765,7 +20768,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "HP_Regeneration",
             targets: 4,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 370,
             total_max_atp: 370,
@@ -20788,7 +20791,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "TP_Regeneration",
             targets: 4,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 370,
             total_max_atp: 370,
@@ -20811,7 +20814,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Burning",
             targets: 4,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 370,
             total_max_atp: 370,
@@ -20834,7 +20837,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Tempest",
             targets: 4,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 370,
             total_max_atp: 370,
@@ -20857,7 +20860,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Blizzard",
             targets: 4,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 370,
             total_max_atp: 370,
@@ -20880,7 +20883,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Arrest",
             targets: 4,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 370,
             total_max_atp: 370,
@@ -20903,7 +20906,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Chaos",
             targets: 4,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 370,
             total_max_atp: 370,
@@ -20926,7 +20929,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Hell",
             targets: 4,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 370,
             total_max_atp: 370,
@@ -20949,7 +20952,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Spirit",
             targets: 4,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 370,
             total_max_atp: 370,
@@ -20972,7 +20975,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Berserk",
             targets: 4,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 370,
             total_max_atp: 370,
@@ -20995,7 +20998,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Demons",
             targets: 4,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 370,
             total_max_atp: 370,
@@ -21018,7 +21021,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Gush",
             targets: 4,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 370,
             total_max_atp: 370,
@@ -21041,7 +21044,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Geist",
             targets: 4,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 370,
             total_max_atp: 370,
@@ -21064,7 +21067,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Kings",
             targets: 4,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 370,
             total_max_atp: 370,
@@ -21087,7 +21090,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Jellen",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 310,
             total_max_atp: 310,
@@ -21110,7 +21113,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Zalure",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 310,
             total_max_atp: 310,
@@ -21133,7 +21136,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "HP_Regeneration",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 310,
             total_max_atp: 310,
@@ -21156,7 +21159,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "TP_Regeneration",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 310,
             total_max_atp: 310,
@@ -21179,7 +21182,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Burning",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 310,
             total_max_atp: 310,
@@ -21202,7 +21205,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Tempest",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 310,
             total_max_atp: 310,
@@ -21225,7 +21228,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Blizzard",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 310,
             total_max_atp: 310,
@@ -21248,7 +21251,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Arrest",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 310,
             total_max_atp: 310,
@@ -21271,7 +21274,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Chaos",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 310,
             total_max_atp: 310,
@@ -21294,7 +21297,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Hell",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 310,
             total_max_atp: 310,
@@ -21317,7 +21320,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Spirit",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 310,
             total_max_atp: 310,
@@ -21340,7 +21343,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Berserk",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 310,
             total_max_atp: 310,
@@ -21363,7 +21366,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Demons",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 310,
             total_max_atp: 310,
@@ -21386,7 +21389,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Gush",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 310,
             total_max_atp: 310,
@@ -21409,7 +21412,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Geist",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 310,
             total_max_atp: 310,
@@ -21432,7 +21435,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 10,
             special: "Kings",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 310,
             total_max_atp: 310,
@@ -21455,7 +21458,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 250,
             special: "Jellen",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 750,
             total_max_atp: 750,
@@ -21478,7 +21481,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 250,
             special: "Zalure",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 750,
             total_max_atp: 750,
@@ -21501,7 +21504,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 250,
             special: "HP_Regeneration",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 750,
             total_max_atp: 750,
@@ -21524,7 +21527,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 250,
             special: "TP_Regeneration",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 750,
             total_max_atp: 750,
@@ -21547,7 +21550,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 250,
             special: "Burning",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 750,
             total_max_atp: 750,
@@ -21570,7 +21573,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 250,
             special: "Tempest",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 750,
             total_max_atp: 750,
@@ -21593,7 +21596,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 250,
             special: "Blizzard",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 750,
             total_max_atp: 750,
@@ -21616,7 +21619,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 250,
             special: "Arrest",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 750,
             total_max_atp: 750,
@@ -21639,7 +21642,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 250,
             special: "Chaos",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 750,
             total_max_atp: 750,
@@ -21662,7 +21665,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 250,
             special: "Hell",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 750,
             total_max_atp: 750,
@@ -21685,7 +21688,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 250,
             special: "Spirit",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 750,
             total_max_atp: 750,
@@ -21708,7 +21711,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 250,
             special: "Berserk",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 750,
             total_max_atp: 750,
@@ -21731,7 +21734,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 250,
             special: "Demons",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 750,
             total_max_atp: 750,
@@ -21754,7 +21757,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 250,
             special: "Gush",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 750,
             total_max_atp: 750,
@@ -21777,7 +21780,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 250,
             special: "Geist",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 750,
             total_max_atp: 750,
@@ -21800,7 +21803,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 250,
             special: "Kings",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 750,
             total_max_atp: 750,
@@ -21823,7 +21826,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 200,
             special: "Jellen",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 700,
             total_max_atp: 700,
@@ -21846,7 +21849,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 200,
             special: "Zalure",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 700,
             total_max_atp: 700,
@@ -21869,7 +21872,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 200,
             special: "HP_Regeneration",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 700,
             total_max_atp: 700,
@@ -21892,7 +21895,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 200,
             special: "TP_Regeneration",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 700,
             total_max_atp: 700,
@@ -21915,7 +21918,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 200,
             special: "Burning",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 700,
             total_max_atp: 700,
@@ -21938,7 +21941,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 200,
             special: "Tempest",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 700,
             total_max_atp: 700,
@@ -21961,7 +21964,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 200,
             special: "Blizzard",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 700,
             total_max_atp: 700,
@@ -21984,7 +21987,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 200,
             special: "Arrest",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 700,
             total_max_atp: 700,
@@ -22007,7 +22010,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 200,
             special: "Chaos",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 700,
             total_max_atp: 700,
@@ -22030,7 +22033,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 200,
             special: "Hell",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 700,
             total_max_atp: 700,
@@ -22053,7 +22056,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 200,
             special: "Spirit",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 700,
             total_max_atp: 700,
@@ -22076,7 +22079,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 200,
             special: "Berserk",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 700,
             total_max_atp: 700,
@@ -22099,7 +22102,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 200,
             special: "Demons",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 700,
             total_max_atp: 700,
@@ -22122,7 +22125,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 200,
             special: "Gush",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 700,
             total_max_atp: 700,
@@ -22145,7 +22148,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 200,
             special: "Geist",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 700,
             total_max_atp: 700,
@@ -22168,7 +22171,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 200,
             special: "Kings",
             targets: 1,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 700,
             total_max_atp: 700,
@@ -22191,7 +22194,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 360,
             special: "Demons",
             targets: 3,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 400,
             total_max_atp: 420,
@@ -22214,7 +22217,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 360,
             special: "Demons",
             targets: 3,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 400,
             total_max_atp: 420,
@@ -22237,7 +22240,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 950,
             special: "Berserk",
             targets: 3,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 900,
             total_max_atp: 950,
@@ -22260,7 +22263,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 950,
             special: "Berserk",
             targets: 3,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl", "RAmr", "RAml", "RAct", "RAcl", "FOmr", "FOml", "FOnm"],
             notes: "",
             total_min_atp: 900,
             total_max_atp: 950,
@@ -22283,7 +22286,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 185,
             special: "Tempest",
             targets: 0,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 193,
             total_max_atp: 203,
@@ -22306,7 +22309,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 185,
             special: "Blizzard",
             targets: 0,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 193,
             total_max_atp: 203,
@@ -22329,7 +22332,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 185,
             special: "Geist",
             targets: 0,
-            classes: [],
+            classes: &[],
             notes: "",
             total_min_atp: 193,
             total_max_atp: 203,
@@ -22352,7 +22355,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 220,
             special: "Other",
             targets: 1,
-            classes: ["RAmr", "RAml", "RAct", "RAcl"],
+            classes: &["RAmr", "RAml", "RAct", "RAcl"],
             notes: "",
             total_min_atp: 224,
             total_max_atp: 244,
@@ -22375,7 +22378,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 220,
             special: "Other",
             targets: 1,
-            classes: ["RAmr", "RAml", "RAct", "RAcl"],
+            classes: &["RAmr", "RAml", "RAct", "RAcl"],
             notes: "",
             total_min_atp: 224,
             total_max_atp: 244,
@@ -22398,7 +22401,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 350,
             special: "Berserk",
             targets: 10,
-            classes: ["HUmr", "HUnl", "HUct", "HUcl"],
+            classes: &["HUmr", "HUnl", "HUct", "HUcl"],
             notes: "",
             total_min_atp: 350,
             total_max_atp: 400,
@@ -22421,7 +22424,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 660,
             special: "Berserk",
             targets: 1,
-            classes: ["RAmr", "RAml", "RAct", "RAcl"],
+            classes: &["RAmr", "RAml", "RAct", "RAcl"],
             notes: "",
             total_min_atp: 590,
             total_max_atp: 700,
@@ -22444,7 +22447,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 660,
             special: "Berserk",
             targets: 1,
-            classes: ["RAmr", "RAml", "RAct", "RAcl"],
+            classes: &["RAmr", "RAml", "RAct", "RAcl"],
             notes: "",
             total_min_atp: 590,
             total_max_atp: 700,
@@ -22467,7 +22470,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 400,
             special: "Anti",
             targets: 1,
-            classes: ["FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 185,
             total_max_atp: 400,
@@ -22490,7 +22493,7 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             max_atp: 400,
             special: "Anti",
             targets: 1,
-            classes: ["FOmr", "FOml", "FOnm", "FOml"],
+            classes: &["FOmr", "FOml", "FOnm", "FOnl"],
             notes: "",
             total_min_atp: 185,
             total_max_atp: 400,
@@ -22498,4 +22501,5 @@ pub fn weapon_info() -> HashMap<u32, Weapon> {
             requirement_ata: 0,
             requirement_mst: 900
         });
-};
\ No newline at end of file
+    weapons
+}
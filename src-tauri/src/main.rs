@@ -9,17 +9,39 @@ use tauri::Manager;
 mod util;
 mod lib {
     pub mod db;
+    pub mod portrait;
+    pub mod mag;
+    pub mod weapon_db;
+    pub mod atp;
+    pub mod equip;
+    pub mod build;
+    pub mod migrations;
 }
 mod config {
     pub mod config;
     pub mod item_codes;
     pub mod item_codes_ja;
+    pub mod level_table;
+    pub mod quests;
+    pub mod mag_feed;
+    pub mod unit_effects;
+    pub mod armor_data;
+    pub mod ephinea_items;
+    pub mod server_profile;
+    pub mod item_tables;
+    pub mod language_pack;
 }
 mod parser {
+    pub mod error;
     pub mod item;
     pub mod types;
     pub mod character;
+    pub mod challenge;
     pub mod shared_bank;
+    pub mod detect;
+    #[cfg(test)]
+    pub mod fixtures;
+    pub mod writer;
 }
 mod command {
     pub mod db;
@@ -35,6 +57,17 @@ use command::{
         get_user,
         get_accounts,
         get_account_data,
+        get_quest_log,
+        get_challenge_records,
+        get_tech_disk_candidates,
+        generate_portrait,
+        predict_mag_evolution,
+        plan_mag_feeding,
+        get_sealed_items,
+        get_locale_names,
+        calculate_weapon_atp,
+        get_equip_compatibility,
+        calculate_build,
         get_dashboard_state,
         get_theme,
         save_selected_account,
@@ -43,7 +76,11 @@ use command::{
         save_lang,
         save_theme,
     },
-    file_parser::parse_files
+    file_parser::{
+        parse_files,
+        encode_file,
+        encode_shared_bank_items
+    }
 };
 
 fn main() {
@@ -61,6 +98,8 @@ fn main() {
     .plugin(tauri_plugin_window_state::Builder::default().build())
     .invoke_handler(tauri::generate_handler![
         parse_files,
+        encode_file,
+        encode_shared_bank_items,
         init_app,
         create_user,
         create_account,
@@ -68,6 +107,17 @@ fn main() {
         get_user,
         get_accounts,
         get_account_data,
+        get_quest_log,
+        get_challenge_records,
+        get_tech_disk_candidates,
+        generate_portrait,
+        predict_mag_evolution,
+        plan_mag_feeding,
+        get_sealed_items,
+        get_locale_names,
+        calculate_weapon_atp,
+        get_equip_compatibility,
+        calculate_build,
         get_dashboard_state,
         get_theme,
         save_selected_account,
@@ -0,0 +1,139 @@
+use crate::parser::types::{ChallengeRecords, StageRecord, BattleRecords};
+
+pub const CHALLENGE_OFFSET: usize = 11456;
+pub const EP1_TIMES_OFFSET: usize = 11460;
+pub const EP1_STAGES: usize = 9;
+pub const EP2_TIMES_OFFSET: usize = 11496;
+pub const EP2_STAGES: usize = 5;
+pub const RANK_OFFSET: usize = CHALLENGE_OFFSET + 0xF4;
+pub const RANK_LENGTH: usize = 12;
+pub const CHALLENGE_LENGTH: usize = 0x140;
+pub const BATTLE_OFFSET: usize = CHALLENGE_OFFSET + CHALLENGE_LENGTH;
+pub const BATTLE_LENGTH: usize = 0x18;
+
+fn read_u16(char_data: &[u8], offset: usize) -> u16 {
+    u16::from_le_bytes([char_data[offset], char_data[offset + 1]])
+}
+
+fn read_u32(char_data: &[u8], offset: usize) -> u32 {
+    u32::from_le_bytes([char_data[offset], char_data[offset + 1], char_data[offset + 2], char_data[offset + 3]])
+}
+
+// A stage that was never cleared keeps a time of zero.
+fn set_stage_records(char_data: &[u8], offset: usize, stages: usize) -> Vec<StageRecord> {
+    (0..stages)
+        .map(|i| {
+            let time = read_u32(char_data, offset + i * 4);
+
+            StageRecord {
+                stage: i as u8 + 1,
+                time: if time == 0 { None } else { Some(time) },
+            }
+        })
+        .collect()
+}
+
+/// The awarded rank is stored as UTF-16 with each character obfuscated against the one
+/// before it. The field is zeroed until the character earns a rank.
+pub fn decode_rank(char_data: &[u8]) -> Option<String> {
+    let mut previous = 0u16;
+    let mut rank = String::new();
+
+    for i in 0..RANK_LENGTH {
+        let encoded = read_u16(char_data, RANK_OFFSET + i * 2);
+        if encoded == 0 {
+            break;
+        }
+
+        let decoded = (encoded.wrapping_sub(previous) ^ 0x7F) & 0xFF;
+        previous = encoded;
+        rank.push(char::from(decoded as u8));
+    }
+
+    let rank = rank.trim();
+    if rank.is_empty() { None } else { Some(String::from(rank)) }
+}
+
+fn set_battle_records(char_data: &[u8]) -> BattleRecords {
+    BattleRecords {
+        first: read_u16(char_data, BATTLE_OFFSET),
+        second: read_u16(char_data, BATTLE_OFFSET + 2),
+        third: read_u16(char_data, BATTLE_OFFSET + 4),
+        fourth: read_u16(char_data, BATTLE_OFFSET + 6),
+        disconnects: read_u16(char_data, BATTLE_OFFSET + 8),
+    }
+}
+
+/// Challenge and battle records sit past the minimum character size, so older or trimmed
+/// files without them have no records rather than failing to parse.
+pub fn create(pso_char: &[u8]) -> Option<ChallengeRecords> {
+    if pso_char.len() < BATTLE_OFFSET + BATTLE_LENGTH {
+        return None;
+    }
+
+    Some(ChallengeRecords {
+        title_color: read_u16(pso_char, CHALLENGE_OFFSET),
+        rank: decode_rank(pso_char),
+        ep1: set_stage_records(pso_char, EP1_TIMES_OFFSET, EP1_STAGES),
+        ep2: set_stage_records(pso_char, EP2_TIMES_OFFSET, EP2_STAGES),
+        battle: set_battle_records(pso_char),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn challenge_file() -> Vec<u8> {
+        vec![0; BATTLE_OFFSET + BATTLE_LENGTH]
+    }
+
+    fn write_rank(file: &mut [u8], rank: &str) {
+        let mut previous = 0u16;
+        for (i, ch) in rank.bytes().enumerate() {
+            let encoded = ((ch as u16 ^ 0x7F) + previous) & 0xFF;
+            previous = encoded;
+            let offset = RANK_OFFSET + i * 2;
+            file[offset..offset + 2].copy_from_slice(&encoded.to_le_bytes());
+        }
+    }
+
+    #[test]
+    fn rank_is_decoded_from_the_challenge_block() {
+        let mut file = challenge_file();
+        write_rank(&mut file, "Bu-EI");
+        // "B" is stored as 0x3D, then each character offset by the previous encoded one.
+        assert_eq!(&file[RANK_OFFSET..RANK_OFFSET + 4], &[0x3D, 0x00, 0x47, 0x00]);
+        file[CHALLENGE_OFFSET..CHALLENGE_OFFSET + 2].copy_from_slice(&0x7C00u16.to_le_bytes());
+
+        let records = create(&file).unwrap();
+        assert_eq!(records.rank, Some(String::from("Bu-EI")));
+        assert_eq!(records.title_color, 0x7C00);
+    }
+
+    #[test]
+    fn unranked_characters_have_no_rank() {
+        let records = create(&challenge_file()).unwrap();
+        assert_eq!(records.rank, None);
+    }
+
+    #[test]
+    fn stage_times_for_both_episodes() {
+        let mut file = challenge_file();
+        file[EP1_TIMES_OFFSET..EP1_TIMES_OFFSET + 4].copy_from_slice(&600u32.to_le_bytes());
+        let ep2_stage_5 = EP2_TIMES_OFFSET + 4 * 4;
+        file[ep2_stage_5..ep2_stage_5 + 4].copy_from_slice(&1800u32.to_le_bytes());
+
+        let records = create(&file).unwrap();
+        assert_eq!(records.ep1.len(), EP1_STAGES);
+        assert_eq!(records.ep1[0].time, Some(600));
+        assert_eq!(records.ep1[1].time, None);
+        assert_eq!(records.ep2.len(), EP2_STAGES);
+        assert_eq!((records.ep2[4].stage, records.ep2[4].time), (5, Some(1800)));
+    }
+
+    #[test]
+    fn short_files_have_no_records() {
+        assert!(create(&vec![0; BATTLE_OFFSET]).is_none());
+    }
+}
@@ -1,11 +1,52 @@
 use std::collections::HashMap;
 use crate::util::Util;
 use crate::parser::item;
-use crate::parser::types::{Item, Slot, Character, Inventory};
+use crate::parser::challenge;
+use crate::parser::types::{
+    Item,
+    Slot,
+    Character,
+    CharacterStats,
+    CharacterAppearance,
+    MaterialUsage,
+    MaterialHeadroom,
+    ExperienceProgress,
+    EpisodeProgress,
+    StoryProgress,
+    QuestStatus,
+    LearnedTech,
+    Inventory
+};
+use crate::parser::error::{ParseError, ensure_len, missing_config};
 use crate::config::config::Config;
+use crate::config::level_table::experience_for_level;
+use crate::config::quests::quest_table;
 
-fn set_mode(char_data: &[u8]) -> String {
-    if char_data[7] == 0x40 {
+pub const CHARACTER_MIN_SIZE: usize = 6600;
+pub const INVENTORY_COUNT_OFFSET: usize = 8;
+pub const HP_MATERIALS_OFFSET: usize = 9;
+pub const TP_MATERIALS_OFFSET: usize = 10;
+pub const INVENTORY_OFFSET: usize = 20;
+pub const INVENTORY_ITEM_LENGTH: usize = 28;
+pub const INVENTORY_CAPACITY: usize = 30;
+pub const APPEARANCE_OFFSET: usize = 912;
+pub const STATS_OFFSET: usize = 852;
+pub const LEVEL_OFFSET: usize = 876;
+pub const EXPERIENCE_OFFSET: usize = 880;
+pub const MESETA_OFFSET: usize = 884;
+pub const TECH_LEVELS_OFFSET: usize = 1232;
+pub const TECH_COUNT: usize = 19;
+pub const QUEST_FLAGS_OFFSET: usize = 1276;
+pub const QUEST_FLAGS_LENGTH: usize = 128;
+pub const BANK_COUNT_OFFSET: usize = 1792;
+pub const BANK_MESETA_OFFSET: usize = 1796;
+pub const BANK_OFFSET: usize = 1800;
+pub const BANK_ITEM_LENGTH: usize = 24;
+pub const BANK_CAPACITY: usize = 200;
+const EQUIPPED_FLAG: u8 = 0x08;
+
+fn set_mode(char_data: &[u8], config: &Config) -> String {
+    if char_data[7] == 0x40 && config.server().has_classic_mode() {
         String::from("CLASSIC")
     } else {
         String::from("NORMAL")
@@ -17,19 +58,26 @@ fn set_name(char_data: &[u8]) -> String {
     let mut name = String::new();
 
     for i in (0..array.len()).step_by(2) {
-        if array[i] + array[i + 1] == 0 {
+        if array[i] == 0 && array[i + 1] == 0 {
             break;
         }
-        name.push(char::from_u32(((array[i + 1] as u32) << 8) | array[i] as u32).unwrap());
+        name.push(char::from_u32(((array[i + 1] as u32) << 8) | array[i] as u32).unwrap_or(char::REPLACEMENT_CHARACTER));
     }
 
     name
 }
 
-fn set_guild_card_number(char_data: &[u8]) -> u32 {
+fn set_guild_card_number(char_data: &[u8]) -> Result<u32, ParseError> {
     let array = &char_data[888..896];
     let guild_card_str: String = array.iter().map(|&b| (b & 0x0F).to_string()).collect();
-    guild_card_str.parse::<u32>().expect("Failed to parse guild card number")
+
+    if array.iter().any(|&b| b & 0x0F > 9) {
+        return Err(ParseError::BadGuildCardDigits { offset: 888, digits: guild_card_str });
+    }
+
+    guild_card_str
+        .parse::<u32>()
+        .map_err(|_| ParseError::BadGuildCardDigits { offset: 888, digits: guild_card_str.clone() })
 }
 
 fn set_class(char_data: &[u8]) -> String {
@@ -57,79 +105,348 @@ fn set_section_id(char_data: &[u8]) -> String {
 }
 
 fn set_level(char_data: &[u8]) -> u8 {
-    char_data[876] + 1
+    char_data[LEVEL_OFFSET].saturating_add(1)
+}
+
+fn read_u16(char_data: &[u8], offset: usize) -> u16 {
+    u16::from_le_bytes([char_data[offset], char_data[offset + 1]])
+}
+
+fn read_f32(char_data: &[u8], offset: usize) -> f32 {
+    f32::from_le_bytes([char_data[offset], char_data[offset + 1], char_data[offset + 2], char_data[offset + 3]])
+}
+
+// Hair color channels are stored as u16 but only ever hold 0-255.
+fn set_appearance(char_data: &[u8]) -> CharacterAppearance {
+    let hair_channel = |offset: usize| read_u16(char_data, APPEARANCE_OFFSET + offset).min(255) as u8;
+
+    CharacterAppearance {
+        name_color: u32::from_le_bytes([
+            char_data[APPEARANCE_OFFSET],
+            char_data[APPEARANCE_OFFSET + 1],
+            char_data[APPEARANCE_OFFSET + 2],
+            char_data[APPEARANCE_OFFSET + 3]
+        ]),
+        costume: read_u16(char_data, APPEARANCE_OFFSET + 32),
+        skin: read_u16(char_data, APPEARANCE_OFFSET + 34),
+        face: read_u16(char_data, APPEARANCE_OFFSET + 36),
+        head: read_u16(char_data, APPEARANCE_OFFSET + 38),
+        hair: read_u16(char_data, APPEARANCE_OFFSET + 40),
+        hair_color: [hair_channel(42), hair_channel(44), hair_channel(46)],
+        proportion_x: read_f32(char_data, APPEARANCE_OFFSET + 48),
+        proportion_y: read_f32(char_data, APPEARANCE_OFFSET + 52),
+    }
+}
+
+fn set_stats(char_data: &[u8]) -> CharacterStats {
+    CharacterStats {
+        atp: read_u16(char_data, STATS_OFFSET),
+        mst: read_u16(char_data, STATS_OFFSET + 2),
+        evp: read_u16(char_data, STATS_OFFSET + 4),
+        hp: read_u16(char_data, STATS_OFFSET + 6),
+        dfp: read_u16(char_data, STATS_OFFSET + 8),
+        ata: read_u16(char_data, STATS_OFFSET + 10),
+        lck: read_u16(char_data, STATS_OFFSET + 12),
+    }
+}
+
+// Power, Mind, Evade, Def and Luck counters live in the last header byte of inventory slots 8-12.
+fn stat_material_count(char_data: &[u8], index: usize) -> u8 {
+    let slot_header = INVENTORY_OFFSET - 8 + INVENTORY_ITEM_LENGTH * (8 + index);
+    char_data[slot_header + 3]
+}
+
+// The equipped flag sits in the flags word of each inventory slot's header. Blank slots are
+// skipped the same way `item::set_items` skips them, so the indices line up with the inventory.
+fn set_equipped(char_data: &[u8]) -> Vec<usize> {
+    (0..INVENTORY_CAPACITY)
+        .map(|slot| INVENTORY_OFFSET + INVENTORY_ITEM_LENGTH * slot)
+        .filter(|&offset| !item::is_blank(&char_data[offset..offset + INVENTORY_ITEM_LENGTH]))
+        .enumerate()
+        .filter(|&(_, offset)| char_data[offset - 4] & EQUIPPED_FLAG != 0)
+        .map(|(index, _)| index)
+        .collect()
+}
+
+fn set_materials(char_data: &[u8]) -> MaterialUsage {
+    MaterialUsage {
+        power: stat_material_count(char_data, 0),
+        mind: stat_material_count(char_data, 1),
+        evade: stat_material_count(char_data, 2),
+        def: stat_material_count(char_data, 3),
+        luck: stat_material_count(char_data, 4),
+        hp: char_data[HP_MATERIALS_OFFSET] / 2,
+        tp: char_data[TP_MATERIALS_OFFSET] / 2,
+    }
+}
+
+pub fn material_headroom(class: &str, materials: &MaterialUsage) -> MaterialHeadroom {
+    let (stat_limit, hp_limit, tp_limit) = Config::material_limits()
+        .get(class)
+        .copied()
+        .unwrap_or((0, 0, 0));
+    let stat_used = [materials.power, materials.mind, materials.evade, materials.def, materials.luck]
+        .iter()
+        .map(|&count| count as u16)
+        .sum::<u16>();
+
+    MaterialHeadroom {
+        stat: stat_limit.saturating_sub(stat_used),
+        hp: hp_limit.saturating_sub(materials.hp),
+        tp: tp_limit.saturating_sub(materials.tp),
+    }
 }
 
 fn set_experience(char_data: &[u8]) -> u32 {
-    // For now just return 0
-    0
+    let mut experience = char_data[EXPERIENCE_OFFSET..EXPERIENCE_OFFSET + 4].to_vec();
+    experience.reverse();
+
+    Util::binary_array_to_int(&experience)
 }
 
-fn progress_count(char_data: &[u8], mut index: usize, max: usize) -> usize {
-    let mut count = 0;
+pub fn experience_progress(level: u8, experience: u32) -> ExperienceProgress {
+    let level_experience = experience_for_level(level).unwrap_or(0);
+    let next_level_experience = level.checked_add(1).and_then(experience_for_level);
 
-    for _ in 0..max {
-        if index + 4 > char_data.len() {
-            break;
-        }
+    let (to_next_level, percent) = match next_level_experience {
+        Some(next) if next > level_experience => {
+            let gained = experience.saturating_sub(level_experience);
+            let needed = next - level_experience;
+            (next.saturating_sub(experience), (gained as f32 / needed as f32 * 100.0).min(100.0))
+        },
+        _ => (0, 100.0),
+    };
 
-        if char_data[index..index + 4].iter().map(|&x| x as u16).sum::<u16>() == 0 {
-            break;
-        }
-        
-        count += 1;
-        index += 4;
+    ExperienceProgress {
+        level,
+        experience,
+        level_experience,
+        next_level_experience,
+        to_next_level,
+        percent,
     }
+}
 
-    count
+pub fn quest_flag_set(char_data: &[u8], difficulty: usize, flag: u16) -> bool {
+    let index = QUEST_FLAGS_OFFSET + QUEST_FLAGS_LENGTH * difficulty + (flag as usize >> 3);
+
+    match char_data.get(index) {
+        Some(byte) => byte & (0x80 >> (flag & 0x07)) != 0,
+        None => false,
+    }
 }
 
-fn set_ep1_progress(char_data: &Vec<u8>, index: usize, number: usize) -> String {
-    let mut progress = String::new();
-    let ep1_titles_map = Config::titles();
-    let count = progress_count(char_data, index, number);
+// Stages count as cleared in order, so a later boss flag without the earlier ones doesn't count.
+fn stages_cleared(char_data: &[u8], difficulty: usize, flags: &[[u16; 4]]) -> u8 {
+    flags
+        .iter()
+        .take_while(|flags| quest_flag_set(char_data, difficulty, flags[difficulty]))
+        .count() as u8
+}
 
-    if count == 0 {
-        progress = String::from("No Progress");
-    } else {
-        match ep1_titles_map.get(&count) {
-            Some(title) => {
-                progress = format!("Stage {} Cleared! | {}", count, String::from(*title));
-            },
-            None => progress = String::from("Unknown Progress")
-        }
+fn set_episode_progress(char_data: &[u8], episode: u8) -> EpisodeProgress {
+    let story_flags = Config::story_flags();
+    let flags = story_flags.get(&episode).map(Vec::as_slice).unwrap_or(&[]);
+
+    EpisodeProgress {
+        stages: flags.len() as u8,
+        normal: stages_cleared(char_data, 0, flags),
+        hard: stages_cleared(char_data, 1, flags),
+        very_hard: stages_cleared(char_data, 2, flags),
+        ultimate: stages_cleared(char_data, 3, flags),
     }
+}
 
-    progress
+fn set_story_progress(char_data: &[u8]) -> StoryProgress {
+    StoryProgress {
+        ep1: set_episode_progress(char_data, 1),
+        ep2: set_episode_progress(char_data, 2),
+        ep4: set_episode_progress(char_data, 4),
+    }
 }
 
-fn set_ep2_progress(char_data: &Vec<u8>, index: usize, number: usize) -> String {
-    let mut progress = String::new();
-    let count = progress_count(char_data, index, number);
+fn set_quest_log(char_data: &[u8], config: &Config) -> Vec<QuestStatus> {
+    let mut quest_log: Vec<QuestStatus> = quest_table()
+        .into_iter()
+        .map(|(flag, (episode, name))| QuestStatus {
+            flag,
+            episode,
+            name: config.quest_name(flag).unwrap_or_else(|| String::from(name)),
+            completed: [0, 1, 2, 3].map(|difficulty| quest_flag_set(char_data, difficulty, flag)),
+        })
+        .collect();
 
-    if count == 0 {
-        progress = String::from("No Progress");
-    } else {
-        progress = format!("Stage {} Cleared!", count);
+    quest_log.sort_by_key(|quest| (quest.episode, quest.flag));
+    quest_log
+}
+
+// Unlearned techniques are stored as 0xFF, learned ones as their level minus one.
+fn set_techniques(char_data: &[u8], config: &Config) -> Vec<LearnedTech> {
+    char_data[TECH_LEVELS_OFFSET..TECH_LEVELS_OFFSET + TECH_COUNT]
+        .iter()
+        .enumerate()
+        .filter(|(_, &level)| level != 0xFF)
+        .map(|(tech, &level)| {
+            let tech = tech as u8;
+            let name = match &config.tech_codes {
+                Some(map) => match map.get(&tech) {
+                    Some(name) => name.clone(),
+                    None => String::from("No name found"),
+                },
+                None => String::from("No map found"),
+            };
+
+            LearnedTech { tech, name, level: level + 1 }
+        })
+        .collect()
+}
+
+/// Highest level of a technique the class can learn. Grants and Megid are forces only,
+/// Ryuker and Reverser only have a single level and Anti stops at 7.
+pub fn tech_level_cap(class: &str, tech: u8) -> u8 {
+    let limit = Config::tech_limits().get(class).copied().unwrap_or(0);
+    let force = limit == 30;
+
+    match tech {
+        0x09 | 0x12 if !force => 0,
+        0x0E | 0x11 => limit.min(1),
+        0x10 => limit.min(7),
+        _ => limit,
     }
-    
-    progress
 }
 
-pub fn create(pso_char: &Vec<u8>, slot: usize, config: Config) -> Character {
-    Character {
+pub fn create(pso_char: &Vec<u8>, slot: usize, config: Config) -> Result<Character, ParseError> {
+    ensure_len(pso_char, CHARACTER_MIN_SIZE)?;
+
+    Ok(Character {
         slot: slot,
-        mode: set_mode(pso_char),
+        mode: set_mode(pso_char, &config),
         name: set_name(pso_char),
-        lang: config.lang.clone().unwrap(),
-        guild_card_number: set_guild_card_number(pso_char),
+        lang: config.lang.clone().ok_or_else(|| missing_config("a language"))?,
+        guild_card_number: set_guild_card_number(pso_char)?,
         class: set_class(pso_char),
         section_id: set_section_id(pso_char),
         level: set_level(pso_char),
         experience: set_experience(pso_char),
-        ep1_progress: set_ep1_progress(pso_char, 11460, 9),
-        ep2_progress: set_ep2_progress(pso_char, 11496, 6),
-        inventory: item::set_items(&pso_char[20..860], Slot::Usize(slot), 28, &pso_char[884..887], config.clone()),
-        bank: item::set_items(&pso_char[1800..6600], Slot::Usize(slot), 24, &pso_char[1795..1799], config.clone()),
+        appearance: set_appearance(pso_char),
+        stats: set_stats(pso_char),
+        materials: set_materials(pso_char),
+        story_progress: set_story_progress(pso_char),
+        quest_log: set_quest_log(pso_char, &config),
+        techniques: set_techniques(pso_char, &config),
+        challenge: challenge::create(pso_char),
+        inventory: item::set_items(
+            &pso_char[INVENTORY_OFFSET..INVENTORY_OFFSET + INVENTORY_ITEM_LENGTH * INVENTORY_CAPACITY],
+            INVENTORY_OFFSET,
+            Slot::Usize(slot),
+            INVENTORY_ITEM_LENGTH,
+            &pso_char[MESETA_OFFSET..MESETA_OFFSET + 4],
+            config.clone()
+        )?,
+        equipped: set_equipped(pso_char),
+        bank: item::set_items(
+            &pso_char[BANK_OFFSET..BANK_OFFSET + BANK_ITEM_LENGTH * BANK_CAPACITY],
+            BANK_OFFSET,
+            Slot::Usize(slot),
+            BANK_ITEM_LENGTH,
+            &pso_char[BANK_MESETA_OFFSET..BANK_MESETA_OFFSET + 4],
+            config.clone()
+        )?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::fixtures;
+
+    fn set_flag(file: &mut [u8], difficulty: usize, flag: u16) {
+        file[QUEST_FLAGS_OFFSET + QUEST_FLAGS_LENGTH * difficulty + (flag as usize >> 3)] |= 0x80 >> (flag & 0x07);
+    }
+
+    #[test]
+    fn equipped_flags_index_the_inventory() {
+        let mut file = fixtures::character_file();
+        // Saber in slot 0 and the mag in slot 4, with the Monomates in slot 2 left alone.
+        for slot in [0, 4] {
+            file[INVENTORY_OFFSET + INVENTORY_ITEM_LENGTH * slot - 4] |= EQUIPPED_FLAG;
+        }
+
+        let character = create(&file, 1, fixtures::config()).unwrap();
+        assert_eq!(character.equipped, vec![0, 2]);
+        assert!(matches!(character.inventory[2].item, Some(Item::Mag { .. })));
+    }
+
+    #[test]
+    fn stats_are_read_as_stored() {
+        let mut file = fixtures::character_file();
+        for (i, stat) in [120u16, 80, 95, 250, 70, 110, 10].iter().enumerate() {
+            file[STATS_OFFSET + i * 2..STATS_OFFSET + i * 2 + 2].copy_from_slice(&stat.to_le_bytes());
+        }
+        // Ten HP materials, stored as the HP they add.
+        file[HP_MATERIALS_OFFSET] = 20;
+
+        let character = create(&file, 1, fixtures::config()).unwrap();
+        let stats = character.stats;
+        assert_eq!((stats.atp, stats.mst, stats.evp, stats.dfp, stats.ata, stats.lck), (120, 80, 95, 70, 110, 10));
+        assert_eq!(stats.hp, 250);
+        assert_eq!(character.materials.hp, 10);
+    }
+
+    #[test]
+    fn final_boss_flags_mark_the_episode_cleared() {
+        let mut file = fixtures::character_file();
+        // Dark Falz on Normal, and only his second form on Hard.
+        set_flag(&mut file, 0, 0x35);
+        set_flag(&mut file, 1, 0x35);
+        // Olga Flow on Ultimate.
+        set_flag(&mut file, 3, 0x57);
+
+        let progress = create(&file, 1, fixtures::config()).unwrap().story_progress;
+        assert_eq!((progress.ep1.stages, progress.ep1.normal, progress.ep1.hard), (1, 1, 0));
+        assert_eq!((progress.ep2.stages, progress.ep2.very_hard, progress.ep2.ultimate), (1, 0, 1));
+        assert_eq!(progress.ep4.stages, 0);
+
+        set_flag(&mut file, 1, 0x37);
+        let progress = create(&file, 1, fixtures::config()).unwrap().story_progress;
+        assert_eq!(progress.ep1.hard, 1);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn quest_log_reads_one_person_quest_flags() {
+        let mut file = fixtures::character_file();
+        // Magnitude of Metal, the first quest, on Normal and Hard, and the 24th on Ultimate.
+        set_flag(&mut file, 0, 0x65);
+        set_flag(&mut file, 1, 0x65);
+        set_flag(&mut file, 3, 0x93);
+
+        let mut config = fixtures::config();
+        config.quest_names.as_mut().unwrap().insert(0x93, String::from("Suche meinen Meister"));
+        let quest_log = create(&file, 1, config).unwrap().quest_log;
+
+        assert_eq!(quest_log.len(), 25);
+        assert_eq!((quest_log[0].flag, quest_log[0].name.as_str()), (0x65, "Magnitude of Metal"));
+        assert_eq!(quest_log[0].completed, [true, true, false, false]);
+        assert_eq!((quest_log[23].flag, quest_log[23].name.as_str()), (0x93, "Suche meinen Meister"));
+        assert_eq!(quest_log[23].completed, [false, false, false, true]);
+        assert!(quest_log[1..23].iter().all(|quest| quest.completed == [false; 4]));
+    }
+
+    #[test]
+    fn tech_caps_follow_the_class_table() {
+        let foie = 0x00;
+        let megid = 0x12;
+        let anti = 0x10;
+
+        assert_eq!(tech_level_cap("HUmar", foie), 15);
+        assert_eq!(tech_level_cap("Hunewearl", foie), 20);
+        assert_eq!(tech_level_cap("RAmar", foie), 15);
+        assert_eq!(tech_level_cap("RAmarl", foie), 20);
+        assert_eq!(tech_level_cap("FOmarl", foie), 30);
+        assert_eq!(tech_level_cap("HUcast", foie), 0);
+
+        assert_eq!(tech_level_cap("RAmarl", megid), 0);
+        assert_eq!(tech_level_cap("FOnewm", megid), 30);
+        assert_eq!(tech_level_cap("HUmar", anti), 7);
+    }
+}
@@ -0,0 +1,206 @@
+use tauri::regex::Regex;
+use crate::util::Util;
+use crate::parser::character::{CHARACTER_MIN_SIZE, BANK_COUNT_OFFSET};
+use crate::parser::shared_bank::{self, SHARED_BANK_SIZE};
+use crate::parser::types::{File, FileKind};
+
+const MAX_INVENTORY_ITEMS: u8 = 30;
+const MAX_BANK_ITEMS: u32 = 200;
+const MAX_BANK_MESETA: u32 = 999_999;
+const MODE_OFFSET: usize = 7;
+const CLASSIC_MODE: u8 = 0x40;
+const GUILD_CARD_OFFSET: usize = 888;
+const GUILD_CARD_DIGITS: usize = 8;
+
+#[derive(Debug, Clone)]
+pub struct Detected {
+    pub kind: FileKind,
+    pub slot: Option<usize>,
+}
+
+fn read_u32(binary: &[u8], offset: usize) -> u32 {
+    let mut value = binary[offset..offset + 4].to_vec();
+    value.reverse();
+
+    Util::binary_array_to_int(&value)
+}
+
+// The guild card number is stored as digits, either ASCII or raw.
+fn guild_card_digits(binary: &[u8]) -> bool {
+    binary[GUILD_CARD_OFFSET..GUILD_CARD_OFFSET + GUILD_CARD_DIGITS]
+        .iter()
+        .all(|&b| b <= 9 || b.is_ascii_digit())
+}
+
+fn looks_like_character(binary: &[u8]) -> bool {
+    if binary.len() < CHARACTER_MIN_SIZE {
+        return false;
+    }
+
+    let inventory_count = binary[8];
+    let level = binary[876];
+    let section_id = binary[936];
+    let class = binary[937];
+
+    inventory_count <= MAX_INVENTORY_ITEMS
+        && level < 200
+        && section_id <= 0x0A
+        && class <= 0x0B
+        && read_u32(binary, BANK_COUNT_OFFSET) <= MAX_BANK_ITEMS
+        && guild_card_digits(binary)
+}
+
+fn looks_like_shared_bank(binary: &[u8]) -> bool {
+    if binary.len() != SHARED_BANK_SIZE {
+        return false;
+    }
+
+    read_u32(binary, shared_bank::BANK_COUNT_OFFSET) <= MAX_BANK_ITEMS
+        && read_u32(binary, shared_bank::BANK_MESETA_OFFSET) <= MAX_BANK_MESETA
+}
+
+fn is_classic_character(binary: &[u8]) -> bool {
+    looks_like_character(binary) && binary[MODE_OFFSET] == CLASSIC_MODE
+}
+
+/// Whether every character file in an import is a classic mode character, going by the mode
+/// byte in each file's header.
+pub fn classic_import(files: &[File]) -> bool {
+    let mut characters = files
+        .iter()
+        .filter(|file| file.kind.map_or(true, |kind| kind == FileKind::Character))
+        .filter(|file| looks_like_character(&file.binary))
+        .peekable();
+
+    characters.peek().is_some() && characters.all(|file| is_classic_character(&file.binary))
+}
+
+fn kind_hint(filename: &str) -> Option<FileKind> {
+    let filename = filename.to_lowercase();
+
+    if filename.contains("psoclassicbank") {
+        Some(FileKind::ClassicSharedBank)
+    } else if filename.contains("psobank") {
+        Some(FileKind::SharedBank)
+    } else if filename.contains("psochar") {
+        Some(FileKind::Character)
+    } else {
+        None
+    }
+}
+
+fn slot_hint(filename: &str) -> Option<usize> {
+    let re_slot = Regex::new(r"(?i)slot_ ?(\d+)").unwrap();
+
+    re_slot
+        .captures(filename)
+        .and_then(|caps| caps.get(1))
+        .and_then(|slot_match| slot_match.as_str().parse().ok())
+}
+
+/// Works out what kind of save a file is from its size and header. Shared bank files have no
+/// mode field, so a bank's mode comes from the filename when it names one, otherwise from the
+/// headers of the characters imported with it (`classic_import`). A file whose contents match
+/// neither kind only gets through on its filename, so the parser can report what is wrong.
+/// Explicit `kind`/`slot` values always win.
+pub fn detect(file: &File, classic_import: bool) -> Option<Detected> {
+    let kind = match file.kind {
+        Some(kind) => kind,
+        None => {
+            if looks_like_character(&file.binary) {
+                FileKind::Character
+            } else if looks_like_shared_bank(&file.binary) {
+                match kind_hint(&file.filename) {
+                    Some(FileKind::ClassicSharedBank) => FileKind::ClassicSharedBank,
+                    Some(FileKind::SharedBank) => FileKind::SharedBank,
+                    _ if classic_import => FileKind::ClassicSharedBank,
+                    _ => FileKind::SharedBank,
+                }
+            } else {
+                kind_hint(&file.filename)?
+            }
+        }
+    };
+
+    let slot = match kind {
+        FileKind::Character => file.slot.or_else(|| slot_hint(&file.filename)),
+        _ => None,
+    };
+
+    Some(Detected { kind, slot })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::fixtures;
+
+    fn file(filename: &str, binary: Vec<u8>) -> File {
+        File { filename: String::from(filename), binary, kind: None, slot: None }
+    }
+
+    fn shared_bank_file() -> Vec<u8> {
+        let mut binary = vec![0; SHARED_BANK_SIZE];
+        binary[0..4].copy_from_slice(&1u32.to_le_bytes());
+        binary[4..8].copy_from_slice(&5000u32.to_le_bytes());
+        binary
+    }
+
+    fn noise(len: usize) -> Vec<u8> {
+        let mut state: u32 = 0x2545_F491;
+        (0..len)
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 17;
+                state ^= state << 5;
+                state as u8
+            })
+            .collect()
+    }
+
+    fn kind(file: &File, classic_import: bool) -> Option<FileKind> {
+        detect(file, classic_import).map(|detected| detected.kind)
+    }
+
+    #[test]
+    fn renamed_files_are_detected_from_their_contents() {
+        assert_eq!(kind(&file("backup (2).bin", fixtures::character_file()), false), Some(FileKind::Character));
+        assert_eq!(kind(&file("bank.bak", shared_bank_file()), false), Some(FileKind::SharedBank));
+        // Contents win over a filename that names another kind.
+        assert_eq!(kind(&file("psobank_1.psobank", fixtures::character_file()), false), Some(FileKind::Character));
+    }
+
+    #[test]
+    fn random_data_is_rejected() {
+        assert!(detect(&file("blob.bin", noise(7000)), false).is_none());
+        assert!(detect(&file("blob.bin", noise(CHARACTER_MIN_SIZE)), false).is_none());
+        assert!(detect(&file("blob.bin", noise(SHARED_BANK_SIZE)), false).is_none());
+    }
+
+    #[test]
+    fn bank_mode_follows_the_imported_characters() {
+        let mut classic = fixtures::character_file();
+        classic[MODE_OFFSET] = CLASSIC_MODE;
+
+        let import = vec![file("a.bin", classic.clone()), file("b.bin", shared_bank_file())];
+        assert!(classic_import(&import));
+        assert_eq!(kind(&import[1], classic_import(&import)), Some(FileKind::ClassicSharedBank));
+
+        let mixed = vec![file("a.bin", classic), file("c.bin", fixtures::character_file())];
+        assert!(!classic_import(&mixed));
+        assert!(!classic_import(&[file("b.bin", shared_bank_file())]));
+
+        // A filename that names the bank's mode still wins.
+        assert_eq!(kind(&file("psobank_1.psobank", shared_bank_file()), true), Some(FileKind::SharedBank));
+    }
+
+    #[test]
+    fn explicit_kind_and_slot_win() {
+        let mut character = file("Slot_ 2.psochar", fixtures::character_file());
+        assert_eq!(detect(&character, false).unwrap().slot, Some(2));
+
+        character.slot = Some(0);
+        character.kind = Some(FileKind::Character);
+        assert_eq!(detect(&character, false).unwrap().slot, Some(0));
+    }
+}
@@ -0,0 +1,64 @@
+use std::fmt;
+use serde::{Serialize, Deserialize};
+use thiserror::Error;
+
+#[derive(Error, Debug, Serialize, Deserialize, Clone)]
+pub enum ParseError {
+    TruncatedFile { offset: usize, size: usize },
+    UnknownMagColor { offset: usize, color: u8 },
+    BadGuildCardDigits { offset: usize, digits: String },
+    BadCustomName { offset: usize },
+    BadItemData { offset: usize },
+    TooManyItems { count: usize, capacity: usize },
+    MissingTemplate,
+    UnknownFileType { filename: String },
+    NoSaveFiles,
+    MissingItemTable { filename: String },
+    BadItemTable { path: String, reason: String },
+    MissingConfig { table: String },
+}
+
+impl ParseError {
+    /// Moves an error reported relative to an item or block to its position in the whole file.
+    pub fn at(self, base: usize) -> Self {
+        match self {
+            ParseError::TruncatedFile { offset, size } => ParseError::TruncatedFile { offset: base + offset, size },
+            ParseError::UnknownMagColor { offset, color } => ParseError::UnknownMagColor { offset: base + offset, color },
+            ParseError::BadGuildCardDigits { offset, digits } => ParseError::BadGuildCardDigits { offset: base + offset, digits },
+            ParseError::BadCustomName { offset } => ParseError::BadCustomName { offset: base + offset },
+            ParseError::BadItemData { offset } => ParseError::BadItemData { offset: base + offset },
+            error => error,
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::TruncatedFile { offset, size } => write!(f, "File truncated: expected data up to offset 0x{:X}, but file is {} bytes", offset, size),
+            ParseError::UnknownMagColor { offset, color } => write!(f, "Unknown mag color 0x{:02X} at offset 0x{:X}", color, offset),
+            ParseError::BadGuildCardDigits { offset, digits } => write!(f, "Bad guild card digits \"{}\" at offset 0x{:X}", digits, offset),
+            ParseError::BadCustomName { offset } => write!(f, "Bad S-Rank custom name at offset 0x{:X}", offset),
+            ParseError::BadItemData { offset } => write!(f, "Bad item data for the slot at offset 0x{:X}", offset),
+            ParseError::TooManyItems { count, capacity } => write!(f, "{} items do not fit in {} slots", count, capacity),
+            ParseError::MissingTemplate => write!(f, "Character files can only be written on top of the original .psochar"),
+            ParseError::UnknownFileType { filename } => write!(f, "{} is not a recognized character or shared bank file", filename),
+            ParseError::NoSaveFiles => write!(f, "No files to parse"),
+            ParseError::MissingItemTable { filename } => write!(f, "Item table {} could not be found", filename),
+            ParseError::BadItemTable { path, reason } => write!(f, "Bad item table {}: {}", path, reason),
+            ParseError::MissingConfig { table } => write!(f, "Config was initialized without {}", table),
+        }
+    }
+}
+
+pub fn missing_config(table: &str) -> ParseError {
+    ParseError::MissingConfig { table: String::from(table) }
+}
+
+pub fn ensure_len(data: &[u8], end: usize) -> Result<(), ParseError> {
+    if data.len() < end {
+        return Err(ParseError::TruncatedFile { offset: end, size: data.len() });
+    }
+
+    Ok(())
+}
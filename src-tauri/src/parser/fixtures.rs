@@ -0,0 +1,81 @@
+use std::path::PathBuf;
+use crate::config::config::Config;
+use crate::config::item_tables::ItemTablePaths;
+use crate::config::server_profile::ServerProfile;
+use crate::parser::character::{
+    INVENTORY_COUNT_OFFSET,
+    INVENTORY_OFFSET,
+    INVENTORY_ITEM_LENGTH,
+    LEVEL_OFFSET,
+    MESETA_OFFSET,
+    BANK_COUNT_OFFSET,
+    BANK_MESETA_OFFSET,
+    BANK_OFFSET,
+    BANK_ITEM_LENGTH,
+    BANK_CAPACITY,
+    CHARACTER_MIN_SIZE,
+};
+
+pub const SABER: [u8; 3] = [0x00, 0x01, 0x00];
+pub const MONOMATE: [u8; 3] = [0x03, 0x00, 0x00];
+pub const MAG: [u8; 3] = [0x02, 0x00, 0x05];
+pub const FRAME: [u8; 3] = [0x01, 0x01, 0x00];
+
+/// English config backed by the bundled item tables.
+pub fn config() -> Config<'static> {
+    let tables = ItemTablePaths {
+        bundled: Some(PathBuf::from(concat!(env!("CARGO_MANIFEST_DIR"), "/data"))),
+        overrides: None,
+    };
+
+    Config::init(String::from("EN"), ServerProfile::VanillaBB, &tables).expect("bundled item tables")
+}
+
+fn inventory_slot(index: usize) -> usize {
+    INVENTORY_OFFSET + INVENTORY_ITEM_LENGTH * index
+}
+
+/// A level 10 HUcast with a Saber +5, three Monomates and a mag in the inventory, 10000 meseta
+/// on hand, and a Frame and 12345 meseta in the bank.
+pub fn character_file() -> Vec<u8> {
+    let mut file = vec![0; CHARACTER_MIN_SIZE];
+
+    for i in 0..BANK_CAPACITY {
+        let slot = BANK_OFFSET + BANK_ITEM_LENGTH * i;
+        file[slot + 12..slot + 16].copy_from_slice(&[0xFF; 4]);
+    }
+
+    let saber = inventory_slot(0);
+    file[saber..saber + 3].copy_from_slice(&SABER);
+    file[saber + 3] = 5;
+    file[saber + 12..saber + 16].copy_from_slice(&[0x01, 0x00, 0x01, 0x00]);
+
+    let monomate = inventory_slot(2);
+    file[monomate..monomate + 3].copy_from_slice(&MONOMATE);
+    file[monomate + 5] = 3;
+    file[monomate + 12..monomate + 16].copy_from_slice(&[0x02, 0x00, 0x01, 0x00]);
+
+    let mag = inventory_slot(4);
+    file[mag..mag + 3].copy_from_slice(&MAG);
+    file[mag + 19] = 0x01;
+    file[mag + 12..mag + 16].copy_from_slice(&[0x03, 0x00, 0x01, 0x00]);
+
+    file[INVENTORY_COUNT_OFFSET] = 3;
+
+    file[BANK_OFFSET..BANK_OFFSET + 3].copy_from_slice(&FRAME);
+    file[BANK_OFFSET + 12..BANK_OFFSET + 16].copy_from_slice(&[0x04, 0x00, 0x01, 0x00]);
+    file[BANK_OFFSET + 20] = 1;
+    file[BANK_COUNT_OFFSET..BANK_COUNT_OFFSET + 4].copy_from_slice(&1u32.to_le_bytes());
+    file[BANK_MESETA_OFFSET..BANK_MESETA_OFFSET + 4].copy_from_slice(&12345u32.to_le_bytes());
+
+    file[MESETA_OFFSET..MESETA_OFFSET + 4].copy_from_slice(&10000u32.to_le_bytes());
+    file[LEVEL_OFFSET] = 9;
+    // Section ID, then class.
+    file[936] = 3;
+    file[937] = 2;
+
+    // Guild card number as ASCII digits.
+    file[888..896].copy_from_slice(b"42004063");
+
+    file
+}
@@ -1,17 +1,30 @@
 use std::collections::HashMap;
 use crate::util::Util;
 use crate::config::config::Config;
+use crate::config::unit_effects::unit_effects;
+use crate::config::armor_data::armor_data;
+use crate::config::ephinea_items::ServerItemRow;
+use crate::parser::error::{ParseError, ensure_len, missing_config};
 use crate::parser::types::{
     Slot,
     Item,
     WrappedItem,
     MagStats,
+    UnitEffect,
+    KillCounter,
+    MagEvolution,
+    PhotonBlast,
+    PhotonBlasts,
     Inventory,
     Attribute,
     Addition,
-    AdditionType
+    AdditionType,
+    ArmorBase,
+    ArmorReference
 };
 
+const WRAPPED_FLAG: u8 = 0x40;
+
 fn is_srank_weapon(item_code: u32) -> bool {
     Config::srank_weapon_codes().contains_key(&(item_code & 0xFFF0))
 }
@@ -54,12 +67,17 @@ fn is_tool(item_code: u32) -> bool {
     range.0 <= item_code && item_code <= range.1
 }
 
+/// Items the server lists by code take precedence over the vanilla tables.
+fn server_item(item_code: u32, config: &Config) -> Option<ServerItemRow> {
+    config.server_items.as_ref()?.get(&item_code).copied()
+}
+
 fn is_meseta(item_code: u32) -> bool {
     let range = Config::MESETA_RANGE;
     range.0 <= item_code && item_code <= range.1
 }
 
-fn get_item_type(item_code: u32) -> u32 {
+fn get_item_type(item_code: u32, config: &Config) -> u32 {
     if is_srank_weapon(item_code) {
         8
     } else if is_weapon(item_code) {
@@ -74,6 +92,8 @@ fn get_item_type(item_code: u32) -> u32 {
         5
     } else if is_tech(item_code) {
         6
+    } else if let Some((_, _, item_type, _)) = server_item(item_code, config) {
+        item_type
     } else if is_tool(item_code) {
         7
     } else if is_meseta(item_code) {
@@ -93,13 +113,14 @@ pub fn weapon(item_code: u32, item_data: Vec<u8>, config: Config) -> Item {
     let hit = get_hit(&item_data);
     let is_common = is_common_weapon(item_code);
     let mut special_code = String::new();
-    let special = if item_data[4] != 0x00 && item_data[4] != 0x80 && is_common {
-        special_code = format!("0x{:02X}", item_data[4]);
+    let special_byte = item_data[4] & !WRAPPED_FLAG;
+    let special = if special_byte != 0x00 && special_byte != 0x80 && is_common {
+        special_code = format!("0x{:02X}", special_byte);
         get_special(&item_data, &config)
     } else {
         let special_values = [0x00E800, 0x00E801, 0x00E900, 0x00EA00, 0x00EB00]; // Type Weps
         if special_values.contains(&item_code) {
-            special_code = format!("0x{:02X}", item_data[4]);
+            special_code = format!("0x{:02X}", special_byte);
             get_special(&item_data, &config)
         } else {
             special_code = format!("0x{:06X}", item_code);
@@ -132,12 +153,14 @@ pub fn weapon(item_code: u32, item_data: Vec<u8>, config: Config) -> Item {
             hit,
         },
         tekked: tekked_mode,
-        rare: !is_common
+        kill_counter: get_kill_counter(item_code, &item_data),
+        rare: !is_common,
+        wrapped: is_wrapped(&item_data)
     }
 }
 
-pub fn s_rank_weapon(item_code: u32, item_data: Vec<u8>, config: Config) -> Item {
-    let custom_name = get_custom_name(&item_data[6..12]);
+pub fn s_rank_weapon(item_code: u32, item_data: Vec<u8>, config: Config) -> Result<Item, ParseError> {
+    let custom_name = get_custom_name(&item_data[6..12]).map_err(|error| error.at(6))?;
     let map = Config::srank_weapon_codes();
     let weapon_code = match map.get(&(item_code & 0xFFFF00)) {
         Some(code) => code,
@@ -147,23 +170,23 @@ pub fn s_rank_weapon(item_code: u32, item_data: Vec<u8>, config: Config) -> Item
     let grind = item_data[3];
     let special = get_srank_special(&item_data, config);
 
-    Item::SRankWeapon {
+    Ok(Item::SRankWeapon {
         name: name.clone().to_string(),
         type_: 8,
         item_data: Util::binary_array_to_hex(&item_data),
         grind,
         special: special.clone(),
-        special_code: format!("0x{:02X}", item_data[2])
-    }
+        special_code: format!("0x{:02X}", item_data[2]),
+        wrapped: is_wrapped(&item_data)
+    })
 }
 
 pub fn frame(item_code: u32, item_data: Vec<u8>, config: Config) -> Item {
     let name = get_item_name(item_code, &config);
     let slot = item_data[5];
     let dfp = item_data[6];
-    let dfp_max_addition = get_addition(&name, &config.frames, 0);
     let evp = item_data[8];
-    let evp_max_addition = get_addition(&name, &config.frames, 1);
+    let max_addition = armor_max_addition(item_code);
 
     Item::Frame {
         name: name.clone(),
@@ -171,19 +194,16 @@ pub fn frame(item_code: u32, item_data: Vec<u8>, config: Config) -> Item {
         item_data: Util::binary_array_to_hex(&item_data),
         slot,
         addition: Addition { dfp: dfp.into(), evp: evp.into() },
-        max_addition: Addition {
-            dfp: dfp_max_addition,
-            evp: evp_max_addition,
-        }
+        max_addition,
+        wrapped: is_wrapped(&item_data)
     }
 }
 
 pub fn barrier(item_code: u32, item_data: Vec<u8>, config: Config) -> Item {
     let name = get_item_name(item_code, &config);
     let dfp = item_data[6];
-    let dfp_max_addition = get_addition(&name, &config.barriers, 0);
     let evp = item_data[8];
-    let evp_max_addition = get_addition(&name, &config.barriers, 1);
+    let max_addition = armor_max_addition(item_code);
 
     Item::Barrier {
         name: name.clone(),
@@ -193,10 +213,8 @@ pub fn barrier(item_code: u32, item_data: Vec<u8>, config: Config) -> Item {
             dfp: dfp.into(),
             evp: evp.into(),
         },
-        max_addition: Addition {
-            dfp: dfp_max_addition,
-            evp: evp_max_addition,
-        }
+        max_addition,
+        wrapped: is_wrapped(&item_data)
     }
 }
 
@@ -206,49 +224,148 @@ pub fn unit(item_code: u32, item_data: Vec<u8>, config: Config) -> Item {
     Item::Unit {
         name: name.clone(),
         type_: 4,
+        modifier: get_unit_modifier(&item_data),
+        kill_counter: get_kill_counter(item_code, &item_data),
         item_data: Util::binary_array_to_hex(&item_data),
+        wrapped: is_wrapped(&item_data)
     }
 }
 
-pub fn mag(item_code: u32, item_data: Vec<u8>, config: Config) -> Item {
+/// Kill counting items keep their count big endian in bytes 10-11, with the top bit set
+/// once the counter is active.
+fn get_kill_counter(item_code: u32, item_data: &[u8]) -> Option<KillCounter> {
+    let kills_to_unseal = *Config::kill_counter_items().get(&item_code)?;
+    let kills = if item_data[10] & 0x80 != 0 {
+        ((item_data[10] as u16 & 0x7F) << 8) | item_data[11] as u16
+    } else {
+        0
+    };
+
+    Some(KillCounter {
+        kills,
+        kills_to_unseal,
+        kills_remaining: kills_to_unseal.saturating_sub(kills),
+    })
+}
+
+/// The modifier is a signed step count: 1 is "+", 2 is "++", -1 is "-" and -2 is "--".
+fn get_unit_modifier(item_data: &[u8]) -> i8 {
+    i16::from_le_bytes([item_data[6], item_data[7]]).clamp(-2, 2) as i8
+}
+
+pub fn unit_effect(item_code: u32, modifier: i8) -> Option<UnitEffect> {
+    let (stats, resists, step, description) = *unit_effects().get(&item_code)?;
+    let modified = |value: i16| if value == 0 { 0 } else { value + step * modifier as i16 };
+
+    Some(UnitEffect {
+        atp: modified(stats[0]),
+        mst: modified(stats[1]),
+        ata: modified(stats[2]),
+        evp: modified(stats[3]),
+        dfp: modified(stats[4]),
+        lck: modified(stats[5]),
+        hp: modified(stats[6]),
+        tp: modified(stats[7]),
+        efr: modified(resists[0]),
+        eic: modified(resists[1]),
+        eth: modified(resists[2]),
+        elt: modified(resists[3]),
+        edk: modified(resists[4]),
+        description: String::from(description),
+    })
+}
+
+pub fn mag(item_code: u32, item_data: Vec<u8>, config: Config) -> Result<Item, ParseError> {
     let name = get_item_name(item_code & 0xFFFF00, &config);
     let level = item_data[2];
     let sync = item_data[16];
     let iq = item_data[17];
-    let color = match config.mag_color_codes.clone().expect("REASON").get(&(item_data[19] as u8)) {
+    let colors = config.mag_color_codes.as_ref().ok_or_else(|| missing_config("mag colors"))?;
+    let color = match colors.get(&item_data[19]) {
         Some(color) => color.clone(),
-        None => panic!("Color not found"),
+        None => return Err(ParseError::UnknownMagColor { offset: 19, color: item_data[19] }),
     };
-    let def = ((item_data[5] as u16) << 8 | item_data[4] as u16) / 100;
-    let pow = ((item_data[7] as u16) << 8 | item_data[6] as u16) / 100;
-    let dex = ((item_data[9] as u16) << 8 | item_data[8] as u16) / 100;
-    let mind = ((item_data[11] as u16) << 8 | item_data[10] as u16) / 100;
-    let hex_data = Util::binary_array_to_hex(&[item_data[3], item_data[18]]);
-    let pbs = get_pbs(&hex_data, config);
-
-    Item::Mag {
+    let rgb = color.0.chars().next().ok_or(ParseError::UnknownMagColor { offset: 19, color: item_data[19] })?;
+    let def = (item_data[5] as u16) << 8 | item_data[4] as u16;
+    let pow = (item_data[7] as u16) << 8 | item_data[6] as u16;
+    let dex = (item_data[9] as u16) << 8 | item_data[8] as u16;
+    let mind = (item_data[11] as u16) << 8 | item_data[10] as u16;
+
+    Ok(Item::Mag {
         name: name,
         type_: 5,
         item_data: Util::binary_array_to_hex(&item_data),
         level,
         sync,
         iq,
-        color: String::from(color.1),
-        rgb: color.0.chars().nth(0).expect("REASON").to_string(),
+        color: color.1,
+        rgb: rgb.to_string(),
         stats: MagStats {
-            def,
-            pow,
-            dex,
-            mind,
+            def: def / 100,
+            pow: pow / 100,
+            dex: dex / 100,
+            mind: mind / 100,
+        },
+        progress: MagStats {
+            def: def % 100,
+            pow: pow % 100,
+            dex: dex % 100,
+            mind: mind % 100,
         },
-        pbs: [pbs[0].clone(), pbs[1].clone(), pbs[2].clone()]
+        photon_blasts: photon_blasts(item_data[3], item_data[18]),
+        evolution: mag_evolution(item_data[1], level),
+        wrapped: is_wrapped(&item_data)
+    })
+}
+
+/// Decodes the PB byte and the PB slot flags. The center and right PBs are stored as
+/// their number, the left one as an index into the PBs not already used by the other two.
+pub fn photon_blasts(pb_data: u8, flags: u8) -> PhotonBlasts {
+    let center = if flags & 0x01 != 0 { PhotonBlast::from_id(pb_data & 0x07) } else { None };
+    let right = if flags & 0x02 != 0 { PhotonBlast::from_id((pb_data & 0x38) >> 3) } else { None };
+    let left = if flags & 0x04 != 0 {
+        PhotonBlast::ALL
+            .iter()
+            .copied()
+            .filter(|pb| Some(*pb) != center && Some(*pb) != right)
+            .nth(((pb_data & 0xC0) >> 6) as usize)
+    } else {
+        None
+    };
+
+    PhotonBlasts { center, right, left }
+}
+
+fn mag_evolution(mag_id: u8, level: u8) -> MagEvolution {
+    if let Some((stage, affinity)) = Config::mag_evolutions().get(&mag_id) {
+        return MagEvolution { stage: *stage, affinity: *affinity };
     }
+
+    let stage = match (mag_id, level) {
+        (0x28.., _) => 4,
+        (_, 50..) => 3,
+        (_, 35..) => 2,
+        (_, 10..) => 1,
+        _ => 0,
+    };
+
+    MagEvolution { stage, affinity: None }
+}
+
+/// Localized names of the filled PB slots, in center, right, left order.
+pub fn photon_blast_names(photon_blasts: &PhotonBlasts, config: &Config) -> [String; 3] {
+    let name = |pb: Option<PhotonBlast>| pb
+        .and_then(|pb| config.photon_blasts.as_ref()?.get(&pb.id()))
+        .cloned()
+        .unwrap_or_default();
+
+    [name(photon_blasts.center), name(photon_blasts.right), name(photon_blasts.left)]
 }
 
 pub fn tech(item_code: u32, item_data: Vec<u8>, config: Config) -> Item {
     let name = match &config.tech_codes {
         Some(map) => match map.get(&(item_data[4] as u8)) {
-            Some(name) => String::from(*name),
+            Some(name) => name.clone(),
             None => String::from("No name found"),
         },
         None => String::from("No map found"),
@@ -260,73 +377,83 @@ pub fn tech(item_code: u32, item_data: Vec<u8>, config: Config) -> Item {
         level,
         type_: 6,
         item_data: Util::binary_array_to_hex(&item_data),
+        wrapped: is_wrapped(&item_data)
     }
 }
 
 pub fn tool(item_code: u32, item_data: Vec<u8>, config: Config) -> Item {
     let name = get_item_name(item_code, &config);
-    let number = if item_data.len() == 28 {
-        item_data[5]
-    } else {
-        item_data[20]
-    };
+    let number = get_stack_size(item_code, &item_data, &config);
     
     Item::Tool {
         name: name.clone(),
         type_: 7,
         item_data: Util::binary_array_to_hex(&item_data),
-        number
+        number,
+        wrapped: is_wrapped(&item_data)
     }
 }
 
 pub fn other(item_code: u32, item_data: Vec<u8>, config: Config) -> Item {
     let name = get_item_name(item_code, &config);
-    let number = if item_data.len() == 28 {
-        item_data[5]
-    } else {
-        item_data[20]
-    };
+    let number = get_stack_size(item_code, &item_data, &config);
 
     Item::Other {
         name: name.clone(),
         type_: 9,
         item_data: Util::binary_array_to_hex(&item_data),
-        number
+        number,
+        wrapped: is_wrapped(&item_data)
     }
 }
 
 pub fn meseta(amount: u32, config: Config) -> Item {
-    let lang = config.lang.clone().unwrap();
-    let name = if lang == "EN" { "MESETA" } else { "メセタ" };
-    
     Item::Meseta {
-        name: String::from(name),
+        name: config.meseta_name.clone().unwrap_or_else(|| String::from("MESETA")),
         type_: 10,
-        amount
+        amount,
+        wrapped: false
+    }
+}
+
+fn get_stack_size(item_code: u32, item_data: &[u8], config: &Config) -> u8 {
+    let number = if item_data.len() == 28 {
+        item_data[5]
+    } else {
+        item_data[20]
+    };
+
+    match server_item(item_code, config) {
+        Some((_, _, _, max_stack)) if max_stack <= 1 => 1,
+        Some((_, _, _, max_stack)) => number.min(max_stack),
+        None => number,
     }
 }
 
 fn get_item_name(item_code: u32, config: &Config) -> String {
-    if let Some(name) = config.item_codes.clone().expect("REASON").get(&item_code) {
-        String::from(*name)
+    if let Some((name, name_ja, _, _)) = server_item(item_code, config) {
+        let name = if config.lang.as_deref() == Some("JA") { name_ja } else { name };
+        String::from(name)
+    } else if let Some(name) = config.item_codes.as_ref().and_then(|codes| codes.get(&item_code)) {
+        name.clone()
     } else {
         format!("undefined. ({})", Util::int_to_hex(item_code))
     }
 }
 
 fn get_special(item_data: &[u8], config: &Config) -> String {
-    let code = item_data[4];
+    let code = item_data[4] & !WRAPPED_FLAG;
 
-    if let Some(special) = config.weapon_special_codes.clone().expect("REASON").get(&code) {
-        String::from(*special)
+    if let Some(special) = config.weapon_special_codes.as_ref().and_then(|specials| specials.get(&code)) {
+        special.clone()
     } else {
         String::from("None")
     }
 }
 
 fn get_rare_special(item_code: u32, config: &Config) -> String {
-    if let Some(special) = config.rare_weapon_special_codes.clone().expect("REASON").get(&item_code) {
-        String::from(*special)
+    if let Some(special) = config.rare_weapon_special_codes.as_ref().and_then(|specials| specials.get(&item_code)) {
+        special.clone()
     } else {
         String::from("None")
     }
@@ -335,8 +462,8 @@ fn get_rare_special(item_code: u32, config: &Config) -> String {
 fn get_srank_special(item_data: &[u8], config: Config) -> String {
     let special_code = item_data[2];
 
-    if let Some(special) = config.srank_special_codes.clone().expect("REASON").get(&special_code) {
-        String::from(*special)
+    if let Some(special) = config.srank_special_codes.as_ref().and_then(|specials| specials.get(&special_code)) {
+        special.clone()
 
     } else {
         String::from("None")
@@ -379,24 +506,24 @@ fn get_attribute(attribute_type: u8, item_data: &[u8]) -> i8 {
     0
 }
 
-fn get_addition(name: &str, additions: &Option<HashMap<&str, [i32; 2]>>, type_: u8) -> i32 {
-    if let Some(addition) = additions.clone().expect("REASON").get(name) {
-        *addition.get(type_ as usize).unwrap_or(&0)
-    } else {
-        0
-    }
+/// Reference data for a frame or barrier, independent of the display language.
+pub fn armor_reference(item_code: u32) -> Option<ArmorReference> {
+    armor_data().get(&item_code).map(|&(base, max_addition, max_slots, level_requirement)| ArmorReference {
+        base: base.map(|[dfp, evp]| ArmorBase { dfp, evp }),
+        max_addition: Addition { dfp: max_addition[0], evp: max_addition[1] },
+        max_slots,
+        level_requirement,
+    })
 }
 
-fn is_tekked(item_data: &[u8], _item_code: u32) -> bool {
-    item_data[4] < 0x80
+fn armor_max_addition(item_code: u32) -> Addition {
+    armor_reference(item_code)
+        .map(|reference| reference.max_addition)
+        .unwrap_or(Addition { dfp: 0, evp: 0 })
 }
 
-fn get_pbs(pbs_code: &str, config: Config) -> [String; 3] {
-    if let Some(pbs) = config.pbs.clone().expect("REASON").get(&pbs_code) {
-        [pbs[0].to_string(), pbs[1].to_string(), pbs[2].to_string()]
-    } else {
-        ["undefined".to_string(), "undefined".to_string(), "undefined".to_string()]
-    }
+fn is_tekked(item_data: &[u8], _item_code: u32) -> bool {
+    item_data[4] < 0x80
 }
 
 fn number_label(number: u8) -> String {
@@ -417,36 +544,35 @@ fn grind_label(number: u8) -> String {
     }
 }
 
-fn get_custom_name(custom_name_data: &[u8]) -> String {
+fn get_custom_name(custom_name_data: &[u8]) -> Result<String, ParseError> {
     let mut temp = vec![];
     let mut data = custom_name_data.to_vec();
 
-    data[0] -= 0x04;
-    temp.extend(three_letters(&data[0..2]));
-    temp.extend(three_letters(&data[2..4]));
-    temp.extend(three_letters(&data[4..6]));
+    data[0] = data[0].checked_sub(0x04).ok_or(ParseError::BadCustomName { offset: 0 })?;
+    temp.extend(three_letters(&data[0..2]).map_err(|error| error.at(0))?);
+    temp.extend(three_letters(&data[2..4]).map_err(|error| error.at(2))?);
+    temp.extend(three_letters(&data[4..6]).map_err(|error| error.at(4))?);
 
     let custom_name: String = temp.into_iter()
         .filter(|&x| x != 0)
         .map(|x| (x + 64) as u8 as char)
         .collect();
 
-    custom_name
+    Ok(custom_name)
 }
 
-fn three_letters(array: &[u8]) -> Vec<u8> {
+fn three_letters(array: &[u8]) -> Result<Vec<u8>, ParseError> {
     let mut array = array.to_vec();
-    array[0] -= 0x80;
+    array[0] = array[0].checked_sub(0x80).ok_or(ParseError::BadCustomName { offset: 0 })?;
     let first = array[0] / 0x04;
     let second = (((array[0] % 0x04) as u16) << 8 | array[1] as u16) / 0x20;
     let third = array[1] % 0x20;
 
-    vec![first, second as u8, third]
+    Ok(vec![first, second as u8, third])
 }
 
-pub fn set_items(items_data: &[u8], slot: Slot, length: usize, meseta_data: &[u8], config: Config) -> Inventory {
+pub fn set_items(items_data: &[u8], offset: usize, slot: Slot, length: usize, meseta_data: &[u8], config: Config) -> Result<Inventory, ParseError> {
     let mut inventory = Vec::new();
-    let lang = config.lang.clone().unwrap();
 
     for i in (0..items_data.len()).step_by(length) {
         let item_data = &items_data[i..i + length];
@@ -457,47 +583,133 @@ pub fn set_items(items_data: &[u8], slot: Slot, length: usize, meseta_data: &[u8
 
         let item_code = Util::binary_array_to_int(&item_data[0..3]);
         let item_hex_code = Util::binary_array_to_hex(&item_data[0..3]);
-        let item = new_item(item_data.to_vec(), item_code, config.clone());
+        let item = new_item(item_data.to_vec(), item_code, config.clone()).map_err(|error| error.at(offset + i))?;
 
         inventory.push(item);
     }
 
-    let meseta_amount = ((meseta_data[2] as u32) << 16) | ((meseta_data[1] as u32) << 8) | meseta_data[0] as u32;
+    let meseta_amount = u32::from_le_bytes([meseta_data[0], meseta_data[1], meseta_data[2], meseta_data[3]]);
     let meseta_hex_code = format!("0x{:06x}", meseta_amount);
     let item = set_meseta(meseta_amount, config.clone());
 
     inventory.push(WrappedItem { item });
-    inventory
+    Ok(inventory)
 }
 
 pub fn set_meseta(amount: u32, config: Config) -> Option<Item> {
     Some(meseta(amount, config))
 }
 
-fn is_blank(item_data: &[u8]) -> bool {
+pub fn is_blank(item_data: &[u8]) -> bool {
     item_data.iter().take(20).all(|&b| b == 0)
         || Util::binary_array_to_hex(item_data) == "000000000000000000000000FFFFFFFF0000000000000000"
         || Util::binary_array_to_hex(item_data).contains("00FF00000000000000000000FFFFFFFF")
 }
 
-fn create_item(item_data: Vec<u8>, item_code: u32, item_type: u32, config: Config) -> Option<Item> {
-    match item_type {
+fn create_item(item_data: Vec<u8>, item_code: u32, item_type: u32, config: Config) -> Result<Option<Item>, ParseError> {
+    let item = match item_type {
         1 => Some(weapon(item_code, item_data, config)),
         2 => Some(frame(item_code, item_data, config)),
         3 => Some(barrier(item_code, item_data, config)),
         4 => Some(unit(item_code, item_data, config)),
-        5 => Some(mag(item_code, item_data, config)),
+        5 => Some(mag(item_code, item_data, config)?),
         6 => Some(tech(item_code, item_data, config)),
         7 => Some(tool(item_code, item_data, config)),
-        8 => Some(s_rank_weapon(item_code, item_data, config)),
+        8 => Some(s_rank_weapon(item_code, item_data, config)?),
         9 => Some(other(item_code, item_data, config)),
         _ => None,
+    };
+
+    Ok(item)
+}
+
+// Mates, fluids, atomizers, antidotes and telepipes, grinders, materials and the photon items
+// carry a stack count. Tech disks (0x02) and everything else are single items.
+fn is_stackable_tool(item_data: &[u8]) -> bool {
+    item_data[0] == 0x03 && match item_data[1] {
+        0x02 => false,
+        0x00..=0x08 | 0x0A | 0x0B | 0x10 => true,
+        _ => false,
+    }
+}
+
+/// Presents keep the inner item's data with a wrapped bit set: in byte 4 for weapons and
+/// armor, in the mag flags byte, and in byte 3 for tech disks and tools that don't stack.
+/// Stacks can't be wrapped.
+pub fn is_wrapped(item_data: &[u8]) -> bool {
+    match item_data[0] {
+        0x00 | 0x01 => item_data[4] & WRAPPED_FLAG != 0,
+        0x02 => item_data[18] & WRAPPED_FLAG != 0,
+        0x03 if !is_stackable_tool(item_data) => item_data[3] & WRAPPED_FLAG != 0,
+        _ => false,
     }
 }
 
-pub fn new_item(item_data: Vec<u8>, item_code: u32, config: Config) -> WrappedItem {
-    let item_type = get_item_type(item_code);
-    let item = create_item(item_data, item_code, item_type, config);
+pub fn new_item(item_data: Vec<u8>, item_code: u32, config: Config) -> Result<WrappedItem, ParseError> {
+    ensure_len(&item_data, 24)?;
+
+    let item_type = get_item_type(item_code, &config);
+    let item = create_item(item_data, item_code, item_type, config)?;
     
-    WrappedItem { item }
-}
\ No newline at end of file
+    Ok(WrappedItem { item })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::server_profile::ServerProfile;
+    use crate::parser::fixtures;
+
+    fn mag_data(color: u8) -> Vec<u8> {
+        let mut item_data = vec![0; 24];
+        item_data[0..3].copy_from_slice(&fixtures::MAG);
+        item_data[19] = color;
+        item_data
+    }
+
+    #[test]
+    fn unknown_mag_color_is_an_error() {
+        let result = mag(0x020005, mag_data(0xFE), fixtures::config());
+        assert!(matches!(result, Err(ParseError::UnknownMagColor { offset: 19, color: 0xFE })));
+    }
+
+    #[test]
+    fn missing_config_tables_are_errors() {
+        let mut config = fixtures::config();
+        config.mag_color_codes = None;
+        assert!(matches!(mag(0x020005, mag_data(0x01), config), Err(ParseError::MissingConfig { .. })));
+
+        let mut config = fixtures::config();
+        config.lang = None;
+        let file = fixtures::character_file();
+        assert!(matches!(crate::parser::character::create(&file, 1, config), Err(ParseError::MissingConfig { .. })));
+    }
+
+    #[test]
+    fn only_unstackable_tools_and_tech_disks_can_be_wrapped() {
+        let mut monomates = vec![0; 24];
+        monomates[0..4].copy_from_slice(&[0x03, 0x00, 0x00, WRAPPED_FLAG]);
+        monomates[5] = 10;
+        assert!(!is_wrapped(&monomates));
+
+        let mut foie = vec![0; 24];
+        foie[0..4].copy_from_slice(&[0x03, 0x02, 0x04, WRAPPED_FLAG]);
+        assert!(is_wrapped(&foie));
+
+        let mut scape_doll = vec![0; 24];
+        scape_doll[0..4].copy_from_slice(&[0x03, 0x09, 0x00, WRAPPED_FLAG]);
+        assert!(is_wrapped(&scape_doll));
+    }
+
+    #[test]
+    fn only_listed_codes_are_server_items() {
+        let mut config = fixtures::config();
+        config.server = Some(ServerProfile::Ephinea);
+        config.server_items = Some(ServerProfile::Ephinea.items());
+
+        assert_eq!(get_item_name(0x031005, &config), "Photon Booster");
+        // Vanilla badges sit between Ephinea's codes and keep their vanilla names.
+        assert_eq!(get_item_name(0x031201, &config), "Weapons Silver Badge");
+        assert!(server_item(0x031201, &config).is_none());
+    }
+}
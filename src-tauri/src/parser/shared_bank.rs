@@ -1,17 +1,34 @@
 use std::collections::HashMap;
 use crate::parser::item;
 use crate::parser::types::{Item, Slot, SharedBank, Inventory};
+use crate::parser::error::{ParseError, ensure_len, missing_config};
 use crate::config::config::Config;
 
+pub const SHARED_BANK_SIZE: usize = 4808;
+pub const BANK_COUNT_OFFSET: usize = 0;
+pub const BANK_MESETA_OFFSET: usize = 4;
+pub const BANK_OFFSET: usize = 8;
+pub const BANK_ITEM_LENGTH: usize = 24;
+pub const BANK_CAPACITY: usize = 200;
+
 fn set_account_type(mode: u8) -> String {
     Config::mode_name(mode)
 }
 
-pub fn create(pso_bank: &Vec<u8>, mode: u8, config: Config) -> SharedBank {
-    SharedBank {
+pub fn create(pso_bank: &Vec<u8>, mode: u8, config: Config) -> Result<SharedBank, ParseError> {
+    ensure_len(pso_bank, SHARED_BANK_SIZE)?;
+
+    Ok(SharedBank {
         account_type: set_account_type(mode),
         mode: mode,
-        bank: item::set_items(&pso_bank[8..4808], Slot::Str(set_account_type(mode)), 24, &pso_bank[4..7], config.clone()),
-        lang: config.lang.unwrap()
-    }
+        bank: item::set_items(
+            &pso_bank[BANK_OFFSET..BANK_OFFSET + BANK_ITEM_LENGTH * BANK_CAPACITY],
+            BANK_OFFSET,
+            Slot::Str(set_account_type(mode)),
+            BANK_ITEM_LENGTH,
+            &pso_bank[BANK_MESETA_OFFSET..BANK_MESETA_OFFSET + 4],
+            config.clone()
+        )?,
+        lang: config.lang.ok_or_else(|| missing_config("a language"))?
+    })
 }
\ No newline at end of file
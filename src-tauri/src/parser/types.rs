@@ -1,6 +1,7 @@
 use std::fmt;
 use serde::{Serialize, Deserialize};
 use std::collections::HashMap;
+use crate::parser::error::ParseError;
 
 pub type Files = Vec<File>;
 
@@ -8,6 +9,18 @@ pub type Files = Vec<File>;
 pub struct File {
     pub filename: String,
     pub binary: Vec<u8>,
+    #[serde(default)]
+    pub kind: Option<FileKind>,
+    /// Zero-based slot, the same number the game puts in `Slot_ N` filenames.
+    #[serde(default)]
+    pub slot: Option<usize>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+pub enum FileKind {
+    Character,
+    SharedBank,
+    ClassicSharedBank,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -23,9 +36,17 @@ pub struct ParsedFile {
     pub data: Data,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct FailedFile {
+    pub filename: String,
+    pub error: ParseError,
+    pub message: String,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ParsedFiles {
-    pub files: Vec<ParsedFile>
+    pub files: Vec<ParsedFile>,
+    pub failed: Vec<FailedFile>
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -47,12 +68,144 @@ pub struct Character {
     pub section_id: String,
     pub level: u8,
     pub experience: u32,
-    pub ep1_progress: String,
-    pub ep2_progress: String,
+    pub appearance: CharacterAppearance,
+    pub stats: CharacterStats,
+    pub materials: MaterialUsage,
+    pub story_progress: StoryProgress,
+    pub quest_log: Vec<QuestStatus>,
+    pub techniques: Vec<LearnedTech>,
+    pub challenge: Option<ChallengeRecords>,
     pub inventory: Inventory,
+    /// Indices into `inventory` of the items the character has equipped.
+    pub equipped: Vec<usize>,
     pub bank: Inventory,
 }
 
+/// Appearance choices from character creation. Hair color is RGB, proportions are the
+/// height and body width sliders from 0.0 to 1.0.
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct CharacterAppearance {
+    pub name_color: u32,
+    pub costume: u16,
+    pub skin: u16,
+    pub face: u16,
+    pub head: u16,
+    pub hair: u16,
+    pub hair_color: [u8; 3],
+    pub proportion_x: f32,
+    pub proportion_y: f32,
+}
+
+/// The stats block of the character file. `hp` is the HP stat as the file stores it, without
+/// HP materials (see `MaterialUsage::hp`) or gear, so it isn't the max HP the game shows. TP
+/// isn't stored at all and so isn't part of this.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct CharacterStats {
+    pub atp: u16,
+    pub mst: u16,
+    pub evp: u16,
+    pub hp: u16,
+    pub dfp: u16,
+    pub ata: u16,
+    pub lck: u16,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct MaterialUsage {
+    pub power: u8,
+    pub mind: u8,
+    pub evade: u8,
+    pub def: u8,
+    pub luck: u8,
+    pub hp: u8,
+    pub tp: u8,
+}
+
+/// Materials a character can still use. Power, Mind, Evade, Def and Luck share one limit.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct MaterialHeadroom {
+    pub stat: u16,
+    pub hp: u8,
+    pub tp: u8,
+}
+
+/// Stages cleared per difficulty, out of `stages`. Left as numbers so the UI can name them.
+/// `stages` only counts bosses with known flags (see `Config::story_flags`), so an episode
+/// with none reports zero stages rather than zero cleared.
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct EpisodeProgress {
+    pub stages: u8,
+    pub normal: u8,
+    pub hard: u8,
+    pub very_hard: u8,
+    pub ultimate: u8,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct StoryProgress {
+    pub ep1: EpisodeProgress,
+    pub ep2: EpisodeProgress,
+    pub ep4: EpisodeProgress,
+}
+
+/// Whether a quest from the bundled quest table has been cleared on each difficulty.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct QuestStatus {
+    pub flag: u16,
+    pub episode: u8,
+    pub name: String,
+    pub completed: [bool; 4],
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct LearnedTech {
+    pub tech: u8,
+    pub name: String,
+    pub level: u8,
+}
+
+/// Best clear time for a challenge stage, in seconds. `None` when the stage was never cleared.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct StageRecord {
+    pub stage: u8,
+    pub time: Option<u32>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct BattleRecords {
+    pub first: u16,
+    pub second: u16,
+    pub third: u16,
+    pub fourth: u16,
+    pub disconnects: u16,
+}
+
+/// The rank awarded in challenge mode and the XRGB1555 color the game draws it in.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ChallengeTitle {
+    pub rank: Option<String>,
+    pub title_color: u16,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ChallengeRecords {
+    pub title_color: u16,
+    pub rank: Option<String>,
+    pub ep1: Vec<StageRecord>,
+    pub ep2: Vec<StageRecord>,
+    pub battle: BattleRecords,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ExperienceProgress {
+    pub level: u8,
+    pub experience: u32,
+    pub level_experience: u32,
+    pub next_level_experience: Option<u32>,
+    pub to_next_level: u32,
+    pub percent: f32,
+}
+
 #[derive(Debug, Clone)]
 pub enum Slot {
     Str(String),
@@ -86,7 +239,9 @@ pub enum Item {
         grind: u8,
         attribute: Attribute,
         tekked: bool,
-        rare: bool
+        rare: bool,
+        kill_counter: Option<KillCounter>,
+        wrapped: bool
     },
     Frame {
         name: String,
@@ -94,19 +249,24 @@ pub enum Item {
         item_data: String,
         slot: u8,
         addition: Addition,
-        max_addition: Addition
+        max_addition: Addition,
+        wrapped: bool
     },
     Barrier {
         name: String,
         type_: u8,
         item_data: String,
         addition: Addition,
-        max_addition: Addition
+        max_addition: Addition,
+        wrapped: bool
     },
     Unit {
         name: String,
         type_: u8,
-        item_data: String
+        item_data: String,
+        modifier: i8,
+        kill_counter: Option<KillCounter>,
+        wrapped: bool
     },
     Mag {
         name: String,
@@ -118,13 +278,17 @@ pub enum Item {
         color: String,
         rgb: String,
         stats: MagStats,
-        pbs: [String; 3]
+        progress: MagStats,
+        photon_blasts: PhotonBlasts,
+        evolution: MagEvolution,
+        wrapped: bool
     },
     Tech {
         name: String,
         level: u8,
         type_: u8,
-        item_data: String
+        item_data: String,
+        wrapped: bool
     },
     SRankWeapon {
         name: String,
@@ -132,27 +296,48 @@ pub enum Item {
         item_data: String,
         grind: u8,
         special: String,
-        special_code: String
+        special_code: String,
+        wrapped: bool
     },
     Tool {
         name: String,
         type_: u8,
         item_data: String,
-        number: u8
+        number: u8,
+        wrapped: bool
     },
     Other {
         name: String,
         type_: u8,
         item_data: String,
-        number: u8
+        number: u8,
+        wrapped: bool
     },
     Meseta {
         name: String,
         type_: u8,
-        amount: u32
+        amount: u32,
+        wrapped: bool
     },
 }
 
+impl Item {
+    pub fn item_data(&self) -> Option<&String> {
+        match self {
+              Item::Weapon { item_data, .. }
+            | Item::Frame { item_data, .. }
+            | Item::Barrier { item_data, .. }
+            | Item::Unit { item_data, .. }
+            | Item::Mag { item_data, .. }
+            | Item::Tech { item_data, .. }
+            | Item::SRankWeapon { item_data, .. }
+            | Item::Tool { item_data, .. }
+            | Item::Other { item_data, .. } => Some(item_data),
+            Item::Meseta { .. } => None,
+        }
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct Attribute {
     pub native: i8,
@@ -170,12 +355,114 @@ pub struct MagStats {
     pub mind: u16,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+pub enum PhotonBlast {
+    Farlla,
+    Estlla,
+    Golla,
+    Pilla,
+    Leilla,
+    MyllaYoulla,
+}
+
+impl PhotonBlast {
+    pub const ALL: [PhotonBlast; 6] = [
+        PhotonBlast::Farlla,
+        PhotonBlast::Estlla,
+        PhotonBlast::Golla,
+        PhotonBlast::Pilla,
+        PhotonBlast::Leilla,
+        PhotonBlast::MyllaYoulla,
+    ];
+
+    pub fn from_id(id: u8) -> Option<Self> {
+        Self::ALL.get(id as usize).copied()
+    }
+
+    pub fn id(&self) -> u8 {
+        *self as u8
+    }
+}
+
+/// Photon blasts in the order the mag learned them. A slot is `None` until it is filled.
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct PhotonBlasts {
+    pub center: Option<PhotonBlast>,
+    pub right: Option<PhotonBlast>,
+    pub left: Option<PhotonBlast>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+pub enum MagClass {
+    Hunter,
+    Ranger,
+    Force,
+}
+
+impl MagClass {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MagClass::Hunter => "HUNTER",
+            MagClass::Ranger => "RANGER",
+            MagClass::Force => "FORCE",
+        }
+    }
+}
+
+/// Stage 0 is the starting Mag, 1 and 2 the level 10 and 35 evolutions, 3 the level 50+
+/// forms and 4 the rare forms. `affinity` is the class line the mag evolved for, if known.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct MagEvolution {
+    pub stage: u8,
+    pub affinity: Option<MagClass>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct KillCounter {
+    pub kills: u16,
+    pub kills_to_unseal: u16,
+    pub kills_remaining: u16,
+}
+
+/// What a unit does once its +/- modifier is applied.
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct UnitEffect {
+    pub atp: i16,
+    pub mst: i16,
+    pub ata: i16,
+    pub evp: i16,
+    pub dfp: i16,
+    pub lck: i16,
+    pub hp: i16,
+    pub tp: i16,
+    pub efr: i16,
+    pub eic: i16,
+    pub eth: i16,
+    pub elt: i16,
+    pub edk: i16,
+    pub description: String,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct Addition {
     pub dfp: i32,
     pub evp: i32,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ArmorBase {
+    pub dfp: i16,
+    pub evp: i16,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ArmorReference {
+    pub base: Option<ArmorBase>,
+    pub max_addition: Addition,
+    pub max_slots: u8,
+    pub level_requirement: Option<u8>,
+}
+
 #[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
 pub enum AdditionType {
     DFP = 0,
@@ -0,0 +1,270 @@
+use crate::parser::item::is_blank;
+use crate::parser::error::{ParseError, ensure_len};
+use crate::parser::types::{Item, Inventory, Character, SharedBank};
+use crate::parser::{character, shared_bank};
+
+const ITEM_DATA_LENGTH: usize = 20;
+const INVENTORY_HEADER_LENGTH: usize = 8;
+
+struct Storage {
+    offset: usize,
+    length: usize,
+    capacity: usize,
+    count_offset: usize,
+    count_length: usize,
+    meseta_offset: usize,
+    has_slot_headers: bool,
+}
+
+const CHARACTER_INVENTORY: Storage = Storage {
+    offset: character::INVENTORY_OFFSET,
+    length: character::INVENTORY_ITEM_LENGTH,
+    capacity: character::INVENTORY_CAPACITY,
+    count_offset: character::INVENTORY_COUNT_OFFSET,
+    count_length: 1,
+    meseta_offset: character::MESETA_OFFSET,
+    has_slot_headers: true,
+};
+
+const CHARACTER_BANK: Storage = Storage {
+    offset: character::BANK_OFFSET,
+    length: character::BANK_ITEM_LENGTH,
+    capacity: character::BANK_CAPACITY,
+    count_offset: character::BANK_COUNT_OFFSET,
+    count_length: 4,
+    meseta_offset: character::BANK_MESETA_OFFSET,
+    has_slot_headers: false,
+};
+
+const SHARED_BANK: Storage = Storage {
+    offset: shared_bank::BANK_OFFSET,
+    length: shared_bank::BANK_ITEM_LENGTH,
+    capacity: shared_bank::BANK_CAPACITY,
+    count_offset: shared_bank::BANK_COUNT_OFFSET,
+    count_length: 4,
+    meseta_offset: shared_bank::BANK_MESETA_OFFSET,
+    has_slot_headers: false,
+};
+
+fn hex_to_bytes(hex: &str, offset: usize) -> Result<Vec<u8>, ParseError> {
+    if hex.len() % 2 != 0 {
+        return Err(ParseError::BadItemData { offset });
+    }
+
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| {
+            hex.get(i..i + 2)
+                .and_then(|byte| u8::from_str_radix(byte, 16).ok())
+                .ok_or(ParseError::BadItemData { offset })
+        })
+        .collect()
+}
+
+fn inventory_blobs(inventory: &Inventory, storage: &Storage) -> Result<(Vec<Vec<u8>>, Option<u32>), ParseError> {
+    let mut blobs = Vec::new();
+    let mut meseta = None;
+
+    for wrapped_item in inventory {
+        match &wrapped_item.item {
+            Some(Item::Meseta { amount, .. }) => meseta = Some(*amount),
+            Some(item) => {
+                let offset = storage.offset + storage.length * blobs.len();
+                let item_data = item.item_data().map(String::as_str).unwrap_or("");
+                blobs.push(hex_to_bytes(item_data, offset)?);
+            },
+            None => {}
+        }
+    }
+
+    Ok((blobs, meseta))
+}
+
+/// Empty bank slots keep an item id of 0xFFFFFFFF, empty inventory slots are zeroed.
+fn empty_slot(storage: &Storage, length: usize) -> Vec<u8> {
+    let mut slot = vec![0; length];
+
+    if !storage.has_slot_headers {
+        slot[12..16].copy_from_slice(&[0xFF; 4]);
+    }
+
+    slot
+}
+
+fn write_meseta(file: &mut [u8], offset: usize, amount: u32) {
+    file[offset..offset + 4].copy_from_slice(&amount.to_le_bytes());
+}
+
+fn write_count(file: &mut [u8], storage: &Storage, count: usize) {
+    let bytes = (count as u32).to_le_bytes();
+    file[storage.count_offset..storage.count_offset + storage.count_length]
+        .copy_from_slice(&bytes[..storage.count_length]);
+}
+
+/// Writes items back into a storage block. When the number of items matches the occupied
+/// slots they are written back in place, so unchanged input comes out byte for byte.
+/// Otherwise the items are packed from the first slot, and moved inventory items lose
+/// their equipped flags.
+fn write_items(file: &mut [u8], storage: &Storage, blobs: &[Vec<u8>]) -> Result<(), ParseError> {
+    if blobs.len() > storage.capacity {
+        return Err(ParseError::TooManyItems { count: blobs.len(), capacity: storage.capacity });
+    }
+
+    let slots: Vec<usize> = (0..storage.capacity)
+        .map(|i| storage.offset + storage.length * i)
+        .collect();
+    let occupied: Vec<usize> = slots
+        .iter()
+        .copied()
+        .filter(|&slot| !is_blank(&file[slot..slot + storage.length]))
+        .collect();
+
+    let data_length = if storage.has_slot_headers { ITEM_DATA_LENGTH } else { storage.length };
+
+    if occupied.len() == blobs.len() {
+        for (&slot, blob) in occupied.iter().zip(blobs) {
+            write_blob(file, slot, data_length, blob);
+        }
+        return Ok(());
+    }
+
+    for (i, &slot) in slots.iter().enumerate() {
+        file[slot..slot + data_length].copy_from_slice(&empty_slot(storage, data_length));
+
+        if let Some(blob) = blobs.get(i) {
+            write_blob(file, slot, data_length, blob);
+        }
+
+        if storage.has_slot_headers {
+            let header = slot - INVENTORY_HEADER_LENGTH;
+            let present: u8 = if i < blobs.len() { 0x01 } else { 0x00 };
+            file[header..header + 2].copy_from_slice(&[present, 0x00]);
+            file[header + 4..header + 8].copy_from_slice(&[0x00; 4]);
+        }
+    }
+
+    write_count(file, storage, blobs.len());
+
+    Ok(())
+}
+
+/// Inventory blobs run 8 bytes into the next slot's header, so only their item data is written.
+fn write_blob(file: &mut [u8], slot: usize, length: usize, blob: &[u8]) {
+    let length = if blob.len() == length { length } else { blob.len().min(ITEM_DATA_LENGTH) };
+    file[slot..slot + length].copy_from_slice(&blob[..length]);
+}
+
+fn write_storage(file: &mut [u8], storage: &Storage, inventory: &Inventory) -> Result<(), ParseError> {
+    let (blobs, meseta) = inventory_blobs(inventory, storage)?;
+
+    write_items(file, storage, &blobs)?;
+
+    if let Some(amount) = meseta {
+        write_meseta(file, storage.meseta_offset, amount);
+    }
+
+    Ok(())
+}
+
+/// Encodes a character's inventory, bank and meseta on top of the original .psochar bytes.
+pub fn encode_character(character: &Character, template: &[u8]) -> Result<Vec<u8>, ParseError> {
+    ensure_len(template, character::CHARACTER_MIN_SIZE)?;
+
+    let mut file = template.to_vec();
+
+    write_storage(&mut file, &CHARACTER_INVENTORY, &character.inventory)?;
+    write_storage(&mut file, &CHARACTER_BANK, &character.bank)?;
+
+    Ok(file)
+}
+
+/// Encodes a shared bank, on top of the original .psobank bytes when given, otherwise into
+/// an empty bank file.
+pub fn encode_shared_bank(shared_bank: &SharedBank, template: Option<&[u8]>) -> Result<Vec<u8>, ParseError> {
+    let mut file = match template {
+        Some(template) => {
+            ensure_len(template, shared_bank::SHARED_BANK_SIZE)?;
+            template.to_vec()
+        },
+        None => empty_shared_bank(),
+    };
+
+    write_storage(&mut file, &SHARED_BANK, &shared_bank.bank)?;
+
+    Ok(file)
+}
+
+/// Builds a shared bank file straight from `item_data` hex blobs.
+pub fn encode_shared_bank_items(items: &[String], meseta: u32) -> Result<Vec<u8>, ParseError> {
+    let mut file = empty_shared_bank();
+    let blobs = items
+        .iter()
+        .enumerate()
+        .map(|(i, item_data)| hex_to_bytes(item_data, SHARED_BANK.offset + SHARED_BANK.length * i))
+        .collect::<Result<Vec<Vec<u8>>, ParseError>>()?;
+
+    write_items(&mut file, &SHARED_BANK, &blobs)?;
+    write_meseta(&mut file, SHARED_BANK.meseta_offset, meseta);
+
+    Ok(file)
+}
+
+fn empty_shared_bank() -> Vec<u8> {
+    let mut file = vec![0; shared_bank::SHARED_BANK_SIZE];
+
+    for i in 0..SHARED_BANK.capacity {
+        let slot = SHARED_BANK.offset + SHARED_BANK.length * i;
+        file[slot..slot + SHARED_BANK.length].copy_from_slice(&empty_slot(&SHARED_BANK, SHARED_BANK.length));
+    }
+
+    file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::fixtures;
+    use crate::parser::types::WrappedItem;
+
+    fn meseta(inventory: &Inventory) -> Option<u32> {
+        inventory.iter().find_map(|wrapped| match &wrapped.item {
+            Some(Item::Meseta { amount, .. }) => Some(*amount),
+            _ => None,
+        })
+    }
+
+    #[test]
+    fn unchanged_character_round_trips() {
+        let file = fixtures::character_file();
+        let character = character::create(&file, 1, fixtures::config()).unwrap();
+
+        assert_eq!(encode_character(&character, &file).unwrap(), file);
+    }
+
+    #[test]
+    fn bank_count_and_meseta_stay_separate() {
+        let file = fixtures::character_file();
+        let mut character = character::create(&file, 1, fixtures::config()).unwrap();
+        let frame = character.bank.remove(0);
+        character.bank.insert(0, WrappedItem { item: frame.item.clone() });
+        character.bank.insert(0, frame);
+
+        let encoded = encode_character(&character, &file).unwrap();
+        let count = &encoded[character::BANK_COUNT_OFFSET..character::BANK_COUNT_OFFSET + 4];
+        assert_eq!(count, &2u32.to_le_bytes());
+
+        let reparsed = character::create(&encoded, 1, fixtures::config()).unwrap();
+        assert_eq!(meseta(&reparsed.bank), Some(12345));
+        assert_eq!(meseta(&reparsed.inventory), Some(10000));
+    }
+
+    #[test]
+    fn shared_bank_items_round_trip() {
+        let items = vec![String::from("000100050000000000000000FFFFFFFF00000000")];
+        let file = encode_shared_bank_items(&items, 300).unwrap();
+        let bank = shared_bank::create(&file, 0, fixtures::config()).unwrap();
+
+        assert_eq!(meseta(&bank.bank), Some(300));
+        assert_eq!(encode_shared_bank(&bank, Some(&file)).unwrap(), file);
+    }
+}
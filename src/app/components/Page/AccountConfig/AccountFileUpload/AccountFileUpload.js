@@ -34,7 +34,8 @@ export function AccountFileUpload({ theme, onComplete }) {
         try {
             const response = await invoke("parse_files", {
                 files: files,
-                lang: dashboardState.lang
+                lang: dashboardState.lang,
+                server: files[0]?.filename.split(".")[0].split("_")[0] ?? ""
             });
             return response;
         } catch (error) {
@@ -225,20 +226,21 @@ export function AccountFileUpload({ theme, onComplete }) {
                         const { name, size, type } = file;
                         const renderFileCountError = index === 0 && fileCountOverLimit > 0;
                         const fileRejection = fileRejections.find(fileRejection => fileRejection.file === file);
+                        const parseFailure = parsedFiles?.failed?.find(failedFile => failedFile.filename === name);
                         const { reason } = fileRejection || {};
 
                         return (
                             <React.Fragment key={`${file.name}-${index}`}>
                                 {renderFileCountError && <Alert intent="danger" marginBottom={majorScale(2)} title={fileCountError} />}
                                 {
-                                    fileRejection != null ?
+                                    fileRejection != null || parseFailure != null ?
                                     <AccountFileCardError 
-                                        isInvalid={fileRejection != null}
+                                        isInvalid={true}
                                         name={name}
                                         onRemove={() => handleRemove(file)}
                                         sizeInBytes={size}
                                         type={type}
-                                        validationMessage={reason}
+                                        validationMessage={reason || parseFailure.message}
                                     />
                                     :
                                     <AccountFileCard
@@ -69,7 +69,10 @@ export function LanguageSelector({ context }) {
                         onChange={handleChange}
                         options={[
                             { value: 'EN', label: 'EN' },
-                            { value: 'JA', label: 'JA' }
+                            { value: 'JA', label: 'JA' },
+                            { value: 'DE', label: 'DE' },
+                            { value: 'FR', label: 'FR' },
+                            { value: 'ES', label: 'ES' }
                         ]}
                     />
                 </Space>
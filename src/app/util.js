@@ -628,6 +628,18 @@ export function displayItem(item, prop, theme) {
             return ": " + str;
         case "level":
             return " LV" + str + " disk";
+        case "wrapped":
+            if (str === true) {
+                return "[W] ";
+            }
+            return "";
+        case "modifier":
+            if (str > 0) {
+                return "+".repeat(str);
+            } else if (str < 0) {
+                return "-".repeat(-str);
+            }
+            return "";
         default:
             return str;
     }
@@ -637,7 +649,7 @@ function displaySrankWeapon(item, theme) {
     return (
     <>
         <StyledText theme={theme} fontWeight={600}>{displayItem(item, "tekked")} </StyledText>
-        <StyledText theme={theme} fontWeight={600} color="#eb0000 !important">{displayItem(item, "name")}</StyledText>
+        <StyledText theme={theme} fontWeight={600} color="#eb0000 !important">{displayItem(item, "wrapped")}{displayItem(item, "name")}</StyledText>
         <StyledText theme={theme} fontWeight={600} color="#25e17b !important">{displayItem(item, "grind")} </StyledText>
         <StyledText theme={theme} fontWeight={600} marginRight={1}> [</StyledText>
         <StyledText theme={theme} fontWeight={600} color={sRankWeaponColor(item.SRankWeapon.special_code)}>{displayItem(item, "special")}</StyledText>
@@ -669,7 +681,7 @@ function displayWeapon(item, theme) {
     return (
     <>
         <StyledText theme={theme} fontWeight={600}>{displayItem(item, "tekked")} </StyledText>
-        <StyledText theme={theme} fontWeight={600} color={nameColor(item)}>{displayItem(item, "name")}</StyledText>
+        <StyledText theme={theme} fontWeight={600} color={nameColor(item)}>{displayItem(item, "wrapped")}{displayItem(item, "name")}</StyledText>
         <StyledText theme={theme} fontWeight={600} color="#25e17b !important">{displayItem(item, "grind")} </StyledText>
         <StyledText theme={theme} fontWeight={600} marginRight={1}> [</StyledText>
         <StyledText theme={theme} fontWeight={600} color={specialColor(item)}>{displayItem(item, "special")}</StyledText>
@@ -685,14 +697,30 @@ function displayWeapon(item, theme) {
         <StyledText theme={theme} fontWeight={600}> | </StyledText>
         <StyledText theme={theme} fontWeight={600} color="#ff7875 !important">{displayItem(item, "hit")}</StyledText>
         <StyledText theme={theme} fontWeight={600}>] </StyledText>
+        {displayWeaponReference(item, theme)}
     </>
     );
 }
 
+function displayWeaponReference(item, theme) {
+    const reference = item.Weapon.reference;
+
+    if (!reference) {
+        return null;
+    }
+
+    return (
+        <StyledText theme={theme} title={reference.classes.join(", ")}>
+            ATP {reference.min_atp}-{reference.max_atp} | +{reference.max_grind} max
+            | Req {reference.requirement_atp} ATP / {reference.requirement_ata} ATA / {reference.requirement_mst} MST
+        </StyledText>
+    );
+}
+
 function displayFrame(item, theme) {
     return (
     <>
-        <StyledText theme={theme} fontWeight={600} color="yellow !important" >{displayItem(item, "name")}</StyledText>
+        <StyledText theme={theme} fontWeight={600} color="yellow !important" >{displayItem(item, "wrapped")}{displayItem(item, "name")}</StyledText>
         <StyledText theme={theme} fontWeight={600}> [</StyledText>
         <StyledText theme={theme} fontWeight={600} color="#25e17b !important">{displayItem(item, "dfp")}</StyledText>
         <StyledText theme={theme}>/</StyledText>
@@ -712,7 +740,7 @@ function displayFrame(item, theme) {
 function displayBarrier(item, theme) {
     return (
     <>
-        <StyledText theme={theme} fontWeight={600} color="yellow !important" >{displayItem(item, "name")}</StyledText>
+        <StyledText theme={theme} fontWeight={600} color="yellow !important" >{displayItem(item, "wrapped")}{displayItem(item, "name")}</StyledText>
         <StyledText theme={theme}> [</StyledText>
         <StyledText theme={theme} fontWeight={600} color="#25e17b !important">{displayItem(item, "dfp")}</StyledText>
         <StyledText theme={theme}>/</StyledText>
@@ -728,14 +756,14 @@ function displayBarrier(item, theme) {
 
 function displayUnit(item, theme) {
     return (
-        <StyledText theme={theme} fontWeight={600} color="yellow !important">{displayItem(item, "name")}</StyledText>
+        <StyledText theme={theme} fontWeight={600} color="yellow !important">{displayItem(item, "wrapped")}{displayItem(item, "name")}{displayItem(item, "modifier")}</StyledText>
     );
 }
 
 function displayMag(item, theme) {
     return (
     <>
-        <StyledText theme={theme} fontWeight={600} color="yellow !important">{displayItem(item, "name")}</StyledText>
+        <StyledText theme={theme} fontWeight={600} color="yellow !important">{displayItem(item, "wrapped")}{displayItem(item, "name")}</StyledText>
         <StyledText theme={theme} fontWeight={600}> [</StyledText>
         <StyledText theme={theme} fontWeight={600} color="#25e17b !important">{displayItem(item, "def")}</StyledText>
         <StyledText theme={theme}>/</StyledText>
@@ -758,7 +786,7 @@ function displayMag(item, theme) {
 function displayTech(item, theme) {
     return (
     <>
-        <StyledText theme={theme}>{displayItem(item, "name")}</StyledText>
+        <StyledText theme={theme}>{displayItem(item, "wrapped")}{displayItem(item, "name")}</StyledText>
         <StyledText theme={theme}>{displayItem(item, "level")}</StyledText>
     </>
     );
@@ -767,7 +795,7 @@ function displayTech(item, theme) {
 function displayTool(item, theme) {
     return (
     <>
-        <StyledText theme={theme}>{displayItem(item, "name")}</StyledText>
+        <StyledText theme={theme}>{displayItem(item, "wrapped")}{displayItem(item, "name")}</StyledText>
         <StyledText theme={theme}>{displayItem(item, "number")}</StyledText>
     </>
     );
@@ -776,7 +804,7 @@ function displayTool(item, theme) {
 function displayOther(item, theme) {
     return (
     <>
-        <StyledText theme={theme}>{displayItem(item, "name")}</StyledText>
+        <StyledText theme={theme}>{displayItem(item, "wrapped")}{displayItem(item, "name")}</StyledText>
         <StyledText theme={theme}>{displayItem(item, "number")}</StyledText>
     </>
     );